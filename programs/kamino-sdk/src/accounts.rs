@@ -0,0 +1,108 @@
+use bytemuck::{Pod, Zeroable};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use std::mem::size_of;
+
+use crate::types::*;
+
+pub const RESERVE_DISCRIMINATOR: [u8; 8] = [43, 242, 204, 202, 26, 247, 59, 127];
+pub const OBLIGATION_DISCRIMINATOR: [u8; 8] = [168, 206, 141, 106, 88, 76, 172, 167];
+
+pub const OBLIGATION_MAX_DEPOSITS: usize = 8;
+pub const OBLIGATION_MAX_BORROWS: usize = 5;
+
+fn read_prefix<T: Pod>(data: &[u8], discriminator: &[u8; 8]) -> Result<T, ProgramError> {
+    if data.len() < size_of::<T>() || &data[..8] != discriminator {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(bytemuck::pod_read_unaligned::<T>(&data[..size_of::<T>()]))
+}
+
+/// Leading bytes of a klend `Reserve` account, up to the end of the borrow limit in its config
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct Reserve {
+    pub discriminator: [u8; 8],
+    pub version: u64,
+    pub last_update: LastUpdate,
+    pub lending_market: Pubkey,
+    pub farm_collateral: Pubkey,
+    pub farm_debt: Pubkey,
+    pub liquidity: ReserveLiquidity,
+    pub reserve_liquidity_padding: [[u64; 6]; 25],
+    pub collateral: ReserveCollateral,
+    pub reserve_collateral_padding: [[u64; 6]; 25],
+    pub config: ReserveConfig,
+}
+
+impl Reserve {
+    pub fn from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        read_prefix(data, &RESERVE_DISCRIMINATOR)
+    }
+
+    /// Amount of liquidity backing one unit of reserve collateral
+    pub fn collateral_exchange_rate(&self) -> f64 {
+        let total_supply = self.liquidity.total_supply();
+        if self.collateral.mint_total_supply == 0 || total_supply <= 0.0 {
+            1.0
+        } else {
+            total_supply / (self.collateral.mint_total_supply as f64)
+        }
+    }
+
+    pub fn collateral_to_liquidity(&self, collateral_amount: u64) -> u64 {
+        ((collateral_amount as f64) * self.collateral_exchange_rate()).floor() as u64
+    }
+
+    pub fn liquidity_to_collateral(&self, liquidity_amount: u64) -> u64 {
+        ((liquidity_amount as f64) / self.collateral_exchange_rate()).floor() as u64
+    }
+
+    pub fn market_price(&self) -> f64 {
+        sf_to_f64(self.liquidity.market_price_sf)
+    }
+}
+
+/// Leading bytes of a klend `Obligation` account, up to the end of its borrows
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct Obligation {
+    pub discriminator: [u8; 8],
+    pub tag: u64,
+    pub last_update: LastUpdate,
+    pub lending_market: Pubkey,
+    pub owner: Pubkey,
+    pub deposits: [ObligationCollateral; OBLIGATION_MAX_DEPOSITS],
+    pub lowest_reserve_deposit_liquidation_ltv: u64,
+    pub deposited_value_sf: [u8; 16],
+    pub borrows: [ObligationLiquidity; OBLIGATION_MAX_BORROWS],
+}
+
+impl Obligation {
+    pub fn from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        read_prefix(data, &OBLIGATION_DISCRIMINATOR)
+    }
+
+    pub fn find_deposit(&self, reserve: &Pubkey) -> Option<&ObligationCollateral> {
+        self.deposits
+            .iter()
+            .find(|deposit| &deposit.deposit_reserve == reserve && deposit.deposited_amount > 0)
+    }
+
+    pub fn find_borrow(&self, reserve: &Pubkey) -> Option<&ObligationLiquidity> {
+        self.borrows.iter().find(|borrow| {
+            &borrow.borrow_reserve == reserve && u128::from_le_bytes(borrow.borrowed_amount_sf) > 0
+        })
+    }
+
+    pub fn active_deposits(&self) -> impl Iterator<Item = &ObligationCollateral> {
+        self.deposits
+            .iter()
+            .filter(|deposit| deposit.deposit_reserve != Pubkey::default())
+    }
+
+    pub fn active_borrows(&self) -> impl Iterator<Item = &ObligationLiquidity> {
+        self.borrows
+            .iter()
+            .filter(|borrow| borrow.borrow_reserve != Pubkey::default())
+    }
+}
@@ -0,0 +1,291 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    system_program, sysvar,
+};
+
+use crate::KAMINO_ID;
+
+pub const INIT_USER_METADATA_DISCRIMINATOR: [u8; 8] = [117, 169, 176, 69, 197, 23, 15, 162];
+pub const INIT_OBLIGATION_DISCRIMINATOR: [u8; 8] = [251, 10, 231, 76, 27, 11, 159, 96];
+pub const REFRESH_RESERVE_DISCRIMINATOR: [u8; 8] = [2, 218, 138, 235, 79, 201, 25, 102];
+pub const REFRESH_OBLIGATION_DISCRIMINATOR: [u8; 8] = [33, 132, 147, 228, 151, 192, 72, 89];
+pub const DEPOSIT_RESERVE_LIQUIDITY_AND_OBLIGATION_COLLATERAL_DISCRIMINATOR: [u8; 8] =
+    [129, 199, 4, 2, 222, 39, 26, 46];
+pub const WITHDRAW_OBLIGATION_COLLATERAL_AND_REDEEM_RESERVE_COLLATERAL_DISCRIMINATOR: [u8; 8] =
+    [75, 93, 93, 220, 34, 150, 218, 196];
+pub const BORROW_OBLIGATION_LIQUIDITY_DISCRIMINATOR: [u8; 8] =
+    [121, 127, 18, 204, 73, 245, 225, 65];
+pub const REPAY_OBLIGATION_LIQUIDITY_DISCRIMINATOR: [u8; 8] = [145, 178, 13, 225, 76, 240, 147, 72];
+pub const FLASH_BORROW_RESERVE_LIQUIDITY_DISCRIMINATOR: [u8; 8] =
+    [135, 231, 52, 167, 7, 52, 212, 193];
+pub const FLASH_REPAY_RESERVE_LIQUIDITY_DISCRIMINATOR: [u8; 8] =
+    [185, 117, 0, 203, 96, 245, 180, 186];
+
+fn instruction_data<T: BorshSerialize>(discriminator: [u8; 8], args: &T) -> Vec<u8> {
+    let mut data = discriminator.to_vec();
+    data.append(&mut args.try_to_vec().unwrap());
+    data
+}
+
+/// Optional accounts are represented by the klend program id
+fn optional_account(account: Option<Pubkey>, is_writable: bool) -> AccountMeta {
+    match account {
+        Some(key) if is_writable => AccountMeta::new(key, false),
+        Some(key) => AccountMeta::new_readonly(key, false),
+        None => AccountMeta::new_readonly(KAMINO_ID, false),
+    }
+}
+
+#[derive(BorshSerialize, Clone, Debug)]
+pub struct InitObligationArgs {
+    pub tag: u8,
+    pub id: u8,
+}
+
+pub fn init_user_metadata(
+    owner: &Pubkey,
+    fee_payer: &Pubkey,
+    user_metadata: &Pubkey,
+    user_lookup_table: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: KAMINO_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*owner, true),
+            AccountMeta::new(*fee_payer, true),
+            AccountMeta::new(*user_metadata, false),
+            optional_account(None, false),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: instruction_data(INIT_USER_METADATA_DISCRIMINATOR, &user_lookup_table),
+    }
+}
+
+pub fn init_obligation(
+    obligation_owner: &Pubkey,
+    fee_payer: &Pubkey,
+    obligation: &Pubkey,
+    lending_market: &Pubkey,
+    owner_user_metadata: &Pubkey,
+    args: InitObligationArgs,
+) -> Instruction {
+    Instruction {
+        program_id: KAMINO_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*obligation_owner, true),
+            AccountMeta::new(*fee_payer, true),
+            AccountMeta::new(*obligation, false),
+            AccountMeta::new_readonly(*lending_market, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(*owner_user_metadata, false),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: instruction_data(INIT_OBLIGATION_DISCRIMINATOR, &args),
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ReserveOracles {
+    pub pyth_oracle: Option<Pubkey>,
+    pub switchboard_price_oracle: Option<Pubkey>,
+    pub switchboard_twap_oracle: Option<Pubkey>,
+    pub scope_prices: Option<Pubkey>,
+}
+
+pub fn refresh_reserve(
+    reserve: &Pubkey,
+    lending_market: &Pubkey,
+    oracles: ReserveOracles,
+) -> Instruction {
+    Instruction {
+        program_id: KAMINO_ID,
+        accounts: vec![
+            AccountMeta::new(*reserve, false),
+            AccountMeta::new_readonly(*lending_market, false),
+            optional_account(oracles.pyth_oracle, false),
+            optional_account(oracles.switchboard_price_oracle, false),
+            optional_account(oracles.switchboard_twap_oracle, false),
+            optional_account(oracles.scope_prices, false),
+        ],
+        data: REFRESH_RESERVE_DISCRIMINATOR.to_vec(),
+    }
+}
+
+/// Every deposit reserve of the obligation, followed by every borrow reserve, must be provided in order
+pub fn refresh_obligation(
+    lending_market: &Pubkey,
+    obligation: &Pubkey,
+    deposit_and_borrow_reserves: &[Pubkey],
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*lending_market, false),
+        AccountMeta::new(*obligation, false),
+    ];
+    accounts.extend(
+        deposit_and_borrow_reserves
+            .iter()
+            .map(|reserve| AccountMeta::new(*reserve, false)),
+    );
+
+    Instruction {
+        program_id: KAMINO_ID,
+        accounts,
+        data: REFRESH_OBLIGATION_DISCRIMINATOR.to_vec(),
+    }
+}
+
+pub struct DepositAccounts {
+    pub owner: Pubkey,
+    pub obligation: Pubkey,
+    pub lending_market: Pubkey,
+    pub lending_market_authority: Pubkey,
+    pub reserve: Pubkey,
+    pub reserve_liquidity_mint: Pubkey,
+    pub reserve_liquidity_supply: Pubkey,
+    pub reserve_collateral_mint: Pubkey,
+    pub reserve_destination_deposit_collateral: Pubkey,
+    pub user_source_liquidity: Pubkey,
+    pub token_program: Pubkey,
+}
+
+pub fn deposit_reserve_liquidity_and_obligation_collateral(
+    accounts: DepositAccounts,
+    liquidity_amount: u64,
+) -> Instruction {
+    Instruction {
+        program_id: KAMINO_ID,
+        accounts: vec![
+            AccountMeta::new(accounts.owner, true),
+            AccountMeta::new(accounts.obligation, false),
+            AccountMeta::new_readonly(accounts.lending_market, false),
+            AccountMeta::new_readonly(accounts.lending_market_authority, false),
+            AccountMeta::new(accounts.reserve, false),
+            AccountMeta::new_readonly(accounts.reserve_liquidity_mint, false),
+            AccountMeta::new(accounts.reserve_liquidity_supply, false),
+            AccountMeta::new(accounts.reserve_collateral_mint, false),
+            AccountMeta::new(accounts.reserve_destination_deposit_collateral, false),
+            AccountMeta::new(accounts.user_source_liquidity, false),
+            optional_account(None, false),
+            AccountMeta::new_readonly(accounts.token_program, false),
+            AccountMeta::new_readonly(accounts.token_program, false),
+            AccountMeta::new_readonly(sysvar::instructions::ID, false),
+        ],
+        data: instruction_data(
+            DEPOSIT_RESERVE_LIQUIDITY_AND_OBLIGATION_COLLATERAL_DISCRIMINATOR,
+            &liquidity_amount,
+        ),
+    }
+}
+
+pub struct WithdrawAccounts {
+    pub owner: Pubkey,
+    pub obligation: Pubkey,
+    pub lending_market: Pubkey,
+    pub lending_market_authority: Pubkey,
+    pub withdraw_reserve: Pubkey,
+    pub reserve_liquidity_mint: Pubkey,
+    pub reserve_source_collateral: Pubkey,
+    pub reserve_collateral_mint: Pubkey,
+    pub reserve_liquidity_supply: Pubkey,
+    pub user_destination_liquidity: Pubkey,
+    pub token_program: Pubkey,
+}
+
+/// `collateral_amount` is denominated in reserve collateral; `u64::MAX` withdraws the whole deposit
+pub fn withdraw_obligation_collateral_and_redeem_reserve_collateral(
+    accounts: WithdrawAccounts,
+    collateral_amount: u64,
+) -> Instruction {
+    Instruction {
+        program_id: KAMINO_ID,
+        accounts: vec![
+            AccountMeta::new(accounts.owner, true),
+            AccountMeta::new(accounts.obligation, false),
+            AccountMeta::new_readonly(accounts.lending_market, false),
+            AccountMeta::new_readonly(accounts.lending_market_authority, false),
+            AccountMeta::new(accounts.withdraw_reserve, false),
+            AccountMeta::new_readonly(accounts.reserve_liquidity_mint, false),
+            AccountMeta::new(accounts.reserve_source_collateral, false),
+            AccountMeta::new(accounts.reserve_collateral_mint, false),
+            AccountMeta::new(accounts.reserve_liquidity_supply, false),
+            AccountMeta::new(accounts.user_destination_liquidity, false),
+            optional_account(None, false),
+            AccountMeta::new_readonly(accounts.token_program, false),
+            AccountMeta::new_readonly(accounts.token_program, false),
+            AccountMeta::new_readonly(sysvar::instructions::ID, false),
+        ],
+        data: instruction_data(
+            WITHDRAW_OBLIGATION_COLLATERAL_AND_REDEEM_RESERVE_COLLATERAL_DISCRIMINATOR,
+            &collateral_amount,
+        ),
+    }
+}
+
+pub struct BorrowAccounts {
+    pub owner: Pubkey,
+    pub obligation: Pubkey,
+    pub lending_market: Pubkey,
+    pub lending_market_authority: Pubkey,
+    pub borrow_reserve: Pubkey,
+    pub borrow_reserve_liquidity_mint: Pubkey,
+    pub reserve_source_liquidity: Pubkey,
+    pub borrow_reserve_liquidity_fee_receiver: Pubkey,
+    pub user_destination_liquidity: Pubkey,
+    pub token_program: Pubkey,
+}
+
+pub fn borrow_obligation_liquidity(accounts: BorrowAccounts, liquidity_amount: u64) -> Instruction {
+    Instruction {
+        program_id: KAMINO_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(accounts.owner, true),
+            AccountMeta::new(accounts.obligation, false),
+            AccountMeta::new_readonly(accounts.lending_market, false),
+            AccountMeta::new_readonly(accounts.lending_market_authority, false),
+            AccountMeta::new(accounts.borrow_reserve, false),
+            AccountMeta::new_readonly(accounts.borrow_reserve_liquidity_mint, false),
+            AccountMeta::new(accounts.reserve_source_liquidity, false),
+            AccountMeta::new(accounts.borrow_reserve_liquidity_fee_receiver, false),
+            AccountMeta::new(accounts.user_destination_liquidity, false),
+            optional_account(None, true),
+            AccountMeta::new_readonly(accounts.token_program, false),
+            AccountMeta::new_readonly(sysvar::instructions::ID, false),
+        ],
+        data: instruction_data(BORROW_OBLIGATION_LIQUIDITY_DISCRIMINATOR, &liquidity_amount),
+    }
+}
+
+pub struct RepayAccounts {
+    pub owner: Pubkey,
+    pub obligation: Pubkey,
+    pub lending_market: Pubkey,
+    pub repay_reserve: Pubkey,
+    pub reserve_liquidity_mint: Pubkey,
+    pub reserve_destination_liquidity: Pubkey,
+    pub user_source_liquidity: Pubkey,
+    pub token_program: Pubkey,
+}
+
+/// `u64::MAX` repays the whole borrow
+pub fn repay_obligation_liquidity(accounts: RepayAccounts, liquidity_amount: u64) -> Instruction {
+    Instruction {
+        program_id: KAMINO_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(accounts.owner, true),
+            AccountMeta::new(accounts.obligation, false),
+            AccountMeta::new_readonly(accounts.lending_market, false),
+            AccountMeta::new(accounts.repay_reserve, false),
+            AccountMeta::new_readonly(accounts.reserve_liquidity_mint, false),
+            AccountMeta::new(accounts.reserve_destination_liquidity, false),
+            AccountMeta::new(accounts.user_source_liquidity, false),
+            AccountMeta::new_readonly(accounts.token_program, false),
+            AccountMeta::new_readonly(sysvar::instructions::ID, false),
+        ],
+        data: instruction_data(REPAY_OBLIGATION_LIQUIDITY_DISCRIMINATOR, &liquidity_amount),
+    }
+}
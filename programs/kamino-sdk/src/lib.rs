@@ -0,0 +1,43 @@
+//! Minimal bindings for the Kamino Lend (klend) program.
+//!
+//! Only the account fields and instructions Solauto relies on are described here. Account
+//! layouts are partial: each struct covers the leading bytes of the on-chain account, which is
+//! enough to read reserve configuration & obligation balances without deserializing the rest.
+
+pub mod accounts;
+pub mod instructions;
+pub mod types;
+
+use solana_program::{pubkey, pubkey::Pubkey};
+
+pub const KAMINO_ID: Pubkey = pubkey!("KLend2g3cP87fffoy8q1mQqGKjrxjC8boSyAYavgmjD");
+
+pub const LENDING_MARKET_AUTH_SEED: &[u8] = b"lma";
+pub const USER_METADATA_SEED: &[u8] = b"user_meta";
+
+pub fn lending_market_authority(lending_market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[LENDING_MARKET_AUTH_SEED, lending_market.as_ref()],
+        &KAMINO_ID,
+    )
+}
+
+pub fn user_metadata(owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[USER_METADATA_SEED, owner.as_ref()], &KAMINO_ID)
+}
+
+/// Address of a vanilla (tag 0) obligation, which is the obligation type Solauto positions use
+pub fn vanilla_obligation(owner: &Pubkey, lending_market: &Pubkey, id: u8) -> (Pubkey, u8) {
+    let default_seed = Pubkey::default();
+    Pubkey::find_program_address(
+        &[
+            &[0],
+            &[id],
+            owner.as_ref(),
+            lending_market.as_ref(),
+            default_seed.as_ref(),
+            default_seed.as_ref(),
+        ],
+        &KAMINO_ID,
+    )
+}
@@ -0,0 +1,138 @@
+use bytemuck::{Pod, Zeroable};
+use solana_program::pubkey::Pubkey;
+
+/// Kamino stores fractional values as u128 fixed-point numbers with 60 fractional bits ("sf").
+/// They are kept as little-endian byte arrays here so that the account structs stay 8-byte aligned.
+pub const FRACTION_BITS: u32 = 60;
+
+pub fn sf_to_f64(sf: [u8; 16]) -> f64 {
+    (u128::from_le_bytes(sf) as f64) / ((1u128 << FRACTION_BITS) as f64)
+}
+
+pub fn sf_to_u64_floor(sf: [u8; 16]) -> u64 {
+    (u128::from_le_bytes(sf) >> FRACTION_BITS) as u64
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct LastUpdate {
+    pub slot: u64,
+    pub stale: u8,
+    pub price_status: u8,
+    pub placeholder: [u8; 6],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct BigFractionBytes {
+    pub value: [u64; 4],
+    pub padding: [u64; 2],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct ReserveLiquidity {
+    pub mint_pubkey: Pubkey,
+    pub supply_vault: Pubkey,
+    pub fee_vault: Pubkey,
+    pub available_amount: u64,
+    pub borrowed_amount_sf: [u8; 16],
+    pub market_price_sf: [u8; 16],
+    pub market_price_last_updated_ts: u64,
+    pub mint_decimals: u64,
+    pub deposit_limit_crossed_timestamp: u64,
+    pub borrow_limit_crossed_timestamp: u64,
+    pub cumulative_borrow_rate_bsf: BigFractionBytes,
+    pub accumulated_protocol_fees_sf: [u8; 16],
+    pub accumulated_referrer_fees_sf: [u8; 16],
+    pub pending_referrer_fees_sf: [u8; 16],
+    pub absolute_referral_rate_sf: [u8; 16],
+    pub token_program: Pubkey,
+    pub padding2: [[u64; 3]; 17],
+    pub padding3: [[u8; 16]; 32],
+}
+
+impl ReserveLiquidity {
+    /// Total liquidity owned by depositors (available + borrowed, net of fees), in base units
+    pub fn total_supply(&self) -> f64 {
+        (self.available_amount as f64) + sf_to_f64(self.borrowed_amount_sf)
+            - sf_to_f64(self.accumulated_protocol_fees_sf)
+            - sf_to_f64(self.accumulated_referrer_fees_sf)
+            - sf_to_f64(self.pending_referrer_fees_sf)
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct ReserveCollateral {
+    pub mint_pubkey: Pubkey,
+    pub mint_total_supply: u64,
+    pub supply_vault: Pubkey,
+    pub padding1: [[u8; 16]; 32],
+    pub padding2: [[u8; 16]; 32],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct ReserveFees {
+    pub borrow_fee_sf: u64,
+    pub flash_loan_fee_sf: u64,
+    pub padding: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct CurvePoint {
+    pub utilization_rate_bps: u32,
+    pub borrow_rate_bps: u32,
+}
+
+/// Leading fields of the reserve config, up to and including the borrow limit
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct ReserveConfig {
+    pub status: u8,
+    pub asset_tier: u8,
+    pub host_fixed_interest_rate_bps: u16,
+    pub reserved_2: [u8; 2],
+    pub reserved_3: [u8; 8],
+    pub protocol_take_rate_pct: u8,
+    pub protocol_liquidation_fee_pct: u8,
+    pub loan_to_value_pct: u8,
+    pub liquidation_threshold_pct: u8,
+    pub min_liquidation_bonus_bps: u16,
+    pub max_liquidation_bonus_bps: u16,
+    pub bad_debt_liquidation_bonus_bps: u16,
+    pub deleveraging_margin_call_period_secs: u64,
+    pub deleveraging_threshold_slots_per_bps: u64,
+    pub fees: ReserveFees,
+    pub borrow_rate_curve: [CurvePoint; 11],
+    pub borrow_factor_pct: u64,
+    pub deposit_limit: u64,
+    pub borrow_limit: u64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct ObligationCollateral {
+    pub deposit_reserve: Pubkey,
+    /// Denominated in reserve collateral (cToken) base units
+    pub deposited_amount: u64,
+    pub market_value_sf: [u8; 16],
+    pub borrowed_amount_against_this_collateral_in_elevation_group: u64,
+    pub padding: [u64; 9],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct ObligationLiquidity {
+    pub borrow_reserve: Pubkey,
+    pub cumulative_borrow_rate_bsf: BigFractionBytes,
+    pub padding: u64,
+    /// Denominated in reserve liquidity base units, including accrued interest
+    pub borrowed_amount_sf: [u8; 16],
+    pub market_value_sf: [u8; 16],
+    pub borrow_factor_adjusted_market_value_sf: [u8; 16],
+    pub borrowed_amount_outside_elevation_groups: u64,
+    pub padding2: [u64; 7],
+}
@@ -0,0 +1,247 @@
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::WAD;
+
+pub const RESERVE_LEN: usize = 619;
+pub const OBLIGATION_LEN: usize = 1300;
+
+pub const OBLIGATION_MAX_RESERVES: usize = 10;
+pub const OBLIGATION_COLLATERAL_LEN: usize = 88;
+pub const OBLIGATION_LIQUIDITY_LEN: usize = 112;
+
+/// Byte offsets of the reserve fields Solauto reads
+pub mod reserve_offsets {
+    pub const VERSION: usize = 0;
+    pub const LENDING_MARKET: usize = 10;
+    pub const LIQUIDITY_MINT: usize = 42;
+    pub const LIQUIDITY_MINT_DECIMALS: usize = 74;
+    pub const LIQUIDITY_SUPPLY: usize = 75;
+    pub const LIQUIDITY_PYTH_ORACLE: usize = 107;
+    pub const LIQUIDITY_SWITCHBOARD_ORACLE: usize = 139;
+    pub const LIQUIDITY_AVAILABLE_AMOUNT: usize = 171;
+    pub const LIQUIDITY_BORROWED_AMOUNT_WADS: usize = 179;
+    pub const LIQUIDITY_MARKET_PRICE: usize = 211;
+    pub const COLLATERAL_MINT: usize = 227;
+    pub const COLLATERAL_MINT_TOTAL_SUPPLY: usize = 259;
+    pub const COLLATERAL_SUPPLY: usize = 267;
+    pub const LOAN_TO_VALUE_RATIO: usize = 300;
+    pub const LIQUIDATION_THRESHOLD: usize = 302;
+    pub const BORROW_FEE_WAD: usize = 306;
+    pub const FLASH_LOAN_FEE_WAD: usize = 314;
+    pub const DEPOSIT_LIMIT: usize = 323;
+    pub const BORROW_LIMIT: usize = 331;
+    pub const FEE_RECEIVER: usize = 339;
+    pub const ACCUMULATED_PROTOCOL_FEES_WADS: usize = 373;
+    pub const ADDED_BORROW_WEIGHT_BPS: usize = 445;
+    pub const LIQUIDITY_SMOOTHED_MARKET_PRICE: usize = 453;
+}
+
+/// Byte offsets of the obligation fields Solauto reads
+pub mod obligation_offsets {
+    pub const VERSION: usize = 0;
+    pub const LENDING_MARKET: usize = 10;
+    pub const OWNER: usize = 42;
+    pub const DEPOSITS_LEN: usize = 202;
+    pub const BORROWS_LEN: usize = 203;
+    pub const DATA_FLAT: usize = 204;
+}
+
+fn read_pubkey(data: &[u8], offset: usize) -> Pubkey {
+    Pubkey::new_from_array(data[offset..offset + 32].try_into().unwrap())
+}
+
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+}
+
+fn read_u128(data: &[u8], offset: usize) -> u128 {
+    u128::from_le_bytes(data[offset..offset + 16].try_into().unwrap())
+}
+
+pub fn wad_to_f64(wad: u128) -> f64 {
+    (wad as f64) / WAD
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Reserve {
+    pub lending_market: Pubkey,
+    pub liquidity_mint: Pubkey,
+    pub liquidity_mint_decimals: u8,
+    pub liquidity_supply: Pubkey,
+    pub liquidity_pyth_oracle: Pubkey,
+    pub liquidity_switchboard_oracle: Pubkey,
+    pub liquidity_available_amount: u64,
+    pub liquidity_borrowed_amount_wads: u128,
+    pub liquidity_market_price: u128,
+    pub liquidity_smoothed_market_price: u128,
+    pub collateral_mint: Pubkey,
+    pub collateral_mint_total_supply: u64,
+    pub collateral_supply: Pubkey,
+    pub loan_to_value_ratio: u8,
+    pub liquidation_threshold: u8,
+    pub borrow_fee_wad: u64,
+    pub flash_loan_fee_wad: u64,
+    pub deposit_limit: u64,
+    pub borrow_limit: u64,
+    pub fee_receiver: Pubkey,
+    pub accumulated_protocol_fees_wads: u128,
+    pub added_borrow_weight_bps: u64,
+}
+
+impl Reserve {
+    pub fn from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        use reserve_offsets::*;
+
+        if data.len() != RESERVE_LEN || data[VERSION] == 0 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            lending_market: read_pubkey(data, LENDING_MARKET),
+            liquidity_mint: read_pubkey(data, LIQUIDITY_MINT),
+            liquidity_mint_decimals: data[LIQUIDITY_MINT_DECIMALS],
+            liquidity_supply: read_pubkey(data, LIQUIDITY_SUPPLY),
+            liquidity_pyth_oracle: read_pubkey(data, LIQUIDITY_PYTH_ORACLE),
+            liquidity_switchboard_oracle: read_pubkey(data, LIQUIDITY_SWITCHBOARD_ORACLE),
+            liquidity_available_amount: read_u64(data, LIQUIDITY_AVAILABLE_AMOUNT),
+            liquidity_borrowed_amount_wads: read_u128(data, LIQUIDITY_BORROWED_AMOUNT_WADS),
+            liquidity_market_price: read_u128(data, LIQUIDITY_MARKET_PRICE),
+            liquidity_smoothed_market_price: read_u128(data, LIQUIDITY_SMOOTHED_MARKET_PRICE),
+            collateral_mint: read_pubkey(data, COLLATERAL_MINT),
+            collateral_mint_total_supply: read_u64(data, COLLATERAL_MINT_TOTAL_SUPPLY),
+            collateral_supply: read_pubkey(data, COLLATERAL_SUPPLY),
+            loan_to_value_ratio: data[LOAN_TO_VALUE_RATIO],
+            liquidation_threshold: data[LIQUIDATION_THRESHOLD],
+            borrow_fee_wad: read_u64(data, BORROW_FEE_WAD),
+            flash_loan_fee_wad: read_u64(data, FLASH_LOAN_FEE_WAD),
+            deposit_limit: read_u64(data, DEPOSIT_LIMIT),
+            borrow_limit: read_u64(data, BORROW_LIMIT),
+            fee_receiver: read_pubkey(data, FEE_RECEIVER),
+            accumulated_protocol_fees_wads: read_u128(data, ACCUMULATED_PROTOCOL_FEES_WADS),
+            added_borrow_weight_bps: read_u64(data, ADDED_BORROW_WEIGHT_BPS),
+        })
+    }
+
+    /// Total liquidity owned by depositors (available + borrowed, net of protocol fees), in base units
+    pub fn total_supply(&self) -> f64 {
+        (self.liquidity_available_amount as f64) + wad_to_f64(self.liquidity_borrowed_amount_wads)
+            - wad_to_f64(self.accumulated_protocol_fees_wads)
+    }
+
+    /// Amount of liquidity backing one unit of reserve collateral
+    pub fn collateral_exchange_rate(&self) -> f64 {
+        let total_supply = self.total_supply();
+        if self.collateral_mint_total_supply == 0 || total_supply <= 0.0 {
+            1.0
+        } else {
+            total_supply / (self.collateral_mint_total_supply as f64)
+        }
+    }
+
+    pub fn collateral_to_liquidity(&self, collateral_amount: u64) -> u64 {
+        ((collateral_amount as f64) * self.collateral_exchange_rate()).floor() as u64
+    }
+
+    pub fn liquidity_to_collateral(&self, liquidity_amount: u64) -> u64 {
+        ((liquidity_amount as f64) / self.collateral_exchange_rate()).floor() as u64
+    }
+
+    pub fn market_price(&self) -> f64 {
+        wad_to_f64(self.liquidity_market_price)
+    }
+
+    /// Falls back to the spot market price for reserves that don't track a smoothed price
+    pub fn smoothed_market_price(&self) -> f64 {
+        if self.liquidity_smoothed_market_price == 0 {
+            self.market_price()
+        } else {
+            wad_to_f64(self.liquidity_smoothed_market_price)
+        }
+    }
+
+    pub fn borrow_weight(&self) -> f64 {
+        1.0 + (self.added_borrow_weight_bps as f64) / 10_000.0
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ObligationCollateral {
+    pub deposit_reserve: Pubkey,
+    /// Denominated in reserve collateral base units
+    pub deposited_amount: u64,
+    pub market_value: u128,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ObligationLiquidity {
+    pub borrow_reserve: Pubkey,
+    pub cumulative_borrow_rate_wads: u128,
+    /// Denominated in reserve liquidity base units, including accrued interest
+    pub borrowed_amount_wads: u128,
+    pub market_value: u128,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Obligation {
+    pub lending_market: Pubkey,
+    pub owner: Pubkey,
+    pub deposits: Vec<ObligationCollateral>,
+    pub borrows: Vec<ObligationLiquidity>,
+}
+
+impl Obligation {
+    pub fn from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        use obligation_offsets::*;
+
+        if data.len() != OBLIGATION_LEN || data[VERSION] == 0 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let deposits_len = data[DEPOSITS_LEN] as usize;
+        let borrows_len = data[BORROWS_LEN] as usize;
+        if deposits_len + borrows_len > OBLIGATION_MAX_RESERVES {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mut offset = DATA_FLAT;
+        let mut deposits = Vec::with_capacity(deposits_len);
+        for _ in 0..deposits_len {
+            deposits.push(ObligationCollateral {
+                deposit_reserve: read_pubkey(data, offset),
+                deposited_amount: read_u64(data, offset + 32),
+                market_value: read_u128(data, offset + 40),
+            });
+            offset += OBLIGATION_COLLATERAL_LEN;
+        }
+
+        let mut borrows = Vec::with_capacity(borrows_len);
+        for _ in 0..borrows_len {
+            borrows.push(ObligationLiquidity {
+                borrow_reserve: read_pubkey(data, offset),
+                cumulative_borrow_rate_wads: read_u128(data, offset + 32),
+                borrowed_amount_wads: read_u128(data, offset + 48),
+                market_value: read_u128(data, offset + 64),
+            });
+            offset += OBLIGATION_LIQUIDITY_LEN;
+        }
+
+        Ok(Self {
+            lending_market: read_pubkey(data, LENDING_MARKET),
+            owner: read_pubkey(data, OWNER),
+            deposits,
+            borrows,
+        })
+    }
+
+    pub fn find_deposit(&self, reserve: &Pubkey) -> Option<&ObligationCollateral> {
+        self.deposits
+            .iter()
+            .find(|deposit| &deposit.deposit_reserve == reserve)
+    }
+
+    pub fn find_borrow(&self, reserve: &Pubkey) -> Option<&ObligationLiquidity> {
+        self.borrows
+            .iter()
+            .find(|borrow| &borrow.borrow_reserve == reserve)
+    }
+}
@@ -0,0 +1,224 @@
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    sysvar,
+};
+
+use crate::SAVE_ID;
+
+pub const REFRESH_RESERVE_TAG: u8 = 3;
+pub const INIT_OBLIGATION_TAG: u8 = 6;
+pub const REFRESH_OBLIGATION_TAG: u8 = 7;
+pub const BORROW_OBLIGATION_LIQUIDITY_TAG: u8 = 10;
+pub const REPAY_OBLIGATION_LIQUIDITY_TAG: u8 = 11;
+pub const DEPOSIT_RESERVE_LIQUIDITY_AND_OBLIGATION_COLLATERAL_TAG: u8 = 14;
+pub const WITHDRAW_OBLIGATION_COLLATERAL_AND_REDEEM_RESERVE_COLLATERAL_TAG: u8 = 15;
+pub const FLASH_BORROW_RESERVE_LIQUIDITY_TAG: u8 = 19;
+pub const FLASH_REPAY_RESERVE_LIQUIDITY_TAG: u8 = 20;
+
+fn amount_data(tag: u8, amount: u64) -> Vec<u8> {
+    let mut data = vec![tag];
+    data.extend_from_slice(&amount.to_le_bytes());
+    data
+}
+
+pub fn refresh_reserve(
+    reserve: &Pubkey,
+    pyth_oracle: &Pubkey,
+    switchboard_oracle: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: SAVE_ID,
+        accounts: vec![
+            AccountMeta::new(*reserve, false),
+            AccountMeta::new_readonly(*pyth_oracle, false),
+            AccountMeta::new_readonly(*switchboard_oracle, false),
+        ],
+        data: vec![REFRESH_RESERVE_TAG],
+    }
+}
+
+/// The obligation account must already be allocated (with `OBLIGATION_LEN` bytes) and owned by the Save program
+pub fn init_obligation(
+    obligation: &Pubkey,
+    lending_market: &Pubkey,
+    obligation_owner: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: SAVE_ID,
+        accounts: vec![
+            AccountMeta::new(*obligation, false),
+            AccountMeta::new_readonly(*lending_market, false),
+            AccountMeta::new_readonly(*obligation_owner, true),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(*token_program, false),
+        ],
+        data: vec![INIT_OBLIGATION_TAG],
+    }
+}
+
+/// Every deposit reserve of the obligation, followed by every borrow reserve, must be provided in order
+pub fn refresh_obligation(
+    obligation: &Pubkey,
+    deposit_and_borrow_reserves: &[Pubkey],
+) -> Instruction {
+    let mut accounts = vec![AccountMeta::new(*obligation, false)];
+    accounts.extend(
+        deposit_and_borrow_reserves
+            .iter()
+            .map(|reserve| AccountMeta::new_readonly(*reserve, false)),
+    );
+
+    Instruction {
+        program_id: SAVE_ID,
+        accounts,
+        data: vec![REFRESH_OBLIGATION_TAG],
+    }
+}
+
+pub struct DepositAccounts {
+    pub source_liquidity: Pubkey,
+    pub user_collateral: Pubkey,
+    pub reserve: Pubkey,
+    pub reserve_liquidity_supply: Pubkey,
+    pub reserve_collateral_mint: Pubkey,
+    pub lending_market: Pubkey,
+    pub lending_market_authority: Pubkey,
+    pub destination_deposit_collateral: Pubkey,
+    pub obligation: Pubkey,
+    pub obligation_owner: Pubkey,
+    pub pyth_oracle: Pubkey,
+    pub switchboard_oracle: Pubkey,
+    pub user_transfer_authority: Pubkey,
+    pub token_program: Pubkey,
+}
+
+pub fn deposit_reserve_liquidity_and_obligation_collateral(
+    accounts: DepositAccounts,
+    liquidity_amount: u64,
+) -> Instruction {
+    Instruction {
+        program_id: SAVE_ID,
+        accounts: vec![
+            AccountMeta::new(accounts.source_liquidity, false),
+            AccountMeta::new(accounts.user_collateral, false),
+            AccountMeta::new(accounts.reserve, false),
+            AccountMeta::new(accounts.reserve_liquidity_supply, false),
+            AccountMeta::new(accounts.reserve_collateral_mint, false),
+            AccountMeta::new_readonly(accounts.lending_market, false),
+            AccountMeta::new_readonly(accounts.lending_market_authority, false),
+            AccountMeta::new(accounts.destination_deposit_collateral, false),
+            AccountMeta::new(accounts.obligation, false),
+            AccountMeta::new_readonly(accounts.obligation_owner, true),
+            AccountMeta::new_readonly(accounts.pyth_oracle, false),
+            AccountMeta::new_readonly(accounts.switchboard_oracle, false),
+            AccountMeta::new_readonly(accounts.user_transfer_authority, true),
+            AccountMeta::new_readonly(accounts.token_program, false),
+        ],
+        data: amount_data(
+            DEPOSIT_RESERVE_LIQUIDITY_AND_OBLIGATION_COLLATERAL_TAG,
+            liquidity_amount,
+        ),
+    }
+}
+
+pub struct WithdrawAccounts {
+    pub reserve_collateral: Pubkey,
+    pub user_collateral: Pubkey,
+    pub withdraw_reserve: Pubkey,
+    pub obligation: Pubkey,
+    pub lending_market: Pubkey,
+    pub lending_market_authority: Pubkey,
+    pub user_liquidity: Pubkey,
+    pub reserve_collateral_mint: Pubkey,
+    pub reserve_liquidity_supply: Pubkey,
+    pub obligation_owner: Pubkey,
+    pub user_transfer_authority: Pubkey,
+    pub token_program: Pubkey,
+}
+
+/// `collateral_amount` is denominated in reserve collateral; `u64::MAX` withdraws the whole deposit
+pub fn withdraw_obligation_collateral_and_redeem_reserve_collateral(
+    accounts: WithdrawAccounts,
+    collateral_amount: u64,
+) -> Instruction {
+    Instruction {
+        program_id: SAVE_ID,
+        accounts: vec![
+            AccountMeta::new(accounts.reserve_collateral, false),
+            AccountMeta::new(accounts.user_collateral, false),
+            AccountMeta::new(accounts.withdraw_reserve, false),
+            AccountMeta::new(accounts.obligation, false),
+            AccountMeta::new_readonly(accounts.lending_market, false),
+            AccountMeta::new_readonly(accounts.lending_market_authority, false),
+            AccountMeta::new(accounts.user_liquidity, false),
+            AccountMeta::new(accounts.reserve_collateral_mint, false),
+            AccountMeta::new(accounts.reserve_liquidity_supply, false),
+            AccountMeta::new_readonly(accounts.obligation_owner, true),
+            AccountMeta::new_readonly(accounts.user_transfer_authority, true),
+            AccountMeta::new_readonly(accounts.token_program, false),
+        ],
+        data: amount_data(
+            WITHDRAW_OBLIGATION_COLLATERAL_AND_REDEEM_RESERVE_COLLATERAL_TAG,
+            collateral_amount,
+        ),
+    }
+}
+
+pub struct BorrowAccounts {
+    pub source_liquidity: Pubkey,
+    pub destination_liquidity: Pubkey,
+    pub borrow_reserve: Pubkey,
+    pub borrow_reserve_liquidity_fee_receiver: Pubkey,
+    pub obligation: Pubkey,
+    pub lending_market: Pubkey,
+    pub lending_market_authority: Pubkey,
+    pub obligation_owner: Pubkey,
+    pub token_program: Pubkey,
+}
+
+pub fn borrow_obligation_liquidity(accounts: BorrowAccounts, liquidity_amount: u64) -> Instruction {
+    Instruction {
+        program_id: SAVE_ID,
+        accounts: vec![
+            AccountMeta::new(accounts.source_liquidity, false),
+            AccountMeta::new(accounts.destination_liquidity, false),
+            AccountMeta::new(accounts.borrow_reserve, false),
+            AccountMeta::new(accounts.borrow_reserve_liquidity_fee_receiver, false),
+            AccountMeta::new(accounts.obligation, false),
+            AccountMeta::new_readonly(accounts.lending_market, false),
+            AccountMeta::new_readonly(accounts.lending_market_authority, false),
+            AccountMeta::new_readonly(accounts.obligation_owner, true),
+            AccountMeta::new_readonly(accounts.token_program, false),
+        ],
+        data: amount_data(BORROW_OBLIGATION_LIQUIDITY_TAG, liquidity_amount),
+    }
+}
+
+pub struct RepayAccounts {
+    pub source_liquidity: Pubkey,
+    pub destination_liquidity: Pubkey,
+    pub repay_reserve: Pubkey,
+    pub obligation: Pubkey,
+    pub lending_market: Pubkey,
+    pub user_transfer_authority: Pubkey,
+    pub token_program: Pubkey,
+}
+
+/// `u64::MAX` repays the whole borrow
+pub fn repay_obligation_liquidity(accounts: RepayAccounts, liquidity_amount: u64) -> Instruction {
+    Instruction {
+        program_id: SAVE_ID,
+        accounts: vec![
+            AccountMeta::new(accounts.source_liquidity, false),
+            AccountMeta::new(accounts.destination_liquidity, false),
+            AccountMeta::new(accounts.repay_reserve, false),
+            AccountMeta::new(accounts.obligation, false),
+            AccountMeta::new_readonly(accounts.lending_market, false),
+            AccountMeta::new_readonly(accounts.user_transfer_authority, true),
+            AccountMeta::new_readonly(accounts.token_program, false),
+        ],
+        data: amount_data(REPAY_OBLIGATION_LIQUIDITY_TAG, liquidity_amount),
+    }
+}
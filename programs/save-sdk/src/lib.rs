@@ -0,0 +1,28 @@
+//! Minimal bindings for the Save (formerly Solend) lending program.
+//!
+//! Save accounts are packed (not zero-copy), so the fields Solauto relies on are read at their
+//! byte offsets. Instructions use a single byte tag followed by packed arguments.
+
+pub mod accounts;
+pub mod instructions;
+
+use solana_program::{pubkey, pubkey::Pubkey};
+
+pub const SAVE_ID: Pubkey = pubkey!("So1endDq2YkqhipRh3WViPa8hdiSpxWy6z3Z6tMCpAo");
+
+/// Save stores decimal values as u128 fixed-point numbers scaled by 10^18
+pub const WAD: f64 = 1_000_000_000_000_000_000.0;
+
+pub fn lending_market_authority(lending_market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[lending_market.as_ref()], &SAVE_ID)
+}
+
+/// Seed used for obligation accounts created with `create_account_with_seed`, matching the Save UI
+pub fn obligation_seed(lending_market: &Pubkey) -> String {
+    lending_market.to_string()[..32].to_string()
+}
+
+pub fn obligation_address(owner: &Pubkey, lending_market: &Pubkey) -> Pubkey {
+    Pubkey::create_with_seed(owner, &obligation_seed(lending_market), &SAVE_ID)
+        .expect("Seed should be valid")
+}
@@ -5,11 +5,19 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+use crate::generated::types::CarryGuard;
+use crate::generated::types::Grid;
+use crate::generated::types::HarvestData;
+use crate::generated::types::LeverageCurve;
+use crate::generated::types::LimitOrder;
 use crate::generated::types::PodBool;
 use crate::generated::types::PositionData;
 use crate::generated::types::PositionState;
 use crate::generated::types::PositionType;
 use crate::generated::types::RebalanceData;
+use crate::generated::types::StopLossOrder;
+use crate::generated::types::TakeProfitOrder;
+use crate::generated::types::TrailingStopOrder;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_program::pubkey::Pubkey;
@@ -30,11 +38,19 @@ pub struct SolautoPosition {
     pub position: PositionData,
     pub state: PositionState,
     pub rebalance: RebalanceData,
-    pub padding: [u32; 20],
+    pub harvest: HarvestData,
+    pub limit_order: LimitOrder,
+    pub take_profit: TakeProfitOrder,
+    pub stop_loss: StopLossOrder,
+    pub trailing_stop: TrailingStopOrder,
+    pub leverage_curve: LeverageCurve,
+    pub grid: Grid,
+    pub carry_guard: CarryGuard,
+    pub padding: [u32; 2],
 }
 
 impl SolautoPosition {
-    pub const LEN: usize = 832;
+    pub const LEN: usize = 2064;
 
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
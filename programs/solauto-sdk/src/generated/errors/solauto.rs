@@ -55,6 +55,69 @@ pub enum SolautoError {
     /// 14 (0xE) - Cannot provide a target liquidation utilization rate if the instruction is not signed by the position authority
     #[error("Cannot provide a target liquidation utilization rate if the instruction is not signed by the position authority")]
     NonAuthorityProvidedTargetLTV,
+    /// 15 (0xF) - The position has more supply or debt legs than a Solauto position can track
+    #[error("The position has more supply or debt legs than a Solauto position can track")]
+    TooManyPositionLegs,
+    /// 16 (0x10) - Invalid limit order provided
+    #[error("Invalid limit order provided")]
+    InvalidLimitOrder,
+    /// 17 (0x11) - Invalid take-profit order provided
+    #[error("Invalid take-profit order provided")]
+    InvalidTakeProfitOrder,
+    /// 18 (0x12) - Invalid stop-loss order provided
+    #[error("Invalid stop-loss order provided")]
+    InvalidStopLossOrder,
+    /// 19 (0x13) - Invalid trailing stop order provided
+    #[error("Invalid trailing stop order provided")]
+    InvalidTrailingStopOrder,
+    /// 20 (0x14) - Invalid leverage curve provided
+    #[error("Invalid leverage curve provided")]
+    InvalidLeverageCurve,
+    /// 21 (0x15) - Invalid grid settings provided
+    #[error("Invalid grid settings provided")]
+    InvalidGridSettings,
+    /// 22 (0x16) - Invalid carry guard provided
+    #[error("Invalid carry guard provided")]
+    InvalidCarryGuard,
+    /// 23 (0x17) - Boosts are refused while the position's net carry is negative
+    #[error("Boosts are refused while the position's net carry is negative")]
+    NegativeCarryBoost,
+    /// 24 (0x18) - Invalid max price divergence provided
+    #[error("Invalid max price divergence provided")]
+    InvalidMaxPriceDivergence,
+    /// 25 (0x19) - Boosts are refused while the realtime & EMA prices diverge by more than the position allows
+    #[error("Boosts are refused while the realtime & EMA prices diverge by more than the position allows")]
+    PriceDivergenceTooHigh,
+    /// 26 (0x1A) - The oracle price is older than the maximum price age
+    #[error("The oracle price is older than the maximum price age")]
+    StaleOraclePrice,
+    /// 27 (0x1B) - The oracle confidence interval is too wide relative to the price
+    #[error("The oracle confidence interval is too wide relative to the price")]
+    OracleConfidenceTooWide,
+    /// 28 (0x1C) - The oracle reported a price that isn't positive
+    #[error("The oracle reported a price that isn't positive")]
+    InvalidOraclePrice,
+    /// 29 (0x1D) - The swap isn't through a supported venue or route, or its instruction data couldn't be decoded
+    #[error("The swap isn't through a supported venue or route, or its instruction data couldn't be decoded")]
+    UnsupportedSwap,
+    /// 30 (0x1E) - The swap's input or output mint doesn't match the rebalance
+    #[error("The swap's input or output mint doesn't match the rebalance")]
+    SwapMintMismatch,
+    /// 31 (0x1F) - The swap's source or destination token account doesn't match the rebalance
+    #[error("The swap's source or destination token account doesn't match the rebalance")]
+    SwapTokenAccountMismatch,
+    /// 32 (0x20) - The swap takes a platform fee
+    #[error("The swap takes a platform fee")]
+    SwapPlatformFee,
+    /// 33 (0x21) - The swap's in-amount doesn't match the rebalance swap-in amount
+    #[error("The swap's in-amount doesn't match the rebalance swap-in amount")]
+    SwapAmountMismatch,
+    /// 34 (0x22) - Invalid max slippage param
+    #[error("Invalid max slippage param")]
+    InvalidMaxSlippage,
+    /// 35 (0x23) - The swap returned less than the oracle prices imply, by more than the max slippage
+    #[error("The swap returned less than the oracle prices imply, by more than the max slippage")]
+    SwapSlippageTooHigh,
 }
 
 impl solana_program::program_error::PrintProgramError for SolautoError {
@@ -0,0 +1,575 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+pub struct CancelLimitOrder {
+    pub signer: solana_program::pubkey::Pubkey,
+
+    pub system_program: solana_program::pubkey::Pubkey,
+
+    pub token_program: solana_program::pubkey::Pubkey,
+
+    pub ata_program: solana_program::pubkey::Pubkey,
+
+    pub solauto_position: solana_program::pubkey::Pubkey,
+
+    pub order_mint: solana_program::pubkey::Pubkey,
+
+    pub position_order_ta: solana_program::pubkey::Pubkey,
+
+    pub signer_order_ta: solana_program::pubkey::Pubkey,
+}
+
+impl CancelLimitOrder {
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.signer,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.ata_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.solauto_position,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.order_mint,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.position_order_ta,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.signer_order_ta,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = CancelLimitOrderInstructionData::new().try_to_vec().unwrap();
+
+        solana_program::instruction::Instruction {
+            program_id: crate::SOLAUTO_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct CancelLimitOrderInstructionData {
+    discriminator: u8,
+}
+
+impl CancelLimitOrderInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 19 }
+    }
+}
+
+/// Instruction builder for `CancelLimitOrder`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` signer
+///   1. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   2. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   3. `[optional]` ata_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
+///   4. `[writable]` solauto_position
+///   5. `[]` order_mint
+///   6. `[writable]` position_order_ta
+///   7. `[writable]` signer_order_ta
+#[derive(Default)]
+pub struct CancelLimitOrderBuilder {
+    signer: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    ata_program: Option<solana_program::pubkey::Pubkey>,
+    solauto_position: Option<solana_program::pubkey::Pubkey>,
+    order_mint: Option<solana_program::pubkey::Pubkey>,
+    position_order_ta: Option<solana_program::pubkey::Pubkey>,
+    signer_order_ta: Option<solana_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl CancelLimitOrderBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn signer(&mut self, signer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.signer = Some(signer);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    /// `[optional account, default to 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL']`
+    #[inline(always)]
+    pub fn ata_program(&mut self, ata_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.ata_program = Some(ata_program);
+        self
+    }
+    #[inline(always)]
+    pub fn solauto_position(
+        &mut self,
+        solauto_position: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.solauto_position = Some(solauto_position);
+        self
+    }
+    #[inline(always)]
+    pub fn order_mint(&mut self, order_mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.order_mint = Some(order_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn position_order_ta(
+        &mut self,
+        position_order_ta: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.position_order_ta = Some(position_order_ta);
+        self
+    }
+    #[inline(always)]
+    pub fn signer_order_ta(
+        &mut self,
+        signer_order_ta: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.signer_order_ta = Some(signer_order_ta);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = CancelLimitOrder {
+            signer: self.signer.expect("signer is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            token_program: self.token_program.unwrap_or(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            ata_program: self.ata_program.unwrap_or(solana_program::pubkey!(
+                "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+            )),
+            solauto_position: self.solauto_position.expect("solauto_position is not set"),
+            order_mint: self.order_mint.expect("order_mint is not set"),
+            position_order_ta: self
+                .position_order_ta
+                .expect("position_order_ta is not set"),
+            signer_order_ta: self.signer_order_ta.expect("signer_order_ta is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `cancel_limit_order` CPI accounts.
+pub struct CancelLimitOrderCpiAccounts<'a, 'b> {
+    pub signer: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ata_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub solauto_position: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub order_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub position_order_ta: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub signer_order_ta: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `cancel_limit_order` CPI instruction.
+pub struct CancelLimitOrderCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub signer: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ata_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub solauto_position: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub order_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub position_order_ta: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub signer_order_ta: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+impl<'a, 'b> CancelLimitOrderCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: CancelLimitOrderCpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            signer: accounts.signer,
+            system_program: accounts.system_program,
+            token_program: accounts.token_program,
+            ata_program: accounts.ata_program,
+            solauto_position: accounts.solauto_position,
+            order_mint: accounts.order_mint,
+            position_order_ta: accounts.position_order_ta,
+            signer_order_ta: accounts.signer_order_ta,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.signer.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.ata_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.solauto_position.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.order_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.position_order_ta.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.signer_order_ta.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let data = CancelLimitOrderInstructionData::new().try_to_vec().unwrap();
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::SOLAUTO_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(8 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.signer.clone());
+        account_infos.push(self.system_program.clone());
+        account_infos.push(self.token_program.clone());
+        account_infos.push(self.ata_program.clone());
+        account_infos.push(self.solauto_position.clone());
+        account_infos.push(self.order_mint.clone());
+        account_infos.push(self.position_order_ta.clone());
+        account_infos.push(self.signer_order_ta.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `CancelLimitOrder` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` signer
+///   1. `[]` system_program
+///   2. `[]` token_program
+///   3. `[]` ata_program
+///   4. `[writable]` solauto_position
+///   5. `[]` order_mint
+///   6. `[writable]` position_order_ta
+///   7. `[writable]` signer_order_ta
+pub struct CancelLimitOrderCpiBuilder<'a, 'b> {
+    instruction: Box<CancelLimitOrderCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> CancelLimitOrderCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(CancelLimitOrderCpiBuilderInstruction {
+            __program: program,
+            signer: None,
+            system_program: None,
+            token_program: None,
+            ata_program: None,
+            solauto_position: None,
+            order_mint: None,
+            position_order_ta: None,
+            signer_order_ta: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    #[inline(always)]
+    pub fn signer(
+        &mut self,
+        signer: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.signer = Some(signer);
+        self
+    }
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn ata_program(
+        &mut self,
+        ata_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.ata_program = Some(ata_program);
+        self
+    }
+    #[inline(always)]
+    pub fn solauto_position(
+        &mut self,
+        solauto_position: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.solauto_position = Some(solauto_position);
+        self
+    }
+    #[inline(always)]
+    pub fn order_mint(
+        &mut self,
+        order_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.order_mint = Some(order_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn position_order_ta(
+        &mut self,
+        position_order_ta: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.position_order_ta = Some(position_order_ta);
+        self
+    }
+    #[inline(always)]
+    pub fn signer_order_ta(
+        &mut self,
+        signer_order_ta: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.signer_order_ta = Some(signer_order_ta);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let instruction = CancelLimitOrderCpi {
+            __program: self.instruction.__program,
+
+            signer: self.instruction.signer.expect("signer is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            token_program: self
+                .instruction
+                .token_program
+                .expect("token_program is not set"),
+
+            ata_program: self
+                .instruction
+                .ata_program
+                .expect("ata_program is not set"),
+
+            solauto_position: self
+                .instruction
+                .solauto_position
+                .expect("solauto_position is not set"),
+
+            order_mint: self.instruction.order_mint.expect("order_mint is not set"),
+
+            position_order_ta: self
+                .instruction
+                .position_order_ta
+                .expect("position_order_ta is not set"),
+
+            signer_order_ta: self
+                .instruction
+                .signer_order_ta
+                .expect("signer_order_ta is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct CancelLimitOrderCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ata_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    solauto_position: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    order_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    position_order_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    signer_order_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
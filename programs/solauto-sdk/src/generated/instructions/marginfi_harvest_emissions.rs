@@ -0,0 +1,1005 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+pub struct MarginfiHarvestEmissions {
+    pub signer: solana_program::pubkey::Pubkey,
+
+    pub marginfi_program: solana_program::pubkey::Pubkey,
+
+    pub system_program: solana_program::pubkey::Pubkey,
+
+    pub token_program: solana_program::pubkey::Pubkey,
+
+    pub ixs_sysvar: solana_program::pubkey::Pubkey,
+
+    pub solauto_position: solana_program::pubkey::Pubkey,
+
+    pub marginfi_group: solana_program::pubkey::Pubkey,
+
+    pub marginfi_account: solana_program::pubkey::Pubkey,
+
+    pub emissions_bank: solana_program::pubkey::Pubkey,
+
+    pub emissions_mint: solana_program::pubkey::Pubkey,
+
+    pub emissions_auth: solana_program::pubkey::Pubkey,
+
+    pub emissions_vault: solana_program::pubkey::Pubkey,
+
+    pub position_emissions_ta: solana_program::pubkey::Pubkey,
+
+    pub intermediary_ta: Option<solana_program::pubkey::Pubkey>,
+
+    pub supply_bank: solana_program::pubkey::Pubkey,
+
+    pub position_supply_ta: solana_program::pubkey::Pubkey,
+
+    pub vault_supply_ta: solana_program::pubkey::Pubkey,
+
+    pub debt_bank: solana_program::pubkey::Pubkey,
+}
+
+impl MarginfiHarvestEmissions {
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(18 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.signer,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.marginfi_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.ixs_sysvar,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.solauto_position,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.marginfi_group,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.marginfi_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.emissions_bank,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.emissions_mint,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.emissions_auth,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.emissions_vault,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.position_emissions_ta,
+            false,
+        ));
+        if let Some(intermediary_ta) = self.intermediary_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                intermediary_ta,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLAUTO_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.supply_bank,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.position_supply_ta,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.vault_supply_ta,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.debt_bank,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = MarginfiHarvestEmissionsInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+
+        solana_program::instruction::Instruction {
+            program_id: crate::SOLAUTO_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct MarginfiHarvestEmissionsInstructionData {
+    discriminator: u8,
+}
+
+impl MarginfiHarvestEmissionsInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 14 }
+    }
+}
+
+/// Instruction builder for `MarginfiHarvestEmissions`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` signer
+///   1. `[]` marginfi_program
+///   2. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   3. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   4. `[]` ixs_sysvar
+///   5. `[writable]` solauto_position
+///   6. `[]` marginfi_group
+///   7. `[writable]` marginfi_account
+///   8. `[writable]` emissions_bank
+///   9. `[]` emissions_mint
+///   10. `[]` emissions_auth
+///   11. `[writable]` emissions_vault
+///   12. `[writable]` position_emissions_ta
+///   13. `[writable, optional]` intermediary_ta
+///   14. `[writable]` supply_bank
+///   15. `[writable]` position_supply_ta
+///   16. `[writable]` vault_supply_ta
+///   17. `[]` debt_bank
+#[derive(Default)]
+pub struct MarginfiHarvestEmissionsBuilder {
+    signer: Option<solana_program::pubkey::Pubkey>,
+    marginfi_program: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    ixs_sysvar: Option<solana_program::pubkey::Pubkey>,
+    solauto_position: Option<solana_program::pubkey::Pubkey>,
+    marginfi_group: Option<solana_program::pubkey::Pubkey>,
+    marginfi_account: Option<solana_program::pubkey::Pubkey>,
+    emissions_bank: Option<solana_program::pubkey::Pubkey>,
+    emissions_mint: Option<solana_program::pubkey::Pubkey>,
+    emissions_auth: Option<solana_program::pubkey::Pubkey>,
+    emissions_vault: Option<solana_program::pubkey::Pubkey>,
+    position_emissions_ta: Option<solana_program::pubkey::Pubkey>,
+    intermediary_ta: Option<solana_program::pubkey::Pubkey>,
+    supply_bank: Option<solana_program::pubkey::Pubkey>,
+    position_supply_ta: Option<solana_program::pubkey::Pubkey>,
+    vault_supply_ta: Option<solana_program::pubkey::Pubkey>,
+    debt_bank: Option<solana_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl MarginfiHarvestEmissionsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn signer(&mut self, signer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.signer = Some(signer);
+        self
+    }
+    #[inline(always)]
+    pub fn marginfi_program(
+        &mut self,
+        marginfi_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.marginfi_program = Some(marginfi_program);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn ixs_sysvar(&mut self, ixs_sysvar: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.ixs_sysvar = Some(ixs_sysvar);
+        self
+    }
+    #[inline(always)]
+    pub fn solauto_position(
+        &mut self,
+        solauto_position: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.solauto_position = Some(solauto_position);
+        self
+    }
+    #[inline(always)]
+    pub fn marginfi_group(&mut self, marginfi_group: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.marginfi_group = Some(marginfi_group);
+        self
+    }
+    #[inline(always)]
+    pub fn marginfi_account(
+        &mut self,
+        marginfi_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.marginfi_account = Some(marginfi_account);
+        self
+    }
+    #[inline(always)]
+    pub fn emissions_bank(&mut self, emissions_bank: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.emissions_bank = Some(emissions_bank);
+        self
+    }
+    #[inline(always)]
+    pub fn emissions_mint(&mut self, emissions_mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.emissions_mint = Some(emissions_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn emissions_auth(&mut self, emissions_auth: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.emissions_auth = Some(emissions_auth);
+        self
+    }
+    #[inline(always)]
+    pub fn emissions_vault(
+        &mut self,
+        emissions_vault: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.emissions_vault = Some(emissions_vault);
+        self
+    }
+    #[inline(always)]
+    pub fn position_emissions_ta(
+        &mut self,
+        position_emissions_ta: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.position_emissions_ta = Some(position_emissions_ta);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn intermediary_ta(
+        &mut self,
+        intermediary_ta: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.intermediary_ta = intermediary_ta;
+        self
+    }
+    #[inline(always)]
+    pub fn supply_bank(&mut self, supply_bank: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.supply_bank = Some(supply_bank);
+        self
+    }
+    #[inline(always)]
+    pub fn position_supply_ta(
+        &mut self,
+        position_supply_ta: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.position_supply_ta = Some(position_supply_ta);
+        self
+    }
+    #[inline(always)]
+    pub fn vault_supply_ta(
+        &mut self,
+        vault_supply_ta: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.vault_supply_ta = Some(vault_supply_ta);
+        self
+    }
+    #[inline(always)]
+    pub fn debt_bank(&mut self, debt_bank: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.debt_bank = Some(debt_bank);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = MarginfiHarvestEmissions {
+            signer: self.signer.expect("signer is not set"),
+            marginfi_program: self.marginfi_program.expect("marginfi_program is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            token_program: self.token_program.unwrap_or(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            ixs_sysvar: self.ixs_sysvar.expect("ixs_sysvar is not set"),
+            solauto_position: self.solauto_position.expect("solauto_position is not set"),
+            marginfi_group: self.marginfi_group.expect("marginfi_group is not set"),
+            marginfi_account: self.marginfi_account.expect("marginfi_account is not set"),
+            emissions_bank: self.emissions_bank.expect("emissions_bank is not set"),
+            emissions_mint: self.emissions_mint.expect("emissions_mint is not set"),
+            emissions_auth: self.emissions_auth.expect("emissions_auth is not set"),
+            emissions_vault: self.emissions_vault.expect("emissions_vault is not set"),
+            position_emissions_ta: self
+                .position_emissions_ta
+                .expect("position_emissions_ta is not set"),
+            intermediary_ta: self.intermediary_ta,
+            supply_bank: self.supply_bank.expect("supply_bank is not set"),
+            position_supply_ta: self
+                .position_supply_ta
+                .expect("position_supply_ta is not set"),
+            vault_supply_ta: self.vault_supply_ta.expect("vault_supply_ta is not set"),
+            debt_bank: self.debt_bank.expect("debt_bank is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `marginfi_harvest_emissions` CPI accounts.
+pub struct MarginfiHarvestEmissionsCpiAccounts<'a, 'b> {
+    pub signer: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub marginfi_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ixs_sysvar: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub solauto_position: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub marginfi_group: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub marginfi_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub emissions_bank: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub emissions_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub emissions_auth: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub emissions_vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub position_emissions_ta: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub intermediary_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub supply_bank: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub position_supply_ta: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_supply_ta: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub debt_bank: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `marginfi_harvest_emissions` CPI instruction.
+pub struct MarginfiHarvestEmissionsCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub signer: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub marginfi_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ixs_sysvar: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub solauto_position: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub marginfi_group: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub marginfi_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub emissions_bank: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub emissions_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub emissions_auth: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub emissions_vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub position_emissions_ta: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub intermediary_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub supply_bank: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub position_supply_ta: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_supply_ta: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub debt_bank: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+impl<'a, 'b> MarginfiHarvestEmissionsCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: MarginfiHarvestEmissionsCpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            signer: accounts.signer,
+            marginfi_program: accounts.marginfi_program,
+            system_program: accounts.system_program,
+            token_program: accounts.token_program,
+            ixs_sysvar: accounts.ixs_sysvar,
+            solauto_position: accounts.solauto_position,
+            marginfi_group: accounts.marginfi_group,
+            marginfi_account: accounts.marginfi_account,
+            emissions_bank: accounts.emissions_bank,
+            emissions_mint: accounts.emissions_mint,
+            emissions_auth: accounts.emissions_auth,
+            emissions_vault: accounts.emissions_vault,
+            position_emissions_ta: accounts.position_emissions_ta,
+            intermediary_ta: accounts.intermediary_ta,
+            supply_bank: accounts.supply_bank,
+            position_supply_ta: accounts.position_supply_ta,
+            vault_supply_ta: accounts.vault_supply_ta,
+            debt_bank: accounts.debt_bank,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(18 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.signer.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.marginfi_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.ixs_sysvar.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.solauto_position.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.marginfi_group.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.marginfi_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.emissions_bank.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.emissions_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.emissions_auth.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.emissions_vault.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.position_emissions_ta.key,
+            false,
+        ));
+        if let Some(intermediary_ta) = self.intermediary_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *intermediary_ta.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLAUTO_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.supply_bank.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.position_supply_ta.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.vault_supply_ta.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.debt_bank.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let data = MarginfiHarvestEmissionsInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::SOLAUTO_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(18 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.signer.clone());
+        account_infos.push(self.marginfi_program.clone());
+        account_infos.push(self.system_program.clone());
+        account_infos.push(self.token_program.clone());
+        account_infos.push(self.ixs_sysvar.clone());
+        account_infos.push(self.solauto_position.clone());
+        account_infos.push(self.marginfi_group.clone());
+        account_infos.push(self.marginfi_account.clone());
+        account_infos.push(self.emissions_bank.clone());
+        account_infos.push(self.emissions_mint.clone());
+        account_infos.push(self.emissions_auth.clone());
+        account_infos.push(self.emissions_vault.clone());
+        account_infos.push(self.position_emissions_ta.clone());
+        if let Some(intermediary_ta) = self.intermediary_ta {
+            account_infos.push(intermediary_ta.clone());
+        }
+        account_infos.push(self.supply_bank.clone());
+        account_infos.push(self.position_supply_ta.clone());
+        account_infos.push(self.vault_supply_ta.clone());
+        account_infos.push(self.debt_bank.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `MarginfiHarvestEmissions` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` signer
+///   1. `[]` marginfi_program
+///   2. `[]` system_program
+///   3. `[]` token_program
+///   4. `[]` ixs_sysvar
+///   5. `[writable]` solauto_position
+///   6. `[]` marginfi_group
+///   7. `[writable]` marginfi_account
+///   8. `[writable]` emissions_bank
+///   9. `[]` emissions_mint
+///   10. `[]` emissions_auth
+///   11. `[writable]` emissions_vault
+///   12. `[writable]` position_emissions_ta
+///   13. `[writable, optional]` intermediary_ta
+///   14. `[writable]` supply_bank
+///   15. `[writable]` position_supply_ta
+///   16. `[writable]` vault_supply_ta
+///   17. `[]` debt_bank
+pub struct MarginfiHarvestEmissionsCpiBuilder<'a, 'b> {
+    instruction: Box<MarginfiHarvestEmissionsCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> MarginfiHarvestEmissionsCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(MarginfiHarvestEmissionsCpiBuilderInstruction {
+            __program: program,
+            signer: None,
+            marginfi_program: None,
+            system_program: None,
+            token_program: None,
+            ixs_sysvar: None,
+            solauto_position: None,
+            marginfi_group: None,
+            marginfi_account: None,
+            emissions_bank: None,
+            emissions_mint: None,
+            emissions_auth: None,
+            emissions_vault: None,
+            position_emissions_ta: None,
+            intermediary_ta: None,
+            supply_bank: None,
+            position_supply_ta: None,
+            vault_supply_ta: None,
+            debt_bank: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    #[inline(always)]
+    pub fn signer(
+        &mut self,
+        signer: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.signer = Some(signer);
+        self
+    }
+    #[inline(always)]
+    pub fn marginfi_program(
+        &mut self,
+        marginfi_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.marginfi_program = Some(marginfi_program);
+        self
+    }
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn ixs_sysvar(
+        &mut self,
+        ixs_sysvar: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.ixs_sysvar = Some(ixs_sysvar);
+        self
+    }
+    #[inline(always)]
+    pub fn solauto_position(
+        &mut self,
+        solauto_position: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.solauto_position = Some(solauto_position);
+        self
+    }
+    #[inline(always)]
+    pub fn marginfi_group(
+        &mut self,
+        marginfi_group: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.marginfi_group = Some(marginfi_group);
+        self
+    }
+    #[inline(always)]
+    pub fn marginfi_account(
+        &mut self,
+        marginfi_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.marginfi_account = Some(marginfi_account);
+        self
+    }
+    #[inline(always)]
+    pub fn emissions_bank(
+        &mut self,
+        emissions_bank: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.emissions_bank = Some(emissions_bank);
+        self
+    }
+    #[inline(always)]
+    pub fn emissions_mint(
+        &mut self,
+        emissions_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.emissions_mint = Some(emissions_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn emissions_auth(
+        &mut self,
+        emissions_auth: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.emissions_auth = Some(emissions_auth);
+        self
+    }
+    #[inline(always)]
+    pub fn emissions_vault(
+        &mut self,
+        emissions_vault: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.emissions_vault = Some(emissions_vault);
+        self
+    }
+    #[inline(always)]
+    pub fn position_emissions_ta(
+        &mut self,
+        position_emissions_ta: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.position_emissions_ta = Some(position_emissions_ta);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn intermediary_ta(
+        &mut self,
+        intermediary_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.intermediary_ta = intermediary_ta;
+        self
+    }
+    #[inline(always)]
+    pub fn supply_bank(
+        &mut self,
+        supply_bank: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.supply_bank = Some(supply_bank);
+        self
+    }
+    #[inline(always)]
+    pub fn position_supply_ta(
+        &mut self,
+        position_supply_ta: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.position_supply_ta = Some(position_supply_ta);
+        self
+    }
+    #[inline(always)]
+    pub fn vault_supply_ta(
+        &mut self,
+        vault_supply_ta: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.vault_supply_ta = Some(vault_supply_ta);
+        self
+    }
+    #[inline(always)]
+    pub fn debt_bank(
+        &mut self,
+        debt_bank: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.debt_bank = Some(debt_bank);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let instruction = MarginfiHarvestEmissionsCpi {
+            __program: self.instruction.__program,
+
+            signer: self.instruction.signer.expect("signer is not set"),
+
+            marginfi_program: self
+                .instruction
+                .marginfi_program
+                .expect("marginfi_program is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            token_program: self
+                .instruction
+                .token_program
+                .expect("token_program is not set"),
+
+            ixs_sysvar: self.instruction.ixs_sysvar.expect("ixs_sysvar is not set"),
+
+            solauto_position: self
+                .instruction
+                .solauto_position
+                .expect("solauto_position is not set"),
+
+            marginfi_group: self
+                .instruction
+                .marginfi_group
+                .expect("marginfi_group is not set"),
+
+            marginfi_account: self
+                .instruction
+                .marginfi_account
+                .expect("marginfi_account is not set"),
+
+            emissions_bank: self
+                .instruction
+                .emissions_bank
+                .expect("emissions_bank is not set"),
+
+            emissions_mint: self
+                .instruction
+                .emissions_mint
+                .expect("emissions_mint is not set"),
+
+            emissions_auth: self
+                .instruction
+                .emissions_auth
+                .expect("emissions_auth is not set"),
+
+            emissions_vault: self
+                .instruction
+                .emissions_vault
+                .expect("emissions_vault is not set"),
+
+            position_emissions_ta: self
+                .instruction
+                .position_emissions_ta
+                .expect("position_emissions_ta is not set"),
+
+            intermediary_ta: self.instruction.intermediary_ta,
+
+            supply_bank: self
+                .instruction
+                .supply_bank
+                .expect("supply_bank is not set"),
+
+            position_supply_ta: self
+                .instruction
+                .position_supply_ta
+                .expect("position_supply_ta is not set"),
+
+            vault_supply_ta: self
+                .instruction
+                .vault_supply_ta
+                .expect("vault_supply_ta is not set"),
+
+            debt_bank: self.instruction.debt_bank.expect("debt_bank is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct MarginfiHarvestEmissionsCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    marginfi_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ixs_sysvar: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    solauto_position: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    marginfi_group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    marginfi_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    emissions_bank: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    emissions_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    emissions_auth: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    emissions_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    position_emissions_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    intermediary_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    supply_bank: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    position_supply_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault_supply_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    debt_bank: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
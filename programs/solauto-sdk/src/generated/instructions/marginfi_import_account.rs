@@ -0,0 +1,1161 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+use crate::generated::types::MarginfiOpenPositionData;
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+pub struct MarginfiImportAccount {
+    pub signer: solana_program::pubkey::Pubkey,
+
+    pub marginfi_program: solana_program::pubkey::Pubkey,
+
+    pub system_program: solana_program::pubkey::Pubkey,
+
+    pub token_program: solana_program::pubkey::Pubkey,
+
+    pub ata_program: solana_program::pubkey::Pubkey,
+
+    pub rent: solana_program::pubkey::Pubkey,
+
+    pub signer_referral_state: solana_program::pubkey::Pubkey,
+
+    pub referred_by_state: Option<solana_program::pubkey::Pubkey>,
+
+    pub referred_by_supply_ta: Option<solana_program::pubkey::Pubkey>,
+
+    pub solauto_position: solana_program::pubkey::Pubkey,
+
+    pub marginfi_group: solana_program::pubkey::Pubkey,
+
+    pub marginfi_account: solana_program::pubkey::Pubkey,
+
+    pub supply_mint: solana_program::pubkey::Pubkey,
+
+    pub supply_bank: solana_program::pubkey::Pubkey,
+
+    pub supply_price_oracle: solana_program::pubkey::Pubkey,
+
+    pub position_supply_ta: solana_program::pubkey::Pubkey,
+
+    pub debt_mint: solana_program::pubkey::Pubkey,
+
+    pub debt_bank: solana_program::pubkey::Pubkey,
+
+    pub debt_price_oracle: solana_program::pubkey::Pubkey,
+
+    pub position_debt_ta: solana_program::pubkey::Pubkey,
+}
+
+impl MarginfiImportAccount {
+    pub fn instruction(
+        &self,
+        args: MarginfiImportAccountInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: MarginfiImportAccountInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(20 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.signer,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.marginfi_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.ata_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.rent, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.signer_referral_state,
+            false,
+        ));
+        if let Some(referred_by_state) = self.referred_by_state {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                referred_by_state,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLAUTO_ID,
+                false,
+            ));
+        }
+        if let Some(referred_by_supply_ta) = self.referred_by_supply_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                referred_by_supply_ta,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLAUTO_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.solauto_position,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.marginfi_group,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.marginfi_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.supply_mint,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.supply_bank,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.supply_price_oracle,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.position_supply_ta,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.debt_mint,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.debt_bank,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.debt_price_oracle,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.position_debt_ta,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = MarginfiImportAccountInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::SOLAUTO_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct MarginfiImportAccountInstructionData {
+    discriminator: u8,
+}
+
+impl MarginfiImportAccountInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 16 }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MarginfiImportAccountInstructionArgs {
+    pub marginfi_open_position_data: MarginfiOpenPositionData,
+}
+
+/// Instruction builder for `MarginfiImportAccount`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` signer
+///   1. `[]` marginfi_program
+///   2. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   3. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   4. `[optional]` ata_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
+///   5. `[optional]` rent (default to `SysvarRent111111111111111111111111111111111`)
+///   6. `[]` signer_referral_state
+///   7. `[optional]` referred_by_state
+///   8. `[writable, optional]` referred_by_supply_ta
+///   9. `[writable]` solauto_position
+///   10. `[]` marginfi_group
+///   11. `[writable]` marginfi_account
+///   12. `[]` supply_mint
+///   13. `[writable]` supply_bank
+///   14. `[]` supply_price_oracle
+///   15. `[writable]` position_supply_ta
+///   16. `[]` debt_mint
+///   17. `[writable]` debt_bank
+///   18. `[]` debt_price_oracle
+///   19. `[writable]` position_debt_ta
+#[derive(Default)]
+pub struct MarginfiImportAccountBuilder {
+    signer: Option<solana_program::pubkey::Pubkey>,
+    marginfi_program: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    ata_program: Option<solana_program::pubkey::Pubkey>,
+    rent: Option<solana_program::pubkey::Pubkey>,
+    signer_referral_state: Option<solana_program::pubkey::Pubkey>,
+    referred_by_state: Option<solana_program::pubkey::Pubkey>,
+    referred_by_supply_ta: Option<solana_program::pubkey::Pubkey>,
+    solauto_position: Option<solana_program::pubkey::Pubkey>,
+    marginfi_group: Option<solana_program::pubkey::Pubkey>,
+    marginfi_account: Option<solana_program::pubkey::Pubkey>,
+    supply_mint: Option<solana_program::pubkey::Pubkey>,
+    supply_bank: Option<solana_program::pubkey::Pubkey>,
+    supply_price_oracle: Option<solana_program::pubkey::Pubkey>,
+    position_supply_ta: Option<solana_program::pubkey::Pubkey>,
+    debt_mint: Option<solana_program::pubkey::Pubkey>,
+    debt_bank: Option<solana_program::pubkey::Pubkey>,
+    debt_price_oracle: Option<solana_program::pubkey::Pubkey>,
+    position_debt_ta: Option<solana_program::pubkey::Pubkey>,
+    marginfi_open_position_data: Option<MarginfiOpenPositionData>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl MarginfiImportAccountBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn signer(&mut self, signer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.signer = Some(signer);
+        self
+    }
+    #[inline(always)]
+    pub fn marginfi_program(
+        &mut self,
+        marginfi_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.marginfi_program = Some(marginfi_program);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    /// `[optional account, default to 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL']`
+    #[inline(always)]
+    pub fn ata_program(&mut self, ata_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.ata_program = Some(ata_program);
+        self
+    }
+    /// `[optional account, default to 'SysvarRent111111111111111111111111111111111']`
+    #[inline(always)]
+    pub fn rent(&mut self, rent: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.rent = Some(rent);
+        self
+    }
+    #[inline(always)]
+    pub fn signer_referral_state(
+        &mut self,
+        signer_referral_state: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.signer_referral_state = Some(signer_referral_state);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn referred_by_state(
+        &mut self,
+        referred_by_state: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.referred_by_state = referred_by_state;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn referred_by_supply_ta(
+        &mut self,
+        referred_by_supply_ta: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.referred_by_supply_ta = referred_by_supply_ta;
+        self
+    }
+    #[inline(always)]
+    pub fn solauto_position(
+        &mut self,
+        solauto_position: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.solauto_position = Some(solauto_position);
+        self
+    }
+    #[inline(always)]
+    pub fn marginfi_group(&mut self, marginfi_group: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.marginfi_group = Some(marginfi_group);
+        self
+    }
+    #[inline(always)]
+    pub fn marginfi_account(
+        &mut self,
+        marginfi_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.marginfi_account = Some(marginfi_account);
+        self
+    }
+    #[inline(always)]
+    pub fn supply_mint(&mut self, supply_mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.supply_mint = Some(supply_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn supply_bank(&mut self, supply_bank: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.supply_bank = Some(supply_bank);
+        self
+    }
+    #[inline(always)]
+    pub fn supply_price_oracle(
+        &mut self,
+        supply_price_oracle: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.supply_price_oracle = Some(supply_price_oracle);
+        self
+    }
+    #[inline(always)]
+    pub fn position_supply_ta(
+        &mut self,
+        position_supply_ta: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.position_supply_ta = Some(position_supply_ta);
+        self
+    }
+    #[inline(always)]
+    pub fn debt_mint(&mut self, debt_mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.debt_mint = Some(debt_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn debt_bank(&mut self, debt_bank: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.debt_bank = Some(debt_bank);
+        self
+    }
+    #[inline(always)]
+    pub fn debt_price_oracle(
+        &mut self,
+        debt_price_oracle: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.debt_price_oracle = Some(debt_price_oracle);
+        self
+    }
+    #[inline(always)]
+    pub fn position_debt_ta(
+        &mut self,
+        position_debt_ta: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.position_debt_ta = Some(position_debt_ta);
+        self
+    }
+    #[inline(always)]
+    pub fn marginfi_open_position_data(
+        &mut self,
+        marginfi_open_position_data: MarginfiOpenPositionData,
+    ) -> &mut Self {
+        self.marginfi_open_position_data = Some(marginfi_open_position_data);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = MarginfiImportAccount {
+            signer: self.signer.expect("signer is not set"),
+            marginfi_program: self.marginfi_program.expect("marginfi_program is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            token_program: self.token_program.unwrap_or(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            ata_program: self.ata_program.unwrap_or(solana_program::pubkey!(
+                "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+            )),
+            rent: self.rent.unwrap_or(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            signer_referral_state: self
+                .signer_referral_state
+                .expect("signer_referral_state is not set"),
+            referred_by_state: self.referred_by_state,
+            referred_by_supply_ta: self.referred_by_supply_ta,
+            solauto_position: self.solauto_position.expect("solauto_position is not set"),
+            marginfi_group: self.marginfi_group.expect("marginfi_group is not set"),
+            marginfi_account: self.marginfi_account.expect("marginfi_account is not set"),
+            supply_mint: self.supply_mint.expect("supply_mint is not set"),
+            supply_bank: self.supply_bank.expect("supply_bank is not set"),
+            supply_price_oracle: self
+                .supply_price_oracle
+                .expect("supply_price_oracle is not set"),
+            position_supply_ta: self
+                .position_supply_ta
+                .expect("position_supply_ta is not set"),
+            debt_mint: self.debt_mint.expect("debt_mint is not set"),
+            debt_bank: self.debt_bank.expect("debt_bank is not set"),
+            debt_price_oracle: self
+                .debt_price_oracle
+                .expect("debt_price_oracle is not set"),
+            position_debt_ta: self.position_debt_ta.expect("position_debt_ta is not set"),
+        };
+        let args = MarginfiImportAccountInstructionArgs {
+            marginfi_open_position_data: self
+                .marginfi_open_position_data
+                .clone()
+                .expect("marginfi_open_position_data is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `marginfi_import_account` CPI accounts.
+pub struct MarginfiImportAccountCpiAccounts<'a, 'b> {
+    pub signer: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub marginfi_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ata_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub rent: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub signer_referral_state: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub referred_by_state: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub referred_by_supply_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub solauto_position: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub marginfi_group: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub marginfi_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub supply_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub supply_bank: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub supply_price_oracle: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub position_supply_ta: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub debt_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub debt_bank: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub debt_price_oracle: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub position_debt_ta: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `marginfi_import_account` CPI instruction.
+pub struct MarginfiImportAccountCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub signer: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub marginfi_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ata_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub rent: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub signer_referral_state: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub referred_by_state: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub referred_by_supply_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub solauto_position: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub marginfi_group: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub marginfi_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub supply_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub supply_bank: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub supply_price_oracle: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub position_supply_ta: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub debt_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub debt_bank: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub debt_price_oracle: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub position_debt_ta: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: MarginfiImportAccountInstructionArgs,
+}
+
+impl<'a, 'b> MarginfiImportAccountCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: MarginfiImportAccountCpiAccounts<'a, 'b>,
+        args: MarginfiImportAccountInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            signer: accounts.signer,
+            marginfi_program: accounts.marginfi_program,
+            system_program: accounts.system_program,
+            token_program: accounts.token_program,
+            ata_program: accounts.ata_program,
+            rent: accounts.rent,
+            signer_referral_state: accounts.signer_referral_state,
+            referred_by_state: accounts.referred_by_state,
+            referred_by_supply_ta: accounts.referred_by_supply_ta,
+            solauto_position: accounts.solauto_position,
+            marginfi_group: accounts.marginfi_group,
+            marginfi_account: accounts.marginfi_account,
+            supply_mint: accounts.supply_mint,
+            supply_bank: accounts.supply_bank,
+            supply_price_oracle: accounts.supply_price_oracle,
+            position_supply_ta: accounts.position_supply_ta,
+            debt_mint: accounts.debt_mint,
+            debt_bank: accounts.debt_bank,
+            debt_price_oracle: accounts.debt_price_oracle,
+            position_debt_ta: accounts.position_debt_ta,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(20 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.signer.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.marginfi_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.ata_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.rent.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.signer_referral_state.key,
+            false,
+        ));
+        if let Some(referred_by_state) = self.referred_by_state {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *referred_by_state.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLAUTO_ID,
+                false,
+            ));
+        }
+        if let Some(referred_by_supply_ta) = self.referred_by_supply_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *referred_by_supply_ta.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLAUTO_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.solauto_position.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.marginfi_group.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.marginfi_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.supply_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.supply_bank.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.supply_price_oracle.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.position_supply_ta.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.debt_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.debt_bank.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.debt_price_oracle.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.position_debt_ta.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = MarginfiImportAccountInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::SOLAUTO_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(20 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.signer.clone());
+        account_infos.push(self.marginfi_program.clone());
+        account_infos.push(self.system_program.clone());
+        account_infos.push(self.token_program.clone());
+        account_infos.push(self.ata_program.clone());
+        account_infos.push(self.rent.clone());
+        account_infos.push(self.signer_referral_state.clone());
+        if let Some(referred_by_state) = self.referred_by_state {
+            account_infos.push(referred_by_state.clone());
+        }
+        if let Some(referred_by_supply_ta) = self.referred_by_supply_ta {
+            account_infos.push(referred_by_supply_ta.clone());
+        }
+        account_infos.push(self.solauto_position.clone());
+        account_infos.push(self.marginfi_group.clone());
+        account_infos.push(self.marginfi_account.clone());
+        account_infos.push(self.supply_mint.clone());
+        account_infos.push(self.supply_bank.clone());
+        account_infos.push(self.supply_price_oracle.clone());
+        account_infos.push(self.position_supply_ta.clone());
+        account_infos.push(self.debt_mint.clone());
+        account_infos.push(self.debt_bank.clone());
+        account_infos.push(self.debt_price_oracle.clone());
+        account_infos.push(self.position_debt_ta.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `MarginfiImportAccount` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` signer
+///   1. `[]` marginfi_program
+///   2. `[]` system_program
+///   3. `[]` token_program
+///   4. `[]` ata_program
+///   5. `[]` rent
+///   6. `[]` signer_referral_state
+///   7. `[optional]` referred_by_state
+///   8. `[writable, optional]` referred_by_supply_ta
+///   9. `[writable]` solauto_position
+///   10. `[]` marginfi_group
+///   11. `[writable]` marginfi_account
+///   12. `[]` supply_mint
+///   13. `[writable]` supply_bank
+///   14. `[]` supply_price_oracle
+///   15. `[writable]` position_supply_ta
+///   16. `[]` debt_mint
+///   17. `[writable]` debt_bank
+///   18. `[]` debt_price_oracle
+///   19. `[writable]` position_debt_ta
+pub struct MarginfiImportAccountCpiBuilder<'a, 'b> {
+    instruction: Box<MarginfiImportAccountCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> MarginfiImportAccountCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(MarginfiImportAccountCpiBuilderInstruction {
+            __program: program,
+            signer: None,
+            marginfi_program: None,
+            system_program: None,
+            token_program: None,
+            ata_program: None,
+            rent: None,
+            signer_referral_state: None,
+            referred_by_state: None,
+            referred_by_supply_ta: None,
+            solauto_position: None,
+            marginfi_group: None,
+            marginfi_account: None,
+            supply_mint: None,
+            supply_bank: None,
+            supply_price_oracle: None,
+            position_supply_ta: None,
+            debt_mint: None,
+            debt_bank: None,
+            debt_price_oracle: None,
+            position_debt_ta: None,
+            marginfi_open_position_data: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    #[inline(always)]
+    pub fn signer(
+        &mut self,
+        signer: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.signer = Some(signer);
+        self
+    }
+    #[inline(always)]
+    pub fn marginfi_program(
+        &mut self,
+        marginfi_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.marginfi_program = Some(marginfi_program);
+        self
+    }
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn ata_program(
+        &mut self,
+        ata_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.ata_program = Some(ata_program);
+        self
+    }
+    #[inline(always)]
+    pub fn rent(&mut self, rent: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.rent = Some(rent);
+        self
+    }
+    #[inline(always)]
+    pub fn signer_referral_state(
+        &mut self,
+        signer_referral_state: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.signer_referral_state = Some(signer_referral_state);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn referred_by_state(
+        &mut self,
+        referred_by_state: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.referred_by_state = referred_by_state;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn referred_by_supply_ta(
+        &mut self,
+        referred_by_supply_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.referred_by_supply_ta = referred_by_supply_ta;
+        self
+    }
+    #[inline(always)]
+    pub fn solauto_position(
+        &mut self,
+        solauto_position: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.solauto_position = Some(solauto_position);
+        self
+    }
+    #[inline(always)]
+    pub fn marginfi_group(
+        &mut self,
+        marginfi_group: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.marginfi_group = Some(marginfi_group);
+        self
+    }
+    #[inline(always)]
+    pub fn marginfi_account(
+        &mut self,
+        marginfi_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.marginfi_account = Some(marginfi_account);
+        self
+    }
+    #[inline(always)]
+    pub fn supply_mint(
+        &mut self,
+        supply_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.supply_mint = Some(supply_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn supply_bank(
+        &mut self,
+        supply_bank: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.supply_bank = Some(supply_bank);
+        self
+    }
+    #[inline(always)]
+    pub fn supply_price_oracle(
+        &mut self,
+        supply_price_oracle: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.supply_price_oracle = Some(supply_price_oracle);
+        self
+    }
+    #[inline(always)]
+    pub fn position_supply_ta(
+        &mut self,
+        position_supply_ta: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.position_supply_ta = Some(position_supply_ta);
+        self
+    }
+    #[inline(always)]
+    pub fn debt_mint(
+        &mut self,
+        debt_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.debt_mint = Some(debt_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn debt_bank(
+        &mut self,
+        debt_bank: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.debt_bank = Some(debt_bank);
+        self
+    }
+    #[inline(always)]
+    pub fn debt_price_oracle(
+        &mut self,
+        debt_price_oracle: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.debt_price_oracle = Some(debt_price_oracle);
+        self
+    }
+    #[inline(always)]
+    pub fn position_debt_ta(
+        &mut self,
+        position_debt_ta: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.position_debt_ta = Some(position_debt_ta);
+        self
+    }
+    #[inline(always)]
+    pub fn marginfi_open_position_data(
+        &mut self,
+        marginfi_open_position_data: MarginfiOpenPositionData,
+    ) -> &mut Self {
+        self.instruction.marginfi_open_position_data = Some(marginfi_open_position_data);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = MarginfiImportAccountInstructionArgs {
+            marginfi_open_position_data: self
+                .instruction
+                .marginfi_open_position_data
+                .clone()
+                .expect("marginfi_open_position_data is not set"),
+        };
+        let instruction = MarginfiImportAccountCpi {
+            __program: self.instruction.__program,
+
+            signer: self.instruction.signer.expect("signer is not set"),
+
+            marginfi_program: self
+                .instruction
+                .marginfi_program
+                .expect("marginfi_program is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            token_program: self
+                .instruction
+                .token_program
+                .expect("token_program is not set"),
+
+            ata_program: self
+                .instruction
+                .ata_program
+                .expect("ata_program is not set"),
+
+            rent: self.instruction.rent.expect("rent is not set"),
+
+            signer_referral_state: self
+                .instruction
+                .signer_referral_state
+                .expect("signer_referral_state is not set"),
+
+            referred_by_state: self.instruction.referred_by_state,
+
+            referred_by_supply_ta: self.instruction.referred_by_supply_ta,
+
+            solauto_position: self
+                .instruction
+                .solauto_position
+                .expect("solauto_position is not set"),
+
+            marginfi_group: self
+                .instruction
+                .marginfi_group
+                .expect("marginfi_group is not set"),
+
+            marginfi_account: self
+                .instruction
+                .marginfi_account
+                .expect("marginfi_account is not set"),
+
+            supply_mint: self
+                .instruction
+                .supply_mint
+                .expect("supply_mint is not set"),
+
+            supply_bank: self
+                .instruction
+                .supply_bank
+                .expect("supply_bank is not set"),
+
+            supply_price_oracle: self
+                .instruction
+                .supply_price_oracle
+                .expect("supply_price_oracle is not set"),
+
+            position_supply_ta: self
+                .instruction
+                .position_supply_ta
+                .expect("position_supply_ta is not set"),
+
+            debt_mint: self.instruction.debt_mint.expect("debt_mint is not set"),
+
+            debt_bank: self.instruction.debt_bank.expect("debt_bank is not set"),
+
+            debt_price_oracle: self
+                .instruction
+                .debt_price_oracle
+                .expect("debt_price_oracle is not set"),
+
+            position_debt_ta: self
+                .instruction
+                .position_debt_ta
+                .expect("position_debt_ta is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct MarginfiImportAccountCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    marginfi_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ata_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    rent: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    signer_referral_state: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    referred_by_state: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    referred_by_supply_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    solauto_position: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    marginfi_group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    marginfi_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    supply_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    supply_bank: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    supply_price_oracle: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    position_supply_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    debt_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    debt_bank: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    debt_price_oracle: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    position_debt_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    marginfi_open_position_data: Option<MarginfiOpenPositionData>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
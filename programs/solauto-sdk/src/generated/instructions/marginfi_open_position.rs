@@ -5,8 +5,7 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
-use crate::generated::types::PositionType;
-use crate::generated::types::UpdatePositionData;
+use crate::generated::types::MarginfiOpenPositionData;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 
@@ -190,8 +189,7 @@ impl MarginfiOpenPositionInstructionData {
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MarginfiOpenPositionInstructionArgs {
-    pub position_type: PositionType,
-    pub position_data: UpdatePositionData,
+    pub marginfi_open_position_data: MarginfiOpenPositionData,
 }
 
 /// Instruction builder for `MarginfiOpenPosition`.
@@ -238,8 +236,7 @@ pub struct MarginfiOpenPositionBuilder {
     debt_bank: Option<solana_program::pubkey::Pubkey>,
     position_debt_ta: Option<solana_program::pubkey::Pubkey>,
     signer_debt_ta: Option<solana_program::pubkey::Pubkey>,
-    position_type: Option<PositionType>,
-    position_data: Option<UpdatePositionData>,
+    marginfi_open_position_data: Option<MarginfiOpenPositionData>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -378,13 +375,11 @@ impl MarginfiOpenPositionBuilder {
         self
     }
     #[inline(always)]
-    pub fn position_type(&mut self, position_type: PositionType) -> &mut Self {
-        self.position_type = Some(position_type);
-        self
-    }
-    #[inline(always)]
-    pub fn position_data(&mut self, position_data: UpdatePositionData) -> &mut Self {
-        self.position_data = Some(position_data);
+    pub fn marginfi_open_position_data(
+        &mut self,
+        marginfi_open_position_data: MarginfiOpenPositionData,
+    ) -> &mut Self {
+        self.marginfi_open_position_data = Some(marginfi_open_position_data);
         self
     }
     /// Add an aditional account to the instruction.
@@ -441,14 +436,10 @@ impl MarginfiOpenPositionBuilder {
             signer_debt_ta: self.signer_debt_ta,
         };
         let args = MarginfiOpenPositionInstructionArgs {
-            position_type: self
-                .position_type
-                .clone()
-                .expect("position_type is not set"),
-            position_data: self
-                .position_data
+            marginfi_open_position_data: self
+                .marginfi_open_position_data
                 .clone()
-                .expect("position_data is not set"),
+                .expect("marginfi_open_position_data is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -810,8 +801,7 @@ impl<'a, 'b> MarginfiOpenPositionCpiBuilder<'a, 'b> {
             debt_bank: None,
             position_debt_ta: None,
             signer_debt_ta: None,
-            position_type: None,
-            position_data: None,
+            marginfi_open_position_data: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -970,13 +960,11 @@ impl<'a, 'b> MarginfiOpenPositionCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
-    pub fn position_type(&mut self, position_type: PositionType) -> &mut Self {
-        self.instruction.position_type = Some(position_type);
-        self
-    }
-    #[inline(always)]
-    pub fn position_data(&mut self, position_data: UpdatePositionData) -> &mut Self {
-        self.instruction.position_data = Some(position_data);
+    pub fn marginfi_open_position_data(
+        &mut self,
+        marginfi_open_position_data: MarginfiOpenPositionData,
+    ) -> &mut Self {
+        self.instruction.marginfi_open_position_data = Some(marginfi_open_position_data);
         self
     }
     /// Add an additional account to the instruction.
@@ -1021,16 +1009,11 @@ impl<'a, 'b> MarginfiOpenPositionCpiBuilder<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
     ) -> solana_program::entrypoint::ProgramResult {
         let args = MarginfiOpenPositionInstructionArgs {
-            position_type: self
-                .instruction
-                .position_type
-                .clone()
-                .expect("position_type is not set"),
-            position_data: self
+            marginfi_open_position_data: self
                 .instruction
-                .position_data
+                .marginfi_open_position_data
                 .clone()
-                .expect("position_data is not set"),
+                .expect("marginfi_open_position_data is not set"),
         };
         let instruction = MarginfiOpenPositionCpi {
             __program: self.instruction.__program,
@@ -1138,8 +1121,7 @@ struct MarginfiOpenPositionCpiBuilderInstruction<'a, 'b> {
     debt_bank: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     position_debt_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     signer_debt_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
-    position_type: Option<PositionType>,
-    position_data: Option<UpdatePositionData>,
+    marginfi_open_position_data: Option<MarginfiOpenPositionData>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
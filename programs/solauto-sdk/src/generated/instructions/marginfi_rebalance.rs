@@ -5,9 +5,7 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
-use crate::generated::types::PriceType;
-use crate::generated::types::SolautoRebalanceType;
-use crate::generated::types::SwapType;
+use crate::generated::types::RebalanceSettings;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 
@@ -291,12 +289,7 @@ impl MarginfiRebalanceInstructionData {
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MarginfiRebalanceInstructionArgs {
-    pub rebalance_type: SolautoRebalanceType,
-    pub swap_in_amount_base_unit: Option<u64>,
-    pub target_liq_utilization_rate_bps: Option<u16>,
-    pub flash_loan_fee_bps: Option<u16>,
-    pub price_type: Option<PriceType>,
-    pub swap_type: Option<SwapType>,
+    pub rebalance_settings: RebalanceSettings,
 }
 
 /// Instruction builder for `MarginfiRebalance`.
@@ -355,12 +348,7 @@ pub struct MarginfiRebalanceBuilder {
     authority_debt_ta: Option<solana_program::pubkey::Pubkey>,
     vault_debt_ta: Option<solana_program::pubkey::Pubkey>,
     debt_vault_authority: Option<solana_program::pubkey::Pubkey>,
-    rebalance_type: Option<SolautoRebalanceType>,
-    swap_in_amount_base_unit: Option<u64>,
-    target_liq_utilization_rate_bps: Option<u16>,
-    flash_loan_fee_bps: Option<u16>,
-    price_type: Option<PriceType>,
-    swap_type: Option<SwapType>,
+    rebalance_settings: Option<RebalanceSettings>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -562,41 +550,8 @@ impl MarginfiRebalanceBuilder {
         self
     }
     #[inline(always)]
-    pub fn rebalance_type(&mut self, rebalance_type: SolautoRebalanceType) -> &mut Self {
-        self.rebalance_type = Some(rebalance_type);
-        self
-    }
-    /// `[optional argument]`
-    #[inline(always)]
-    pub fn swap_in_amount_base_unit(&mut self, swap_in_amount_base_unit: u64) -> &mut Self {
-        self.swap_in_amount_base_unit = Some(swap_in_amount_base_unit);
-        self
-    }
-    /// `[optional argument]`
-    #[inline(always)]
-    pub fn target_liq_utilization_rate_bps(
-        &mut self,
-        target_liq_utilization_rate_bps: u16,
-    ) -> &mut Self {
-        self.target_liq_utilization_rate_bps = Some(target_liq_utilization_rate_bps);
-        self
-    }
-    /// `[optional argument]`
-    #[inline(always)]
-    pub fn flash_loan_fee_bps(&mut self, flash_loan_fee_bps: u16) -> &mut Self {
-        self.flash_loan_fee_bps = Some(flash_loan_fee_bps);
-        self
-    }
-    /// `[optional argument]`
-    #[inline(always)]
-    pub fn price_type(&mut self, price_type: PriceType) -> &mut Self {
-        self.price_type = Some(price_type);
-        self
-    }
-    /// `[optional argument]`
-    #[inline(always)]
-    pub fn swap_type(&mut self, swap_type: SwapType) -> &mut Self {
-        self.swap_type = Some(swap_type);
+    pub fn rebalance_settings(&mut self, rebalance_settings: RebalanceSettings) -> &mut Self {
+        self.rebalance_settings = Some(rebalance_settings);
         self
     }
     /// Add an aditional account to the instruction.
@@ -655,15 +610,10 @@ impl MarginfiRebalanceBuilder {
             debt_vault_authority: self.debt_vault_authority,
         };
         let args = MarginfiRebalanceInstructionArgs {
-            rebalance_type: self
-                .rebalance_type
+            rebalance_settings: self
+                .rebalance_settings
                 .clone()
-                .expect("rebalance_type is not set"),
-            swap_in_amount_base_unit: self.swap_in_amount_base_unit.clone(),
-            target_liq_utilization_rate_bps: self.target_liq_utilization_rate_bps.clone(),
-            flash_loan_fee_bps: self.flash_loan_fee_bps.clone(),
-            price_type: self.price_type.clone(),
-            swap_type: self.swap_type.clone(),
+                .expect("rebalance_settings is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -1178,12 +1128,7 @@ impl<'a, 'b> MarginfiRebalanceCpiBuilder<'a, 'b> {
             authority_debt_ta: None,
             vault_debt_ta: None,
             debt_vault_authority: None,
-            rebalance_type: None,
-            swap_in_amount_base_unit: None,
-            target_liq_utilization_rate_bps: None,
-            flash_loan_fee_bps: None,
-            price_type: None,
-            swap_type: None,
+            rebalance_settings: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -1401,41 +1346,8 @@ impl<'a, 'b> MarginfiRebalanceCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
-    pub fn rebalance_type(&mut self, rebalance_type: SolautoRebalanceType) -> &mut Self {
-        self.instruction.rebalance_type = Some(rebalance_type);
-        self
-    }
-    /// `[optional argument]`
-    #[inline(always)]
-    pub fn swap_in_amount_base_unit(&mut self, swap_in_amount_base_unit: u64) -> &mut Self {
-        self.instruction.swap_in_amount_base_unit = Some(swap_in_amount_base_unit);
-        self
-    }
-    /// `[optional argument]`
-    #[inline(always)]
-    pub fn target_liq_utilization_rate_bps(
-        &mut self,
-        target_liq_utilization_rate_bps: u16,
-    ) -> &mut Self {
-        self.instruction.target_liq_utilization_rate_bps = Some(target_liq_utilization_rate_bps);
-        self
-    }
-    /// `[optional argument]`
-    #[inline(always)]
-    pub fn flash_loan_fee_bps(&mut self, flash_loan_fee_bps: u16) -> &mut Self {
-        self.instruction.flash_loan_fee_bps = Some(flash_loan_fee_bps);
-        self
-    }
-    /// `[optional argument]`
-    #[inline(always)]
-    pub fn price_type(&mut self, price_type: PriceType) -> &mut Self {
-        self.instruction.price_type = Some(price_type);
-        self
-    }
-    /// `[optional argument]`
-    #[inline(always)]
-    pub fn swap_type(&mut self, swap_type: SwapType) -> &mut Self {
-        self.instruction.swap_type = Some(swap_type);
+    pub fn rebalance_settings(&mut self, rebalance_settings: RebalanceSettings) -> &mut Self {
+        self.instruction.rebalance_settings = Some(rebalance_settings);
         self
     }
     /// Add an additional account to the instruction.
@@ -1480,19 +1392,11 @@ impl<'a, 'b> MarginfiRebalanceCpiBuilder<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
     ) -> solana_program::entrypoint::ProgramResult {
         let args = MarginfiRebalanceInstructionArgs {
-            rebalance_type: self
+            rebalance_settings: self
                 .instruction
-                .rebalance_type
+                .rebalance_settings
                 .clone()
-                .expect("rebalance_type is not set"),
-            swap_in_amount_base_unit: self.instruction.swap_in_amount_base_unit.clone(),
-            target_liq_utilization_rate_bps: self
-                .instruction
-                .target_liq_utilization_rate_bps
-                .clone(),
-            flash_loan_fee_bps: self.instruction.flash_loan_fee_bps.clone(),
-            price_type: self.instruction.price_type.clone(),
-            swap_type: self.instruction.swap_type.clone(),
+                .expect("rebalance_settings is not set"),
         };
         let instruction = MarginfiRebalanceCpi {
             __program: self.instruction.__program,
@@ -1612,12 +1516,7 @@ struct MarginfiRebalanceCpiBuilderInstruction<'a, 'b> {
     authority_debt_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     vault_debt_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     debt_vault_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
-    rebalance_type: Option<SolautoRebalanceType>,
-    swap_in_amount_base_unit: Option<u64>,
-    target_liq_utilization_rate_bps: Option<u16>,
-    flash_loan_fee_bps: Option<u16>,
-    price_type: Option<PriceType>,
-    swap_type: Option<SwapType>,
+    rebalance_settings: Option<RebalanceSettings>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
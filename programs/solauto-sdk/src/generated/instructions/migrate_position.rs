@@ -0,0 +1,890 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+use crate::generated::types::LendingPlatform;
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+pub struct MigratePosition {
+    pub signer: solana_program::pubkey::Pubkey,
+
+    pub source_lending_program: solana_program::pubkey::Pubkey,
+
+    pub destination_lending_program: solana_program::pubkey::Pubkey,
+
+    pub system_program: solana_program::pubkey::Pubkey,
+
+    pub token_program: solana_program::pubkey::Pubkey,
+
+    pub ata_program: solana_program::pubkey::Pubkey,
+
+    pub rent: solana_program::pubkey::Pubkey,
+
+    pub ixs_sysvar: solana_program::pubkey::Pubkey,
+
+    pub solauto_position: solana_program::pubkey::Pubkey,
+
+    pub intermediary_ta: solana_program::pubkey::Pubkey,
+
+    pub supply_mint: solana_program::pubkey::Pubkey,
+
+    pub position_supply_ta: solana_program::pubkey::Pubkey,
+
+    pub debt_mint: solana_program::pubkey::Pubkey,
+
+    pub position_debt_ta: solana_program::pubkey::Pubkey,
+}
+
+impl MigratePosition {
+    pub fn instruction(
+        &self,
+        args: MigratePositionInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: MigratePositionInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.signer,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.source_lending_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.destination_lending_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.ata_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.rent, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.ixs_sysvar,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.solauto_position,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.intermediary_ta,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.supply_mint,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.position_supply_ta,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.debt_mint,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.position_debt_ta,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = MigratePositionInstructionData::new().try_to_vec().unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::SOLAUTO_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct MigratePositionInstructionData {
+    discriminator: u8,
+}
+
+impl MigratePositionInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 15 }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MigratePositionInstructionArgs {
+    pub lending_platform: LendingPlatform,
+    pub source_accounts_len: u8,
+}
+
+/// Instruction builder for `MigratePosition`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` signer
+///   1. `[]` source_lending_program
+///   2. `[]` destination_lending_program
+///   3. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   4. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   5. `[optional]` ata_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
+///   6. `[optional]` rent (default to `SysvarRent111111111111111111111111111111111`)
+///   7. `[]` ixs_sysvar
+///   8. `[writable]` solauto_position
+///   9. `[writable]` intermediary_ta
+///   10. `[]` supply_mint
+///   11. `[writable]` position_supply_ta
+///   12. `[]` debt_mint
+///   13. `[writable]` position_debt_ta
+#[derive(Default)]
+pub struct MigratePositionBuilder {
+    signer: Option<solana_program::pubkey::Pubkey>,
+    source_lending_program: Option<solana_program::pubkey::Pubkey>,
+    destination_lending_program: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    ata_program: Option<solana_program::pubkey::Pubkey>,
+    rent: Option<solana_program::pubkey::Pubkey>,
+    ixs_sysvar: Option<solana_program::pubkey::Pubkey>,
+    solauto_position: Option<solana_program::pubkey::Pubkey>,
+    intermediary_ta: Option<solana_program::pubkey::Pubkey>,
+    supply_mint: Option<solana_program::pubkey::Pubkey>,
+    position_supply_ta: Option<solana_program::pubkey::Pubkey>,
+    debt_mint: Option<solana_program::pubkey::Pubkey>,
+    position_debt_ta: Option<solana_program::pubkey::Pubkey>,
+    lending_platform: Option<LendingPlatform>,
+    source_accounts_len: Option<u8>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl MigratePositionBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn signer(&mut self, signer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.signer = Some(signer);
+        self
+    }
+    #[inline(always)]
+    pub fn source_lending_program(
+        &mut self,
+        source_lending_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.source_lending_program = Some(source_lending_program);
+        self
+    }
+    #[inline(always)]
+    pub fn destination_lending_program(
+        &mut self,
+        destination_lending_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.destination_lending_program = Some(destination_lending_program);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    /// `[optional account, default to 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL']`
+    #[inline(always)]
+    pub fn ata_program(&mut self, ata_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.ata_program = Some(ata_program);
+        self
+    }
+    /// `[optional account, default to 'SysvarRent111111111111111111111111111111111']`
+    #[inline(always)]
+    pub fn rent(&mut self, rent: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.rent = Some(rent);
+        self
+    }
+    #[inline(always)]
+    pub fn ixs_sysvar(&mut self, ixs_sysvar: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.ixs_sysvar = Some(ixs_sysvar);
+        self
+    }
+    #[inline(always)]
+    pub fn solauto_position(
+        &mut self,
+        solauto_position: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.solauto_position = Some(solauto_position);
+        self
+    }
+    #[inline(always)]
+    pub fn intermediary_ta(
+        &mut self,
+        intermediary_ta: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.intermediary_ta = Some(intermediary_ta);
+        self
+    }
+    #[inline(always)]
+    pub fn supply_mint(&mut self, supply_mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.supply_mint = Some(supply_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn position_supply_ta(
+        &mut self,
+        position_supply_ta: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.position_supply_ta = Some(position_supply_ta);
+        self
+    }
+    #[inline(always)]
+    pub fn debt_mint(&mut self, debt_mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.debt_mint = Some(debt_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn position_debt_ta(
+        &mut self,
+        position_debt_ta: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.position_debt_ta = Some(position_debt_ta);
+        self
+    }
+    #[inline(always)]
+    pub fn lending_platform(&mut self, lending_platform: LendingPlatform) -> &mut Self {
+        self.lending_platform = Some(lending_platform);
+        self
+    }
+    #[inline(always)]
+    pub fn source_accounts_len(&mut self, source_accounts_len: u8) -> &mut Self {
+        self.source_accounts_len = Some(source_accounts_len);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = MigratePosition {
+            signer: self.signer.expect("signer is not set"),
+            source_lending_program: self
+                .source_lending_program
+                .expect("source_lending_program is not set"),
+            destination_lending_program: self
+                .destination_lending_program
+                .expect("destination_lending_program is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            token_program: self.token_program.unwrap_or(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            ata_program: self.ata_program.unwrap_or(solana_program::pubkey!(
+                "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+            )),
+            rent: self.rent.unwrap_or(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            ixs_sysvar: self.ixs_sysvar.expect("ixs_sysvar is not set"),
+            solauto_position: self.solauto_position.expect("solauto_position is not set"),
+            intermediary_ta: self.intermediary_ta.expect("intermediary_ta is not set"),
+            supply_mint: self.supply_mint.expect("supply_mint is not set"),
+            position_supply_ta: self
+                .position_supply_ta
+                .expect("position_supply_ta is not set"),
+            debt_mint: self.debt_mint.expect("debt_mint is not set"),
+            position_debt_ta: self.position_debt_ta.expect("position_debt_ta is not set"),
+        };
+        let args = MigratePositionInstructionArgs {
+            lending_platform: self
+                .lending_platform
+                .clone()
+                .expect("lending_platform is not set"),
+            source_accounts_len: self
+                .source_accounts_len
+                .clone()
+                .expect("source_accounts_len is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `migrate_position` CPI accounts.
+pub struct MigratePositionCpiAccounts<'a, 'b> {
+    pub signer: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub source_lending_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub destination_lending_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ata_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub rent: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ixs_sysvar: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub solauto_position: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub intermediary_ta: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub supply_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub position_supply_ta: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub debt_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub position_debt_ta: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `migrate_position` CPI instruction.
+pub struct MigratePositionCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub signer: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub source_lending_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub destination_lending_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ata_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub rent: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ixs_sysvar: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub solauto_position: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub intermediary_ta: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub supply_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub position_supply_ta: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub debt_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub position_debt_ta: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: MigratePositionInstructionArgs,
+}
+
+impl<'a, 'b> MigratePositionCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: MigratePositionCpiAccounts<'a, 'b>,
+        args: MigratePositionInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            signer: accounts.signer,
+            source_lending_program: accounts.source_lending_program,
+            destination_lending_program: accounts.destination_lending_program,
+            system_program: accounts.system_program,
+            token_program: accounts.token_program,
+            ata_program: accounts.ata_program,
+            rent: accounts.rent,
+            ixs_sysvar: accounts.ixs_sysvar,
+            solauto_position: accounts.solauto_position,
+            intermediary_ta: accounts.intermediary_ta,
+            supply_mint: accounts.supply_mint,
+            position_supply_ta: accounts.position_supply_ta,
+            debt_mint: accounts.debt_mint,
+            position_debt_ta: accounts.position_debt_ta,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.signer.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.source_lending_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.destination_lending_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.ata_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.rent.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.ixs_sysvar.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.solauto_position.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.intermediary_ta.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.supply_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.position_supply_ta.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.debt_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.position_debt_ta.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = MigratePositionInstructionData::new().try_to_vec().unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::SOLAUTO_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(14 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.signer.clone());
+        account_infos.push(self.source_lending_program.clone());
+        account_infos.push(self.destination_lending_program.clone());
+        account_infos.push(self.system_program.clone());
+        account_infos.push(self.token_program.clone());
+        account_infos.push(self.ata_program.clone());
+        account_infos.push(self.rent.clone());
+        account_infos.push(self.ixs_sysvar.clone());
+        account_infos.push(self.solauto_position.clone());
+        account_infos.push(self.intermediary_ta.clone());
+        account_infos.push(self.supply_mint.clone());
+        account_infos.push(self.position_supply_ta.clone());
+        account_infos.push(self.debt_mint.clone());
+        account_infos.push(self.position_debt_ta.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `MigratePosition` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` signer
+///   1. `[]` source_lending_program
+///   2. `[]` destination_lending_program
+///   3. `[]` system_program
+///   4. `[]` token_program
+///   5. `[]` ata_program
+///   6. `[]` rent
+///   7. `[]` ixs_sysvar
+///   8. `[writable]` solauto_position
+///   9. `[writable]` intermediary_ta
+///   10. `[]` supply_mint
+///   11. `[writable]` position_supply_ta
+///   12. `[]` debt_mint
+///   13. `[writable]` position_debt_ta
+pub struct MigratePositionCpiBuilder<'a, 'b> {
+    instruction: Box<MigratePositionCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> MigratePositionCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(MigratePositionCpiBuilderInstruction {
+            __program: program,
+            signer: None,
+            source_lending_program: None,
+            destination_lending_program: None,
+            system_program: None,
+            token_program: None,
+            ata_program: None,
+            rent: None,
+            ixs_sysvar: None,
+            solauto_position: None,
+            intermediary_ta: None,
+            supply_mint: None,
+            position_supply_ta: None,
+            debt_mint: None,
+            position_debt_ta: None,
+            lending_platform: None,
+            source_accounts_len: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    #[inline(always)]
+    pub fn signer(
+        &mut self,
+        signer: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.signer = Some(signer);
+        self
+    }
+    #[inline(always)]
+    pub fn source_lending_program(
+        &mut self,
+        source_lending_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.source_lending_program = Some(source_lending_program);
+        self
+    }
+    #[inline(always)]
+    pub fn destination_lending_program(
+        &mut self,
+        destination_lending_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.destination_lending_program = Some(destination_lending_program);
+        self
+    }
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn ata_program(
+        &mut self,
+        ata_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.ata_program = Some(ata_program);
+        self
+    }
+    #[inline(always)]
+    pub fn rent(&mut self, rent: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.rent = Some(rent);
+        self
+    }
+    #[inline(always)]
+    pub fn ixs_sysvar(
+        &mut self,
+        ixs_sysvar: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.ixs_sysvar = Some(ixs_sysvar);
+        self
+    }
+    #[inline(always)]
+    pub fn solauto_position(
+        &mut self,
+        solauto_position: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.solauto_position = Some(solauto_position);
+        self
+    }
+    #[inline(always)]
+    pub fn intermediary_ta(
+        &mut self,
+        intermediary_ta: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.intermediary_ta = Some(intermediary_ta);
+        self
+    }
+    #[inline(always)]
+    pub fn supply_mint(
+        &mut self,
+        supply_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.supply_mint = Some(supply_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn position_supply_ta(
+        &mut self,
+        position_supply_ta: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.position_supply_ta = Some(position_supply_ta);
+        self
+    }
+    #[inline(always)]
+    pub fn debt_mint(
+        &mut self,
+        debt_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.debt_mint = Some(debt_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn position_debt_ta(
+        &mut self,
+        position_debt_ta: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.position_debt_ta = Some(position_debt_ta);
+        self
+    }
+    #[inline(always)]
+    pub fn lending_platform(&mut self, lending_platform: LendingPlatform) -> &mut Self {
+        self.instruction.lending_platform = Some(lending_platform);
+        self
+    }
+    #[inline(always)]
+    pub fn source_accounts_len(&mut self, source_accounts_len: u8) -> &mut Self {
+        self.instruction.source_accounts_len = Some(source_accounts_len);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = MigratePositionInstructionArgs {
+            lending_platform: self
+                .instruction
+                .lending_platform
+                .clone()
+                .expect("lending_platform is not set"),
+            source_accounts_len: self
+                .instruction
+                .source_accounts_len
+                .clone()
+                .expect("source_accounts_len is not set"),
+        };
+        let instruction = MigratePositionCpi {
+            __program: self.instruction.__program,
+
+            signer: self.instruction.signer.expect("signer is not set"),
+
+            source_lending_program: self
+                .instruction
+                .source_lending_program
+                .expect("source_lending_program is not set"),
+
+            destination_lending_program: self
+                .instruction
+                .destination_lending_program
+                .expect("destination_lending_program is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            token_program: self
+                .instruction
+                .token_program
+                .expect("token_program is not set"),
+
+            ata_program: self
+                .instruction
+                .ata_program
+                .expect("ata_program is not set"),
+
+            rent: self.instruction.rent.expect("rent is not set"),
+
+            ixs_sysvar: self.instruction.ixs_sysvar.expect("ixs_sysvar is not set"),
+
+            solauto_position: self
+                .instruction
+                .solauto_position
+                .expect("solauto_position is not set"),
+
+            intermediary_ta: self
+                .instruction
+                .intermediary_ta
+                .expect("intermediary_ta is not set"),
+
+            supply_mint: self
+                .instruction
+                .supply_mint
+                .expect("supply_mint is not set"),
+
+            position_supply_ta: self
+                .instruction
+                .position_supply_ta
+                .expect("position_supply_ta is not set"),
+
+            debt_mint: self.instruction.debt_mint.expect("debt_mint is not set"),
+
+            position_debt_ta: self
+                .instruction
+                .position_debt_ta
+                .expect("position_debt_ta is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct MigratePositionCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    source_lending_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    destination_lending_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ata_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    rent: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ixs_sysvar: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    solauto_position: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    intermediary_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    supply_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    position_supply_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    debt_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    position_debt_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    lending_platform: Option<LendingPlatform>,
+    source_accounts_len: Option<u8>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
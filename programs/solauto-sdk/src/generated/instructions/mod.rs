@@ -6,23 +6,45 @@
 //!
 
 pub(crate) mod r#cancel_d_c_a;
+pub(crate) mod r#cancel_limit_order;
 pub(crate) mod r#claim_referral_fees;
 pub(crate) mod r#close_position;
 pub(crate) mod r#convert_referral_fees;
+pub(crate) mod r#marginfi_export_account;
+pub(crate) mod r#marginfi_harvest_emissions;
+pub(crate) mod r#marginfi_import_account;
 pub(crate) mod r#marginfi_open_position;
 pub(crate) mod r#marginfi_protocol_interaction;
 pub(crate) mod r#marginfi_rebalance;
 pub(crate) mod r#marginfi_refresh_data;
+pub(crate) mod r#migrate_position;
+pub(crate) mod r#open_position;
+pub(crate) mod r#place_limit_order;
+pub(crate) mod r#protocol_interaction;
+pub(crate) mod r#rebalance;
+pub(crate) mod r#refresh_data;
 pub(crate) mod r#update_position;
 pub(crate) mod r#update_referral_states;
+pub(crate) mod r#upgrade_position;
 
 pub use self::r#cancel_d_c_a::*;
+pub use self::r#cancel_limit_order::*;
 pub use self::r#claim_referral_fees::*;
 pub use self::r#close_position::*;
 pub use self::r#convert_referral_fees::*;
+pub use self::r#marginfi_export_account::*;
+pub use self::r#marginfi_harvest_emissions::*;
+pub use self::r#marginfi_import_account::*;
 pub use self::r#marginfi_open_position::*;
 pub use self::r#marginfi_protocol_interaction::*;
 pub use self::r#marginfi_rebalance::*;
 pub use self::r#marginfi_refresh_data::*;
+pub use self::r#migrate_position::*;
+pub use self::r#open_position::*;
+pub use self::r#place_limit_order::*;
+pub use self::r#protocol_interaction::*;
+pub use self::r#rebalance::*;
+pub use self::r#refresh_data::*;
 pub use self::r#update_position::*;
 pub use self::r#update_referral_states::*;
+pub use self::r#upgrade_position::*;
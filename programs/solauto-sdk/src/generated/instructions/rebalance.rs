@@ -0,0 +1,1091 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+use crate::generated::types::RebalanceSettings;
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+pub struct Rebalance {
+    pub signer: solana_program::pubkey::Pubkey,
+
+    pub lending_program: solana_program::pubkey::Pubkey,
+
+    pub system_program: solana_program::pubkey::Pubkey,
+
+    pub token_program: solana_program::pubkey::Pubkey,
+
+    pub ixs_sysvar: solana_program::pubkey::Pubkey,
+
+    pub solauto_fees_ta: Option<solana_program::pubkey::Pubkey>,
+
+    pub authority_referral_state: solana_program::pubkey::Pubkey,
+
+    pub referred_by_ta: Option<solana_program::pubkey::Pubkey>,
+
+    pub position_authority: Option<solana_program::pubkey::Pubkey>,
+
+    pub solauto_position: solana_program::pubkey::Pubkey,
+
+    pub intermediary_ta: Option<solana_program::pubkey::Pubkey>,
+
+    pub supply_mint: solana_program::pubkey::Pubkey,
+
+    pub position_supply_ta: solana_program::pubkey::Pubkey,
+
+    pub authority_supply_ta: Option<solana_program::pubkey::Pubkey>,
+
+    pub debt_mint: solana_program::pubkey::Pubkey,
+
+    pub position_debt_ta: solana_program::pubkey::Pubkey,
+
+    pub authority_debt_ta: Option<solana_program::pubkey::Pubkey>,
+}
+
+impl Rebalance {
+    pub fn instruction(
+        &self,
+        args: RebalanceInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: RebalanceInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(17 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.signer,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.lending_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.ixs_sysvar,
+            false,
+        ));
+        if let Some(solauto_fees_ta) = self.solauto_fees_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                solauto_fees_ta,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLAUTO_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.authority_referral_state,
+            false,
+        ));
+        if let Some(referred_by_ta) = self.referred_by_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                referred_by_ta,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLAUTO_ID,
+                false,
+            ));
+        }
+        if let Some(position_authority) = self.position_authority {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                position_authority,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLAUTO_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.solauto_position,
+            false,
+        ));
+        if let Some(intermediary_ta) = self.intermediary_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                intermediary_ta,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLAUTO_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.supply_mint,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.position_supply_ta,
+            false,
+        ));
+        if let Some(authority_supply_ta) = self.authority_supply_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                authority_supply_ta,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLAUTO_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.debt_mint,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.position_debt_ta,
+            false,
+        ));
+        if let Some(authority_debt_ta) = self.authority_debt_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                authority_debt_ta,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLAUTO_ID,
+                false,
+            ));
+        }
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = RebalanceInstructionData::new().try_to_vec().unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::SOLAUTO_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct RebalanceInstructionData {
+    discriminator: u8,
+}
+
+impl RebalanceInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 13 }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RebalanceInstructionArgs {
+    pub rebalance_settings: RebalanceSettings,
+}
+
+/// Instruction builder for `Rebalance`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` signer
+///   1. `[]` lending_program
+///   2. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   3. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   4. `[]` ixs_sysvar
+///   5. `[writable, optional]` solauto_fees_ta
+///   6. `[]` authority_referral_state
+///   7. `[writable, optional]` referred_by_ta
+///   8. `[writable, optional]` position_authority
+///   9. `[writable]` solauto_position
+///   10. `[writable, optional]` intermediary_ta
+///   11. `[]` supply_mint
+///   12. `[writable]` position_supply_ta
+///   13. `[writable, optional]` authority_supply_ta
+///   14. `[]` debt_mint
+///   15. `[writable]` position_debt_ta
+///   16. `[writable, optional]` authority_debt_ta
+#[derive(Default)]
+pub struct RebalanceBuilder {
+    signer: Option<solana_program::pubkey::Pubkey>,
+    lending_program: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    ixs_sysvar: Option<solana_program::pubkey::Pubkey>,
+    solauto_fees_ta: Option<solana_program::pubkey::Pubkey>,
+    authority_referral_state: Option<solana_program::pubkey::Pubkey>,
+    referred_by_ta: Option<solana_program::pubkey::Pubkey>,
+    position_authority: Option<solana_program::pubkey::Pubkey>,
+    solauto_position: Option<solana_program::pubkey::Pubkey>,
+    intermediary_ta: Option<solana_program::pubkey::Pubkey>,
+    supply_mint: Option<solana_program::pubkey::Pubkey>,
+    position_supply_ta: Option<solana_program::pubkey::Pubkey>,
+    authority_supply_ta: Option<solana_program::pubkey::Pubkey>,
+    debt_mint: Option<solana_program::pubkey::Pubkey>,
+    position_debt_ta: Option<solana_program::pubkey::Pubkey>,
+    authority_debt_ta: Option<solana_program::pubkey::Pubkey>,
+    rebalance_settings: Option<RebalanceSettings>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl RebalanceBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn signer(&mut self, signer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.signer = Some(signer);
+        self
+    }
+    #[inline(always)]
+    pub fn lending_program(
+        &mut self,
+        lending_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.lending_program = Some(lending_program);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn ixs_sysvar(&mut self, ixs_sysvar: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.ixs_sysvar = Some(ixs_sysvar);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn solauto_fees_ta(
+        &mut self,
+        solauto_fees_ta: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.solauto_fees_ta = solauto_fees_ta;
+        self
+    }
+    #[inline(always)]
+    pub fn authority_referral_state(
+        &mut self,
+        authority_referral_state: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.authority_referral_state = Some(authority_referral_state);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn referred_by_ta(
+        &mut self,
+        referred_by_ta: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.referred_by_ta = referred_by_ta;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn position_authority(
+        &mut self,
+        position_authority: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.position_authority = position_authority;
+        self
+    }
+    #[inline(always)]
+    pub fn solauto_position(
+        &mut self,
+        solauto_position: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.solauto_position = Some(solauto_position);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn intermediary_ta(
+        &mut self,
+        intermediary_ta: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.intermediary_ta = intermediary_ta;
+        self
+    }
+    #[inline(always)]
+    pub fn supply_mint(&mut self, supply_mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.supply_mint = Some(supply_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn position_supply_ta(
+        &mut self,
+        position_supply_ta: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.position_supply_ta = Some(position_supply_ta);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn authority_supply_ta(
+        &mut self,
+        authority_supply_ta: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.authority_supply_ta = authority_supply_ta;
+        self
+    }
+    #[inline(always)]
+    pub fn debt_mint(&mut self, debt_mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.debt_mint = Some(debt_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn position_debt_ta(
+        &mut self,
+        position_debt_ta: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.position_debt_ta = Some(position_debt_ta);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn authority_debt_ta(
+        &mut self,
+        authority_debt_ta: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.authority_debt_ta = authority_debt_ta;
+        self
+    }
+    #[inline(always)]
+    pub fn rebalance_settings(&mut self, rebalance_settings: RebalanceSettings) -> &mut Self {
+        self.rebalance_settings = Some(rebalance_settings);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = Rebalance {
+            signer: self.signer.expect("signer is not set"),
+            lending_program: self.lending_program.expect("lending_program is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            token_program: self.token_program.unwrap_or(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            ixs_sysvar: self.ixs_sysvar.expect("ixs_sysvar is not set"),
+            solauto_fees_ta: self.solauto_fees_ta,
+            authority_referral_state: self
+                .authority_referral_state
+                .expect("authority_referral_state is not set"),
+            referred_by_ta: self.referred_by_ta,
+            position_authority: self.position_authority,
+            solauto_position: self.solauto_position.expect("solauto_position is not set"),
+            intermediary_ta: self.intermediary_ta,
+            supply_mint: self.supply_mint.expect("supply_mint is not set"),
+            position_supply_ta: self
+                .position_supply_ta
+                .expect("position_supply_ta is not set"),
+            authority_supply_ta: self.authority_supply_ta,
+            debt_mint: self.debt_mint.expect("debt_mint is not set"),
+            position_debt_ta: self.position_debt_ta.expect("position_debt_ta is not set"),
+            authority_debt_ta: self.authority_debt_ta,
+        };
+        let args = RebalanceInstructionArgs {
+            rebalance_settings: self
+                .rebalance_settings
+                .clone()
+                .expect("rebalance_settings is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `rebalance` CPI accounts.
+pub struct RebalanceCpiAccounts<'a, 'b> {
+    pub signer: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub lending_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ixs_sysvar: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub solauto_fees_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub authority_referral_state: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub referred_by_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub position_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub solauto_position: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub intermediary_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub supply_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub position_supply_ta: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub authority_supply_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub debt_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub position_debt_ta: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub authority_debt_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+}
+
+/// `rebalance` CPI instruction.
+pub struct RebalanceCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub signer: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub lending_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ixs_sysvar: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub solauto_fees_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub authority_referral_state: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub referred_by_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub position_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub solauto_position: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub intermediary_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub supply_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub position_supply_ta: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub authority_supply_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub debt_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub position_debt_ta: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub authority_debt_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The arguments for the instruction.
+    pub __args: RebalanceInstructionArgs,
+}
+
+impl<'a, 'b> RebalanceCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: RebalanceCpiAccounts<'a, 'b>,
+        args: RebalanceInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            signer: accounts.signer,
+            lending_program: accounts.lending_program,
+            system_program: accounts.system_program,
+            token_program: accounts.token_program,
+            ixs_sysvar: accounts.ixs_sysvar,
+            solauto_fees_ta: accounts.solauto_fees_ta,
+            authority_referral_state: accounts.authority_referral_state,
+            referred_by_ta: accounts.referred_by_ta,
+            position_authority: accounts.position_authority,
+            solauto_position: accounts.solauto_position,
+            intermediary_ta: accounts.intermediary_ta,
+            supply_mint: accounts.supply_mint,
+            position_supply_ta: accounts.position_supply_ta,
+            authority_supply_ta: accounts.authority_supply_ta,
+            debt_mint: accounts.debt_mint,
+            position_debt_ta: accounts.position_debt_ta,
+            authority_debt_ta: accounts.authority_debt_ta,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(17 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.signer.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.lending_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.ixs_sysvar.key,
+            false,
+        ));
+        if let Some(solauto_fees_ta) = self.solauto_fees_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *solauto_fees_ta.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLAUTO_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.authority_referral_state.key,
+            false,
+        ));
+        if let Some(referred_by_ta) = self.referred_by_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *referred_by_ta.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLAUTO_ID,
+                false,
+            ));
+        }
+        if let Some(position_authority) = self.position_authority {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *position_authority.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLAUTO_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.solauto_position.key,
+            false,
+        ));
+        if let Some(intermediary_ta) = self.intermediary_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *intermediary_ta.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLAUTO_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.supply_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.position_supply_ta.key,
+            false,
+        ));
+        if let Some(authority_supply_ta) = self.authority_supply_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *authority_supply_ta.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLAUTO_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.debt_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.position_debt_ta.key,
+            false,
+        ));
+        if let Some(authority_debt_ta) = self.authority_debt_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *authority_debt_ta.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLAUTO_ID,
+                false,
+            ));
+        }
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = RebalanceInstructionData::new().try_to_vec().unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::SOLAUTO_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(17 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.signer.clone());
+        account_infos.push(self.lending_program.clone());
+        account_infos.push(self.system_program.clone());
+        account_infos.push(self.token_program.clone());
+        account_infos.push(self.ixs_sysvar.clone());
+        if let Some(solauto_fees_ta) = self.solauto_fees_ta {
+            account_infos.push(solauto_fees_ta.clone());
+        }
+        account_infos.push(self.authority_referral_state.clone());
+        if let Some(referred_by_ta) = self.referred_by_ta {
+            account_infos.push(referred_by_ta.clone());
+        }
+        if let Some(position_authority) = self.position_authority {
+            account_infos.push(position_authority.clone());
+        }
+        account_infos.push(self.solauto_position.clone());
+        if let Some(intermediary_ta) = self.intermediary_ta {
+            account_infos.push(intermediary_ta.clone());
+        }
+        account_infos.push(self.supply_mint.clone());
+        account_infos.push(self.position_supply_ta.clone());
+        if let Some(authority_supply_ta) = self.authority_supply_ta {
+            account_infos.push(authority_supply_ta.clone());
+        }
+        account_infos.push(self.debt_mint.clone());
+        account_infos.push(self.position_debt_ta.clone());
+        if let Some(authority_debt_ta) = self.authority_debt_ta {
+            account_infos.push(authority_debt_ta.clone());
+        }
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `Rebalance` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` signer
+///   1. `[]` lending_program
+///   2. `[]` system_program
+///   3. `[]` token_program
+///   4. `[]` ixs_sysvar
+///   5. `[writable, optional]` solauto_fees_ta
+///   6. `[]` authority_referral_state
+///   7. `[writable, optional]` referred_by_ta
+///   8. `[writable, optional]` position_authority
+///   9. `[writable]` solauto_position
+///   10. `[writable, optional]` intermediary_ta
+///   11. `[]` supply_mint
+///   12. `[writable]` position_supply_ta
+///   13. `[writable, optional]` authority_supply_ta
+///   14. `[]` debt_mint
+///   15. `[writable]` position_debt_ta
+///   16. `[writable, optional]` authority_debt_ta
+pub struct RebalanceCpiBuilder<'a, 'b> {
+    instruction: Box<RebalanceCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> RebalanceCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(RebalanceCpiBuilderInstruction {
+            __program: program,
+            signer: None,
+            lending_program: None,
+            system_program: None,
+            token_program: None,
+            ixs_sysvar: None,
+            solauto_fees_ta: None,
+            authority_referral_state: None,
+            referred_by_ta: None,
+            position_authority: None,
+            solauto_position: None,
+            intermediary_ta: None,
+            supply_mint: None,
+            position_supply_ta: None,
+            authority_supply_ta: None,
+            debt_mint: None,
+            position_debt_ta: None,
+            authority_debt_ta: None,
+            rebalance_settings: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    #[inline(always)]
+    pub fn signer(
+        &mut self,
+        signer: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.signer = Some(signer);
+        self
+    }
+    #[inline(always)]
+    pub fn lending_program(
+        &mut self,
+        lending_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.lending_program = Some(lending_program);
+        self
+    }
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn ixs_sysvar(
+        &mut self,
+        ixs_sysvar: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.ixs_sysvar = Some(ixs_sysvar);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn solauto_fees_ta(
+        &mut self,
+        solauto_fees_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.solauto_fees_ta = solauto_fees_ta;
+        self
+    }
+    #[inline(always)]
+    pub fn authority_referral_state(
+        &mut self,
+        authority_referral_state: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.authority_referral_state = Some(authority_referral_state);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn referred_by_ta(
+        &mut self,
+        referred_by_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.referred_by_ta = referred_by_ta;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn position_authority(
+        &mut self,
+        position_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.position_authority = position_authority;
+        self
+    }
+    #[inline(always)]
+    pub fn solauto_position(
+        &mut self,
+        solauto_position: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.solauto_position = Some(solauto_position);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn intermediary_ta(
+        &mut self,
+        intermediary_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.intermediary_ta = intermediary_ta;
+        self
+    }
+    #[inline(always)]
+    pub fn supply_mint(
+        &mut self,
+        supply_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.supply_mint = Some(supply_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn position_supply_ta(
+        &mut self,
+        position_supply_ta: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.position_supply_ta = Some(position_supply_ta);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn authority_supply_ta(
+        &mut self,
+        authority_supply_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.authority_supply_ta = authority_supply_ta;
+        self
+    }
+    #[inline(always)]
+    pub fn debt_mint(
+        &mut self,
+        debt_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.debt_mint = Some(debt_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn position_debt_ta(
+        &mut self,
+        position_debt_ta: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.position_debt_ta = Some(position_debt_ta);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn authority_debt_ta(
+        &mut self,
+        authority_debt_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.authority_debt_ta = authority_debt_ta;
+        self
+    }
+    #[inline(always)]
+    pub fn rebalance_settings(&mut self, rebalance_settings: RebalanceSettings) -> &mut Self {
+        self.instruction.rebalance_settings = Some(rebalance_settings);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = RebalanceInstructionArgs {
+            rebalance_settings: self
+                .instruction
+                .rebalance_settings
+                .clone()
+                .expect("rebalance_settings is not set"),
+        };
+        let instruction = RebalanceCpi {
+            __program: self.instruction.__program,
+
+            signer: self.instruction.signer.expect("signer is not set"),
+
+            lending_program: self
+                .instruction
+                .lending_program
+                .expect("lending_program is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            token_program: self
+                .instruction
+                .token_program
+                .expect("token_program is not set"),
+
+            ixs_sysvar: self.instruction.ixs_sysvar.expect("ixs_sysvar is not set"),
+
+            solauto_fees_ta: self.instruction.solauto_fees_ta,
+
+            authority_referral_state: self
+                .instruction
+                .authority_referral_state
+                .expect("authority_referral_state is not set"),
+
+            referred_by_ta: self.instruction.referred_by_ta,
+
+            position_authority: self.instruction.position_authority,
+
+            solauto_position: self
+                .instruction
+                .solauto_position
+                .expect("solauto_position is not set"),
+
+            intermediary_ta: self.instruction.intermediary_ta,
+
+            supply_mint: self
+                .instruction
+                .supply_mint
+                .expect("supply_mint is not set"),
+
+            position_supply_ta: self
+                .instruction
+                .position_supply_ta
+                .expect("position_supply_ta is not set"),
+
+            authority_supply_ta: self.instruction.authority_supply_ta,
+
+            debt_mint: self.instruction.debt_mint.expect("debt_mint is not set"),
+
+            position_debt_ta: self
+                .instruction
+                .position_debt_ta
+                .expect("position_debt_ta is not set"),
+
+            authority_debt_ta: self.instruction.authority_debt_ta,
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct RebalanceCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    lending_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ixs_sysvar: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    solauto_fees_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authority_referral_state: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    referred_by_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    position_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    solauto_position: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    intermediary_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    supply_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    position_supply_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authority_supply_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    debt_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    position_debt_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authority_debt_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    rebalance_settings: Option<RebalanceSettings>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -0,0 +1,21 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Grid {
+    pub lower_liq_utilization_rate_bps: u16,
+    pub upper_liq_utilization_rate_bps: u16,
+    pub price_step_bps: u16,
+    pub level_count: u8,
+    pub last_level: u8,
+    pub anchor_price_ratio: u64,
+    pub padding: [u8; 32],
+}
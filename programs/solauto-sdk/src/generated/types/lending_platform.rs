@@ -15,4 +15,6 @@ use num_derive::FromPrimitive;
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LendingPlatform {
     Marginfi,
+    Kamino,
+    Save,
 }
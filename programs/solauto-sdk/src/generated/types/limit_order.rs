@@ -0,0 +1,22 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+use crate::generated::types::PodBool;
+use crate::generated::types::TokenType;
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LimitOrder {
+    pub amount_base_unit: u64,
+    pub trigger_price_usd: u64,
+    pub token_type: TokenType,
+    pub trigger_above: PodBool,
+    pub padding1: [u8; 6],
+    pub padding: [u8; 32],
+}
@@ -7,11 +7,23 @@
 
 pub(crate) mod r#automation_settings;
 pub(crate) mod r#automation_settings_inp;
+pub(crate) mod r#carry_guard;
+pub(crate) mod r#carry_guard_inp;
 pub(crate) mod r#d_c_a_settings;
 pub(crate) mod r#d_c_a_settings_inp;
+pub(crate) mod r#grid;
+pub(crate) mod r#grid_settings_inp;
+pub(crate) mod r#harvest_data;
 pub(crate) mod r#lending_platform;
+pub(crate) mod r#leverage_curve;
+pub(crate) mod r#leverage_curve_band;
+pub(crate) mod r#leverage_curve_band_inp;
+pub(crate) mod r#leverage_curve_inp;
+pub(crate) mod r#limit_order;
+pub(crate) mod r#marginfi_open_position_data;
 pub(crate) mod r#pod_bool;
 pub(crate) mod r#position_data;
+pub(crate) mod r#position_leg;
 pub(crate) mod r#position_state;
 pub(crate) mod r#position_token_state;
 pub(crate) mod r#position_type;
@@ -19,27 +31,48 @@ pub(crate) mod r#price_type;
 pub(crate) mod r#rebalance_data;
 pub(crate) mod r#rebalance_direction;
 pub(crate) mod r#rebalance_instruction_data;
+pub(crate) mod r#rebalance_settings;
 pub(crate) mod r#rebalance_state_values;
 pub(crate) mod r#rebalance_step;
+pub(crate) mod r#rebalance_strategy_type;
 pub(crate) mod r#solauto_action;
 pub(crate) mod r#solauto_rebalance_type;
 pub(crate) mod r#solauto_settings_parameters;
 pub(crate) mod r#solauto_settings_parameters_inp;
+pub(crate) mod r#stop_loss_order;
+pub(crate) mod r#stop_loss_order_inp;
+pub(crate) mod r#swap_cpi_data;
 pub(crate) mod r#swap_type;
+pub(crate) mod r#take_profit_order;
+pub(crate) mod r#take_profit_order_inp;
 pub(crate) mod r#token_amount;
 pub(crate) mod r#token_balance_amount;
 pub(crate) mod r#token_balance_change;
 pub(crate) mod r#token_balance_change_type;
 pub(crate) mod r#token_type;
+pub(crate) mod r#trailing_stop_order;
+pub(crate) mod r#trailing_stop_order_inp;
 pub(crate) mod r#update_position_data;
 
 pub use self::r#automation_settings::*;
 pub use self::r#automation_settings_inp::*;
+pub use self::r#carry_guard::*;
+pub use self::r#carry_guard_inp::*;
 pub use self::r#d_c_a_settings::*;
 pub use self::r#d_c_a_settings_inp::*;
+pub use self::r#grid::*;
+pub use self::r#grid_settings_inp::*;
+pub use self::r#harvest_data::*;
 pub use self::r#lending_platform::*;
+pub use self::r#leverage_curve::*;
+pub use self::r#leverage_curve_band::*;
+pub use self::r#leverage_curve_band_inp::*;
+pub use self::r#leverage_curve_inp::*;
+pub use self::r#limit_order::*;
+pub use self::r#marginfi_open_position_data::*;
 pub use self::r#pod_bool::*;
 pub use self::r#position_data::*;
+pub use self::r#position_leg::*;
 pub use self::r#position_state::*;
 pub use self::r#position_token_state::*;
 pub use self::r#position_type::*;
@@ -47,16 +80,25 @@ pub use self::r#price_type::*;
 pub use self::r#rebalance_data::*;
 pub use self::r#rebalance_direction::*;
 pub use self::r#rebalance_instruction_data::*;
+pub use self::r#rebalance_settings::*;
 pub use self::r#rebalance_state_values::*;
 pub use self::r#rebalance_step::*;
+pub use self::r#rebalance_strategy_type::*;
 pub use self::r#solauto_action::*;
 pub use self::r#solauto_rebalance_type::*;
 pub use self::r#solauto_settings_parameters::*;
 pub use self::r#solauto_settings_parameters_inp::*;
+pub use self::r#stop_loss_order::*;
+pub use self::r#stop_loss_order_inp::*;
+pub use self::r#swap_cpi_data::*;
 pub use self::r#swap_type::*;
+pub use self::r#take_profit_order::*;
+pub use self::r#take_profit_order_inp::*;
 pub use self::r#token_amount::*;
 pub use self::r#token_balance_amount::*;
 pub use self::r#token_balance_change::*;
 pub use self::r#token_balance_change_type::*;
 pub use self::r#token_type::*;
+pub use self::r#trailing_stop_order::*;
+pub use self::r#trailing_stop_order_inp::*;
 pub use self::r#update_position_data::*;
@@ -5,7 +5,9 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+use crate::generated::types::DCASettings;
 use crate::generated::types::LendingPlatform;
+use crate::generated::types::RebalanceStrategyType;
 use crate::generated::types::SolautoSettingsParameters;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
@@ -15,7 +17,9 @@ use solana_program::pubkey::Pubkey;
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PositionData {
     pub lending_platform: LendingPlatform,
-    pub padding1: [u8; 7],
+    pub rebalance_strategy: RebalanceStrategyType,
+    pub max_price_divergence_bps: u16,
+    pub padding1: [u8; 4],
     #[cfg_attr(
         feature = "serde",
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
@@ -37,5 +41,5 @@ pub struct PositionData {
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
     )]
     pub lp_pool_account: Pubkey,
-    pub padding: [u32; 20],
+    pub dca: DCASettings,
 }
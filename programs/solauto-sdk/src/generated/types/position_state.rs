@@ -5,6 +5,7 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+use crate::generated::types::PositionLeg;
 use crate::generated::types::PositionTokenState;
 use crate::generated::types::TokenAmount;
 use borsh::BorshDeserialize;
@@ -14,7 +15,8 @@ use borsh::BorshSerialize;
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PositionState {
     pub liq_utilization_rate_bps: u16,
-    pub padding1: [u8; 6],
+    pub price_divergence_bps: u16,
+    pub padding1: [u8; 4],
     pub net_worth: TokenAmount,
     pub supply: PositionTokenState,
     pub debt: PositionTokenState,
@@ -22,5 +24,8 @@ pub struct PositionState {
     pub liq_threshold_bps: u16,
     pub padding2: [u8; 4],
     pub last_refreshed: u64,
-    pub padding: [u32; 2],
+    pub emissions_harvested_usd: u64,
+    pub supply_price_high_water_mark_usd: u64,
+    pub additional_supply: [PositionLeg; 3],
+    pub additional_debt: [PositionLeg; 3],
 }
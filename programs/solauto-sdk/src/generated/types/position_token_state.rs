@@ -24,6 +24,7 @@ pub struct PositionTokenState {
     pub amount_used: TokenAmount,
     pub amount_can_be_used: TokenAmount,
     pub base_amount_market_price_usd: u64,
-    pub padding2: [u8; 8],
+    pub supply_apr_bps: u32,
+    pub borrow_apr_bps: u32,
     pub padding: [u8; 32],
 }
@@ -17,7 +17,10 @@ pub struct RebalanceInstructionData {
     pub active: PodBool,
     pub rebalance_type: SolautoRebalanceType,
     pub swap_type: SwapType,
-    pub padding1: [u8; 5],
+    pub padding1: [u8; 1],
+    pub flash_loan_fee_bps: u16,
+    pub padding2: [u8; 2],
     pub flash_loan_amount: u64,
-    pub padding: [u32; 4],
+    pub swap_in_amount_base_unit: u64,
+    pub pre_swap_output_balance: u64,
 }
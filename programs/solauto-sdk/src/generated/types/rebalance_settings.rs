@@ -0,0 +1,24 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+use crate::generated::types::PriceType;
+use crate::generated::types::SolautoRebalanceType;
+use crate::generated::types::SwapCpiData;
+use crate::generated::types::SwapType;
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RebalanceSettings {
+    pub rebalance_type: SolautoRebalanceType,
+    pub swap_in_amount_base_unit: Option<u64>,
+    pub target_liq_utilization_rate_bps: Option<u16>,
+    pub price_type: Option<PriceType>,
+    pub swap_type: Option<SwapType>,
+    pub swap_cpi: Option<SwapCpiData>,
+}
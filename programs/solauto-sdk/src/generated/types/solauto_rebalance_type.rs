@@ -18,4 +18,5 @@ pub enum SolautoRebalanceType {
     DoubleRebalanceWithFL,
     FLSwapThenRebalance,
     FLRebalanceThenSwap,
+    SwapCpi,
 }
@@ -5,6 +5,7 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+use crate::generated::types::AutomationSettings;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 
@@ -15,5 +16,10 @@ pub struct SolautoSettingsParameters {
     pub boost_gap: u16,
     pub repay_to_bps: u16,
     pub repay_gap: u16,
-    pub padding: [u32; 24],
+    pub target_boost_to_bps: u16,
+    pub target_repay_to_bps: u16,
+    pub max_slippage_bps: u16,
+    pub padding1: [u8; 2],
+    pub automation: AutomationSettings,
+    pub padding: [u8; 32],
 }
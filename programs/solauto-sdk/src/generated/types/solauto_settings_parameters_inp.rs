@@ -5,6 +5,7 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+use crate::generated::types::AutomationSettingsInp;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 
@@ -15,4 +16,8 @@ pub struct SolautoSettingsParametersInp {
     pub boost_gap: u16,
     pub repay_to_bps: u16,
     pub repay_gap: u16,
+    pub target_boost_to_bps: Option<u16>,
+    pub target_repay_to_bps: Option<u16>,
+    pub automation: Option<AutomationSettingsInp>,
+    pub max_slippage_bps: Option<u16>,
 }
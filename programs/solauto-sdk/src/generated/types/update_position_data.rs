@@ -5,8 +5,15 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+use crate::generated::types::CarryGuardInp;
 use crate::generated::types::DCASettingsInp;
+use crate::generated::types::GridSettingsInp;
+use crate::generated::types::LeverageCurveInp;
+use crate::generated::types::RebalanceStrategyType;
 use crate::generated::types::SolautoSettingsParametersInp;
+use crate::generated::types::StopLossOrderInp;
+use crate::generated::types::TakeProfitOrderInp;
+use crate::generated::types::TrailingStopOrderInp;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 
@@ -15,5 +22,13 @@ use borsh::BorshSerialize;
 pub struct UpdatePositionData {
     pub position_id: u8,
     pub settings: Option<SolautoSettingsParametersInp>,
+    pub rebalance_strategy: Option<RebalanceStrategyType>,
+    pub max_price_divergence_bps: Option<u16>,
+    pub leverage_curve: Option<LeverageCurveInp>,
+    pub grid: Option<GridSettingsInp>,
+    pub carry_guard: Option<CarryGuardInp>,
     pub dca: Option<DCASettingsInp>,
+    pub take_profit: Option<TakeProfitOrderInp>,
+    pub stop_loss: Option<StopLossOrderInp>,
+    pub trailing_stop: Option<TrailingStopOrderInp>,
 }
@@ -37,6 +37,10 @@ mod open_position {
             boost_gap: 500,
             repay_to_bps: 7500,
             repay_gap: 500,
+            target_boost_to_bps: None,
+            target_repay_to_bps: None,
+            automation: None,
+            max_slippage_bps: None,
         };
         data.open_position(Some(settings.clone()), None).await.unwrap();
 
@@ -27,6 +27,7 @@ use solauto_sdk::{
         },
         types::{
             DCASettingsInp,
+            MarginfiOpenPositionData,
             PositionType,
             SolautoAction,
             SolautoSettingsParametersInp,
@@ -204,6 +205,10 @@ impl<'a> GeneralTestData<'a> {
                 boost_gap: 500,
                 repay_to_bps: 7500,
                 repay_gap: 500,
+                target_boost_to_bps: None,
+                target_repay_to_bps: None,
+                automation: None,
+                max_slippage_bps: None,
             },
         }
     }
@@ -399,7 +404,15 @@ impl<'a> GeneralTestData<'a> {
         let position_data = UpdatePositionData {
             position_id: self.position_id,
             settings,
+            rebalance_strategy: None,
+            max_price_divergence_bps: None,
+            leverage_curve: None,
+            grid: None,
+            carry_guard: None,
             dca,
+            take_profit: None,
+            stop_loss: None,
+            trailing_stop: None,
         };
         builder
             .signer(self.ctx.payer.pubkey())
@@ -511,7 +524,15 @@ impl<'a> MarginfiTestData<'a> {
         let position_data = UpdatePositionData {
             position_id: self.general.position_id,
             settings,
+            rebalance_strategy: None,
+            max_price_divergence_bps: None,
+            leverage_curve: None,
+            grid: None,
+            carry_guard: None,
             dca,
+            take_profit: None,
+            stop_loss: None,
+            trailing_stop: None,
         };
         builder
             .signer(self.general.ctx.payer.pubkey())
@@ -529,8 +550,10 @@ impl<'a> MarginfiTestData<'a> {
             .debt_bank(Pubkey::default())
             .signer_debt_ta(Some(self.general.signer_debt_ta))
             .position_debt_ta(self.general.position_debt_ta)
-            .position_type(PositionType::Leverage)
-            .position_data(position_data);
+            .marginfi_open_position_data(MarginfiOpenPositionData {
+                position_type: PositionType::Leverage,
+                position_data,
+            });
         builder
     }
 
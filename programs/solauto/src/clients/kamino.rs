@@ -0,0 +1,1122 @@
+use kamino_sdk::{
+    accounts::{Obligation, Reserve},
+    instructions::*,
+    types::{sf_to_f64, sf_to_u64_floor},
+    KAMINO_ID,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, instruction::Instruction, msg,
+    program_error::ProgramError,
+};
+use std::{
+    cell::Cell,
+    cmp::min,
+    ops::{Div, Mul},
+};
+
+use crate::{
+    check,
+    types::{
+        errors::SolautoError,
+        instruction::SolautoStandardAccounts,
+        lending_protocol::{
+            required_account, LendingProtocolAccounts, LendingProtocolAccountsParser,
+            LendingProtocolClient, LendingProtocolTokenAccounts,
+        },
+        shared::{
+            DeserializedAccount, PriceType, RefreshStateProps, RefreshedTokenState,
+            TokenBalanceAmount,
+        },
+    },
+    utils::{ix_utils::*, solana_utils::*, solauto_utils::*, validation_utils::*},
+};
+
+pub struct KaminoReserveAccounts<'a> {
+    pub reserve: DeserializedAccount<'a, Reserve>,
+    pub collateral_mint: Option<&'a AccountInfo<'a>>,
+    pub collateral_vault: Option<&'a AccountInfo<'a>>,
+    pub fee_vault: Option<&'a AccountInfo<'a>>,
+    /// `mint` is the reserve liquidity mint & `protocol_ta` the reserve liquidity supply vault
+    pub token_accounts: LendingProtocolTokenAccounts<'a>,
+}
+
+#[derive(Clone, Copy, Default)]
+pub struct KaminoReserveOracles<'a> {
+    pub pyth_oracle: Option<&'a AccountInfo<'a>>,
+    pub switchboard_price_oracle: Option<&'a AccountInfo<'a>>,
+    pub switchboard_twap_oracle: Option<&'a AccountInfo<'a>>,
+    pub scope_prices: Option<&'a AccountInfo<'a>>,
+}
+
+/// Remaining accounts layout of the generic position instructions:
+/// lending_market, lending_market_authority?, obligation, user_metadata?,
+/// supply_reserve, supply_liquidity_vault?, supply_collateral_mint?, supply_collateral_vault?,
+/// supply_pyth_oracle?, supply_switchboard_price_oracle?, supply_switchboard_twap_oracle?, supply_scope_prices?,
+/// debt_reserve, debt_liquidity_vault?, debt_fee_vault?,
+/// debt_pyth_oracle?, debt_switchboard_price_oracle?, debt_switchboard_twap_oracle?, debt_scope_prices?
+pub struct KaminoProtocolAccounts<'a> {
+    pub kamino_program: &'a AccountInfo<'a>,
+    pub lending_market: &'a AccountInfo<'a>,
+    pub lending_market_authority: Option<&'a AccountInfo<'a>>,
+    pub obligation: &'a AccountInfo<'a>,
+    pub user_metadata: Option<&'a AccountInfo<'a>>,
+    pub supply_reserve: &'a AccountInfo<'a>,
+    pub supply_liquidity_vault: Option<&'a AccountInfo<'a>>,
+    pub supply_collateral_mint: Option<&'a AccountInfo<'a>>,
+    pub supply_collateral_vault: Option<&'a AccountInfo<'a>>,
+    pub supply_oracles: KaminoReserveOracles<'a>,
+    pub debt_reserve: &'a AccountInfo<'a>,
+    pub debt_liquidity_vault: Option<&'a AccountInfo<'a>>,
+    pub debt_fee_vault: Option<&'a AccountInfo<'a>>,
+    pub debt_oracles: KaminoReserveOracles<'a>,
+}
+
+impl<'a> KaminoProtocolAccounts<'a> {
+    pub fn parse(
+        kamino_program: &'a AccountInfo<'a>,
+        remaining_accounts: &'a [AccountInfo<'a>],
+    ) -> Result<Self, ProgramError> {
+        let mut parser = LendingProtocolAccountsParser::from(remaining_accounts);
+        Ok(Self {
+            kamino_program,
+            lending_market: parser.next_account()?,
+            lending_market_authority: parser.next_optional_account()?,
+            obligation: parser.next_account()?,
+            user_metadata: parser.next_optional_account()?,
+            supply_reserve: parser.next_account()?,
+            supply_liquidity_vault: parser.next_optional_account()?,
+            supply_collateral_mint: parser.next_optional_account()?,
+            supply_collateral_vault: parser.next_optional_account()?,
+            supply_oracles: KaminoReserveOracles::parse(&mut parser)?,
+            debt_reserve: parser.next_account()?,
+            debt_liquidity_vault: parser.next_optional_account()?,
+            debt_fee_vault: parser.next_optional_account()?,
+            debt_oracles: KaminoReserveOracles::parse(&mut parser)?,
+        })
+    }
+}
+
+impl<'a> KaminoReserveOracles<'a> {
+    fn parse(parser: &mut LendingProtocolAccountsParser<'a>) -> Result<Self, ProgramError> {
+        Ok(Self {
+            pyth_oracle: parser.next_optional_account()?,
+            switchboard_price_oracle: parser.next_optional_account()?,
+            switchboard_twap_oracle: parser.next_optional_account()?,
+            scope_prices: parser.next_optional_account()?,
+        })
+    }
+}
+
+impl<'a> LendingProtocolAccounts<'a> for KaminoProtocolAccounts<'a> {
+    fn lp_pool_account(&self) -> &'a AccountInfo<'a> {
+        self.lending_market
+    }
+
+    fn lp_user_account(&self) -> &'a AccountInfo<'a> {
+        self.obligation
+    }
+
+    fn lp_supply_account(&self) -> &'a AccountInfo<'a> {
+        self.supply_reserve
+    }
+
+    fn lp_debt_account(&self) -> &'a AccountInfo<'a> {
+        self.debt_reserve
+    }
+
+    fn protocol_tas(&self) -> (Option<&'a AccountInfo<'a>>, Option<&'a AccountInfo<'a>>) {
+        (self.supply_liquidity_vault, self.debt_liquidity_vault)
+    }
+
+    fn get_max_ltv_and_liq_threshold(&self) -> Result<(f64, f64), ProgramError> {
+        KaminoClient::get_max_ltv_and_liq_threshold(self.supply_reserve, self.debt_reserve)
+    }
+
+    fn initialize(&self, std_accounts: &SolautoStandardAccounts<'a>) -> ProgramResult {
+        let rent = required_account(std_accounts.rent)?;
+        let user_metadata = required_account(self.user_metadata)?;
+        let obligation_owner = get_owner(&std_accounts.solauto_position, std_accounts.signer);
+
+        if !account_has_data(user_metadata) {
+            solauto_invoke_instruction(
+                init_user_metadata(
+                    obligation_owner.key,
+                    std_accounts.signer.key,
+                    user_metadata.key,
+                    Default::default(),
+                ),
+                &[
+                    obligation_owner.clone(),
+                    std_accounts.signer.clone(),
+                    user_metadata.clone(),
+                    self.kamino_program.clone(),
+                    rent.clone(),
+                    std_accounts.system_program.clone(),
+                ],
+                &std_accounts.solauto_position,
+            )?;
+        }
+
+        if account_has_data(self.obligation) {
+            return Ok(());
+        }
+
+        solauto_invoke_instruction(
+            init_obligation(
+                obligation_owner.key,
+                std_accounts.signer.key,
+                self.obligation.key,
+                self.lending_market.key,
+                user_metadata.key,
+                InitObligationArgs { tag: 0, id: 0 },
+            ),
+            &[
+                obligation_owner.clone(),
+                std_accounts.signer.clone(),
+                self.obligation.clone(),
+                self.lending_market.clone(),
+                user_metadata.clone(),
+                rent.clone(),
+                std_accounts.system_program.clone(),
+                self.kamino_program.clone(),
+            ],
+            &std_accounts.solauto_position,
+        )
+    }
+
+    /// Kamino only accepts the first deposit, withdraw, borrow or repay of an instruction after top-level refresh
+    /// instructions (see `validate_kamino_refresh_instructions`). This refresh through CPI keeps the reserves & obligation
+    /// that are read afterwards current, and covers obligations that are created within the instruction
+    fn refresh_accounts(&self) -> ProgramResult {
+        KaminoClient::refresh_reserve(
+            self.kamino_program,
+            self.lending_market,
+            self.supply_reserve,
+            self.supply_oracles,
+        )?;
+        KaminoClient::refresh_reserve(
+            self.kamino_program,
+            self.lending_market,
+            self.debt_reserve,
+            self.debt_oracles,
+        )?;
+        KaminoClient::refresh_obligation(
+            self.kamino_program,
+            self.lending_market,
+            self.obligation,
+            self.supply_reserve,
+            self.debt_reserve,
+        )
+    }
+
+    fn get_updated_state(&self, _price_type: PriceType) -> Result<RefreshStateProps, ProgramError> {
+        KaminoClient::get_updated_state(self.obligation, self.supply_reserve, self.debt_reserve)
+    }
+
+    fn load_prices(&self, _price_type: PriceType) -> Result<(f64, f64), ProgramError> {
+        KaminoClient::load_prices(self.supply_reserve, self.debt_reserve)
+    }
+
+    fn load_leg_prices(&self, _price_type: PriceType) -> Result<Vec<f64>, ProgramError> {
+        Ok(Vec::new())
+    }
+
+    fn client(
+        &self,
+        signer: &'a AccountInfo<'a>,
+        supply_tas: LendingProtocolTokenAccounts<'a>,
+        debt_tas: LendingProtocolTokenAccounts<'a>,
+    ) -> Result<Box<dyn LendingProtocolClient<'a> + 'a>, ProgramError> {
+        Ok(Box::new(KaminoClient::from(
+            self, signer, supply_tas, debt_tas,
+        )?))
+    }
+}
+
+/// Returns the reserve's current (supply, borrow) APR in bps, from its utilization & borrow rate curve
+fn get_reserve_apr_bps(reserve: &Reserve) -> (u32, u32) {
+    let total_supply = reserve.liquidity.total_supply();
+    let utilization_rate = if total_supply > 0.0 {
+        sf_to_f64(reserve.liquidity.borrowed_amount_sf)
+            .div(total_supply)
+            .min(1.0)
+    } else {
+        0.0
+    };
+    let utilization_rate_bps = utilization_rate.mul(10_000.0);
+
+    let curve = &reserve.config.borrow_rate_curve;
+    let curve_rate_bps = curve
+        .windows(2)
+        .find(|points| utilization_rate_bps <= points[1].utilization_rate_bps as f64)
+        .map_or(curve[curve.len() - 1].borrow_rate_bps as f64, |points| {
+            let (start, end) = (&points[0], &points[1]);
+            let utilization_span =
+                end.utilization_rate_bps as f64 - start.utilization_rate_bps as f64;
+            if utilization_span <= 0.0 {
+                return end.borrow_rate_bps as f64;
+            }
+            start.borrow_rate_bps as f64
+                + (end.borrow_rate_bps as f64 - start.borrow_rate_bps as f64)
+                    * (utilization_rate_bps - start.utilization_rate_bps as f64)
+                        .div(utilization_span)
+        });
+
+    // The host's fixed rate is only paid by borrowers, and the protocol takes its cut before lenders are paid
+    let supply_rate_bps = curve_rate_bps
+        * utilization_rate
+        * (1.0 - (reserve.config.protocol_take_rate_pct as f64).div(100.0));
+    let borrow_rate_bps = curve_rate_bps + reserve.config.host_fixed_interest_rate_bps as f64;
+
+    (
+        supply_rate_bps.round() as u32,
+        borrow_rate_bps.round() as u32,
+    )
+}
+
+pub struct KaminoClient<'a> {
+    signer: &'a AccountInfo<'a>,
+    program: &'a AccountInfo<'a>,
+    lending_market: &'a AccountInfo<'a>,
+    lending_market_authority: &'a AccountInfo<'a>,
+    obligation: &'a AccountInfo<'a>,
+    supply: KaminoReserveAccounts<'a>,
+    supply_oracles: KaminoReserveOracles<'a>,
+    debt: KaminoReserveAccounts<'a>,
+    debt_oracles: KaminoReserveOracles<'a>,
+    obligation_stale: Cell<bool>,
+}
+
+impl<'a> KaminoClient<'a> {
+    pub fn from(
+        accounts: &KaminoProtocolAccounts<'a>,
+        signer: &'a AccountInfo<'a>,
+        supply_tas: LendingProtocolTokenAccounts<'a>,
+        debt_tas: LendingProtocolTokenAccounts<'a>,
+    ) -> Result<Self, ProgramError> {
+        let supply = KaminoReserveAccounts {
+            reserve: KaminoClient::load_reserve(accounts.supply_reserve)?,
+            collateral_mint: accounts.supply_collateral_mint,
+            collateral_vault: accounts.supply_collateral_vault,
+            fee_vault: None,
+            token_accounts: supply_tas,
+        };
+
+        let debt = KaminoReserveAccounts {
+            reserve: KaminoClient::load_reserve(accounts.debt_reserve)?,
+            collateral_mint: None,
+            collateral_vault: None,
+            fee_vault: accounts.debt_fee_vault,
+            token_accounts: debt_tas,
+        };
+
+        Ok(Self {
+            signer,
+            program: accounts.kamino_program,
+            lending_market: accounts.lending_market,
+            lending_market_authority: required_account(accounts.lending_market_authority)?,
+            obligation: accounts.obligation,
+            supply,
+            supply_oracles: accounts.supply_oracles,
+            debt,
+            debt_oracles: accounts.debt_oracles,
+            obligation_stale: Cell::new(false),
+        })
+    }
+
+    pub fn load_reserve(
+        reserve: &'a AccountInfo<'a>,
+    ) -> Result<DeserializedAccount<'a, Reserve>, ProgramError> {
+        check!(reserve.owner == &KAMINO_ID, SolautoError::IncorrectAccounts);
+        let data = Reserve::from_bytes(&reserve.data.borrow()).map_err(|_| {
+            msg!("Failed to deserialize Kamino reserve");
+            SolautoError::FailedAccountDeserialization
+        })?;
+        Ok(DeserializedAccount {
+            account_info: reserve,
+            data: Box::new(data),
+        })
+    }
+
+    pub fn load_obligation(
+        obligation: &'a AccountInfo<'a>,
+    ) -> Result<DeserializedAccount<'a, Obligation>, ProgramError> {
+        check!(
+            obligation.owner == &KAMINO_ID,
+            SolautoError::IncorrectAccounts
+        );
+        let data = Obligation::from_bytes(&obligation.data.borrow()).map_err(|_| {
+            msg!("Failed to deserialize Kamino obligation");
+            SolautoError::FailedAccountDeserialization
+        })?;
+        Ok(DeserializedAccount {
+            account_info: obligation,
+            data: Box::new(data),
+        })
+    }
+
+    pub fn get_max_ltv_and_liq_threshold(
+        supply_reserve: &'a AccountInfo<'a>,
+        debt_reserve: &'a AccountInfo<'a>,
+    ) -> Result<(f64, f64), ProgramError> {
+        let supply_reserve = KaminoClient::load_reserve(supply_reserve)?;
+        let debt_reserve = KaminoClient::load_reserve(debt_reserve)?;
+
+        let borrow_factor = (debt_reserve.data.config.borrow_factor_pct.max(100) as f64).div(100.0);
+
+        let max_ltv = (supply_reserve.data.config.loan_to_value_pct as f64)
+            .div(100.0)
+            .div(borrow_factor);
+        let liq_threshold = (supply_reserve.data.config.liquidation_threshold_pct as f64)
+            .div(100.0)
+            .div(borrow_factor);
+
+        Ok((max_ltv, liq_threshold))
+    }
+
+    pub fn get_supply_token_usage(
+        obligation: &DeserializedAccount<Obligation>,
+        supply_reserve: &DeserializedAccount<Reserve>,
+    ) -> RefreshedTokenState {
+        let reserve = &supply_reserve.data;
+
+        let base_unit_account_deposits = obligation
+            .data
+            .find_deposit(supply_reserve.account_info.key)
+            .map_or(0, |deposit| {
+                reserve.collateral_to_liquidity(deposit.deposited_amount)
+            });
+
+        let total_deposited = reserve.liquidity.total_supply();
+        let base_unit_deposit_room_available =
+            (reserve.config.deposit_limit as f64 - total_deposited).max(0.0) as u64;
+        let (supply_apr_bps, borrow_apr_bps) = get_reserve_apr_bps(reserve);
+
+        RefreshedTokenState {
+            mint: reserve.liquidity.mint_pubkey,
+            decimals: reserve.liquidity.mint_decimals as u8,
+            amount_used: base_unit_account_deposits,
+            amount_can_be_used: base_unit_deposit_room_available,
+            market_price: reserve.market_price(),
+            borrow_fee_bps: None,
+            supply_apr_bps,
+            borrow_apr_bps,
+        }
+    }
+
+    pub fn get_debt_token_usage(
+        obligation: &DeserializedAccount<Obligation>,
+        debt_reserve: &DeserializedAccount<Reserve>,
+    ) -> RefreshedTokenState {
+        let reserve = &debt_reserve.data;
+
+        let base_unit_account_debt = obligation
+            .data
+            .find_borrow(debt_reserve.account_info.key)
+            .map_or(0, |borrow| {
+                sf_to_f64(borrow.borrowed_amount_sf).ceil() as u64
+            });
+
+        let total_borrows = sf_to_u64_floor(reserve.liquidity.borrowed_amount_sf);
+        let amount_can_be_used = min(
+            reserve.config.borrow_limit.saturating_sub(total_borrows),
+            reserve.liquidity.available_amount,
+        );
+
+        let borrow_fee_bps = (reserve.config.fees.borrow_fee_sf as f64)
+            .div((1u64 << 60) as f64)
+            .mul(10_000.0)
+            .round() as u16;
+        let (supply_apr_bps, borrow_apr_bps) = get_reserve_apr_bps(reserve);
+
+        RefreshedTokenState {
+            mint: reserve.liquidity.mint_pubkey,
+            decimals: reserve.liquidity.mint_decimals as u8,
+            amount_used: base_unit_account_debt,
+            amount_can_be_used,
+            market_price: reserve.market_price(),
+            borrow_fee_bps: Some(borrow_fee_bps),
+            supply_apr_bps,
+            borrow_apr_bps,
+        }
+    }
+
+    /// Kamino reserves hold a market price that has already been validated against the reserve's oracles
+    /// (and its TWAP) during `refresh_reserve`, so it is used regardless of the requested price type
+    pub fn get_updated_state(
+        obligation: &'a AccountInfo<'a>,
+        supply_reserve: &'a AccountInfo<'a>,
+        debt_reserve: &'a AccountInfo<'a>,
+    ) -> Result<RefreshStateProps, ProgramError> {
+        let (max_ltv, liq_threshold) =
+            KaminoClient::get_max_ltv_and_liq_threshold(supply_reserve, debt_reserve)?;
+
+        let obligation = KaminoClient::load_obligation(obligation)?;
+        let supply = KaminoClient::get_supply_token_usage(
+            &obligation,
+            &KaminoClient::load_reserve(supply_reserve)?,
+        );
+        let debt = KaminoClient::get_debt_token_usage(
+            &obligation,
+            &KaminoClient::load_reserve(debt_reserve)?,
+        );
+
+        Ok(RefreshStateProps {
+            max_ltv,
+            liq_threshold,
+            supply,
+            debt,
+            additional_supply: Vec::new(),
+            additional_debt: Vec::new(),
+        })
+    }
+
+    pub fn load_prices(
+        supply_reserve: &'a AccountInfo<'a>,
+        debt_reserve: &'a AccountInfo<'a>,
+    ) -> Result<(f64, f64), ProgramError> {
+        Ok((
+            KaminoClient::load_reserve(supply_reserve)?
+                .data
+                .market_price(),
+            KaminoClient::load_reserve(debt_reserve)?
+                .data
+                .market_price(),
+        ))
+    }
+
+    pub fn refresh_reserve(
+        program: &'a AccountInfo<'a>,
+        lending_market: &'a AccountInfo<'a>,
+        reserve: &'a AccountInfo<'a>,
+        oracles: KaminoReserveOracles<'a>,
+    ) -> ProgramResult {
+        let oracle_or_program = |oracle: Option<&'a AccountInfo<'a>>| oracle.unwrap_or(program);
+
+        let ix = kamino_sdk::instructions::refresh_reserve(
+            reserve.key,
+            lending_market.key,
+            ReserveOracles {
+                pyth_oracle: oracles.pyth_oracle.map(|a| *a.key),
+                switchboard_price_oracle: oracles.switchboard_price_oracle.map(|a| *a.key),
+                switchboard_twap_oracle: oracles.switchboard_twap_oracle.map(|a| *a.key),
+                scope_prices: oracles.scope_prices.map(|a| *a.key),
+            },
+        );
+        invoke_instruction(
+            &ix,
+            &[
+                reserve.clone(),
+                lending_market.clone(),
+                oracle_or_program(oracles.pyth_oracle).clone(),
+                oracle_or_program(oracles.switchboard_price_oracle).clone(),
+                oracle_or_program(oracles.switchboard_twap_oracle).clone(),
+                oracle_or_program(oracles.scope_prices).clone(),
+                program.clone(),
+            ],
+            None,
+        )
+    }
+
+    /// The obligation's deposit reserves followed by its borrow reserves must be passed in the order they
+    /// are stored on the obligation. Solauto positions only ever hold the supply & debt reserve.
+    pub fn refresh_obligation(
+        program: &'a AccountInfo<'a>,
+        lending_market: &'a AccountInfo<'a>,
+        obligation: &'a AccountInfo<'a>,
+        supply_reserve: &'a AccountInfo<'a>,
+        debt_reserve: &'a AccountInfo<'a>,
+    ) -> ProgramResult {
+        let obligation_data = KaminoClient::load_obligation(obligation)?;
+
+        let mut reserves = Vec::with_capacity(2);
+        for reserve_key in obligation_data
+            .data
+            .active_deposits()
+            .map(|deposit| deposit.deposit_reserve)
+            .chain(
+                obligation_data
+                    .data
+                    .active_borrows()
+                    .map(|borrow| borrow.borrow_reserve),
+            )
+        {
+            let reserve = [supply_reserve, debt_reserve]
+                .into_iter()
+                .find(|reserve| reserve.key == &reserve_key)
+                .ok_or(SolautoError::IncorrectAccounts)?;
+            reserves.push(reserve);
+        }
+
+        let ix = kamino_sdk::instructions::refresh_obligation(
+            lending_market.key,
+            obligation.key,
+            reserves
+                .iter()
+                .map(|reserve| *reserve.key)
+                .collect::<Vec<_>>()
+                .as_slice(),
+        );
+
+        let mut account_infos = vec![lending_market.clone(), obligation.clone()];
+        account_infos.extend(reserves.into_iter().cloned());
+        account_infos.push(program.clone());
+
+        invoke_instruction(&ix, account_infos.as_slice(), None)
+    }
+
+    /// Kamino marks the obligation & the reserve stale after each deposit, withdraw, borrow & repay. The top-level refresh
+    /// instructions only cover the first of them, so any that follows in the same instruction refreshes through CPI first
+    fn refresh_if_stale(&self) -> ProgramResult {
+        if !self.obligation_stale.replace(true) {
+            return Ok(());
+        }
+
+        KaminoClient::refresh_reserve(
+            self.program,
+            self.lending_market,
+            self.supply.reserve.account_info,
+            self.supply_oracles,
+        )?;
+        KaminoClient::refresh_reserve(
+            self.program,
+            self.lending_market,
+            self.debt.reserve.account_info,
+            self.debt_oracles,
+        )?;
+        KaminoClient::refresh_obligation(
+            self.program,
+            self.lending_market,
+            self.obligation,
+            self.supply.reserve.account_info,
+            self.debt.reserve.account_info,
+        )
+    }
+
+    fn invoke(
+        &self,
+        ix: Instruction,
+        mut account_infos: Vec<AccountInfo<'a>>,
+        std_accounts: &SolautoStandardAccounts<'a>,
+    ) -> ProgramResult {
+        account_infos.push(self.program.clone());
+        solauto_invoke_instruction(ix, account_infos.as_slice(), &std_accounts.solauto_position)
+    }
+}
+
+impl<'a> LendingProtocolClient<'a> for KaminoClient<'a> {
+    fn validate(&self, std_accounts: &Box<SolautoStandardAccounts<'a>>) -> ProgramResult {
+        validate_token_accounts(
+            &std_accounts.solauto_position,
+            self.supply.token_accounts.position_ta,
+            self.debt.token_accounts.position_ta,
+        )?;
+        validate_token_accounts(
+            &std_accounts.solauto_position,
+            self.supply.token_accounts.authority_ta,
+            self.debt.token_accounts.authority_ta,
+        )?;
+        Ok(())
+    }
+
+    fn deposit<'c>(
+        &self,
+        base_unit_amount: u64,
+        std_accounts: &'c Box<SolautoStandardAccounts<'a>>,
+    ) -> ProgramResult {
+        let authority = get_owner(&std_accounts.solauto_position, self.signer);
+        self.refresh_if_stale()?;
+
+        let user_source_liquidity = if !std_accounts.solauto_position.data.self_managed.val {
+            required_account(self.supply.token_accounts.position_ta)?
+        } else {
+            required_account(self.supply.token_accounts.authority_ta)?
+        };
+        let reserve_liquidity_mint = required_account(self.supply.token_accounts.mint)?;
+        let reserve_liquidity_supply = required_account(self.supply.token_accounts.protocol_ta)?;
+        let reserve_collateral_mint = required_account(self.supply.collateral_mint)?;
+        let reserve_collateral_vault = required_account(self.supply.collateral_vault)?;
+
+        let ix = deposit_reserve_liquidity_and_obligation_collateral(
+            DepositAccounts {
+                owner: *authority.key,
+                obligation: *self.obligation.key,
+                lending_market: *self.lending_market.key,
+                lending_market_authority: *self.lending_market_authority.key,
+                reserve: *self.supply.reserve.account_info.key,
+                reserve_liquidity_mint: *reserve_liquidity_mint.key,
+                reserve_liquidity_supply: *reserve_liquidity_supply.key,
+                reserve_collateral_mint: *reserve_collateral_mint.key,
+                reserve_destination_deposit_collateral: *reserve_collateral_vault.key,
+                user_source_liquidity: *user_source_liquidity.key,
+                token_program: *std_accounts.token_program.key,
+            },
+            base_unit_amount,
+        );
+
+        self.invoke(
+            ix,
+            vec![
+                authority.clone(),
+                self.obligation.clone(),
+                self.lending_market.clone(),
+                self.lending_market_authority.clone(),
+                self.supply.reserve.account_info.clone(),
+                reserve_liquidity_mint.clone(),
+                reserve_liquidity_supply.clone(),
+                reserve_collateral_mint.clone(),
+                reserve_collateral_vault.clone(),
+                user_source_liquidity.clone(),
+                std_accounts.token_program.clone(),
+                required_account(std_accounts.ixs_sysvar)?.clone(),
+            ],
+            std_accounts,
+        )
+    }
+
+    fn withdraw<'c>(
+        &self,
+        amount: TokenBalanceAmount,
+        destination: &'a AccountInfo<'a>,
+        std_accounts: &'c Box<SolautoStandardAccounts<'a>>,
+    ) -> ProgramResult {
+        let authority = get_owner(&std_accounts.solauto_position, self.signer);
+        self.refresh_if_stale()?;
+
+        let collateral_amount = match amount {
+            TokenBalanceAmount::Some(num) => self.supply.reserve.data.liquidity_to_collateral(num),
+            TokenBalanceAmount::All => u64::MAX,
+        };
+
+        let reserve_liquidity_mint = required_account(self.supply.token_accounts.mint)?;
+        let reserve_liquidity_supply = required_account(self.supply.token_accounts.protocol_ta)?;
+        let reserve_collateral_mint = required_account(self.supply.collateral_mint)?;
+        let reserve_collateral_vault = required_account(self.supply.collateral_vault)?;
+
+        let ix = withdraw_obligation_collateral_and_redeem_reserve_collateral(
+            WithdrawAccounts {
+                owner: *authority.key,
+                obligation: *self.obligation.key,
+                lending_market: *self.lending_market.key,
+                lending_market_authority: *self.lending_market_authority.key,
+                withdraw_reserve: *self.supply.reserve.account_info.key,
+                reserve_liquidity_mint: *reserve_liquidity_mint.key,
+                reserve_source_collateral: *reserve_collateral_vault.key,
+                reserve_collateral_mint: *reserve_collateral_mint.key,
+                reserve_liquidity_supply: *reserve_liquidity_supply.key,
+                user_destination_liquidity: *destination.key,
+                token_program: *std_accounts.token_program.key,
+            },
+            collateral_amount,
+        );
+
+        self.invoke(
+            ix,
+            vec![
+                authority.clone(),
+                self.obligation.clone(),
+                self.lending_market.clone(),
+                self.lending_market_authority.clone(),
+                self.supply.reserve.account_info.clone(),
+                reserve_liquidity_mint.clone(),
+                reserve_collateral_vault.clone(),
+                reserve_collateral_mint.clone(),
+                reserve_liquidity_supply.clone(),
+                destination.clone(),
+                std_accounts.token_program.clone(),
+                required_account(std_accounts.ixs_sysvar)?.clone(),
+            ],
+            std_accounts,
+        )
+    }
+
+    fn borrow<'c>(
+        &self,
+        base_unit_amount: u64,
+        destination: &'a AccountInfo<'a>,
+        std_accounts: &'c Box<SolautoStandardAccounts<'a>>,
+    ) -> ProgramResult {
+        let authority = get_owner(&std_accounts.solauto_position, self.signer);
+        self.refresh_if_stale()?;
+
+        let reserve_liquidity_mint = required_account(self.debt.token_accounts.mint)?;
+        let reserve_liquidity_supply = required_account(self.debt.token_accounts.protocol_ta)?;
+        let reserve_fee_vault = required_account(self.debt.fee_vault)?;
+
+        let ix = borrow_obligation_liquidity(
+            BorrowAccounts {
+                owner: *authority.key,
+                obligation: *self.obligation.key,
+                lending_market: *self.lending_market.key,
+                lending_market_authority: *self.lending_market_authority.key,
+                borrow_reserve: *self.debt.reserve.account_info.key,
+                borrow_reserve_liquidity_mint: *reserve_liquidity_mint.key,
+                reserve_source_liquidity: *reserve_liquidity_supply.key,
+                borrow_reserve_liquidity_fee_receiver: *reserve_fee_vault.key,
+                user_destination_liquidity: *destination.key,
+                token_program: *std_accounts.token_program.key,
+            },
+            base_unit_amount,
+        );
+
+        self.invoke(
+            ix,
+            vec![
+                authority.clone(),
+                self.obligation.clone(),
+                self.lending_market.clone(),
+                self.lending_market_authority.clone(),
+                self.debt.reserve.account_info.clone(),
+                reserve_liquidity_mint.clone(),
+                reserve_liquidity_supply.clone(),
+                reserve_fee_vault.clone(),
+                destination.clone(),
+                std_accounts.token_program.clone(),
+                required_account(std_accounts.ixs_sysvar)?.clone(),
+            ],
+            std_accounts,
+        )
+    }
+
+    fn repay<'c>(
+        &self,
+        amount: TokenBalanceAmount,
+        std_accounts: &'c Box<SolautoStandardAccounts<'a>>,
+    ) -> ProgramResult {
+        let authority = get_owner(&std_accounts.solauto_position, self.signer);
+        self.refresh_if_stale()?;
+
+        let liquidity_amount = match amount {
+            TokenBalanceAmount::Some(num) => num,
+            TokenBalanceAmount::All => u64::MAX,
+        };
+
+        let user_source_liquidity = if !std_accounts.solauto_position.data.self_managed.val {
+            required_account(self.debt.token_accounts.position_ta)?
+        } else {
+            required_account(self.debt.token_accounts.authority_ta)?
+        };
+        let reserve_liquidity_mint = required_account(self.debt.token_accounts.mint)?;
+        let reserve_liquidity_supply = required_account(self.debt.token_accounts.protocol_ta)?;
+
+        let ix = repay_obligation_liquidity(
+            RepayAccounts {
+                owner: *authority.key,
+                obligation: *self.obligation.key,
+                lending_market: *self.lending_market.key,
+                repay_reserve: *self.debt.reserve.account_info.key,
+                reserve_liquidity_mint: *reserve_liquidity_mint.key,
+                reserve_destination_liquidity: *reserve_liquidity_supply.key,
+                user_source_liquidity: *user_source_liquidity.key,
+                token_program: *std_accounts.token_program.key,
+            },
+            liquidity_amount,
+        );
+
+        self.invoke(
+            ix,
+            vec![
+                authority.clone(),
+                self.obligation.clone(),
+                self.lending_market.clone(),
+                self.debt.reserve.account_info.clone(),
+                reserve_liquidity_mint.clone(),
+                reserve_liquidity_supply.clone(),
+                user_source_liquidity.clone(),
+                std_accounts.token_program.clone(),
+                required_account(std_accounts.ixs_sysvar)?.clone(),
+            ],
+            std_accounts,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashSet,
+        sync::{Arc, Mutex},
+    };
+
+    use bytemuck::Zeroable;
+    use solana_program::{
+        program_stubs::{set_syscall_stubs, SyscallStubs},
+        pubkey::Pubkey,
+    };
+
+    use super::*;
+    use crate::state::solauto_position::SolautoPosition;
+
+    const SF_ONE: u128 = 1 << 60;
+
+    fn fake_account(key: Pubkey, data: Vec<u8>) -> &'static AccountInfo<'static> {
+        fake_account_with_owner(key, KAMINO_ID, data)
+    }
+
+    fn fake_account_with_owner(
+        key: Pubkey,
+        owner: Pubkey,
+        data: Vec<u8>,
+    ) -> &'static AccountInfo<'static> {
+        let key = Box::leak(Box::new(key));
+        let owner = Box::leak(Box::new(owner));
+        let lamports = Box::leak(Box::new(0u64));
+        let data = Box::leak(data.into_boxed_slice());
+        Box::leak(Box::new(AccountInfo::new(
+            key, false, true, lamports, data, owner, false, 0,
+        )))
+    }
+
+    fn fake_reserve(
+        ltv_pct: u8,
+        liq_threshold_pct: u8,
+        borrow_factor_pct: u64,
+        price: u128,
+    ) -> Reserve {
+        let mut reserve = Reserve::zeroed();
+        reserve.discriminator = kamino_sdk::accounts::RESERVE_DISCRIMINATOR;
+        reserve.liquidity.mint_pubkey = Pubkey::new_unique();
+        reserve.liquidity.mint_decimals = 6;
+        reserve.liquidity.available_amount = 1_500_000;
+        reserve.liquidity.borrowed_amount_sf = (500_000 * SF_ONE).to_le_bytes();
+        reserve.liquidity.market_price_sf = (price * SF_ONE).to_le_bytes();
+        reserve.collateral.mint_total_supply = 1_000_000;
+        reserve.config.loan_to_value_pct = ltv_pct;
+        reserve.config.liquidation_threshold_pct = liq_threshold_pct;
+        reserve.config.borrow_factor_pct = borrow_factor_pct;
+        reserve.config.deposit_limit = 5_000_000;
+        reserve.config.borrow_limit = 1_000_000;
+        reserve.config.fees.borrow_fee_sf = (SF_ONE / 1000) as u64;
+        reserve.config.protocol_take_rate_pct = 20;
+        reserve.config.host_fixed_interest_rate_bps = 50;
+        let curve = &mut reserve.config.borrow_rate_curve;
+        curve[1].utilization_rate_bps = 8000;
+        curve[1].borrow_rate_bps = 1000;
+        for point in curve[2..].iter_mut() {
+            point.utilization_rate_bps = 10_000;
+            point.borrow_rate_bps = 5000;
+        }
+        reserve
+    }
+
+    #[test]
+    fn test_get_updated_state() {
+        let supply_reserve_data = fake_reserve(80, 85, 100, 150);
+        let debt_reserve_data = fake_reserve(0, 0, 125, 1);
+        let supply_reserve = fake_account(
+            Pubkey::new_unique(),
+            bytemuck::bytes_of(&supply_reserve_data).to_vec(),
+        );
+        let debt_reserve = fake_account(
+            Pubkey::new_unique(),
+            bytemuck::bytes_of(&debt_reserve_data).to_vec(),
+        );
+
+        let mut obligation_data = Obligation::zeroed();
+        obligation_data.discriminator = kamino_sdk::accounts::OBLIGATION_DISCRIMINATOR;
+        obligation_data.deposits[0].deposit_reserve = *supply_reserve.key;
+        obligation_data.deposits[0].deposited_amount = 100_000;
+        obligation_data.borrows[0].borrow_reserve = *debt_reserve.key;
+        obligation_data.borrows[0].borrowed_amount_sf = (2_500_000 * SF_ONE / 10).to_le_bytes();
+        let obligation = fake_account(
+            Pubkey::new_unique(),
+            bytemuck::bytes_of(&obligation_data).to_vec(),
+        );
+
+        let state = KaminoClient::get_updated_state(obligation, supply_reserve, debt_reserve)
+            .expect("Should deserialize");
+
+        assert_eq!(state.max_ltv, 0.8 / 1.25);
+        assert_eq!(state.liq_threshold, 0.85 / 1.25);
+
+        // 2,000,000 liquidity backing 1,000,000 collateral
+        assert_eq!(state.supply.amount_used, 200_000);
+        assert_eq!(state.supply.amount_can_be_used, 3_000_000);
+        assert_eq!(state.supply.market_price, 150.0);
+        assert_eq!(state.supply.mint, supply_reserve_data.liquidity.mint_pubkey);
+
+        assert_eq!(state.debt.amount_used, 250_000);
+        assert_eq!(state.debt.amount_can_be_used, 500_000);
+        assert_eq!(state.debt.market_price, 1.0);
+        assert_eq!(state.debt.borrow_fee_bps, Some(10));
+
+        // 25% utilization is 312.5 bps along the curve, plus the host's fixed rate for borrowers
+        assert_eq!(state.debt.borrow_apr_bps, 363);
+        assert_eq!(state.debt.supply_apr_bps, 63);
+    }
+
+    #[test]
+    fn test_rejects_wrong_account_type() {
+        let reserve = fake_account(
+            Pubkey::new_unique(),
+            bytemuck::bytes_of(&fake_reserve(80, 85, 100, 1)).to_vec(),
+        );
+        assert!(KaminoClient::load_obligation(reserve).is_err());
+    }
+
+    #[test]
+    fn test_validates_reserve_owner_and_market() {
+        let lending_market = Pubkey::new_unique();
+        let mut reserve_data = fake_reserve(80, 85, 100, 1);
+        reserve_data.lending_market = lending_market;
+        let mint = reserve_data.liquidity.mint_pubkey;
+
+        let reserve = fake_account(
+            Pubkey::new_unique(),
+            bytemuck::bytes_of(&reserve_data).to_vec(),
+        );
+        assert!(validate_kamino_reserve(reserve, &mint, &lending_market).is_ok());
+        assert!(validate_kamino_reserve(reserve, &mint, &Pubkey::new_unique()).is_err());
+
+        let foreign_reserve = fake_account_with_owner(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            bytemuck::bytes_of(&reserve_data).to_vec(),
+        );
+        assert!(KaminoClient::load_reserve(foreign_reserve).is_err());
+    }
+
+    /// Local stand-in for klend. Each deposit, withdraw, borrow & repay requires a refreshed obligation & reserves,
+    /// and leaves them stale, while refresh_obligation requires its reserves to be refreshed
+    struct KlendStandIn {
+        obligation_and_reserves: HashSet<Pubkey>,
+        stale: Mutex<HashSet<Pubkey>>,
+        invoked: Arc<Mutex<Vec<[u8; 8]>>>,
+    }
+
+    impl SyscallStubs for KlendStandIn {
+        fn sol_invoke_signed(
+            &self,
+            instruction: &Instruction,
+            _account_infos: &[AccountInfo],
+            _signers_seeds: &[&[&[u8]]],
+        ) -> ProgramResult {
+            if instruction.program_id != KAMINO_ID {
+                return Ok(());
+            }
+
+            let discriminator: [u8; 8] = instruction.data[..8].try_into().unwrap();
+            let keys: Vec<Pubkey> = instruction
+                .accounts
+                .iter()
+                .map(|meta| meta.pubkey)
+                .filter(|key| self.obligation_and_reserves.contains(key))
+                .collect();
+            let mut stale = self.stale.lock().unwrap();
+            match discriminator {
+                REFRESH_RESERVE_DISCRIMINATOR => {
+                    stale.remove(&keys[0]);
+                }
+                REFRESH_OBLIGATION_DISCRIMINATOR => {
+                    if keys[1..].iter().any(|key| stale.contains(key)) {
+                        return Err(ProgramError::Custom(0));
+                    }
+                    stale.remove(&keys[0]);
+                }
+                _ => {
+                    if keys.iter().any(|key| stale.contains(key)) {
+                        return Err(ProgramError::Custom(0));
+                    }
+                    stale.extend(keys);
+                }
+            }
+            self.invoked.lock().unwrap().push(discriminator);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_refreshes_between_kamino_instructions() {
+        let empty_account = || fake_account(Pubkey::new_unique(), vec![]);
+        let supply_reserve = fake_account(
+            Pubkey::new_unique(),
+            bytemuck::bytes_of(&fake_reserve(80, 85, 100, 150)).to_vec(),
+        );
+        let debt_reserve = fake_account(
+            Pubkey::new_unique(),
+            bytemuck::bytes_of(&fake_reserve(0, 0, 100, 1)).to_vec(),
+        );
+        let mut obligation_data = Obligation::zeroed();
+        obligation_data.discriminator = kamino_sdk::accounts::OBLIGATION_DISCRIMINATOR;
+        obligation_data.deposits[0].deposit_reserve = *supply_reserve.key;
+        obligation_data.deposits[0].deposited_amount = 100_000;
+        obligation_data.borrows[0].borrow_reserve = *debt_reserve.key;
+        obligation_data.borrows[0].borrowed_amount_sf = (100 * SF_ONE).to_le_bytes();
+        let obligation = fake_account(
+            Pubkey::new_unique(),
+            bytemuck::bytes_of(&obligation_data).to_vec(),
+        );
+
+        let invoked = Arc::new(Mutex::new(Vec::new()));
+        set_syscall_stubs(Box::new(KlendStandIn {
+            obligation_and_reserves: HashSet::from([
+                *obligation.key,
+                *supply_reserve.key,
+                *debt_reserve.key,
+            ]),
+            stale: Mutex::new(HashSet::new()),
+            invoked: invoked.clone(),
+        }));
+
+        let token_accounts = || LendingProtocolTokenAccounts {
+            mint: Some(empty_account()),
+            position_ta: Some(empty_account()),
+            authority_ta: None,
+            protocol_ta: Some(empty_account()),
+        };
+        let accounts = KaminoProtocolAccounts {
+            kamino_program: fake_account(KAMINO_ID, vec![]),
+            lending_market: empty_account(),
+            lending_market_authority: Some(empty_account()),
+            obligation,
+            user_metadata: None,
+            supply_reserve,
+            supply_liquidity_vault: None,
+            supply_collateral_mint: Some(empty_account()),
+            supply_collateral_vault: Some(empty_account()),
+            supply_oracles: KaminoReserveOracles::default(),
+            debt_reserve,
+            debt_liquidity_vault: None,
+            debt_fee_vault: Some(empty_account()),
+            debt_oracles: KaminoReserveOracles::default(),
+        };
+        let client = KaminoClient::from(
+            &accounts,
+            empty_account(),
+            token_accounts(),
+            token_accounts(),
+        )
+        .unwrap();
+
+        let std_accounts = Box::new(SolautoStandardAccounts {
+            signer: empty_account(),
+            lending_protocol: fake_account(KAMINO_ID, vec![]),
+            system_program: empty_account(),
+            token_program: empty_account(),
+            ata_program: None,
+            rent: None,
+            ixs_sysvar: Some(empty_account()),
+            solauto_position: DeserializedAccount {
+                account_info: empty_account(),
+                data: Box::new(SolautoPosition::zeroed()),
+            },
+            solauto_fees_ta: None,
+            authority_referral_state: None,
+            referred_by_ta: None,
+        });
+
+        client.deposit(100, &std_accounts).unwrap();
+        client.borrow(50, empty_account(), &std_accounts).unwrap();
+
+        assert_eq!(
+            *invoked.lock().unwrap(),
+            vec![
+                DEPOSIT_RESERVE_LIQUIDITY_AND_OBLIGATION_COLLATERAL_DISCRIMINATOR,
+                REFRESH_RESERVE_DISCRIMINATOR,
+                REFRESH_RESERVE_DISCRIMINATOR,
+                REFRESH_OBLIGATION_DISCRIMINATOR,
+                BORROW_OBLIGATION_LIQUIDITY_DISCRIMINATOR,
+            ]
+        );
+    }
+}
@@ -16,19 +16,22 @@ use std::{
     ops::{Div, Mul, Sub},
 };
 use switchboard_on_demand::PullFeedAccountData;
-use switchboard_v2::AggregatorAccountData;
+use switchboard_v2::{AggregatorAccountData, SwitchboardDecimal};
 
 use crate::{
+    check,
+    constants::{MAX_ORACLE_CONFIDENCE_BPS, MAX_ORACLE_PRICE_AGE_SECS},
+    error_if,
     state::solauto_position::SolautoPosition,
     types::{
         errors::SolautoError,
-        instruction::{
-            accounts::{Context, MarginfiOpenPositionAccounts},
-            SolautoStandardAccounts,
+        instruction::SolautoStandardAccounts,
+        lending_protocol::{
+            required_account, LendingProtocolAccounts, LendingProtocolAccountsParser,
+            LendingProtocolClient, LendingProtocolTokenAccounts,
         },
-        lending_protocol::{LendingProtocolClient, LendingProtocolTokenAccounts},
         shared::{
-            AccountMetaFlags, DeserializedAccount, PriceType, RefreshStateProps,
+            AccountMetaFlags, DeserializedAccount, PriceType, RefreshStateProps, RefreshedLegState,
             RefreshedTokenState, TokenBalanceAmount, TokenType,
         },
     },
@@ -45,6 +48,182 @@ pub struct MarginfiBankAccounts<'a> {
     pub token_accounts: LendingProtocolTokenAccounts<'a>,
 }
 
+/// Bank & price oracle of a position leg other than the supply & debt legs an instruction acts on.
+/// Every other active balance of the Marginfi account must be provided, as (bank, price_oracle) pairs,
+/// so that the position health can be computed across all of them
+#[derive(Clone, Copy)]
+pub struct MarginfiLegAccounts<'a> {
+    pub bank: &'a AccountInfo<'a>,
+    pub price_oracle: &'a AccountInfo<'a>,
+}
+
+impl<'a> MarginfiLegAccounts<'a> {
+    pub fn parse(accounts: &'a [AccountInfo<'a>]) -> Result<Vec<Self>, ProgramError> {
+        let chunks = accounts.chunks_exact(2);
+        check!(
+            chunks.remainder().is_empty(),
+            SolautoError::IncorrectAccounts
+        );
+        Ok(chunks
+            .map(|chunk| Self {
+                bank: &chunk[0],
+                price_oracle: &chunk[1],
+            })
+            .collect())
+    }
+}
+
+pub struct MarginfiEmissionsAccounts<'a> {
+    pub bank: &'a AccountInfo<'a>,
+    pub emissions_mint: &'a AccountInfo<'a>,
+    pub emissions_auth: &'a AccountInfo<'a>,
+    pub emissions_vault: &'a AccountInfo<'a>,
+}
+
+/// Remaining accounts layout of the generic position instructions:
+/// marginfi_group, marginfi_account, supply_bank, supply_price_oracle?, vault_supply_ta?, supply_vault_authority?,
+/// debt_bank, debt_price_oracle?, vault_debt_ta?, debt_vault_authority?, followed by the `MarginfiLegAccounts` pairs
+pub struct MarginfiProtocolAccounts<'a> {
+    pub marginfi_program: &'a AccountInfo<'a>,
+    pub marginfi_group: &'a AccountInfo<'a>,
+    pub marginfi_account: &'a AccountInfo<'a>,
+    pub supply_bank: &'a AccountInfo<'a>,
+    pub supply_price_oracle: Option<&'a AccountInfo<'a>>,
+    pub vault_supply_ta: Option<&'a AccountInfo<'a>>,
+    pub supply_vault_authority: Option<&'a AccountInfo<'a>>,
+    pub debt_bank: &'a AccountInfo<'a>,
+    pub debt_price_oracle: Option<&'a AccountInfo<'a>>,
+    pub vault_debt_ta: Option<&'a AccountInfo<'a>>,
+    pub debt_vault_authority: Option<&'a AccountInfo<'a>>,
+    pub leg_accounts: Vec<MarginfiLegAccounts<'a>>,
+}
+
+impl<'a> MarginfiProtocolAccounts<'a> {
+    pub fn parse(
+        marginfi_program: &'a AccountInfo<'a>,
+        remaining_accounts: &'a [AccountInfo<'a>],
+    ) -> Result<Self, ProgramError> {
+        let mut parser = LendingProtocolAccountsParser::from(remaining_accounts);
+        Ok(Self {
+            marginfi_program,
+            marginfi_group: parser.next_account()?,
+            marginfi_account: parser.next_account()?,
+            supply_bank: parser.next_account()?,
+            supply_price_oracle: parser.next_optional_account()?,
+            vault_supply_ta: parser.next_optional_account()?,
+            supply_vault_authority: parser.next_optional_account()?,
+            debt_bank: parser.next_account()?,
+            debt_price_oracle: parser.next_optional_account()?,
+            vault_debt_ta: parser.next_optional_account()?,
+            debt_vault_authority: parser.next_optional_account()?,
+            leg_accounts: MarginfiLegAccounts::parse(parser.remaining())?,
+        })
+    }
+}
+
+impl<'a> LendingProtocolAccounts<'a> for MarginfiProtocolAccounts<'a> {
+    fn lp_pool_account(&self) -> &'a AccountInfo<'a> {
+        self.marginfi_group
+    }
+
+    fn lp_user_account(&self) -> &'a AccountInfo<'a> {
+        self.marginfi_account
+    }
+
+    fn lp_supply_account(&self) -> &'a AccountInfo<'a> {
+        self.supply_bank
+    }
+
+    fn lp_debt_account(&self) -> &'a AccountInfo<'a> {
+        self.debt_bank
+    }
+
+    fn protocol_tas(&self) -> (Option<&'a AccountInfo<'a>>, Option<&'a AccountInfo<'a>>) {
+        (self.vault_supply_ta, self.vault_debt_ta)
+    }
+
+    fn get_max_ltv_and_liq_threshold(&self) -> Result<(f64, f64), ProgramError> {
+        MarginfiClient::get_max_ltv_and_liq_threshold(self.supply_bank, self.debt_bank)
+    }
+
+    fn initialize(&self, std_accounts: &SolautoStandardAccounts<'a>) -> ProgramResult {
+        MarginfiClient::initialize(
+            std_accounts.signer,
+            self.marginfi_program,
+            std_accounts.system_program,
+            self.marginfi_group,
+            self.marginfi_account,
+            &std_accounts.solauto_position,
+        )
+    }
+
+    fn refresh_accounts(&self) -> ProgramResult {
+        MarginfiClient::refresh_bank(self.marginfi_program, self.marginfi_group, self.supply_bank)?;
+        MarginfiClient::refresh_bank(self.marginfi_program, self.marginfi_group, self.debt_bank)?;
+        for leg in self.leg_accounts.iter() {
+            MarginfiClient::refresh_bank(self.marginfi_program, self.marginfi_group, leg.bank)?;
+        }
+        Ok(())
+    }
+
+    fn get_updated_state(&self, price_type: PriceType) -> Result<RefreshStateProps, ProgramError> {
+        let marginfi_account =
+            DeserializedAccount::<MarginfiAccount>::zerocopy(Some(self.marginfi_account))?.unwrap();
+        MarginfiClient::get_updated_state(
+            &marginfi_account,
+            self.supply_bank,
+            required_account(self.supply_price_oracle)?,
+            self.debt_bank,
+            required_account(self.debt_price_oracle)?,
+            &self.leg_accounts,
+            price_type,
+        )
+    }
+
+    fn load_prices(&self, price_type: PriceType) -> Result<(f64, f64), ProgramError> {
+        let supply_price = MarginfiClient::load_price(
+            &DeserializedAccount::<Bank>::zerocopy(Some(self.supply_bank))?.unwrap(),
+            required_account(self.supply_price_oracle)?,
+            price_type,
+            TokenType::Supply,
+        )?;
+        let debt_price = MarginfiClient::load_price(
+            &DeserializedAccount::<Bank>::zerocopy(Some(self.debt_bank))?.unwrap(),
+            required_account(self.debt_price_oracle)?,
+            price_type,
+            TokenType::Debt,
+        )?;
+        Ok((supply_price, debt_price))
+    }
+
+    fn load_leg_prices(&self, price_type: PriceType) -> Result<Vec<f64>, ProgramError> {
+        MarginfiClient::load_leg_prices(self.marginfi_account, &self.leg_accounts, price_type)
+    }
+
+    fn client(
+        &self,
+        signer: &'a AccountInfo<'a>,
+        supply_tas: LendingProtocolTokenAccounts<'a>,
+        debt_tas: LendingProtocolTokenAccounts<'a>,
+    ) -> Result<Box<dyn LendingProtocolClient<'a> + 'a>, ProgramError> {
+        Ok(Box::new(MarginfiClient::from(
+            signer,
+            self.marginfi_program,
+            self.marginfi_group,
+            self.marginfi_account,
+            self.supply_bank,
+            self.supply_price_oracle,
+            supply_tas,
+            self.supply_vault_authority,
+            self.debt_bank,
+            self.debt_price_oracle,
+            debt_tas,
+            self.debt_vault_authority,
+            self.leg_accounts.clone(),
+        )?))
+    }
+}
+
 pub struct MarginfiClient<'a> {
     signer: &'a AccountInfo<'a>,
     program: &'a AccountInfo<'a>,
@@ -52,32 +231,175 @@ pub struct MarginfiClient<'a> {
     marginfi_group: &'a AccountInfo<'a>,
     supply: MarginfiBankAccounts<'a>,
     debt: MarginfiBankAccounts<'a>,
+    legs: Vec<MarginfiLegAccounts<'a>>,
+}
+
+/// USD value & Marginfi weights of a position leg, used to blend the position's max LTV & liquidation threshold
+#[derive(Clone, Copy, Debug)]
+struct LegWeights {
+    usd_value: f64,
+    weight_init: f64,
+    weight_maint: f64,
+}
+
+impl LegWeights {
+    fn from(token: &RefreshedTokenState, weight_init: f64, weight_maint: f64) -> Self {
+        Self {
+            usd_value: base_unit_to_usd_value(
+                token.amount_used,
+                token.decimals,
+                token.market_price,
+            ),
+            weight_init,
+            weight_maint,
+        }
+    }
+}
+
+/// Value-weighted average of the legs' (init, maint) weights. Falls back to the weights of the first leg
+/// when none of the legs hold any value
+fn get_blended_weights(legs: &[LegWeights]) -> (f64, f64) {
+    let total_usd: f64 = legs.iter().map(|leg| leg.usd_value).sum();
+    if total_usd == 0.0 {
+        return (legs[0].weight_init, legs[0].weight_maint);
+    }
+
+    let weight_init: f64 = legs
+        .iter()
+        .map(|leg| leg.usd_value.mul(leg.weight_init))
+        .sum();
+    let weight_maint: f64 = legs
+        .iter()
+        .map(|leg| leg.usd_value.mul(leg.weight_maint))
+        .sum();
+    (weight_init.div(total_usd), weight_maint.div(total_usd))
+}
+
+/// Marginfi health across all legs is sum(asset_value * asset_weight) vs. sum(liability_value * liability_weight),
+/// which is equivalent to comparing the totals with the blended weights
+fn get_blended_max_ltv_and_liq_threshold(
+    supply_legs: &[LegWeights],
+    debt_legs: &[LegWeights],
+) -> (f64, f64) {
+    let (asset_weight_init, asset_weight_maint) = get_blended_weights(supply_legs);
+    let (liability_weight_init, liability_weight_maint) = get_blended_weights(debt_legs);
+    (
+        asset_weight_init.div(liability_weight_init),
+        asset_weight_maint.div(liability_weight_maint),
+    )
+}
+
+/// The first active balance that isn't a supply on the supply bank or a debt on the debt bank
+fn find_unexpected_balance<'b>(
+    balances: &'b [Balance],
+    supply_bank: &Pubkey,
+    debt_bank: &Pubkey,
+) -> Option<&'b Balance> {
+    balances.iter().find(|balance| {
+        let asset_shares = I80F48::from_le_bytes(balance.asset_shares.value);
+        let liability_shares = I80F48::from_le_bytes(balance.liability_shares.value);
+        balance.active == 1
+            && !(&balance.bank_pk == supply_bank && liability_shares == I80F48::ZERO)
+            && !(&balance.bank_pk == debt_bank && asset_shares == I80F48::ZERO)
+    })
+}
+
+/// Rejects prices that are too old, aren't positive, or whose confidence interval is too wide to be relied on
+fn validate_oracle_price(
+    price: f64,
+    conf_interval: f64,
+    publish_time: i64,
+    curr_unix_timestamp: i64,
+) -> ProgramResult {
+    if curr_unix_timestamp.saturating_sub(publish_time) > MAX_ORACLE_PRICE_AGE_SECS {
+        msg!(
+            "Oracle price was published at {}, more than {} seconds ago",
+            publish_time,
+            MAX_ORACLE_PRICE_AGE_SECS
+        );
+        return Err(SolautoError::StaleOraclePrice.into());
+    }
+    error_if!(price <= 0.0, SolautoError::InvalidOraclePrice);
+    error_if!(
+        conf_interval.div(price) > from_bps(MAX_ORACLE_CONFIDENCE_BPS),
+        SolautoError::OracleConfidenceTooWide
+    );
+
+    Ok(())
+}
+
+/// Returns the bank's current (supply, borrow) APR in bps, from its utilization & interest rate curve
+fn get_bank_apr_bps(bank: &Bank) -> (u32, u32) {
+    let total_deposited = I80F48::from_le_bytes(bank.total_asset_shares.value)
+        .mul(I80F48::from_le_bytes(bank.asset_share_value.value));
+    let total_borrows = I80F48::from_le_bytes(bank.total_liability_shares.value)
+        .mul(I80F48::from_le_bytes(bank.liability_share_value.value));
+    let utilization_rate = if total_deposited > I80F48::ZERO {
+        i80f48_to_f64(total_borrows)
+            .div(i80f48_to_f64(total_deposited))
+            .min(1.0)
+    } else {
+        0.0
+    };
+
+    let config = &bank.config.interest_rate_config;
+    let rate = |value: [u8; 16]| i80f48_to_f64(I80F48::from_le_bytes(value));
+    let optimal_utilization_rate = rate(config.optimal_utilization_rate.value);
+    let plateau_interest_rate = rate(config.plateau_interest_rate.value);
+    let max_interest_rate = rate(config.max_interest_rate.value);
+
+    let base_rate = if utilization_rate <= optimal_utilization_rate {
+        if optimal_utilization_rate > 0.0 {
+            utilization_rate.div(optimal_utilization_rate) * plateau_interest_rate
+        } else {
+            plateau_interest_rate
+        }
+    } else {
+        (utilization_rate - optimal_utilization_rate).div(1.0 - optimal_utilization_rate)
+            * (max_interest_rate - plateau_interest_rate)
+            + plateau_interest_rate
+    };
+
+    let supply_rate = base_rate * utilization_rate;
+    let borrow_rate = base_rate
+        * (1.0 + rate(config.insurance_ir_fee.value) + rate(config.protocol_ir_fee.value))
+        + rate(config.insurance_fee_fixed_apr.value)
+        + rate(config.protocol_fixed_fee_apr.value);
+
+    (
+        supply_rate.mul(10_000.0).round() as u32,
+        borrow_rate.mul(10_000.0).round() as u32,
+    )
 }
 
 impl<'a> MarginfiClient<'a> {
     pub fn initialize<'c>(
-        ctx: &'c Context<'a, MarginfiOpenPositionAccounts<'a>>,
+        signer: &'a AccountInfo<'a>,
+        marginfi_program: &'a AccountInfo<'a>,
+        system_program: &'a AccountInfo<'a>,
+        marginfi_group: &'a AccountInfo<'a>,
+        marginfi_account: &'a AccountInfo<'a>,
         solauto_position: &'c DeserializedAccount<'a, SolautoPosition>,
     ) -> ProgramResult {
-        if account_has_data(ctx.accounts.marginfi_account) {
+        if account_has_data(marginfi_account) {
             return Ok(());
         }
 
-        let marginfi_account_owner = get_owner(solauto_position, ctx.accounts.signer);
+        let marginfi_account_owner = get_owner(solauto_position, signer);
         let cpi = MarginfiAccountInitializeCpi::new(
-            ctx.accounts.marginfi_program,
+            marginfi_program,
             MarginfiAccountInitializeCpiAccounts {
-                marginfi_group: ctx.accounts.marginfi_group,
-                marginfi_account: ctx.accounts.marginfi_account,
+                marginfi_group,
+                marginfi_account,
                 authority: marginfi_account_owner,
-                fee_payer: ctx.accounts.signer,
-                system_program: ctx.accounts.system_program,
+                fee_payer: signer,
+                system_program,
             },
         );
         if marginfi_account_owner.key == solauto_position.account_info.key {
             let mut marginfi_account_seeds = vec![
                 solauto_position.account_info.key.as_ref(),
-                ctx.accounts.marginfi_group.key.as_ref(),
+                marginfi_group.key.as_ref(),
             ];
             let (_, bump) =
                 Pubkey::find_program_address(marginfi_account_seeds.as_slice(), &crate::ID);
@@ -93,6 +415,60 @@ impl<'a> MarginfiClient<'a> {
         }
     }
 
+    /// Validates that an existing Marginfi account can be imported into a Solauto position: it must belong to the authority & group,
+    /// and only hold a supply balance on the supply bank and a debt balance on the debt bank
+    pub fn validate_imported_account(
+        marginfi_account: &DeserializedAccount<MarginfiAccount>,
+        authority: &Pubkey,
+        marginfi_group: &Pubkey,
+        supply_bank: &Pubkey,
+        debt_bank: &Pubkey,
+    ) -> ProgramResult {
+        check!(
+            &marginfi_account.data.authority == authority
+                && &marginfi_account.data.group == marginfi_group,
+            SolautoError::IncorrectAccounts
+        );
+
+        if let Some(balance) = find_unexpected_balance(
+            &marginfi_account.data.lending_account.balances,
+            supply_bank,
+            debt_bank,
+        ) {
+            msg!("Unexpected active balance on bank {}", balance.bank_pk);
+            return Err(SolautoError::IncorrectAccounts.into());
+        }
+
+        Ok(())
+    }
+
+    /// Moves the authority of the Marginfi account. When the Solauto position is the current authority, it signs for the change
+    pub fn set_account_authority<'c>(
+        marginfi_program: &'a AccountInfo<'a>,
+        marginfi_group: &'a AccountInfo<'a>,
+        marginfi_account: &'a AccountInfo<'a>,
+        authority: &'a AccountInfo<'a>,
+        new_authority: &'a AccountInfo<'a>,
+        fee_payer: &'a AccountInfo<'a>,
+        solauto_position: &'c DeserializedAccount<'a, SolautoPosition>,
+    ) -> ProgramResult {
+        let cpi = SetNewAccountAuthorityCpi::new(
+            marginfi_program,
+            SetNewAccountAuthorityCpiAccounts {
+                marginfi_account,
+                marginfi_group,
+                signer: authority,
+                new_authority,
+                fee_payer,
+            },
+        );
+        if authority.key == solauto_position.account_info.key {
+            cpi.invoke_signed(&[solauto_position.data.seeds_with_bump().as_slice()])
+        } else {
+            cpi.invoke()
+        }
+    }
+
     pub fn from(
         signer: &'a AccountInfo<'a>,
         program: &'a AccountInfo<'a>,
@@ -106,6 +482,7 @@ impl<'a> MarginfiClient<'a> {
         debt_price_oracle: Option<&'a AccountInfo<'a>>,
         debt_tas: LendingProtocolTokenAccounts<'a>,
         debt_vault_authority: Option<&'a AccountInfo<'a>>,
+        legs: Vec<MarginfiLegAccounts<'a>>,
     ) -> Result<Self, ProgramError> {
         let supply = MarginfiBankAccounts {
             bank: DeserializedAccount::<Bank>::zerocopy(Some(supply_bank))?.unwrap(),
@@ -128,6 +505,7 @@ impl<'a> MarginfiClient<'a> {
             marginfi_group,
             supply,
             debt,
+            legs,
         })
     }
 
@@ -150,36 +528,52 @@ impl<'a> MarginfiClient<'a> {
         })
     }
 
+    /// Returns the bank's (init, maint) asset weights for supply, or liability weights for debt
+    fn get_bank_weights(
+        bank: &'a AccountInfo<'a>,
+        token_type: TokenType,
+    ) -> Result<(f64, f64), ProgramError> {
+        let bank = DeserializedAccount::<Bank>::zerocopy(Some(bank))?.unwrap();
+        let (weight_init, weight_maint) = if token_type == TokenType::Supply {
+            (
+                bank.data.config.asset_weight_init.value,
+                bank.data.config.asset_weight_maint.value,
+            )
+        } else {
+            (
+                bank.data.config.liability_weight_init.value,
+                bank.data.config.liability_weight_maint.value,
+            )
+        };
+
+        Ok((
+            i80f48_to_f64(I80F48::from_le_bytes(weight_init)),
+            i80f48_to_f64(I80F48::from_le_bytes(weight_maint)),
+        ))
+    }
+
     pub fn get_max_ltv_and_liq_threshold(
         supply_bank: &'a AccountInfo<'a>,
         debt_bank: &'a AccountInfo<'a>,
     ) -> Result<(f64, f64), ProgramError> {
-        let supply_bank = DeserializedAccount::<Bank>::zerocopy(Some(supply_bank))?.unwrap();
-        let debt_bank = DeserializedAccount::<Bank>::zerocopy(Some(debt_bank))?.unwrap();
+        let (asset_weight_init, asset_weight_maint) =
+            MarginfiClient::get_bank_weights(supply_bank, TokenType::Supply)?;
+        let (liability_weight_init, liability_weight_maint) =
+            MarginfiClient::get_bank_weights(debt_bank, TokenType::Debt)?;
 
-        let max_ltv = i80f48_to_f64(I80F48::from_le_bytes(
-            supply_bank.data.config.asset_weight_init.value,
-        ))
-        .div(i80f48_to_f64(I80F48::from_le_bytes(
-            debt_bank.data.config.liability_weight_init.value,
-        )));
-
-        let liq_threshold = i80f48_to_f64(I80F48::from_le_bytes(
-            supply_bank.data.config.asset_weight_maint.value,
+        Ok((
+            asset_weight_init.div(liability_weight_init),
+            asset_weight_maint.div(liability_weight_maint),
         ))
-        .div(i80f48_to_f64(I80F48::from_le_bytes(
-            debt_bank.data.config.liability_weight_maint.value,
-        )));
-
-        Ok((max_ltv, liq_threshold))
     }
 
+    /// Also returns the asset init weight, discounted if the bank's total deposits exceed its total asset value init limit
     pub fn get_supply_token_usage(
         account_balances: &[Balance],
         supply_bank: &'a AccountInfo<'a>,
         price_oracle: &'a AccountInfo<'a>,
         price_type: PriceType,
-        mut max_ltv: f64,
+        mut asset_weight_init: f64,
     ) -> Result<(RefreshedTokenState, f64), ProgramError> {
         let bank = DeserializedAccount::<Bank>::zerocopy(Some(supply_bank))?.unwrap();
 
@@ -208,9 +602,11 @@ impl<'a> MarginfiClient<'a> {
         {
             let discount_factor =
                 (bank.data.config.total_asset_value_init_limit as f64).div(bank_deposits_usd_value);
-            max_ltv = max_ltv * discount_factor;
+            asset_weight_init *= discount_factor;
         }
 
+        let (supply_apr_bps, borrow_apr_bps) = get_bank_apr_bps(&bank.data);
+
         Ok((
             RefreshedTokenState {
                 mint: bank.data.mint,
@@ -219,8 +615,10 @@ impl<'a> MarginfiClient<'a> {
                 amount_can_be_used: i80f48_to_u64(base_unit_deposit_room_available),
                 market_price,
                 borrow_fee_bps: None,
+                supply_apr_bps,
+                borrow_apr_bps,
             },
-            max_ltv,
+            asset_weight_init,
         ))
     }
 
@@ -267,6 +665,7 @@ impl<'a> MarginfiClient<'a> {
         ))
         .mul(10_000.0)
         .round() as u16;
+        let (supply_apr_bps, borrow_apr_bps) = get_bank_apr_bps(&bank.data);
 
         Ok(RefreshedTokenState {
             mint: bank.data.mint,
@@ -275,44 +674,146 @@ impl<'a> MarginfiClient<'a> {
             amount_can_be_used,
             market_price,
             borrow_fee_bps: Some(borrow_fee_bps),
+            supply_apr_bps,
+            borrow_apr_bps,
         })
     }
 
-    pub fn get_updated_state<'b>(
+    pub fn get_updated_state(
         marginfi_account: &DeserializedAccount<MarginfiAccount>,
         supply_bank: &'a AccountInfo<'a>,
         supply_price_oracle: &'a AccountInfo<'a>,
         debt_bank: &'a AccountInfo<'a>,
         debt_price_oracle: &'a AccountInfo<'a>,
+        leg_accounts: &[MarginfiLegAccounts<'a>],
         price_type: PriceType,
     ) -> Result<RefreshStateProps, ProgramError> {
-        let (max_ltv, liq_threshold) =
-            MarginfiClient::get_max_ltv_and_liq_threshold(supply_bank, debt_bank)?;
+        let account_balances = &marginfi_account.data.lending_account.balances[..];
 
-        let account_balances = &marginfi_account.data.lending_account.balances[..2];
         let debt = MarginfiClient::get_debt_token_usage(
             account_balances,
             debt_bank,
             debt_price_oracle,
             price_type,
         )?;
-
-        let (supply, max_ltv) = MarginfiClient::get_supply_token_usage(
+        let (liability_weight_init, liability_weight_maint) =
+            MarginfiClient::get_bank_weights(debt_bank, TokenType::Debt)?;
+        let mut debt_weights = vec![LegWeights::from(
+            &debt,
+            liability_weight_init,
+            liability_weight_maint,
+        )];
+
+        let (asset_weight_init, asset_weight_maint) =
+            MarginfiClient::get_bank_weights(supply_bank, TokenType::Supply)?;
+        let (supply, asset_weight_init) = MarginfiClient::get_supply_token_usage(
             account_balances,
             supply_bank,
             supply_price_oracle,
             price_type,
-            max_ltv,
+            asset_weight_init,
         )?;
+        let mut supply_weights = vec![LegWeights::from(
+            &supply,
+            asset_weight_init,
+            asset_weight_maint,
+        )];
+
+        let mut additional_supply = Vec::new();
+        let mut additional_debt = Vec::new();
+        for balance in account_balances.iter().filter(|balance| {
+            balance.active == 1
+                && &balance.bank_pk != supply_bank.key
+                && &balance.bank_pk != debt_bank.key
+        }) {
+            let leg = leg_accounts
+                .iter()
+                .find(|leg| leg.bank.key == &balance.bank_pk)
+                .ok_or_else(|| {
+                    msg!(
+                        "Missing bank & price oracle for active balance {}",
+                        balance.bank_pk
+                    );
+                    SolautoError::IncorrectAccounts
+                })?;
+
+            if I80F48::from_le_bytes(balance.liability_shares.value) > I80F48::ZERO {
+                let token = MarginfiClient::get_debt_token_usage(
+                    account_balances,
+                    leg.bank,
+                    leg.price_oracle,
+                    price_type,
+                )?;
+                let (weight_init, weight_maint) =
+                    MarginfiClient::get_bank_weights(leg.bank, TokenType::Debt)?;
+                debt_weights.push(LegWeights::from(&token, weight_init, weight_maint));
+                additional_debt.push(RefreshedLegState {
+                    lp_account: *leg.bank.key,
+                    token,
+                });
+            } else {
+                let (weight_init, weight_maint) =
+                    MarginfiClient::get_bank_weights(leg.bank, TokenType::Supply)?;
+                let (token, weight_init) = MarginfiClient::get_supply_token_usage(
+                    account_balances,
+                    leg.bank,
+                    leg.price_oracle,
+                    price_type,
+                    weight_init,
+                )?;
+                supply_weights.push(LegWeights::from(&token, weight_init, weight_maint));
+                additional_supply.push(RefreshedLegState {
+                    lp_account: *leg.bank.key,
+                    token,
+                });
+            }
+        }
+
+        let (max_ltv, liq_threshold) =
+            get_blended_max_ltv_and_liq_threshold(&supply_weights, &debt_weights);
 
         Ok(RefreshStateProps {
             max_ltv,
             liq_threshold,
             supply,
             debt,
+            additional_supply,
+            additional_debt,
         })
     }
 
+    /// Prices of the additional legs, in the order of the leg accounts
+    pub fn load_leg_prices(
+        marginfi_account: &'a AccountInfo<'a>,
+        leg_accounts: &[MarginfiLegAccounts<'a>],
+        price_type: PriceType,
+    ) -> Result<Vec<f64>, ProgramError> {
+        let marginfi_account =
+            DeserializedAccount::<MarginfiAccount>::zerocopy(Some(marginfi_account))?.unwrap();
+        let account_balances = &marginfi_account.data.lending_account.balances[..];
+
+        leg_accounts
+            .iter()
+            .map(|leg| {
+                let token_type = if account_balances.iter().any(|balance| {
+                    balance.active == 1
+                        && &balance.bank_pk == leg.bank.key
+                        && I80F48::from_le_bytes(balance.liability_shares.value) > I80F48::ZERO
+                }) {
+                    TokenType::Debt
+                } else {
+                    TokenType::Supply
+                };
+                MarginfiClient::load_price(
+                    &DeserializedAccount::<Bank>::zerocopy(Some(leg.bank))?.unwrap(),
+                    leg.price_oracle,
+                    price_type,
+                    token_type,
+                )
+            })
+            .collect()
+    }
+
     pub fn load_price(
         bank: &DeserializedAccount<Bank>,
         price_oracle: &AccountInfo,
@@ -320,10 +821,6 @@ impl<'a> MarginfiClient<'a> {
         token_type: TokenType,
     ) -> Result<f64, ProgramError> {
         let clock = Clock::get()?;
-        let max_price_age = 120; // Default used by Marginfi is 60
-
-        // We don't need to check confidence intervals, since Marginfi will already throw stale orcale errors
-        // when we take actions like withdrawing or borrowing
 
         match bank.data.config.oracle_setup {
             OracleSetup::None => {
@@ -333,13 +830,18 @@ impl<'a> MarginfiClient<'a> {
             OracleSetup::PythLegacy => {
                 let price_feed = SolanaPriceAccount::account_info_to_feed(price_oracle)?;
 
-                let price = if price_type == PriceType::Ema {
-                    let price_result = price_feed.get_price_unchecked();
-                    derive_value(price_result.price, price_result.expo)
+                let price_result = if price_type == PriceType::Ema {
+                    price_feed.get_ema_price_unchecked()
                 } else {
-                    let price_result = price_feed.get_price_unchecked();
-                    derive_value(price_result.price, price_result.expo)
+                    price_feed.get_price_unchecked()
                 };
+                let price = derive_value(price_result.price, price_result.expo);
+                validate_oracle_price(
+                    price,
+                    derive_value(price_result.conf as i64, price_result.expo),
+                    price_result.publish_time,
+                    clock.unix_timestamp,
+                )?;
 
                 Ok(price)
             }
@@ -347,22 +849,28 @@ impl<'a> MarginfiClient<'a> {
                 let price_feed_data = price_oracle.try_borrow_data()?;
                 let price_feed = PriceUpdateV2::deserialize(&mut &price_feed_data.as_ref()[8..])?;
 
+                let feed_id = &bank.data.config.oracle_keys[0].to_bytes();
+                let price_result = price_feed
+                    .get_price_unchecked(feed_id)
+                    .map_err(|_| SolautoError::IncorrectAccounts)?;
                 let (price, conf_interval) = if price_type == PriceType::Ema {
-                    let ema_price = price_feed.price_message.ema_price;
-                    let conf = price_feed.price_message.ema_conf;
-                    let exponent = price_feed.price_message.exponent;
+                    let price_message = &price_feed.price_message;
                     (
-                        derive_value(ema_price, exponent),
-                        derive_value(conf as i64, exponent),
+                        derive_value(price_message.ema_price, price_message.exponent),
+                        derive_value(price_message.ema_conf as i64, price_message.exponent),
                     )
                 } else {
-                    let feed_id = &bank.data.config.oracle_keys[0].to_bytes();
-                    let price_result = price_feed.get_price_unchecked(feed_id).unwrap();
                     (
                         derive_value(price_result.price, price_result.exponent),
                         derive_value(price_result.conf as i64, price_result.exponent),
                     )
                 };
+                validate_oracle_price(
+                    price,
+                    conf_interval,
+                    price_result.publish_time,
+                    clock.unix_timestamp,
+                )?;
 
                 Ok(with_conf_interval(
                     price,
@@ -373,15 +881,23 @@ impl<'a> MarginfiClient<'a> {
             OracleSetup::SwitchboardLegacy => {
                 let data = price_oracle.data.borrow();
                 let aggregator_account = AggregatorAccountData::new_from_bytes(&data)?;
-                aggregator_account.check_staleness(clock.unix_timestamp, max_price_age as i64)?;
-                let sw_decimal = aggregator_account.get_result()?;
-
-                let price = if sw_decimal.scale == 0 {
-                    sw_decimal.mantissa as f64
-                } else {
-                    from_base_unit::<i128, u32, f64>(sw_decimal.mantissa, sw_decimal.scale)
+                let round = &aggregator_account.latest_confirmed_round;
+                let switchboard_decimal_value = |sw_decimal: SwitchboardDecimal| {
+                    if sw_decimal.scale == 0 {
+                        sw_decimal.mantissa as f64
+                    } else {
+                        from_base_unit::<i128, u32, f64>(sw_decimal.mantissa, sw_decimal.scale)
+                    }
                 };
 
+                let price = switchboard_decimal_value(aggregator_account.get_result()?);
+                validate_oracle_price(
+                    price,
+                    switchboard_decimal_value(round.std_deviation),
+                    round.round_open_timestamp,
+                    clock.unix_timestamp,
+                )?;
+
                 Ok(price)
             }
             OracleSetup::SwitchboardPull => {
@@ -392,12 +908,21 @@ impl<'a> MarginfiClient<'a> {
                 let price = (feed.result.value as f64)
                     // 10^18
                     .div(1000000000000000000.0);
-                let conf_interval = (feed.result.std_dev as f64)
+                let std_dev = (feed.result.std_dev as f64)
                     // 10^18
-                    .div(1000000000000000000.0)
-                    .mul(STD_DEV_MULTIPLE);
+                    .div(1000000000000000000.0);
+                validate_oracle_price(
+                    price,
+                    std_dev,
+                    feed.last_update_timestamp,
+                    clock.unix_timestamp,
+                )?;
 
-                Ok(with_conf_interval(price, conf_interval, token_type))
+                Ok(with_conf_interval(
+                    price,
+                    std_dev.mul(STD_DEV_MULTIPLE),
+                    token_type,
+                ))
             }
         }
     }
@@ -417,6 +942,70 @@ impl<'a> MarginfiClient<'a> {
         cpi.invoke()
     }
 
+    /// Settles the emissions earned on the bank, and withdraws them to the destination token account.
+    /// The Marginfi account must be owned by the Solauto position
+    pub fn harvest_emissions<'c>(
+        program: &'a AccountInfo<'a>,
+        marginfi_group: &'a AccountInfo<'a>,
+        marginfi_account: &'a AccountInfo<'a>,
+        emissions: MarginfiEmissionsAccounts<'a>,
+        destination: &'a AccountInfo<'a>,
+        token_program: &'a AccountInfo<'a>,
+        solauto_position: &'c DeserializedAccount<'a, SolautoPosition>,
+    ) -> ProgramResult {
+        LendingAccountSettleEmissionsCpi::new(
+            program,
+            LendingAccountSettleEmissionsCpiAccounts {
+                marginfi_account,
+                bank: emissions.bank,
+            },
+        )
+        .invoke()?;
+
+        LendingAccountWithdrawEmissionsCpi::new(
+            program,
+            LendingAccountWithdrawEmissionsCpiAccounts {
+                marginfi_group,
+                marginfi_account,
+                signer: solauto_position.account_info,
+                bank: emissions.bank,
+                emissions_mint: emissions.emissions_mint,
+                emissions_auth: emissions.emissions_auth,
+                emissions_vault: emissions.emissions_vault,
+                destination_account: destination,
+                token_program,
+            },
+        )
+        .invoke_signed(&[solauto_position.data.seeds_with_bump().as_slice()])
+    }
+
+    /// Banks & price oracles of the other legs with an active balance, which Marginfi requires for its health checks
+    fn active_leg_accounts(
+        &self,
+        active_balances: &Vec<&Balance>,
+    ) -> Result<Vec<AccountMetaFlags<'a>>, ProgramError> {
+        let mut accounts = Vec::new();
+        for balance in active_balances.iter().filter(|balance| {
+            &balance.bank_pk != self.supply.bank.account_info.key
+                && &balance.bank_pk != self.debt.bank.account_info.key
+        }) {
+            let leg = self
+                .legs
+                .iter()
+                .find(|leg| leg.bank.key == &balance.bank_pk)
+                .ok_or_else(|| {
+                    msg!(
+                        "Missing bank & price oracle for active balance {}",
+                        balance.bank_pk
+                    );
+                    SolautoError::IncorrectAccounts
+                })?;
+            accounts.push((leg.bank, false, false));
+            accounts.push((leg.price_oracle, false, false));
+        }
+        Ok(accounts)
+    }
+
     pub fn compose_remaining_accounts(accounts: Vec<AccountMetaFlags>) -> Vec<AccountMetaFlags> {
         let mut banks_and_oracles: Vec<(AccountMetaFlags, AccountMetaFlags)> = accounts
             .chunks(2)
@@ -557,10 +1146,14 @@ impl<'a> LendingProtocolClient<'a> for MarginfiClient<'a> {
             remaining_accounts.push((self.supply.price_oracle.unwrap(), false, false));
         }
 
-        if active_balances.len() == 2 {
+        if active_balances
+            .iter()
+            .any(|balance| &balance.bank_pk == self.debt.bank.account_info.key)
+        {
             remaining_accounts.push((self.debt.bank.account_info, false, false));
             remaining_accounts.push((self.debt.price_oracle.unwrap(), false, false));
         }
+        remaining_accounts.extend(self.active_leg_accounts(&active_balances)?);
 
         if !std_accounts.solauto_position.data.self_managed.val {
             cpi.invoke_signed_with_remaining_accounts(
@@ -603,12 +1196,24 @@ impl<'a> LendingProtocolClient<'a> for MarginfiClient<'a> {
             },
         );
 
+        let marginfi_account_data =
+            DeserializedAccount::<MarginfiAccount>::zerocopy(Some(self.marginfi_account))?
+                .unwrap()
+                .data;
+        let active_balances = marginfi_account_data
+            .lending_account
+            .balances
+            .iter()
+            .filter(|balance| balance.active == 1)
+            .collect::<Vec<_>>();
+
         let mut remaining_accounts: Vec<AccountMetaFlags> =
             Vec::<AccountMetaFlags>::with_capacity(4);
         remaining_accounts.push((self.supply.bank.account_info, false, false));
         remaining_accounts.push((self.supply.price_oracle.unwrap(), false, false));
         remaining_accounts.push((self.debt.bank.account_info, false, true));
         remaining_accounts.push((self.debt.price_oracle.unwrap(), false, false));
+        remaining_accounts.extend(self.active_leg_accounts(&active_balances)?);
 
         if !std_accounts.solauto_position.data.self_managed.val {
             cpi.invoke_signed_with_remaining_accounts(
@@ -673,3 +1278,130 @@ impl<'a> LendingProtocolClient<'a> for MarginfiClient<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::Zeroable;
+
+    use super::*;
+
+    fn leg(usd_value: f64, weight_init: f64, weight_maint: f64) -> LegWeights {
+        LegWeights {
+            usd_value,
+            weight_init,
+            weight_maint,
+        }
+    }
+
+    #[test]
+    fn test_blended_max_ltv_and_liq_threshold() {
+        let (max_ltv, liq_threshold) =
+            get_blended_max_ltv_and_liq_threshold(&[leg(100.0, 0.8, 0.9)], &[leg(50.0, 1.0, 1.0)]);
+        assert!(max_ltv == 0.8);
+        assert!(liq_threshold == 0.9);
+
+        // SOL + jitoSOL against USDC & USDT
+        let (max_ltv, liq_threshold) = get_blended_max_ltv_and_liq_threshold(
+            &[leg(300.0, 0.8, 0.9), leg(100.0, 0.6, 0.7)],
+            &[leg(150.0, 1.0, 1.0), leg(50.0, 1.25, 1.1)],
+        );
+        assert!((max_ltv - 0.75 / 1.0625).abs() < 1e-9);
+        assert!((liq_threshold - 0.85 / 1.025).abs() < 1e-9);
+
+        // Health computed from the blended values matches Marginfi's weighted sums
+        let weighted_assets = 300.0 * 0.9 + 100.0 * 0.7;
+        let weighted_liabilities = 150.0 * 1.0 + 50.0 * 1.1;
+        let liq_utilization_rate = 200.0 / (400.0 * liq_threshold);
+        assert!((liq_utilization_rate - weighted_liabilities / weighted_assets).abs() < 1e-9);
+    }
+
+    fn balance(bank_pk: Pubkey, asset_shares: f64, liability_shares: f64) -> Balance {
+        let mut balance = Balance::zeroed();
+        balance.active = 1;
+        balance.bank_pk = bank_pk;
+        balance.asset_shares.value = I80F48::from_num(asset_shares).to_le_bytes();
+        balance.liability_shares.value = I80F48::from_num(liability_shares).to_le_bytes();
+        balance
+    }
+
+    #[test]
+    fn test_find_unexpected_balance() {
+        let supply_bank = Pubkey::new_unique();
+        let debt_bank = Pubkey::new_unique();
+        let mut balances = [Balance::zeroed(); 4];
+        balances[0] = balance(supply_bank, 100.0, 0.0);
+        balances[1] = balance(debt_bank, 0.0, 50.0);
+        assert!(find_unexpected_balance(&balances, &supply_bank, &debt_bank).is_none());
+
+        // Debt on the supply bank
+        balances[2] = balance(supply_bank, 0.0, 10.0);
+        assert!(find_unexpected_balance(&balances, &supply_bank, &debt_bank).is_some());
+
+        // Balance on another bank
+        balances[2] = balance(Pubkey::new_unique(), 10.0, 0.0);
+        assert!(find_unexpected_balance(&balances, &supply_bank, &debt_bank).is_some());
+
+        balances[2].active = 0;
+        assert!(find_unexpected_balance(&balances, &supply_bank, &debt_bank).is_none());
+    }
+
+    #[test]
+    fn test_validate_oracle_price() {
+        let now = 1_000_000;
+        assert!(validate_oracle_price(100.0, 1.0, now - 10, now).is_ok());
+        // Published slightly ahead of the validator clock
+        assert!(validate_oracle_price(100.0, 1.0, now + 2, now).is_ok());
+
+        assert_eq!(
+            validate_oracle_price(100.0, 1.0, now - MAX_ORACLE_PRICE_AGE_SECS - 1, now),
+            Err(SolautoError::StaleOraclePrice.into())
+        );
+        assert_eq!(
+            validate_oracle_price(0.0, 0.0, now, now),
+            Err(SolautoError::InvalidOraclePrice.into())
+        );
+        assert_eq!(
+            validate_oracle_price(100.0, 6.0, now, now),
+            Err(SolautoError::OracleConfidenceTooWide.into())
+        );
+    }
+
+    #[test]
+    fn test_bank_apr() {
+        let mut bank = Bank::zeroed();
+        bank.total_asset_shares.value = I80F48::from_num(1000).to_le_bytes();
+        bank.asset_share_value.value = I80F48::ONE.to_le_bytes();
+        bank.liability_share_value.value = I80F48::ONE.to_le_bytes();
+        let config = &mut bank.config.interest_rate_config;
+        config.optimal_utilization_rate.value = I80F48::from_num(0.8).to_le_bytes();
+        config.plateau_interest_rate.value = I80F48::from_num(0.1).to_le_bytes();
+        config.max_interest_rate.value = I80F48::from_num(2).to_le_bytes();
+        config.protocol_ir_fee.value = I80F48::from_num(0.1).to_le_bytes();
+        config.protocol_fixed_fee_apr.value = I80F48::from_num(0.01).to_le_bytes();
+
+        // Below the optimal utilization rate
+        bank.total_liability_shares.value = I80F48::from_num(400).to_le_bytes();
+        let (supply_apr_bps, borrow_apr_bps) = get_bank_apr_bps(&bank);
+        assert!(supply_apr_bps == 200);
+        assert!(borrow_apr_bps == 650);
+
+        // Above the optimal utilization rate
+        bank.total_liability_shares.value = I80F48::from_num(900).to_le_bytes();
+        let (supply_apr_bps, borrow_apr_bps) = get_bank_apr_bps(&bank);
+        assert!(supply_apr_bps == 9450);
+        assert!(borrow_apr_bps == 11650);
+
+        // An empty bank
+        assert!(get_bank_apr_bps(&Bank::zeroed()) == (0, 0));
+    }
+
+    #[test]
+    fn test_blended_weights_without_value() {
+        let (max_ltv, liq_threshold) = get_blended_max_ltv_and_liq_threshold(
+            &[leg(0.0, 0.8, 0.9), leg(0.0, 0.5, 0.6)],
+            &[leg(0.0, 1.0, 1.25)],
+        );
+        assert!(max_ltv == 0.8);
+        assert!(liq_threshold == 0.9 / 1.25);
+    }
+}
@@ -1 +1,33 @@
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+
+use crate::types::{lending_protocol::LendingProtocolAccounts, shared::LendingPlatform};
+
+use self::{
+    kamino::KaminoProtocolAccounts, marginfi::MarginfiProtocolAccounts, save::SaveProtocolAccounts,
+};
+
+pub mod kamino;
 pub mod marginfi;
+pub mod save;
+
+/// Parses the platform-specific accounts of the generic position instructions from their remaining accounts
+pub fn parse_lending_protocol_accounts<'a>(
+    lending_platform: LendingPlatform,
+    lending_program: &'a AccountInfo<'a>,
+    remaining_accounts: &'a [AccountInfo<'a>],
+) -> Result<Box<dyn LendingProtocolAccounts<'a> + 'a>, ProgramError> {
+    match lending_platform {
+        LendingPlatform::Marginfi => Ok(Box::new(MarginfiProtocolAccounts::parse(
+            lending_program,
+            remaining_accounts,
+        )?)),
+        LendingPlatform::Kamino => Ok(Box::new(KaminoProtocolAccounts::parse(
+            lending_program,
+            remaining_accounts,
+        )?)),
+        LendingPlatform::Save => Ok(Box::new(SaveProtocolAccounts::parse(
+            lending_program,
+            remaining_accounts,
+        )?)),
+    }
+}
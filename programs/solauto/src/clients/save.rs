@@ -0,0 +1,986 @@
+use save_sdk::{
+    accounts::{wad_to_f64, Obligation, Reserve, OBLIGATION_LEN},
+    instructions::*,
+    SAVE_ID,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, instruction::Instruction, msg,
+    program_error::ProgramError, rent::Rent, system_instruction, sysvar::Sysvar,
+};
+use std::{
+    cmp::min,
+    ops::{Div, Mul},
+};
+
+use crate::{
+    check,
+    types::{
+        errors::SolautoError,
+        instruction::SolautoStandardAccounts,
+        lending_protocol::{
+            required_account, LendingProtocolAccounts, LendingProtocolAccountsParser,
+            LendingProtocolClient, LendingProtocolTokenAccounts,
+        },
+        shared::{
+            DeserializedAccount, PriceType, RefreshStateProps, RefreshedTokenState,
+            TokenBalanceAmount,
+        },
+    },
+    utils::{ix_utils::*, solana_utils::*, solauto_utils::*, validation_utils::*},
+};
+
+pub struct SaveReserveAccounts<'a> {
+    pub reserve: DeserializedAccount<'a, Reserve>,
+    pub oracles: SaveReserveOracles<'a>,
+    /// Collateral (cToken) token account of the obligation owner
+    pub collateral_ta: Option<&'a AccountInfo<'a>>,
+    pub collateral_mint: Option<&'a AccountInfo<'a>>,
+    pub collateral_vault: Option<&'a AccountInfo<'a>>,
+    pub fee_receiver: Option<&'a AccountInfo<'a>>,
+    /// `protocol_ta` is the reserve liquidity supply
+    pub token_accounts: LendingProtocolTokenAccounts<'a>,
+}
+
+#[derive(Clone, Copy)]
+pub struct SaveReserveOracles<'a> {
+    pub pyth_oracle: &'a AccountInfo<'a>,
+    pub switchboard_oracle: &'a AccountInfo<'a>,
+}
+
+/// Remaining accounts layout of the generic position instructions:
+/// lending_market, lending_market_authority?, obligation,
+/// supply_reserve, supply_pyth_oracle, supply_switchboard_oracle, supply_liquidity_vault?,
+/// supply_collateral_mint?, supply_collateral_vault?, supply_collateral_ta?,
+/// debt_reserve, debt_pyth_oracle, debt_switchboard_oracle, debt_liquidity_vault?, debt_fee_receiver?
+pub struct SaveProtocolAccounts<'a> {
+    pub save_program: &'a AccountInfo<'a>,
+    pub lending_market: &'a AccountInfo<'a>,
+    pub lending_market_authority: Option<&'a AccountInfo<'a>>,
+    pub obligation: &'a AccountInfo<'a>,
+    pub supply_reserve: &'a AccountInfo<'a>,
+    pub supply_oracles: SaveReserveOracles<'a>,
+    pub supply_liquidity_vault: Option<&'a AccountInfo<'a>>,
+    pub supply_collateral_mint: Option<&'a AccountInfo<'a>>,
+    pub supply_collateral_vault: Option<&'a AccountInfo<'a>>,
+    pub supply_collateral_ta: Option<&'a AccountInfo<'a>>,
+    pub debt_reserve: &'a AccountInfo<'a>,
+    pub debt_oracles: SaveReserveOracles<'a>,
+    pub debt_liquidity_vault: Option<&'a AccountInfo<'a>>,
+    pub debt_fee_receiver: Option<&'a AccountInfo<'a>>,
+}
+
+impl<'a> SaveProtocolAccounts<'a> {
+    pub fn parse(
+        save_program: &'a AccountInfo<'a>,
+        remaining_accounts: &'a [AccountInfo<'a>],
+    ) -> Result<Self, ProgramError> {
+        let mut parser = LendingProtocolAccountsParser::from(remaining_accounts);
+        Ok(Self {
+            save_program,
+            lending_market: parser.next_account()?,
+            lending_market_authority: parser.next_optional_account()?,
+            obligation: parser.next_account()?,
+            supply_reserve: parser.next_account()?,
+            supply_oracles: SaveReserveOracles {
+                pyth_oracle: parser.next_account()?,
+                switchboard_oracle: parser.next_account()?,
+            },
+            supply_liquidity_vault: parser.next_optional_account()?,
+            supply_collateral_mint: parser.next_optional_account()?,
+            supply_collateral_vault: parser.next_optional_account()?,
+            supply_collateral_ta: parser.next_optional_account()?,
+            debt_reserve: parser.next_account()?,
+            debt_oracles: SaveReserveOracles {
+                pyth_oracle: parser.next_account()?,
+                switchboard_oracle: parser.next_account()?,
+            },
+            debt_liquidity_vault: parser.next_optional_account()?,
+            debt_fee_receiver: parser.next_optional_account()?,
+        })
+    }
+}
+
+impl<'a> LendingProtocolAccounts<'a> for SaveProtocolAccounts<'a> {
+    fn lp_pool_account(&self) -> &'a AccountInfo<'a> {
+        self.lending_market
+    }
+
+    fn lp_user_account(&self) -> &'a AccountInfo<'a> {
+        self.obligation
+    }
+
+    fn lp_supply_account(&self) -> &'a AccountInfo<'a> {
+        self.supply_reserve
+    }
+
+    fn lp_debt_account(&self) -> &'a AccountInfo<'a> {
+        self.debt_reserve
+    }
+
+    fn protocol_tas(&self) -> (Option<&'a AccountInfo<'a>>, Option<&'a AccountInfo<'a>>) {
+        (self.supply_liquidity_vault, self.debt_liquidity_vault)
+    }
+
+    fn get_max_ltv_and_liq_threshold(&self) -> Result<(f64, f64), ProgramError> {
+        SaveClient::get_max_ltv_and_liq_threshold(self.supply_reserve, self.debt_reserve)
+    }
+
+    fn initialize(&self, std_accounts: &SolautoStandardAccounts<'a>) -> ProgramResult {
+        let rent = required_account(std_accounts.rent)?;
+        let obligation_owner = get_owner(&std_accounts.solauto_position, std_accounts.signer);
+
+        init_ata_if_needed(
+            std_accounts.token_program,
+            std_accounts.system_program,
+            std_accounts.signer,
+            obligation_owner,
+            required_account(self.supply_collateral_ta)?,
+            required_account(self.supply_collateral_mint)?,
+        )?;
+
+        if account_has_data(self.obligation) {
+            return Ok(());
+        }
+
+        let seed = save_sdk::obligation_seed(self.lending_market.key);
+        check!(
+            self.obligation.key
+                == &save_sdk::obligation_address(obligation_owner.key, self.lending_market.key),
+            SolautoError::IncorrectAccounts
+        );
+
+        let rent_sysvar = Rent::from_account_info(rent)?;
+        solauto_invoke_instruction(
+            system_instruction::create_account_with_seed(
+                std_accounts.signer.key,
+                self.obligation.key,
+                obligation_owner.key,
+                seed.as_str(),
+                rent_sysvar.minimum_balance(OBLIGATION_LEN),
+                OBLIGATION_LEN as u64,
+                &SAVE_ID,
+            ),
+            &[
+                std_accounts.signer.clone(),
+                self.obligation.clone(),
+                obligation_owner.clone(),
+                std_accounts.system_program.clone(),
+            ],
+            &std_accounts.solauto_position,
+        )?;
+
+        solauto_invoke_instruction(
+            init_obligation(
+                self.obligation.key,
+                self.lending_market.key,
+                obligation_owner.key,
+                std_accounts.token_program.key,
+            ),
+            &[
+                self.obligation.clone(),
+                self.lending_market.clone(),
+                obligation_owner.clone(),
+                rent.clone(),
+                std_accounts.token_program.clone(),
+                self.save_program.clone(),
+            ],
+            &std_accounts.solauto_position,
+        )
+    }
+
+    fn refresh_accounts(&self) -> ProgramResult {
+        SaveClient::refresh_accounts(
+            self.save_program,
+            self.obligation,
+            self.supply_reserve,
+            self.supply_oracles,
+            self.debt_reserve,
+            self.debt_oracles,
+        )
+    }
+
+    fn get_updated_state(&self, price_type: PriceType) -> Result<RefreshStateProps, ProgramError> {
+        SaveClient::get_updated_state(
+            self.obligation,
+            self.supply_reserve,
+            self.debt_reserve,
+            price_type,
+        )
+    }
+
+    /// Reserve prices are only updated by a reserve refresh
+    fn load_prices(&self, price_type: PriceType) -> Result<(f64, f64), ProgramError> {
+        SaveClient::refresh_reserve(self.save_program, self.supply_reserve, self.supply_oracles)?;
+        SaveClient::refresh_reserve(self.save_program, self.debt_reserve, self.debt_oracles)?;
+        SaveClient::load_prices(self.supply_reserve, self.debt_reserve, price_type)
+    }
+
+    fn load_leg_prices(&self, _price_type: PriceType) -> Result<Vec<f64>, ProgramError> {
+        Ok(Vec::new())
+    }
+
+    fn client(
+        &self,
+        signer: &'a AccountInfo<'a>,
+        supply_tas: LendingProtocolTokenAccounts<'a>,
+        debt_tas: LendingProtocolTokenAccounts<'a>,
+    ) -> Result<Box<dyn LendingProtocolClient<'a> + 'a>, ProgramError> {
+        Ok(Box::new(SaveClient::from(
+            self, signer, supply_tas, debt_tas,
+        )?))
+    }
+}
+
+pub struct SaveClient<'a> {
+    signer: &'a AccountInfo<'a>,
+    program: &'a AccountInfo<'a>,
+    lending_market: &'a AccountInfo<'a>,
+    lending_market_authority: &'a AccountInfo<'a>,
+    obligation: &'a AccountInfo<'a>,
+    supply: SaveReserveAccounts<'a>,
+    debt: SaveReserveAccounts<'a>,
+}
+
+impl<'a> SaveClient<'a> {
+    pub fn from(
+        accounts: &SaveProtocolAccounts<'a>,
+        signer: &'a AccountInfo<'a>,
+        supply_tas: LendingProtocolTokenAccounts<'a>,
+        debt_tas: LendingProtocolTokenAccounts<'a>,
+    ) -> Result<Self, ProgramError> {
+        let supply = SaveReserveAccounts {
+            reserve: SaveClient::load_reserve(accounts.supply_reserve)?,
+            oracles: accounts.supply_oracles,
+            collateral_ta: accounts.supply_collateral_ta,
+            collateral_mint: accounts.supply_collateral_mint,
+            collateral_vault: accounts.supply_collateral_vault,
+            fee_receiver: None,
+            token_accounts: supply_tas,
+        };
+
+        let debt = SaveReserveAccounts {
+            reserve: SaveClient::load_reserve(accounts.debt_reserve)?,
+            oracles: accounts.debt_oracles,
+            collateral_ta: None,
+            collateral_mint: None,
+            collateral_vault: None,
+            fee_receiver: accounts.debt_fee_receiver,
+            token_accounts: debt_tas,
+        };
+
+        Ok(Self {
+            signer,
+            program: accounts.save_program,
+            lending_market: accounts.lending_market,
+            lending_market_authority: required_account(accounts.lending_market_authority)?,
+            obligation: accounts.obligation,
+            supply,
+            debt,
+        })
+    }
+
+    pub fn load_reserve(
+        reserve: &'a AccountInfo<'a>,
+    ) -> Result<DeserializedAccount<'a, Reserve>, ProgramError> {
+        let data = Reserve::from_bytes(&reserve.data.borrow()).map_err(|_| {
+            msg!("Failed to deserialize Save reserve");
+            SolautoError::FailedAccountDeserialization
+        })?;
+        Ok(DeserializedAccount {
+            account_info: reserve,
+            data: Box::new(data),
+        })
+    }
+
+    pub fn load_obligation(
+        obligation: &'a AccountInfo<'a>,
+    ) -> Result<DeserializedAccount<'a, Obligation>, ProgramError> {
+        let data = Obligation::from_bytes(&obligation.data.borrow()).map_err(|_| {
+            msg!("Failed to deserialize Save obligation");
+            SolautoError::FailedAccountDeserialization
+        })?;
+        Ok(DeserializedAccount {
+            account_info: obligation,
+            data: Box::new(data),
+        })
+    }
+
+    pub fn get_max_ltv_and_liq_threshold(
+        supply_reserve: &'a AccountInfo<'a>,
+        debt_reserve: &'a AccountInfo<'a>,
+    ) -> Result<(f64, f64), ProgramError> {
+        let supply_reserve = SaveClient::load_reserve(supply_reserve)?;
+        let debt_reserve = SaveClient::load_reserve(debt_reserve)?;
+
+        let borrow_weight = debt_reserve.data.borrow_weight();
+
+        let max_ltv = (supply_reserve.data.loan_to_value_ratio as f64)
+            .div(100.0)
+            .div(borrow_weight);
+        let liq_threshold = (supply_reserve.data.liquidation_threshold as f64)
+            .div(100.0)
+            .div(borrow_weight);
+
+        Ok((max_ltv, liq_threshold))
+    }
+
+    fn market_price(reserve: &Reserve, price_type: PriceType) -> f64 {
+        if price_type == PriceType::Ema {
+            reserve.smoothed_market_price()
+        } else {
+            reserve.market_price()
+        }
+    }
+
+    pub fn get_supply_token_usage(
+        obligation: &DeserializedAccount<Obligation>,
+        supply_reserve: &DeserializedAccount<Reserve>,
+        price_type: PriceType,
+    ) -> RefreshedTokenState {
+        let reserve = &supply_reserve.data;
+
+        let base_unit_account_deposits = obligation
+            .data
+            .find_deposit(supply_reserve.account_info.key)
+            .map_or(0, |deposit| {
+                reserve.collateral_to_liquidity(deposit.deposited_amount)
+            });
+
+        let base_unit_deposit_room_available =
+            (reserve.deposit_limit as f64 - reserve.total_supply()).max(0.0) as u64;
+
+        RefreshedTokenState {
+            mint: reserve.liquidity_mint,
+            decimals: reserve.liquidity_mint_decimals,
+            amount_used: base_unit_account_deposits,
+            amount_can_be_used: base_unit_deposit_room_available,
+            market_price: SaveClient::market_price(reserve, price_type),
+            borrow_fee_bps: None,
+            supply_apr_bps: 0,
+            borrow_apr_bps: 0,
+        }
+    }
+
+    pub fn get_debt_token_usage(
+        obligation: &DeserializedAccount<Obligation>,
+        debt_reserve: &DeserializedAccount<Reserve>,
+        price_type: PriceType,
+    ) -> RefreshedTokenState {
+        let reserve = &debt_reserve.data;
+
+        let base_unit_account_debt = obligation
+            .data
+            .find_borrow(debt_reserve.account_info.key)
+            .map_or(0, |borrow| {
+                wad_to_f64(borrow.borrowed_amount_wads).ceil() as u64
+            });
+
+        let total_borrows = wad_to_f64(reserve.liquidity_borrowed_amount_wads) as u64;
+        let amount_can_be_used = min(
+            reserve.borrow_limit.saturating_sub(total_borrows),
+            reserve.liquidity_available_amount,
+        );
+
+        let borrow_fee_bps = wad_to_f64(reserve.borrow_fee_wad as u128)
+            .mul(10_000.0)
+            .round() as u16;
+
+        RefreshedTokenState {
+            mint: reserve.liquidity_mint,
+            decimals: reserve.liquidity_mint_decimals,
+            amount_used: base_unit_account_debt,
+            amount_can_be_used,
+            market_price: SaveClient::market_price(reserve, price_type),
+            borrow_fee_bps: Some(borrow_fee_bps),
+            supply_apr_bps: 0,
+            borrow_apr_bps: 0,
+        }
+    }
+
+    pub fn get_updated_state(
+        obligation: &'a AccountInfo<'a>,
+        supply_reserve: &'a AccountInfo<'a>,
+        debt_reserve: &'a AccountInfo<'a>,
+        price_type: PriceType,
+    ) -> Result<RefreshStateProps, ProgramError> {
+        let (max_ltv, liq_threshold) =
+            SaveClient::get_max_ltv_and_liq_threshold(supply_reserve, debt_reserve)?;
+
+        let obligation = SaveClient::load_obligation(obligation)?;
+        let supply = SaveClient::get_supply_token_usage(
+            &obligation,
+            &SaveClient::load_reserve(supply_reserve)?,
+            price_type,
+        );
+        let debt = SaveClient::get_debt_token_usage(
+            &obligation,
+            &SaveClient::load_reserve(debt_reserve)?,
+            price_type,
+        );
+
+        Ok(RefreshStateProps {
+            max_ltv,
+            liq_threshold,
+            supply,
+            debt,
+            additional_supply: Vec::new(),
+            additional_debt: Vec::new(),
+        })
+    }
+
+    pub fn load_prices(
+        supply_reserve: &'a AccountInfo<'a>,
+        debt_reserve: &'a AccountInfo<'a>,
+        price_type: PriceType,
+    ) -> Result<(f64, f64), ProgramError> {
+        Ok((
+            SaveClient::market_price(&SaveClient::load_reserve(supply_reserve)?.data, price_type),
+            SaveClient::market_price(&SaveClient::load_reserve(debt_reserve)?.data, price_type),
+        ))
+    }
+
+    pub fn refresh_reserve(
+        program: &'a AccountInfo<'a>,
+        reserve: &'a AccountInfo<'a>,
+        oracles: SaveReserveOracles<'a>,
+    ) -> ProgramResult {
+        invoke_instruction(
+            &save_sdk::instructions::refresh_reserve(
+                reserve.key,
+                oracles.pyth_oracle.key,
+                oracles.switchboard_oracle.key,
+            ),
+            &[
+                reserve.clone(),
+                oracles.pyth_oracle.clone(),
+                oracles.switchboard_oracle.clone(),
+                program.clone(),
+            ],
+            None,
+        )
+    }
+
+    /// The obligation's deposit reserves followed by its borrow reserves must be passed in the order they
+    /// are stored on the obligation. Solauto positions only ever hold the supply & debt reserve.
+    pub fn refresh_obligation(
+        program: &'a AccountInfo<'a>,
+        obligation: &'a AccountInfo<'a>,
+        supply_reserve: &'a AccountInfo<'a>,
+        debt_reserve: &'a AccountInfo<'a>,
+    ) -> ProgramResult {
+        let obligation_data = SaveClient::load_obligation(obligation)?;
+
+        let mut reserves = Vec::with_capacity(2);
+        for reserve_key in obligation_data
+            .data
+            .deposits
+            .iter()
+            .map(|deposit| deposit.deposit_reserve)
+            .chain(
+                obligation_data
+                    .data
+                    .borrows
+                    .iter()
+                    .map(|borrow| borrow.borrow_reserve),
+            )
+        {
+            let reserve = [supply_reserve, debt_reserve]
+                .into_iter()
+                .find(|reserve| reserve.key == &reserve_key)
+                .ok_or(SolautoError::IncorrectAccounts)?;
+            reserves.push(reserve);
+        }
+
+        let ix = save_sdk::instructions::refresh_obligation(
+            obligation.key,
+            reserves
+                .iter()
+                .map(|reserve| *reserve.key)
+                .collect::<Vec<_>>()
+                .as_slice(),
+        );
+
+        let mut account_infos = vec![obligation.clone()];
+        account_infos.extend(reserves.into_iter().cloned());
+        account_infos.push(program.clone());
+
+        invoke_instruction(&ix, account_infos.as_slice(), None)
+    }
+
+    pub fn refresh_accounts(
+        program: &'a AccountInfo<'a>,
+        obligation: &'a AccountInfo<'a>,
+        supply_reserve: &'a AccountInfo<'a>,
+        supply_oracles: SaveReserveOracles<'a>,
+        debt_reserve: &'a AccountInfo<'a>,
+        debt_oracles: SaveReserveOracles<'a>,
+    ) -> ProgramResult {
+        SaveClient::refresh_reserve(program, supply_reserve, supply_oracles)?;
+        SaveClient::refresh_reserve(program, debt_reserve, debt_oracles)?;
+        SaveClient::refresh_obligation(program, obligation, supply_reserve, debt_reserve)
+    }
+
+    /// Save requires the reserves & obligation to be refreshed in the same slot as every action,
+    /// and each action marks them stale again
+    fn refresh(&self) -> ProgramResult {
+        SaveClient::refresh_accounts(
+            self.program,
+            self.obligation,
+            self.supply.reserve.account_info,
+            self.supply.oracles,
+            self.debt.reserve.account_info,
+            self.debt.oracles,
+        )
+    }
+
+    fn invoke(
+        &self,
+        ix: Instruction,
+        mut account_infos: Vec<AccountInfo<'a>>,
+        std_accounts: &SolautoStandardAccounts<'a>,
+    ) -> ProgramResult {
+        account_infos.push(self.program.clone());
+        solauto_invoke_instruction(ix, account_infos.as_slice(), &std_accounts.solauto_position)
+    }
+}
+
+impl<'a> LendingProtocolClient<'a> for SaveClient<'a> {
+    fn validate(&self, std_accounts: &Box<SolautoStandardAccounts<'a>>) -> ProgramResult {
+        validate_token_accounts(
+            &std_accounts.solauto_position,
+            self.supply.token_accounts.position_ta,
+            self.debt.token_accounts.position_ta,
+        )?;
+        validate_token_accounts(
+            &std_accounts.solauto_position,
+            self.supply.token_accounts.authority_ta,
+            self.debt.token_accounts.authority_ta,
+        )?;
+
+        if let Some(collateral_ta) = self.supply.collateral_ta {
+            let obligation_owner = get_owner(&std_accounts.solauto_position, self.signer);
+            check!(
+                correct_token_account(
+                    collateral_ta.key,
+                    obligation_owner.key,
+                    &self.supply.reserve.data.collateral_mint,
+                ),
+                SolautoError::IncorrectAccounts
+            );
+        }
+        Ok(())
+    }
+
+    fn deposit<'c>(
+        &self,
+        base_unit_amount: u64,
+        std_accounts: &'c Box<SolautoStandardAccounts<'a>>,
+    ) -> ProgramResult {
+        self.refresh()?;
+
+        let authority = get_owner(&std_accounts.solauto_position, self.signer);
+
+        let source_liquidity = if !std_accounts.solauto_position.data.self_managed.val {
+            required_account(self.supply.token_accounts.position_ta)?
+        } else {
+            required_account(self.supply.token_accounts.authority_ta)?
+        };
+        let user_collateral = required_account(self.supply.collateral_ta)?;
+        let reserve_liquidity_supply = required_account(self.supply.token_accounts.protocol_ta)?;
+        let reserve_collateral_mint = required_account(self.supply.collateral_mint)?;
+        let reserve_collateral_vault = required_account(self.supply.collateral_vault)?;
+
+        let ix = deposit_reserve_liquidity_and_obligation_collateral(
+            DepositAccounts {
+                source_liquidity: *source_liquidity.key,
+                user_collateral: *user_collateral.key,
+                reserve: *self.supply.reserve.account_info.key,
+                reserve_liquidity_supply: *reserve_liquidity_supply.key,
+                reserve_collateral_mint: *reserve_collateral_mint.key,
+                lending_market: *self.lending_market.key,
+                lending_market_authority: *self.lending_market_authority.key,
+                destination_deposit_collateral: *reserve_collateral_vault.key,
+                obligation: *self.obligation.key,
+                obligation_owner: *authority.key,
+                pyth_oracle: *self.supply.oracles.pyth_oracle.key,
+                switchboard_oracle: *self.supply.oracles.switchboard_oracle.key,
+                user_transfer_authority: *authority.key,
+                token_program: *std_accounts.token_program.key,
+            },
+            base_unit_amount,
+        );
+
+        self.invoke(
+            ix,
+            vec![
+                source_liquidity.clone(),
+                user_collateral.clone(),
+                self.supply.reserve.account_info.clone(),
+                reserve_liquidity_supply.clone(),
+                reserve_collateral_mint.clone(),
+                self.lending_market.clone(),
+                self.lending_market_authority.clone(),
+                reserve_collateral_vault.clone(),
+                self.obligation.clone(),
+                authority.clone(),
+                self.supply.oracles.pyth_oracle.clone(),
+                self.supply.oracles.switchboard_oracle.clone(),
+                std_accounts.token_program.clone(),
+            ],
+            std_accounts,
+        )
+    }
+
+    fn withdraw<'c>(
+        &self,
+        amount: TokenBalanceAmount,
+        destination: &'a AccountInfo<'a>,
+        std_accounts: &'c Box<SolautoStandardAccounts<'a>>,
+    ) -> ProgramResult {
+        self.refresh()?;
+
+        let authority = get_owner(&std_accounts.solauto_position, self.signer);
+
+        // Exchange rate has to be read after the reserve refresh
+        let collateral_amount = match amount {
+            TokenBalanceAmount::Some(num) => {
+                SaveClient::load_reserve(self.supply.reserve.account_info)?
+                    .data
+                    .liquidity_to_collateral(num)
+            }
+            TokenBalanceAmount::All => u64::MAX,
+        };
+
+        let user_collateral = required_account(self.supply.collateral_ta)?;
+        let reserve_liquidity_supply = required_account(self.supply.token_accounts.protocol_ta)?;
+        let reserve_collateral_mint = required_account(self.supply.collateral_mint)?;
+        let reserve_collateral_vault = required_account(self.supply.collateral_vault)?;
+
+        let ix = withdraw_obligation_collateral_and_redeem_reserve_collateral(
+            WithdrawAccounts {
+                reserve_collateral: *reserve_collateral_vault.key,
+                user_collateral: *user_collateral.key,
+                withdraw_reserve: *self.supply.reserve.account_info.key,
+                obligation: *self.obligation.key,
+                lending_market: *self.lending_market.key,
+                lending_market_authority: *self.lending_market_authority.key,
+                user_liquidity: *destination.key,
+                reserve_collateral_mint: *reserve_collateral_mint.key,
+                reserve_liquidity_supply: *reserve_liquidity_supply.key,
+                obligation_owner: *authority.key,
+                user_transfer_authority: *authority.key,
+                token_program: *std_accounts.token_program.key,
+            },
+            collateral_amount,
+        );
+
+        self.invoke(
+            ix,
+            vec![
+                reserve_collateral_vault.clone(),
+                user_collateral.clone(),
+                self.supply.reserve.account_info.clone(),
+                self.obligation.clone(),
+                self.lending_market.clone(),
+                self.lending_market_authority.clone(),
+                destination.clone(),
+                reserve_collateral_mint.clone(),
+                reserve_liquidity_supply.clone(),
+                authority.clone(),
+                std_accounts.token_program.clone(),
+            ],
+            std_accounts,
+        )
+    }
+
+    fn borrow<'c>(
+        &self,
+        base_unit_amount: u64,
+        destination: &'a AccountInfo<'a>,
+        std_accounts: &'c Box<SolautoStandardAccounts<'a>>,
+    ) -> ProgramResult {
+        self.refresh()?;
+
+        let authority = get_owner(&std_accounts.solauto_position, self.signer);
+
+        let reserve_liquidity_supply = required_account(self.debt.token_accounts.protocol_ta)?;
+        let reserve_fee_receiver = required_account(self.debt.fee_receiver)?;
+
+        let ix = borrow_obligation_liquidity(
+            BorrowAccounts {
+                source_liquidity: *reserve_liquidity_supply.key,
+                destination_liquidity: *destination.key,
+                borrow_reserve: *self.debt.reserve.account_info.key,
+                borrow_reserve_liquidity_fee_receiver: *reserve_fee_receiver.key,
+                obligation: *self.obligation.key,
+                lending_market: *self.lending_market.key,
+                lending_market_authority: *self.lending_market_authority.key,
+                obligation_owner: *authority.key,
+                token_program: *std_accounts.token_program.key,
+            },
+            base_unit_amount,
+        );
+
+        self.invoke(
+            ix,
+            vec![
+                reserve_liquidity_supply.clone(),
+                destination.clone(),
+                self.debt.reserve.account_info.clone(),
+                reserve_fee_receiver.clone(),
+                self.obligation.clone(),
+                self.lending_market.clone(),
+                self.lending_market_authority.clone(),
+                authority.clone(),
+                std_accounts.token_program.clone(),
+            ],
+            std_accounts,
+        )
+    }
+
+    fn repay<'c>(
+        &self,
+        amount: TokenBalanceAmount,
+        std_accounts: &'c Box<SolautoStandardAccounts<'a>>,
+    ) -> ProgramResult {
+        self.refresh()?;
+
+        let authority = get_owner(&std_accounts.solauto_position, self.signer);
+
+        let liquidity_amount = match amount {
+            TokenBalanceAmount::Some(num) => num,
+            TokenBalanceAmount::All => u64::MAX,
+        };
+
+        let source_liquidity = if !std_accounts.solauto_position.data.self_managed.val {
+            required_account(self.debt.token_accounts.position_ta)?
+        } else {
+            required_account(self.debt.token_accounts.authority_ta)?
+        };
+        let reserve_liquidity_supply = required_account(self.debt.token_accounts.protocol_ta)?;
+
+        let ix = repay_obligation_liquidity(
+            RepayAccounts {
+                source_liquidity: *source_liquidity.key,
+                destination_liquidity: *reserve_liquidity_supply.key,
+                repay_reserve: *self.debt.reserve.account_info.key,
+                obligation: *self.obligation.key,
+                lending_market: *self.lending_market.key,
+                user_transfer_authority: *authority.key,
+                token_program: *std_accounts.token_program.key,
+            },
+            liquidity_amount,
+        );
+
+        self.invoke(
+            ix,
+            vec![
+                source_liquidity.clone(),
+                reserve_liquidity_supply.clone(),
+                self.debt.reserve.account_info.clone(),
+                self.obligation.clone(),
+                self.lending_market.clone(),
+                authority.clone(),
+                std_accounts.token_program.clone(),
+            ],
+            std_accounts,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use save_sdk::accounts::{
+        obligation_offsets, reserve_offsets, OBLIGATION_COLLATERAL_LEN, RESERVE_LEN,
+    };
+    use solana_program::pubkey::Pubkey;
+
+    use super::*;
+
+    const WAD: u128 = 1_000_000_000_000_000_000;
+
+    fn fake_account(key: Pubkey, data: Vec<u8>) -> &'static AccountInfo<'static> {
+        let key = Box::leak(Box::new(key));
+        let owner = Box::leak(Box::new(SAVE_ID));
+        let lamports = Box::leak(Box::new(0u64));
+        let data = Box::leak(data.into_boxed_slice());
+        Box::leak(Box::new(AccountInfo::new(
+            key, false, true, lamports, data, owner, false, 0,
+        )))
+    }
+
+    fn write(data: &mut [u8], offset: usize, bytes: &[u8]) {
+        data[offset..offset + bytes.len()].copy_from_slice(bytes);
+    }
+
+    fn fake_reserve(
+        mint: &Pubkey,
+        ltv_pct: u8,
+        liq_threshold_pct: u8,
+        added_borrow_weight_bps: u64,
+        price: u128,
+        smoothed_price: u128,
+    ) -> Vec<u8> {
+        use reserve_offsets::*;
+
+        let mut data = vec![0u8; RESERVE_LEN];
+        data[VERSION] = 1;
+        write(&mut data, LIQUIDITY_MINT, mint.as_ref());
+        data[LIQUIDITY_MINT_DECIMALS] = 6;
+        write(
+            &mut data,
+            LIQUIDITY_AVAILABLE_AMOUNT,
+            &1_500_000u64.to_le_bytes(),
+        );
+        write(
+            &mut data,
+            LIQUIDITY_BORROWED_AMOUNT_WADS,
+            &(500_000 * WAD).to_le_bytes(),
+        );
+        write(
+            &mut data,
+            LIQUIDITY_MARKET_PRICE,
+            &(price * WAD).to_le_bytes(),
+        );
+        write(
+            &mut data,
+            LIQUIDITY_SMOOTHED_MARKET_PRICE,
+            &(smoothed_price * WAD).to_le_bytes(),
+        );
+        write(
+            &mut data,
+            COLLATERAL_MINT_TOTAL_SUPPLY,
+            &1_000_000u64.to_le_bytes(),
+        );
+        data[LOAN_TO_VALUE_RATIO] = ltv_pct;
+        data[LIQUIDATION_THRESHOLD] = liq_threshold_pct;
+        write(
+            &mut data,
+            BORROW_FEE_WAD,
+            &((WAD / 1000) as u64).to_le_bytes(),
+        );
+        write(&mut data, DEPOSIT_LIMIT, &5_000_000u64.to_le_bytes());
+        write(&mut data, BORROW_LIMIT, &1_000_000u64.to_le_bytes());
+        write(
+            &mut data,
+            ADDED_BORROW_WEIGHT_BPS,
+            &added_borrow_weight_bps.to_le_bytes(),
+        );
+        data
+    }
+
+    fn fake_obligation(supply_reserve: &Pubkey, debt_reserve: &Pubkey) -> Vec<u8> {
+        use obligation_offsets::*;
+
+        let mut data = vec![0u8; OBLIGATION_LEN];
+        data[VERSION] = 1;
+        data[DEPOSITS_LEN] = 1;
+        data[BORROWS_LEN] = 1;
+        write(&mut data, DATA_FLAT, supply_reserve.as_ref());
+        write(&mut data, DATA_FLAT + 32, &100_000u64.to_le_bytes());
+        let borrow_offset = DATA_FLAT + OBLIGATION_COLLATERAL_LEN;
+        write(&mut data, borrow_offset, debt_reserve.as_ref());
+        write(
+            &mut data,
+            borrow_offset + 48,
+            &(2_500_000 * WAD / 10).to_le_bytes(),
+        );
+        data
+    }
+
+    #[test]
+    fn test_get_updated_state() {
+        let supply_mint = Pubkey::new_unique();
+        let supply_reserve = fake_account(
+            Pubkey::new_unique(),
+            fake_reserve(&supply_mint, 80, 85, 0, 150, 140),
+        );
+        let debt_reserve = fake_account(
+            Pubkey::new_unique(),
+            fake_reserve(&Pubkey::new_unique(), 0, 0, 2500, 1, 0),
+        );
+        let obligation = fake_account(
+            Pubkey::new_unique(),
+            fake_obligation(supply_reserve.key, debt_reserve.key),
+        );
+
+        let state = SaveClient::get_updated_state(
+            obligation,
+            supply_reserve,
+            debt_reserve,
+            PriceType::Realtime,
+        )
+        .expect("Should deserialize");
+
+        assert_eq!(state.max_ltv, 0.8 / 1.25);
+        assert_eq!(state.liq_threshold, 0.85 / 1.25);
+
+        // 2,000,000 liquidity backing 1,000,000 collateral
+        assert_eq!(state.supply.amount_used, 200_000);
+        assert_eq!(state.supply.amount_can_be_used, 3_000_000);
+        assert_eq!(state.supply.market_price, 150.0);
+        assert_eq!(state.supply.mint, supply_mint);
+
+        assert_eq!(state.debt.amount_used, 250_000);
+        assert_eq!(state.debt.amount_can_be_used, 500_000);
+        assert_eq!(state.debt.market_price, 1.0);
+        assert_eq!(state.debt.borrow_fee_bps, Some(10));
+
+        let ema_state =
+            SaveClient::get_updated_state(obligation, supply_reserve, debt_reserve, PriceType::Ema)
+                .expect("Should deserialize");
+
+        assert_eq!(ema_state.supply.market_price, 140.0);
+        // Reserves without a smoothed price fall back to the market price
+        assert_eq!(ema_state.debt.market_price, 1.0);
+    }
+
+    #[test]
+    fn test_parse_protocol_accounts() {
+        let program = fake_account(SAVE_ID, vec![]);
+        let placeholder = || fake_account(crate::ID, vec![]).clone();
+        let account = || fake_account(Pubkey::new_unique(), vec![]).clone();
+
+        let remaining_accounts: &'static [AccountInfo<'static>] = Box::leak(
+            vec![
+                account(),
+                placeholder(),
+                account(),
+                account(),
+                account(),
+                account(),
+                account(),
+                placeholder(),
+                placeholder(),
+                placeholder(),
+                account(),
+                account(),
+                account(),
+                account(),
+                account(),
+            ]
+            .into_boxed_slice(),
+        );
+
+        let accounts = SaveProtocolAccounts::parse(program, remaining_accounts)
+            .expect("Should parse all accounts");
+        assert!(accounts.lending_market_authority.is_none());
+        assert_eq!(accounts.obligation.key, remaining_accounts[2].key);
+        assert_eq!(accounts.supply_reserve.key, remaining_accounts[3].key);
+        assert!(accounts.supply_liquidity_vault.is_some());
+        assert!(accounts.supply_collateral_ta.is_none());
+        assert_eq!(accounts.debt_reserve.key, remaining_accounts[10].key);
+        assert!(accounts.debt_fee_receiver.is_some());
+
+        assert!(SaveProtocolAccounts::parse(program, &remaining_accounts[..14]).is_err());
+    }
+
+    #[test]
+    fn test_rejects_wrong_account_type() {
+        let reserve = fake_account(
+            Pubkey::new_unique(),
+            fake_reserve(&Pubkey::new_unique(), 80, 85, 0, 1, 1),
+        );
+        assert!(SaveClient::load_obligation(reserve).is_err());
+    }
+}
@@ -7,8 +7,19 @@ pub const WSOL_MINT: Pubkey = pubkey!("So111111111111111111111111111111111111111
 pub const MARGINFI_PROD_PROGRAM: Pubkey = pubkey!("MFv2hWf31Z9kbCa1snEPYctwafyhdvnV7FZnsebVacA");
 pub const MARGINFI_STAGING_PROGRAM: Pubkey = pubkey!("stag8sTKds2h4KzjUw3zKTsxbqvT4XKHdaR9X9E6Rct");
 
+pub const WHIRLPOOL_PROGRAM: Pubkey = pubkey!("whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc");
+
 pub const USD_DECIMALS: u8 = 9;
 
+/// Oldest oracle price (in seconds) a position can be priced with. Marginfi's own default is 60
+pub const MAX_ORACLE_PRICE_AGE_SECS: i64 = 120;
+/// Widest oracle confidence interval, or standard deviation, relative to the price
+pub const MAX_ORACLE_CONFIDENCE_BPS: u16 = 500;
+/// Most a rebalance swap can return below the oracle-implied amount. Used by positions without their own max slippage
+pub const MAX_SWAP_SLIPPAGE_BPS: u16 = 500;
+/// Highest fee of the flash loan a rebalance can be funded by, which the rebalancer chooses
+pub const MAX_FLASH_LOAN_FEE_BPS: u16 = 150;
+
 pub const DEFAULT_LIMIT_GAP_BPS: u16 = 1000;
 pub const MIN_REPAY_GAP_BPS: u16 = 50;
 pub const MIN_BOOST_GAP_BPS: u16 = 50;
@@ -38,5 +38,20 @@ pub fn process_instruction<'a>(
             process_marginfi_interaction_instruction(accounts, action)
         }
         Instruction::MarginfiRebalance(args) => process_marginfi_rebalance(accounts, args),
+
+        Instruction::OpenPosition(args) => process_open_position_instruction(accounts, args),
+        Instruction::RefreshData(price_type) => process_refresh_data(accounts, price_type),
+        Instruction::ProtocolInteraction(action) => {
+            process_interaction_instruction(accounts, action)
+        }
+        Instruction::Rebalance(args) => process_rebalance(accounts, args),
+
+        Instruction::MarginfiHarvestEmissions => process_marginfi_harvest_emissions(accounts),
+        Instruction::MigratePosition(args) => process_migrate_position(accounts, args),
+        Instruction::MarginfiImportAccount(args) => process_marginfi_import_account(accounts, args),
+        Instruction::MarginfiExportAccount => process_marginfi_export_account(accounts),
+        Instruction::PlaceLimitOrder(args) => process_place_limit_order(accounts, args),
+        Instruction::CancelLimitOrder => process_cancel_limit_order(accounts),
+        Instruction::UpgradePosition => process_upgrade_position(accounts),
     }
 }
@@ -0,0 +1,123 @@
+use std::ops::Div;
+
+use kamino_sdk::{instructions::FLASH_BORROW_RESERVE_LIQUIDITY_DISCRIMINATOR, KAMINO_ID};
+use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{clients::kamino::KaminoClient, types::errors::SolautoError};
+
+use super::{fee_rate_to_bps, flash_loan_account, FlashBorrowIx, FlashLoanDetector};
+
+/// A Kamino `flash_borrow_reserve_liquidity`, which is charged the reserve's flash loan fee
+pub struct KaminoFlashLoanDetector {}
+
+impl FlashLoanDetector for KaminoFlashLoanDetector {
+    fn program_ids(&self) -> Vec<Pubkey> {
+        vec![KAMINO_ID]
+    }
+
+    fn accounts_len(&self) -> u16 {
+        4
+    }
+
+    fn is_flash_borrow(&self, data: &[u8]) -> bool {
+        data.len() >= 16 && data[..8] == FLASH_BORROW_RESERVE_LIQUIDITY_DISCRIMINATOR
+    }
+
+    fn flash_loan_amount(&self, borrow_ix: &FlashBorrowIx) -> Result<u64, ProgramError> {
+        let amount = borrow_ix.data[8..16]
+            .try_into()
+            .map_err(|_| SolautoError::IncorrectInstructions)?;
+        Ok(u64::from_le_bytes(amount))
+    }
+
+    fn flash_loan_fee_bps<'a>(
+        &self,
+        _ixs_sysvar: &'a AccountInfo<'a>,
+        borrow_ix: &FlashBorrowIx,
+        accounts: &'a [AccountInfo<'a>],
+    ) -> Result<u16, ProgramError> {
+        let reserve = KaminoClient::load_reserve(flash_loan_account(accounts, borrow_ix, 3)?)?;
+        Ok(fee_rate_to_bps(
+            (reserve.data.config.fees.flash_loan_fee_sf as f64).div((1u64 << 60) as f64),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::Zeroable;
+    use kamino_sdk::accounts::{Reserve, RESERVE_DISCRIMINATOR};
+
+    use super::*;
+
+    const SF_ONE: u64 = 1 << 60;
+
+    fn fake_account(key: Pubkey, owner: Pubkey, data: Vec<u8>) -> AccountInfo<'static> {
+        AccountInfo::new(
+            Box::leak(Box::new(key)),
+            false,
+            true,
+            Box::leak(Box::new(0u64)),
+            Box::leak(data.into_boxed_slice()),
+            Box::leak(Box::new(owner)),
+            false,
+            0,
+        )
+    }
+
+    #[test]
+    fn test_kamino_flash_loan() {
+        let mut reserve_data = Reserve::zeroed();
+        reserve_data.discriminator = RESERVE_DISCRIMINATOR;
+        reserve_data.config.fees.flash_loan_fee_sf = SF_ONE / 10_000 * 9;
+        let reserve = fake_account(
+            Pubkey::new_unique(),
+            KAMINO_ID,
+            bytemuck::bytes_of(&reserve_data).to_vec(),
+        );
+
+        let mut data = FLASH_BORROW_RESERVE_LIQUIDITY_DISCRIMINATOR.to_vec();
+        data.extend(1_500_000u64.to_le_bytes());
+        let borrow_ix = FlashBorrowIx {
+            ix_idx: 0,
+            program_id: KAMINO_ID,
+            data,
+            accounts: vec![
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                *reserve.key,
+            ],
+        };
+
+        let detector = KaminoFlashLoanDetector {};
+        assert!(detector.is_flash_borrow(&borrow_ix.data));
+        assert!(!detector.is_flash_borrow(&borrow_ix.data[..8]));
+        assert_eq!(detector.flash_loan_amount(&borrow_ix).unwrap(), 1_500_000);
+
+        let ixs_sysvar = Box::leak(Box::new(reserve.clone()));
+        let accounts = Box::leak(vec![reserve.clone()].into_boxed_slice());
+        assert_eq!(
+            detector
+                .flash_loan_fee_bps(ixs_sysvar, &borrow_ix, accounts)
+                .unwrap(),
+            9
+        );
+
+        // The reserve has to be provided, and be owned by Kamino
+        let other_account = fake_account(Pubkey::new_unique(), KAMINO_ID, vec![]);
+        let accounts = Box::leak(vec![other_account].into_boxed_slice());
+        assert!(detector
+            .flash_loan_fee_bps(ixs_sysvar, &borrow_ix, accounts)
+            .is_err());
+        let fake_reserve = fake_account(
+            *reserve.key,
+            Pubkey::new_unique(),
+            bytemuck::bytes_of(&reserve_data).to_vec(),
+        );
+        let accounts = Box::leak(vec![fake_reserve].into_boxed_slice());
+        assert!(detector
+            .flash_loan_fee_bps(ixs_sysvar, &borrow_ix, accounts)
+            .is_err());
+    }
+}
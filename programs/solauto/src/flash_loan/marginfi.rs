@@ -0,0 +1,247 @@
+use borsh::BorshDeserialize;
+use fixed::types::I80F48;
+use marginfi_sdk::generated::{
+    accounts::Bank,
+    instructions::{
+        LendingAccountBorrowInstructionArgs, LendingAccountStartFlashloanInstructionArgs,
+    },
+};
+use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    constants::{MARGINFI_PROD_PROGRAM, MARGINFI_STAGING_PROGRAM},
+    types::{errors::SolautoError, shared::DeserializedAccount},
+    utils::{
+        ix_utils::{get_anchor_ix_discriminator, pick_ix_data, PickIxDataReq},
+        math_utils::i80f48_to_f64,
+    },
+};
+
+use super::{fee_rate_to_bps, flash_loan_account, FlashBorrowIx, FlashLoanDetector};
+
+/// A Marginfi `lending_account_borrow`, either on its own and repaid later in the transaction, or within a
+/// `lending_account_start_flashloan` / `lending_account_end_flashloan` pair
+pub struct MarginfiFlashLoanDetector {}
+
+impl MarginfiFlashLoanDetector {
+    /// Whether the borrow is made within a flash loan of the same Marginfi account. Marginfi itself checks that the
+    /// end index of the flash loan points to its `lending_account_end_flashloan`
+    fn in_flashloan<'a>(ixs_sysvar: &'a AccountInfo<'a>, borrow_ix: &FlashBorrowIx) -> bool {
+        let marginfi_account = borrow_ix.accounts[1];
+        let start_flashloan_discriminator =
+            get_anchor_ix_discriminator("lending_account_start_flashloan").to_le_bytes();
+
+        (0..borrow_ix.ix_idx).any(|ix_idx| {
+            let data = match pick_ix_data(PickIxDataReq {
+                ixs_sysvar,
+                ix_idx,
+                data_start_idx: Some(0),
+                data_len: None,
+                account_indices: Some(vec![0]),
+            }) {
+                Ok(data) => data,
+                Err(_) => return false,
+            };
+            if data.program_id != borrow_ix.program_id
+                || data.data.len() < 16
+                || data.data[..8] != start_flashloan_discriminator
+                || data.accounts.first() != Some(&marginfi_account)
+            {
+                return false;
+            }
+
+            match LendingAccountStartFlashloanInstructionArgs::deserialize(&mut &data.data[8..]) {
+                Ok(args) => args.end_index > borrow_ix.ix_idx as u64,
+                Err(_) => false,
+            }
+        })
+    }
+}
+
+impl FlashLoanDetector for MarginfiFlashLoanDetector {
+    fn program_ids(&self) -> Vec<Pubkey> {
+        vec![MARGINFI_PROD_PROGRAM, MARGINFI_STAGING_PROGRAM]
+    }
+
+    fn accounts_len(&self) -> u16 {
+        4
+    }
+
+    fn is_flash_borrow(&self, data: &[u8]) -> bool {
+        data.len() >= 16
+            && data[..8] == get_anchor_ix_discriminator("lending_account_borrow").to_le_bytes()
+    }
+
+    fn flash_loan_amount(&self, borrow_ix: &FlashBorrowIx) -> Result<u64, ProgramError> {
+        let args = LendingAccountBorrowInstructionArgs::deserialize(&mut &borrow_ix.data[8..])
+            .map_err(|_| SolautoError::IncorrectInstructions)?;
+        Ok(args.amount)
+    }
+
+    /// Borrows within a flash loan aren't charged the bank's origination fee
+    fn flash_loan_fee_bps<'a>(
+        &self,
+        ixs_sysvar: &'a AccountInfo<'a>,
+        borrow_ix: &FlashBorrowIx,
+        accounts: &'a [AccountInfo<'a>],
+    ) -> Result<u16, ProgramError> {
+        if Self::in_flashloan(ixs_sysvar, borrow_ix) {
+            return Ok(0);
+        }
+
+        let bank = DeserializedAccount::<Bank>::zerocopy(Some(flash_loan_account(
+            accounts, borrow_ix, 3,
+        )?))?
+        .unwrap();
+        Ok(fee_rate_to_bps(i80f48_to_f64(I80F48::from_le_bytes(
+            bank.data
+                .config
+                .interest_rate_config
+                .protocol_origination_fee
+                .value,
+        ))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::Zeroable;
+    use marginfi_sdk::generated::instructions::{
+        LendingAccountBorrowBuilder, LendingAccountStartFlashloanBuilder,
+    };
+    use solana_program::instruction::Instruction;
+
+    use super::*;
+    use crate::utils::ix_utils::tests::fake_ixs_sysvar;
+
+    fn fake_account(key: Pubkey, owner: Pubkey, data: Vec<u8>) -> AccountInfo<'static> {
+        AccountInfo::new(
+            Box::leak(Box::new(key)),
+            false,
+            true,
+            Box::leak(Box::new(0u64)),
+            Box::leak(data.into_boxed_slice()),
+            Box::leak(Box::new(owner)),
+            false,
+            0,
+        )
+    }
+
+    fn borrow_ix(program_id: Pubkey, marginfi_account: Pubkey, bank: Pubkey) -> Instruction {
+        let mut ix = LendingAccountBorrowBuilder::new()
+            .marginfi_group(Pubkey::new_unique())
+            .marginfi_account(marginfi_account)
+            .signer(Pubkey::new_unique())
+            .bank(bank)
+            .destination_token_account(Pubkey::new_unique())
+            .bank_liquidity_vault_authority(Pubkey::new_unique())
+            .bank_liquidity_vault(Pubkey::new_unique())
+            .amount(2_000_000)
+            .instruction();
+        ix.program_id = program_id;
+        ix
+    }
+
+    fn start_flashloan_ix(
+        program_id: Pubkey,
+        marginfi_account: Pubkey,
+        end_index: u64,
+    ) -> Instruction {
+        let mut ix = LendingAccountStartFlashloanBuilder::new()
+            .marginfi_account(marginfi_account)
+            .signer(Pubkey::new_unique())
+            .ixs_sysvar(solana_program::sysvar::instructions::ID)
+            .end_index(end_index)
+            .instruction();
+        ix.program_id = program_id;
+        ix
+    }
+
+    fn flash_loan_fee_bps(
+        program_id: Pubkey,
+        ixs: &[Instruction],
+        bank: &AccountInfo<'static>,
+    ) -> Result<u16, ProgramError> {
+        let borrow_ix_idx = ixs.len() - 1;
+        let borrow_ix = FlashBorrowIx {
+            ix_idx: borrow_ix_idx,
+            program_id,
+            data: ixs[borrow_ix_idx].data.clone(),
+            accounts: ixs[borrow_ix_idx]
+                .accounts
+                .iter()
+                .map(|meta| meta.pubkey)
+                .collect(),
+        };
+
+        let detector = MarginfiFlashLoanDetector {};
+        assert!(detector.program_ids().contains(&program_id));
+        assert!(detector.is_flash_borrow(&borrow_ix.data));
+        assert_eq!(detector.flash_loan_amount(&borrow_ix).unwrap(), 2_000_000);
+
+        let accounts = Box::leak(vec![bank.clone()].into_boxed_slice());
+        detector.flash_loan_fee_bps(
+            fake_ixs_sysvar(ixs, borrow_ix_idx as u16),
+            &borrow_ix,
+            accounts,
+        )
+    }
+
+    #[test]
+    fn test_marginfi_flash_loan() {
+        for program_id in [MARGINFI_PROD_PROGRAM, MARGINFI_STAGING_PROGRAM] {
+            let marginfi_account = Pubkey::new_unique();
+            let mut bank_data = Bank::zeroed();
+            bank_data
+                .config
+                .interest_rate_config
+                .protocol_origination_fee
+                .value = I80F48::from_num(0.0025).to_le_bytes();
+            let bank = fake_account(
+                Pubkey::new_unique(),
+                program_id,
+                bytemuck::bytes_of(&bank_data).to_vec(),
+            );
+            let borrow = borrow_ix(program_id, marginfi_account, *bank.key);
+
+            // Borrows within a flash loan are free
+            let ixs = vec![
+                start_flashloan_ix(program_id, marginfi_account, 2),
+                borrow.clone(),
+            ];
+            assert_eq!(flash_loan_fee_bps(program_id, &ixs, &bank).unwrap(), 0);
+
+            // Other borrows pay the bank's origination fee
+            assert_eq!(
+                flash_loan_fee_bps(program_id, std::slice::from_ref(&borrow), &bank).unwrap(),
+                25
+            );
+            // Including when the flash loan ends before the borrow, or is of another account
+            let ixs = vec![
+                start_flashloan_ix(program_id, marginfi_account, 0),
+                borrow.clone(),
+            ];
+            assert_eq!(flash_loan_fee_bps(program_id, &ixs, &bank).unwrap(), 25);
+            let ixs = vec![
+                start_flashloan_ix(program_id, Pubkey::new_unique(), 2),
+                borrow.clone(),
+            ];
+            assert_eq!(flash_loan_fee_bps(program_id, &ixs, &bank).unwrap(), 25);
+
+            // The bank has to be owned by the Marginfi program that was borrowed from
+            let fake_bank = fake_account(
+                *bank.key,
+                Pubkey::new_unique(),
+                bytemuck::bytes_of(&bank_data).to_vec(),
+            );
+            assert!(flash_loan_fee_bps(program_id, &[borrow], &fake_bank).is_err());
+        }
+
+        // Borrows of other programs aren't Marginfi flash loans
+        let detector = MarginfiFlashLoanDetector {};
+        assert!(!detector.program_ids().contains(&Pubkey::new_unique()));
+        assert!(!detector.is_flash_borrow(
+            &get_anchor_ix_discriminator("lending_account_deposit").to_le_bytes()
+        ));
+    }
+}
@@ -0,0 +1,132 @@
+use std::ops::Mul;
+
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    check,
+    types::errors::SolautoError,
+    utils::ix_utils::{pick_ix_data, PickIxDataReq},
+};
+
+use self::{
+    kamino::KaminoFlashLoanDetector, marginfi::MarginfiFlashLoanDetector,
+    save::SaveFlashLoanDetector,
+};
+
+pub mod kamino;
+pub mod marginfi;
+pub mod save;
+
+/// A flash borrow instruction, as read from the instructions sysvar
+pub struct FlashBorrowIx {
+    pub ix_idx: usize,
+    pub program_id: Pubkey,
+    pub data: Vec<u8>,
+    pub accounts: Vec<Pubkey>,
+}
+
+/// The flash loan that funds a rebalance or a position migration
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FlashLoan {
+    pub amount: u64,
+    pub fee_bps: u16,
+}
+
+/// A flash loan provider. Each provider decodes its own flash borrow instruction, and reads the fee from its own
+/// accounts, so that neither the amount nor the fee is taken from the instruction args
+pub trait FlashLoanDetector {
+    fn program_ids(&self) -> Vec<Pubkey>;
+
+    /// Number of leading accounts that hold every account the flash loan is read from
+    fn accounts_len(&self) -> u16;
+
+    fn is_flash_borrow(&self, data: &[u8]) -> bool;
+
+    fn flash_loan_amount(&self, borrow_ix: &FlashBorrowIx) -> Result<u64, ProgramError>;
+
+    /// `accounts` are the accounts of the Solauto instruction, which must include the account the fee is read from
+    fn flash_loan_fee_bps<'a>(
+        &self,
+        ixs_sysvar: &'a AccountInfo<'a>,
+        borrow_ix: &FlashBorrowIx,
+        accounts: &'a [AccountInfo<'a>],
+    ) -> Result<u16, ProgramError>;
+}
+
+pub fn get_flash_loan_detectors() -> Vec<Box<dyn FlashLoanDetector>> {
+    vec![
+        Box::new(MarginfiFlashLoanDetector {}),
+        Box::new(KaminoFlashLoanDetector {}),
+        Box::new(SaveFlashLoanDetector {}),
+    ]
+}
+
+/// Reads the flash loan of the instruction at the index, if it's a flash borrow of any of the supported providers
+pub fn detect_flash_loan<'a>(
+    ixs_sysvar: &'a AccountInfo<'a>,
+    ix_idx: usize,
+    accounts: &'a [AccountInfo<'a>],
+) -> Result<Option<FlashLoan>, ProgramError> {
+    let detectors = get_flash_loan_detectors();
+    let accounts_len = detectors
+        .iter()
+        .map(|detector| detector.accounts_len())
+        .max()
+        .unwrap_or(0);
+    let data = pick_ix_data(PickIxDataReq {
+        ixs_sysvar,
+        ix_idx,
+        data_start_idx: Some(0),
+        data_len: None,
+        account_indices: Some((0..accounts_len).collect()),
+    })
+    .map_err(|_| SolautoError::IncorrectInstructions)?;
+
+    let detector = match detectors.iter().find(|detector| {
+        detector.program_ids().contains(&data.program_id) && detector.is_flash_borrow(&data.data)
+    }) {
+        Some(detector) => detector,
+        None => return Ok(None),
+    };
+    check!(
+        data.accounts.len() >= detector.accounts_len() as usize,
+        SolautoError::IncorrectInstructions
+    );
+
+    let borrow_ix = FlashBorrowIx {
+        ix_idx,
+        program_id: data.program_id,
+        data: data.data,
+        accounts: data.accounts,
+    };
+    Ok(Some(FlashLoan {
+        amount: detector.flash_loan_amount(&borrow_ix)?,
+        fee_bps: detector.flash_loan_fee_bps(ixs_sysvar, &borrow_ix, accounts)?,
+    }))
+}
+
+/// Finds the flash borrow's account at the index among the Solauto instruction's accounts
+pub fn flash_loan_account<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    borrow_ix: &FlashBorrowIx,
+    account_idx: usize,
+) -> Result<&'a AccountInfo<'a>, ProgramError> {
+    let key = borrow_ix.accounts[account_idx];
+    let account = accounts
+        .iter()
+        .find(|account| account.key == &key)
+        .ok_or_else(|| {
+            msg!("Missing flash loan account {}", key);
+            SolautoError::IncorrectAccounts
+        })?;
+    check!(
+        account.owner == &borrow_ix.program_id,
+        SolautoError::IncorrectAccounts
+    );
+    Ok(account)
+}
+
+/// Rounded the same way as the lending protocols' borrow fees
+pub fn fee_rate_to_bps(fee_rate: f64) -> u16 {
+    fee_rate.mul(10_000.0).round() as u16
+}
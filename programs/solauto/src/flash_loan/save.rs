@@ -0,0 +1,96 @@
+use save_sdk::{accounts::wad_to_f64, instructions::FLASH_BORROW_RESERVE_LIQUIDITY_TAG, SAVE_ID};
+use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{clients::save::SaveClient, types::errors::SolautoError};
+
+use super::{fee_rate_to_bps, flash_loan_account, FlashBorrowIx, FlashLoanDetector};
+
+/// A Save (formerly Solend) `FlashBorrowReserveLiquidity`, which is charged the reserve's flash loan fee. The host
+/// fee is a share of that fee, not on top of it
+pub struct SaveFlashLoanDetector {}
+
+impl FlashLoanDetector for SaveFlashLoanDetector {
+    fn program_ids(&self) -> Vec<Pubkey> {
+        vec![SAVE_ID]
+    }
+
+    fn accounts_len(&self) -> u16 {
+        3
+    }
+
+    fn is_flash_borrow(&self, data: &[u8]) -> bool {
+        data.len() >= 9 && data[0] == FLASH_BORROW_RESERVE_LIQUIDITY_TAG
+    }
+
+    fn flash_loan_amount(&self, borrow_ix: &FlashBorrowIx) -> Result<u64, ProgramError> {
+        let amount = borrow_ix.data[1..9]
+            .try_into()
+            .map_err(|_| SolautoError::IncorrectInstructions)?;
+        Ok(u64::from_le_bytes(amount))
+    }
+
+    fn flash_loan_fee_bps<'a>(
+        &self,
+        _ixs_sysvar: &'a AccountInfo<'a>,
+        borrow_ix: &FlashBorrowIx,
+        accounts: &'a [AccountInfo<'a>],
+    ) -> Result<u16, ProgramError> {
+        let reserve = SaveClient::load_reserve(flash_loan_account(accounts, borrow_ix, 2)?)?;
+        Ok(fee_rate_to_bps(wad_to_f64(
+            reserve.data.flash_loan_fee_wad as u128,
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use save_sdk::{
+        accounts::{reserve_offsets, RESERVE_LEN},
+        instructions::FLASH_REPAY_RESERVE_LIQUIDITY_TAG,
+    };
+
+    use super::*;
+
+    const WAD: u128 = 1_000_000_000_000_000_000;
+
+    #[test]
+    fn test_save_flash_loan() {
+        let mut reserve_data = vec![0u8; RESERVE_LEN];
+        reserve_data[reserve_offsets::VERSION] = 1;
+        reserve_data[reserve_offsets::FLASH_LOAN_FEE_WAD..reserve_offsets::FLASH_LOAN_FEE_WAD + 8]
+            .copy_from_slice(&((WAD * 3 / 10_000) as u64).to_le_bytes());
+        let reserve = AccountInfo::new(
+            Box::leak(Box::new(Pubkey::new_unique())),
+            false,
+            true,
+            Box::leak(Box::new(0u64)),
+            Box::leak(reserve_data.into_boxed_slice()),
+            &SAVE_ID,
+            false,
+            0,
+        );
+
+        let mut data = vec![FLASH_BORROW_RESERVE_LIQUIDITY_TAG];
+        data.extend(2_000_000u64.to_le_bytes());
+        let borrow_ix = FlashBorrowIx {
+            ix_idx: 0,
+            program_id: SAVE_ID,
+            data,
+            accounts: vec![Pubkey::new_unique(), Pubkey::new_unique(), *reserve.key],
+        };
+
+        let detector = SaveFlashLoanDetector {};
+        assert!(detector.is_flash_borrow(&borrow_ix.data));
+        assert!(!detector.is_flash_borrow(&[FLASH_REPAY_RESERVE_LIQUIDITY_TAG; 9]));
+        assert_eq!(detector.flash_loan_amount(&borrow_ix).unwrap(), 2_000_000);
+
+        let ixs_sysvar = Box::leak(Box::new(reserve.clone()));
+        let accounts = Box::leak(vec![reserve].into_boxed_slice());
+        assert_eq!(
+            detector
+                .flash_loan_fee_bps(ixs_sysvar, &borrow_ix, accounts)
+                .unwrap(),
+            3
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use solana_program::entrypoint::ProgramResult;
+
+use crate::{
+    clients::marginfi::MarginfiClient,
+    instructions::{close_position::close_position_accounts, open_position, refresh},
+    state::solauto_position::SolautoPosition,
+    types::{
+        instruction::accounts::{
+            Context, MarginfiExportAccountAccounts, MarginfiImportAccountAccounts,
+        },
+        shared::{DeserializedAccount, PriceType},
+    },
+};
+
+pub fn marginfi_import_account<'a>(
+    ctx: Context<'a, MarginfiImportAccountAccounts<'a>>,
+    mut solauto_position: DeserializedAccount<'a, SolautoPosition>,
+) -> ProgramResult {
+    open_position::initialize_solauto_position(
+        &mut solauto_position,
+        ctx.accounts.system_program,
+        ctx.accounts.token_program,
+        ctx.accounts.rent,
+        ctx.accounts.signer,
+        ctx.accounts.supply_mint,
+        ctx.accounts.position_supply_ta,
+        ctx.accounts.debt_mint,
+        ctx.accounts.position_debt_ta,
+        None,
+    )?;
+
+    MarginfiClient::set_account_authority(
+        ctx.accounts.marginfi_program,
+        ctx.accounts.marginfi_group,
+        ctx.accounts.marginfi_account,
+        ctx.accounts.signer,
+        ctx.accounts.solauto_position,
+        ctx.accounts.signer,
+        &solauto_position,
+    )?;
+
+    refresh::marginfi_refresh_accounts(
+        ctx.accounts.marginfi_program,
+        ctx.accounts.marginfi_group,
+        ctx.accounts.marginfi_account,
+        ctx.accounts.supply_bank,
+        ctx.accounts.supply_price_oracle,
+        ctx.accounts.debt_bank,
+        ctx.accounts.debt_price_oracle,
+        &[],
+        &mut solauto_position,
+        PriceType::Realtime,
+    )
+}
+
+pub fn marginfi_export_account<'a>(
+    ctx: Context<'a, MarginfiExportAccountAccounts<'a>>,
+    solauto_position: DeserializedAccount<'a, SolautoPosition>,
+) -> ProgramResult {
+    MarginfiClient::set_account_authority(
+        ctx.accounts.marginfi_program,
+        ctx.accounts.marginfi_group,
+        ctx.accounts.marginfi_account,
+        ctx.accounts.solauto_position,
+        ctx.accounts.signer,
+        ctx.accounts.signer,
+        &solauto_position,
+    )?;
+
+    close_position_accounts(
+        ctx.accounts.token_program,
+        ctx.accounts.signer,
+        &solauto_position,
+        (
+            ctx.accounts.position_supply_ta,
+            ctx.accounts.signer_supply_ta,
+        ),
+        (ctx.accounts.position_debt_ta, ctx.accounts.signer_debt_ta),
+    )
+}
@@ -11,7 +11,8 @@ use crate::{
 };
 
 pub fn close_position_ta<'a>(
-    ctx: &Context<ClosePositionAccounts<'a>>,
+    token_program: &'a AccountInfo<'a>,
+    signer: &'a AccountInfo<'a>,
     solauto_position: &DeserializedAccount<'a, SolautoPosition>,
     position_ta: &'a AccountInfo<'a>,
     signer_ta: &'a AccountInfo<'a>,
@@ -23,7 +24,7 @@ pub fn close_position_ta<'a>(
 
     if position_ta_data.amount > 0 && position_ta_data.mint != WSOL_MINT {
         solana_utils::spl_token_transfer(
-            ctx.accounts.token_program,
+            token_program,
             SplTokenTransferArgs {
                 source: position_ta,
                 authority: solauto_position.account_info,
@@ -35,10 +36,10 @@ pub fn close_position_ta<'a>(
     }
 
     solana_utils::close_token_account(
-        ctx.accounts.token_program,
+        token_program,
         position_ta,
-        ctx.accounts.signer,
-        ctx.accounts.solauto_position,
+        signer,
+        solauto_position.account_info,
         Some(solauto_position_seeds),
     )
 }
@@ -46,18 +47,41 @@ pub fn close_position_ta<'a>(
 pub fn close_position<'a>(
     ctx: &Context<ClosePositionAccounts<'a>>,
     solauto_position: &DeserializedAccount<'a, SolautoPosition>,
+) -> ProgramResult {
+    close_position_accounts(
+        ctx.accounts.token_program,
+        ctx.accounts.signer,
+        solauto_position,
+        (
+            ctx.accounts.position_supply_ta,
+            ctx.accounts.signer_supply_ta,
+        ),
+        (ctx.accounts.position_debt_ta, ctx.accounts.signer_debt_ta),
+    )
+}
+
+/// Moves the balances of the position token accounts to the signer token accounts, then closes
+/// the position token accounts & the Solauto position
+pub fn close_position_accounts<'a>(
+    token_program: &'a AccountInfo<'a>,
+    signer: &'a AccountInfo<'a>,
+    solauto_position: &DeserializedAccount<'a, SolautoPosition>,
+    supply_tas: (&'a AccountInfo<'a>, &'a AccountInfo<'a>),
+    debt_tas: (&'a AccountInfo<'a>, &'a AccountInfo<'a>),
 ) -> ProgramResult {
     close_position_ta(
-        ctx,
+        token_program,
+        signer,
         solauto_position,
-        ctx.accounts.position_supply_ta,
-        ctx.accounts.signer_supply_ta,
+        supply_tas.0,
+        supply_tas.1,
     )?;
     close_position_ta(
-        ctx,
+        token_program,
+        signer,
         solauto_position,
-        ctx.accounts.position_debt_ta,
-        ctx.accounts.signer_debt_ta,
+        debt_tas.0,
+        debt_tas.1,
     )?;
-    solana_utils::close_pda(ctx.accounts.solauto_position, ctx.accounts.signer)
+    solana_utils::close_pda(solauto_position.account_info, signer)
 }
@@ -0,0 +1,126 @@
+use solana_program::{entrypoint::ProgramResult, msg};
+
+use crate::{
+    check,
+    clients::marginfi::{MarginfiClient, MarginfiEmissionsAccounts},
+    state::solauto_position::HarvestData,
+    types::{
+        errors::SolautoError,
+        instruction::{
+            accounts::{Context, MarginfiHarvestEmissionsAccounts},
+            SolautoAction, SolautoStandardAccounts,
+        },
+        lending_protocol::LendingProtocolTokenAccounts,
+        shared::SplTokenTransferArgs,
+        solauto_manager::{SolautoManager, SolautoManagerAccounts},
+    },
+    utils::{ix_utils, solana_utils, solauto_utils},
+};
+
+pub fn marginfi_harvest_emissions<'a>(
+    ctx: Context<'a, MarginfiHarvestEmissionsAccounts<'a>>,
+    mut std_accounts: Box<SolautoStandardAccounts<'a>>,
+) -> ProgramResult {
+    if std_accounts.solauto_position.data.harvest.active.val {
+        let swap_output =
+            solauto_utils::get_token_account_balance(ctx.accounts.position_supply_ta)?
+                .saturating_sub(std_accounts.solauto_position.data.harvest.supply_ta_balance);
+        std_accounts.solauto_position.data.harvest = HarvestData::default();
+        return deposit_harvest(ctx, std_accounts, swap_output);
+    }
+
+    let emissions_ta_balance =
+        solauto_utils::get_token_account_balance(ctx.accounts.position_emissions_ta)?;
+    MarginfiClient::harvest_emissions(
+        ctx.accounts.marginfi_program,
+        ctx.accounts.marginfi_group,
+        ctx.accounts.marginfi_account,
+        MarginfiEmissionsAccounts {
+            bank: ctx.accounts.emissions_bank,
+            emissions_mint: ctx.accounts.emissions_mint,
+            emissions_auth: ctx.accounts.emissions_auth,
+            emissions_vault: ctx.accounts.emissions_vault,
+        },
+        ctx.accounts.position_emissions_ta,
+        ctx.accounts.token_program,
+        &std_accounts.solauto_position,
+    )?;
+    let harvested = solauto_utils::get_token_account_balance(ctx.accounts.position_emissions_ta)?
+        .saturating_sub(emissions_ta_balance);
+    msg!(
+        "Withdrew {} of emissions mint {}",
+        harvested,
+        ctx.accounts.emissions_mint.key
+    );
+    check!(harvested > 0, SolautoError::IncorrectInstructions);
+
+    if ctx.accounts.emissions_mint.key == &std_accounts.solauto_position.data.state.supply.mint {
+        return deposit_harvest(ctx, std_accounts, harvested);
+    }
+
+    let intermediary_ta = ctx
+        .accounts
+        .intermediary_ta
+        .ok_or(SolautoError::IncorrectAccounts)?;
+    solana_utils::spl_token_transfer(
+        ctx.accounts.token_program,
+        SplTokenTransferArgs {
+            source: ctx.accounts.position_emissions_ta,
+            authority: ctx.accounts.solauto_position,
+            recipient: intermediary_ta,
+            amount: harvested,
+            authority_seeds: Some(&std_accounts.solauto_position.data.seeds_with_bump()),
+        },
+    )?;
+
+    std_accounts.solauto_position.data.harvest = HarvestData::from(
+        solauto_utils::get_token_account_balance(ctx.accounts.position_supply_ta)?,
+    );
+    ix_utils::update_data(&mut std_accounts.solauto_position)
+}
+
+fn deposit_harvest<'a>(
+    ctx: Context<'a, MarginfiHarvestEmissionsAccounts<'a>>,
+    std_accounts: Box<SolautoStandardAccounts<'a>>,
+    base_unit_amount: u64,
+) -> ProgramResult {
+    let supply_tas = LendingProtocolTokenAccounts::from(
+        None,
+        Some(ctx.accounts.position_supply_ta),
+        None,
+        Some(ctx.accounts.vault_supply_ta),
+    )?;
+    let debt_tas = LendingProtocolTokenAccounts::from(None, None, None, None)?;
+
+    let marginfi_client = Box::new(MarginfiClient::from(
+        ctx.accounts.signer,
+        ctx.accounts.marginfi_program,
+        ctx.accounts.marginfi_group,
+        ctx.accounts.marginfi_account,
+        ctx.accounts.supply_bank,
+        None,
+        supply_tas.clone(),
+        None,
+        ctx.accounts.debt_bank,
+        None,
+        debt_tas.clone(),
+        None,
+        Vec::new(),
+    )?);
+    let solauto_manager_accounts = SolautoManagerAccounts::from(supply_tas, debt_tas, None, None)?;
+
+    let mut solauto_manager = SolautoManager::from(
+        marginfi_client,
+        solauto_manager_accounts,
+        std_accounts,
+        None,
+    )?;
+    solauto_manager.protocol_interaction(SolautoAction::Deposit(base_unit_amount))?;
+    solauto_manager
+        .std_accounts
+        .solauto_position
+        .data
+        .record_emissions_harvest(base_unit_amount);
+
+    ix_utils::update_data(&mut solauto_manager.std_accounts.solauto_position)
+}
@@ -0,0 +1,140 @@
+use math_utils::{from_bps, to_bps};
+use solana_program::{entrypoint::ProgramResult, msg, program_error::ProgramError};
+
+use crate::{
+    error_if,
+    flash_loan::FlashLoan,
+    types::{
+        errors::SolautoError,
+        instruction::{
+            accounts::{Context, MigratePositionAccounts},
+            MigratePositionData, SolautoStandardAccounts,
+        },
+        lending_protocol::{LendingProtocolAccounts, LendingProtocolTokenAccounts},
+        shared::{LendingPlatform, SplTokenTransferArgs, TokenBalanceAmount},
+        solauto::{FromLendingPlatformAction, SolautoAccount, SolautoCpiAction},
+        solauto_manager::{SolautoManager, SolautoManagerAccounts},
+    },
+    utils::*,
+};
+
+pub fn migrate_position<'a>(
+    ctx: Context<'a, MigratePositionAccounts<'a>>,
+    std_accounts: Box<SolautoStandardAccounts<'a>>,
+    source_lp_accounts: Box<dyn LendingProtocolAccounts<'a> + 'a>,
+    destination_lp_accounts: Box<dyn LendingProtocolAccounts<'a> + 'a>,
+    args: MigratePositionData,
+    flash_loan: FlashLoan,
+) -> ProgramResult {
+    solana_utils::spl_token_transfer(
+        ctx.accounts.token_program,
+        SplTokenTransferArgs {
+            source: ctx.accounts.intermediary_ta,
+            authority: ctx.accounts.signer,
+            recipient: ctx.accounts.position_debt_ta,
+            amount: flash_loan.amount,
+            authority_seeds: None,
+        },
+    )?;
+
+    let supply_ta_balance =
+        solauto_utils::get_token_account_balance(ctx.accounts.position_supply_ta)?;
+    let mut solauto_manager = get_solauto_manager(&ctx, source_lp_accounts.as_ref(), std_accounts)?;
+    solauto_manager.execute_cpi_actions(vec![
+        SolautoCpiAction::Repay(TokenBalanceAmount::All),
+        SolautoCpiAction::Withdraw(FromLendingPlatformAction {
+            amount: TokenBalanceAmount::All,
+            to_wallet_ta: SolautoAccount::SolautoPositionSupplyTa,
+        }),
+    ])?;
+    let supply_amount = solauto_utils::get_token_account_balance(ctx.accounts.position_supply_ta)?
+        .saturating_sub(supply_ta_balance);
+    msg!(
+        "Migrating {} supply from {:?} to {:?}",
+        supply_amount,
+        solauto_manager
+            .std_accounts
+            .solauto_position
+            .data
+            .position
+            .lending_platform,
+        args.lending_platform
+    );
+
+    let mut std_accounts = solauto_manager.std_accounts;
+    std_accounts.lending_protocol = ctx.accounts.destination_lending_program;
+    let position = &mut std_accounts.solauto_position.data.position;
+    position.lending_platform = args.lending_platform;
+    position.lp_user_account = *destination_lp_accounts.lp_user_account().key;
+    position.lp_supply_account = *destination_lp_accounts.lp_supply_account().key;
+    position.lp_debt_account = *destination_lp_accounts.lp_debt_account().key;
+    position.lp_pool_account = *destination_lp_accounts.lp_pool_account().key;
+
+    destination_lp_accounts.initialize(&std_accounts)?;
+    // The source's repay & withdraw leave shared Kamino reserves stale, and a new obligation has never been refreshed
+    if args.lending_platform == LendingPlatform::Kamino {
+        destination_lp_accounts.refresh_accounts()?;
+    }
+
+    let (max_ltv, liq_threshold) = if cfg!(feature = "local") {
+        (0.65, 0.8)
+    } else {
+        destination_lp_accounts.get_max_ltv_and_liq_threshold()?
+    };
+    std_accounts.solauto_position.data.state.max_ltv_bps = to_bps(max_ltv);
+    std_accounts.solauto_position.data.state.liq_threshold_bps = to_bps(liq_threshold);
+
+    let fl_repay_amount = flash_loan.amount
+        + ((flash_loan.amount as f64) * from_bps(flash_loan.fee_bps)).ceil() as u64;
+
+    let mut solauto_manager =
+        get_solauto_manager(&ctx, destination_lp_accounts.as_ref(), std_accounts)?;
+    solauto_manager.execute_cpi_actions(vec![
+        SolautoCpiAction::Deposit(supply_amount),
+        SolautoCpiAction::Borrow(FromLendingPlatformAction {
+            amount: fl_repay_amount,
+            to_wallet_ta: SolautoAccount::IntermediaryTa,
+        }),
+    ])?;
+
+    let solauto_position = &mut solauto_manager.std_accounts.solauto_position;
+    solauto_position.data.refresh_state();
+    validation_utils::validate_position_settings(&solauto_position.data)?;
+    error_if!(
+        solauto_position.data.state.liq_utilization_rate_bps
+            > solauto_position.data.repay_from_bps(),
+        SolautoError::InvalidRebalanceCondition
+    );
+
+    ix_utils::update_data(solauto_position)
+}
+
+fn get_solauto_manager<'a>(
+    ctx: &Context<'a, MigratePositionAccounts<'a>>,
+    lp_accounts: &dyn LendingProtocolAccounts<'a>,
+    std_accounts: Box<SolautoStandardAccounts<'a>>,
+) -> Result<SolautoManager<'a>, ProgramError> {
+    let (supply_protocol_ta, debt_protocol_ta) = lp_accounts.protocol_tas();
+    let supply_tas = LendingProtocolTokenAccounts::from(
+        Some(ctx.accounts.supply_mint),
+        Some(ctx.accounts.position_supply_ta),
+        None,
+        supply_protocol_ta,
+    )?;
+    let debt_tas = LendingProtocolTokenAccounts::from(
+        Some(ctx.accounts.debt_mint),
+        Some(ctx.accounts.position_debt_ta),
+        None,
+        debt_protocol_ta,
+    )?;
+
+    let client = lp_accounts.client(ctx.accounts.signer, supply_tas.clone(), debt_tas.clone())?;
+    let solauto_manager_accounts = SolautoManagerAccounts::from(
+        supply_tas,
+        debt_tas,
+        Some(ctx.accounts.intermediary_ta),
+        None,
+    )?;
+
+    SolautoManager::from(client, solauto_manager_accounts, std_accounts, None)
+}
@@ -1,4 +1,7 @@
+pub mod account_transfer;
 pub mod close_position;
+pub mod harvest_emissions;
+pub mod migrate_position;
 pub mod open_position;
 pub mod protocol_interaction;
 pub mod rebalance;
@@ -1,11 +1,18 @@
 use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
+use spl_associated_token_account::get_associated_token_address;
 
 use crate::{
+    check,
     clients::marginfi::MarginfiClient,
     state::solauto_position::SolautoPosition,
     types::{
-        instruction::accounts::{Context, MarginfiOpenPositionAccounts},
-        shared::DeserializedAccount,
+        errors::SolautoError,
+        instruction::{
+            accounts::{Context, MarginfiOpenPositionAccounts, OpenPositionAccounts},
+            SolautoStandardAccounts,
+        },
+        lending_protocol::LendingProtocolAccounts,
+        shared::{DeserializedAccount, SplTokenTransferArgs, TokenType},
     },
     utils::*,
 };
@@ -29,10 +36,38 @@ pub fn marginfi_open_position<'a>(
         ctx.accounts.signer_debt_ta,
     )?;
 
-    MarginfiClient::initialize(&ctx, &solauto_position)
+    MarginfiClient::initialize(
+        ctx.accounts.signer,
+        ctx.accounts.marginfi_program,
+        ctx.accounts.system_program,
+        ctx.accounts.marginfi_group,
+        ctx.accounts.marginfi_account,
+        &solauto_position,
+    )
 }
 
-fn initialize_solauto_position<'a, 'b>(
+pub fn open_position<'a>(
+    ctx: Context<'a, OpenPositionAccounts<'a>>,
+    mut std_accounts: Box<SolautoStandardAccounts<'a>>,
+    lp_accounts: Box<dyn LendingProtocolAccounts<'a> + 'a>,
+) -> ProgramResult {
+    initialize_solauto_position(
+        &mut std_accounts.solauto_position,
+        ctx.accounts.system_program,
+        ctx.accounts.token_program,
+        ctx.accounts.rent,
+        ctx.accounts.signer,
+        ctx.accounts.supply_mint,
+        ctx.accounts.position_supply_ta,
+        ctx.accounts.debt_mint,
+        ctx.accounts.position_debt_ta,
+        ctx.accounts.signer_debt_ta,
+    )?;
+
+    lp_accounts.initialize(&std_accounts)
+}
+
+pub fn initialize_solauto_position<'a, 'b>(
     solauto_position: &'b mut DeserializedAccount<'a, SolautoPosition>,
     system_program: &'a AccountInfo<'a>,
     token_program: &'a AccountInfo<'a>,
@@ -73,5 +108,28 @@ fn initialize_solauto_position<'a, 'b>(
         debt_mint,
     )?;
 
+    // A DCA started with the position is funded from the signer's debt token account
+    let dca = solauto_position.data.position.dca;
+    if dca.dca_in() {
+        check!(
+            dca.token_type == TokenType::Debt
+                && signer_debt_ta.is_some()
+                && signer_debt_ta.unwrap().key
+                    == &get_associated_token_address(signer.key, debt_mint.key),
+            SolautoError::InvalidDCASettings
+        );
+
+        solana_utils::spl_token_transfer(
+            token_program,
+            SplTokenTransferArgs {
+                source: signer_debt_ta.unwrap(),
+                authority: signer,
+                recipient: position_debt_ta,
+                amount: dca.dca_in_base_unit,
+                authority_seeds: None,
+            },
+        )?;
+    }
+
     ix_utils::update_data(solauto_position)
 }
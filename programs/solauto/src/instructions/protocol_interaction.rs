@@ -1,13 +1,15 @@
 use solana_program::entrypoint::ProgramResult;
 
 use crate::{
-    clients::marginfi::MarginfiClient,
+    clients::marginfi::{MarginfiClient, MarginfiLegAccounts},
     types::{
         instruction::{
-            accounts::{Context, MarginfiProtocolInteractionAccounts},
+            accounts::{Context, MarginfiProtocolInteractionAccounts, ProtocolInteractionAccounts},
             SolautoAction, SolautoStandardAccounts,
         },
-        lending_protocol::{LendingProtocolClient, LendingProtocolTokenAccounts},
+        lending_protocol::{
+            LendingProtocolAccounts, LendingProtocolClient, LendingProtocolTokenAccounts,
+        },
         solauto_manager::{SolautoManager, SolautoManagerAccounts},
     },
     utils::ix_utils,
@@ -44,6 +46,7 @@ pub fn marginfi_interaction<'a>(
         ctx.accounts.debt_price_oracle,
         debt_tas.clone(),
         ctx.accounts.debt_vault_authority,
+        MarginfiLegAccounts::parse(ctx.remaining_accounts)?,
     )?);
     let solauto_manager_accounts = SolautoManagerAccounts::from(supply_tas, debt_tas, None, None)?;
 
@@ -55,6 +58,32 @@ pub fn marginfi_interaction<'a>(
     )
 }
 
+pub fn interaction<'a>(
+    ctx: Context<'a, ProtocolInteractionAccounts<'a>>,
+    std_accounts: Box<SolautoStandardAccounts<'a>>,
+    lp_accounts: Box<dyn LendingProtocolAccounts<'a> + 'a>,
+    action: SolautoAction,
+) -> ProgramResult {
+    let (supply_protocol_ta, debt_protocol_ta) = lp_accounts.protocol_tas();
+    let supply_tas = LendingProtocolTokenAccounts::from(
+        ctx.accounts.supply_mint,
+        ctx.accounts.position_supply_ta,
+        None,
+        supply_protocol_ta,
+    )?;
+    let debt_tas = LendingProtocolTokenAccounts::from(
+        ctx.accounts.debt_mint,
+        ctx.accounts.position_debt_ta,
+        None,
+        debt_protocol_ta,
+    )?;
+
+    let client = lp_accounts.client(ctx.accounts.signer, supply_tas.clone(), debt_tas.clone())?;
+    let solauto_manager_accounts = SolautoManagerAccounts::from(supply_tas, debt_tas, None, None)?;
+
+    protocol_interaction(client, solauto_manager_accounts, std_accounts, action)
+}
+
 fn protocol_interaction<'a>(
     client: Box<dyn LendingProtocolClient<'a> + 'a>,
     solauto_manager_accounts: SolautoManagerAccounts<'a>,
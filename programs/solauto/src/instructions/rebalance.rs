@@ -1,4 +1,7 @@
-use std::ops::Sub;
+use std::{
+    cmp::max,
+    ops::{Div, Sub},
+};
 
 use marginfi_sdk::generated::accounts::Bank;
 use solana_program::{
@@ -8,20 +11,24 @@ use solana_program::{
 
 use crate::{
     check,
-    clients::marginfi::MarginfiClient,
-    rebalance::solauto_fees::SolautoFeesBps,
+    clients::marginfi::{MarginfiClient, MarginfiLegAccounts},
+    constants::MAX_FLASH_LOAN_FEE_BPS,
+    rebalance::{solauto_fees::SolautoFeesBps, utils::full_unwind_triggered},
     state::solauto_position::SolautoPosition,
+    swap::SwapCpi,
     types::{
         errors::SolautoError,
         instruction::{
-            accounts::{Context, MarginfiRebalanceAccounts},
+            accounts::{Context, MarginfiRebalanceAccounts, RebalanceAccounts},
             RebalanceSettings, SolautoStandardAccounts,
         },
-        lending_protocol::{LendingProtocolClient, LendingProtocolTokenAccounts},
-        shared::{DeserializedAccount, RebalanceStep, SolautoRebalanceType, TokenType},
+        lending_protocol::{
+            LendingProtocolAccounts, LendingProtocolClient, LendingProtocolTokenAccounts,
+        },
+        shared::{DeserializedAccount, PriceType, RebalanceStep, SolautoRebalanceType, TokenType},
         solauto_manager::{SolautoManager, SolautoManagerAccounts},
     },
-    utils::ix_utils,
+    utils::{ix_utils, math_utils::to_bps},
 };
 
 use super::refresh;
@@ -32,6 +39,7 @@ pub fn marginfi_rebalance<'a>(
     rebalance_step: RebalanceStep,
     args: RebalanceSettings,
 ) -> ProgramResult {
+    let leg_accounts = MarginfiLegAccounts::parse(ctx.remaining_accounts)?;
     let supply_tas = LendingProtocolTokenAccounts::from(
         None,
         Some(ctx.accounts.position_supply_ta),
@@ -58,6 +66,7 @@ pub fn marginfi_rebalance<'a>(
         ctx.accounts.debt_price_oracle,
         debt_tas.clone(),
         ctx.accounts.debt_vault_authority,
+        leg_accounts.clone(),
     )?);
     let solauto_manager_accounts =
         SolautoManagerAccounts::from(supply_tas, debt_tas, ctx.accounts.intermediary_ta, None)?;
@@ -80,24 +89,34 @@ pub fn marginfi_rebalance<'a>(
                 ctx.accounts.supply_price_oracle.unwrap(),
                 ctx.accounts.debt_bank,
                 ctx.accounts.debt_price_oracle.unwrap(),
+                &leg_accounts,
                 &mut std_accounts.solauto_position,
                 args.price_type.unwrap().clone(),
             )?;
         } else {
-            let supply_price = MarginfiClient::load_price(
-                &DeserializedAccount::<Bank>::zerocopy(Some(ctx.accounts.supply_bank))?.unwrap(),
-                ctx.accounts.supply_price_oracle.unwrap(),
-                args.price_type.unwrap().clone(),
-                TokenType::Supply,
-            )?;
-            let debt_price = MarginfiClient::load_price(
-                &DeserializedAccount::<Bank>::zerocopy(Some(ctx.accounts.debt_bank))?.unwrap(),
-                ctx.accounts.debt_price_oracle.unwrap(),
-                args.price_type.unwrap().clone(),
-                TokenType::Debt,
-            )?;
+            let (supply_price, debt_price) = load_marginfi_prices(&ctx, args.price_type.unwrap())?;
             update_token_prices(&mut std_accounts, supply_price, debt_price);
         }
+
+        if price_divergence_guard_on(&std_accounts) {
+            apply_conservative_prices(
+                &mut std_accounts,
+                load_marginfi_prices(&ctx, PriceType::Realtime)?,
+                load_marginfi_prices(&ctx, PriceType::Ema)?,
+                (
+                    MarginfiClient::load_leg_prices(
+                        ctx.accounts.marginfi_account,
+                        &leg_accounts,
+                        PriceType::Realtime,
+                    )?,
+                    MarginfiClient::load_leg_prices(
+                        ctx.accounts.marginfi_account,
+                        &leg_accounts,
+                        PriceType::Ema,
+                    )?,
+                ),
+            );
+        }
     }
 
     rebalance(
@@ -106,6 +125,76 @@ pub fn marginfi_rebalance<'a>(
         std_accounts,
         rebalance_step,
         args,
+        None,
+    )
+}
+
+pub fn rebalance_position<'a>(
+    ctx: Context<'a, RebalanceAccounts<'a>>,
+    mut std_accounts: Box<SolautoStandardAccounts<'a>>,
+    lp_accounts: Box<dyn LendingProtocolAccounts<'a> + 'a>,
+    rebalance_step: RebalanceStep,
+    args: RebalanceSettings,
+    swap_cpi: Option<SwapCpi<'a>>,
+) -> ProgramResult {
+    let (supply_protocol_ta, debt_protocol_ta) = lp_accounts.protocol_tas();
+    let supply_tas = LendingProtocolTokenAccounts::from(
+        Some(ctx.accounts.supply_mint),
+        Some(ctx.accounts.position_supply_ta),
+        ctx.accounts.authority_supply_ta,
+        supply_protocol_ta,
+    )?;
+    let debt_tas = LendingProtocolTokenAccounts::from(
+        Some(ctx.accounts.debt_mint),
+        Some(ctx.accounts.position_debt_ta),
+        ctx.accounts.authority_debt_ta,
+        debt_protocol_ta,
+    )?;
+
+    let client = lp_accounts.client(ctx.accounts.signer, supply_tas.clone(), debt_tas.clone())?;
+    let solauto_manager_accounts =
+        SolautoManagerAccounts::from(supply_tas, debt_tas, ctx.accounts.intermediary_ta, None)?;
+
+    let rebalance_type = std_accounts
+        .solauto_position
+        .data
+        .rebalance
+        .ixs
+        .rebalance_type;
+    if rebalance_step == RebalanceStep::PreSwap
+        || rebalance_type == SolautoRebalanceType::FLSwapThenRebalance
+    {
+        if needs_refresh(&std_accounts.solauto_position)? {
+            refresh::refresh_accounts(
+                lp_accounts.as_ref(),
+                &mut std_accounts.solauto_position,
+                args.price_type.unwrap(),
+            )?;
+        } else {
+            let (supply_price, debt_price) = lp_accounts.load_prices(args.price_type.unwrap())?;
+            update_token_prices(&mut std_accounts, supply_price, debt_price);
+        }
+
+        if price_divergence_guard_on(&std_accounts) {
+            apply_conservative_prices(
+                &mut std_accounts,
+                lp_accounts.load_prices(PriceType::Realtime)?,
+                lp_accounts.load_prices(PriceType::Ema)?,
+                (
+                    lp_accounts.load_leg_prices(PriceType::Realtime)?,
+                    lp_accounts.load_leg_prices(PriceType::Ema)?,
+                ),
+            );
+        }
+    }
+
+    rebalance(
+        client,
+        solauto_manager_accounts,
+        std_accounts,
+        rebalance_step,
+        args,
+        swap_cpi,
     )
 }
 
@@ -129,6 +218,73 @@ fn update_token_prices<'a>(
     std_accounts.solauto_position.data.refresh_state();
 }
 
+fn load_marginfi_prices<'a>(
+    ctx: &Context<'a, MarginfiRebalanceAccounts<'a>>,
+    price_type: PriceType,
+) -> Result<(f64, f64), ProgramError> {
+    let supply_price = MarginfiClient::load_price(
+        &DeserializedAccount::<Bank>::zerocopy(Some(ctx.accounts.supply_bank))?.unwrap(),
+        ctx.accounts.supply_price_oracle.unwrap(),
+        price_type,
+        TokenType::Supply,
+    )?;
+    let debt_price = MarginfiClient::load_price(
+        &DeserializedAccount::<Bank>::zerocopy(Some(ctx.accounts.debt_bank))?.unwrap(),
+        ctx.accounts.debt_price_oracle.unwrap(),
+        price_type,
+        TokenType::Debt,
+    )?;
+    Ok((supply_price, debt_price))
+}
+
+fn price_divergence_guard_on(std_accounts: &SolautoStandardAccounts) -> bool {
+    std_accounts
+        .solauto_position
+        .data
+        .position
+        .max_price_divergence_bps
+        > 0
+}
+
+/// Prices the position at the lower supply & higher debt price of its realtime & EMA prices, so that neither a spiking
+/// nor a lagging price understates its liq utilization rate. How far the two diverge, on any of the position's legs, is
+/// recorded so that boosts can be refused
+fn apply_conservative_prices<'a>(
+    std_accounts: &mut Box<SolautoStandardAccounts<'a>>,
+    (realtime_supply_price, realtime_debt_price): (f64, f64),
+    (ema_supply_price, ema_debt_price): (f64, f64),
+    (realtime_leg_prices, ema_leg_prices): (Vec<f64>, Vec<f64>),
+) {
+    let divergence_bps = |realtime_price: f64, ema_price: f64| {
+        if ema_price > 0.0 {
+            to_bps((realtime_price - ema_price).abs().div(ema_price))
+        } else {
+            u16::MAX
+        }
+    };
+    std_accounts
+        .solauto_position
+        .data
+        .state
+        .price_divergence_bps = realtime_leg_prices
+        .iter()
+        .zip(ema_leg_prices.iter())
+        .map(|(realtime_price, ema_price)| divergence_bps(*realtime_price, *ema_price))
+        .fold(
+            max(
+                divergence_bps(realtime_supply_price, ema_supply_price),
+                divergence_bps(realtime_debt_price, ema_debt_price),
+            ),
+            max,
+        );
+
+    update_token_prices(
+        std_accounts,
+        realtime_supply_price.min(ema_supply_price),
+        realtime_debt_price.max(ema_debt_price),
+    );
+}
+
 fn needs_refresh(
     solauto_position: &DeserializedAccount<SolautoPosition>,
 ) -> Result<bool, ProgramError> {
@@ -152,19 +308,41 @@ fn rebalance<'a>(
     std_accounts: Box<SolautoStandardAccounts<'a>>,
     rebalance_step: RebalanceStep,
     args: RebalanceSettings,
+    swap_cpi: Option<SwapCpi<'a>>,
 ) -> ProgramResult {
+    // Only the generic rebalance instruction can swap through a CPI, and only with the SwapCpi rebalance type
+    check!(
+        (std_accounts
+            .solauto_position
+            .data
+            .rebalance
+            .ixs
+            .rebalance_type
+            == SolautoRebalanceType::SwapCpi)
+            == swap_cpi.is_some(),
+        SolautoError::IncorrectInstructions
+    );
+    // Besides the authority, only a full unwind of a triggered stop-loss or trailing stop can be given a target
     check!(
         args.target_liq_utilization_rate_bps.is_none()
-            || std_accounts.signer.key == &std_accounts.solauto_position.data.authority,
+            || std_accounts.signer.key == &std_accounts.solauto_position.data.authority
+            || (args.target_liq_utilization_rate_bps == Some(0)
+                && full_unwind_triggered(&std_accounts.solauto_position.data)),
         SolautoError::NonAuthorityProvidedTargetLTV
     );
     check!(
         std_accounts.authority_referral_state.is_some(),
         SolautoError::IncorrectAccounts
     );
-    #[cfg(not(feature = "test"))]
+    // The flash loan provider is picked by the rebalancer, so its fee is capped
     check!(
-        args.flash_loan_fee_bps.unwrap_or(0) <= 150,
+        std_accounts
+            .solauto_position
+            .data
+            .rebalance
+            .ixs
+            .flash_loan_fee_bps
+            <= MAX_FLASH_LOAN_FEE_BPS,
         SolautoError::IncorrectInstructions
     );
 
@@ -191,7 +369,11 @@ fn rebalance<'a>(
         std_accounts,
         Some(fees_bps),
     )?;
-    solauto_manager.rebalance(args, rebalance_step)?;
+    if let Some(swap_cpi) = swap_cpi {
+        solauto_manager.rebalance_with_swap_cpi(args, &swap_cpi)?;
+    } else {
+        solauto_manager.rebalance(args, rebalance_step)?;
+    }
 
     ix_utils::update_data(&mut solauto_manager.std_accounts.solauto_position)
 }
@@ -4,9 +4,10 @@ use solana_program::{
 };
 
 use crate::{
-    clients::marginfi::MarginfiClient,
+    clients::marginfi::{MarginfiClient, MarginfiLegAccounts},
     state::solauto_position::SolautoPosition,
     types::{
+        lending_protocol::LendingProtocolAccounts,
         shared::{DeserializedAccount, PriceType},
         solauto_manager::SolautoManager,
     },
@@ -21,11 +22,15 @@ pub fn marginfi_refresh_accounts<'a, 'b>(
     supply_price_oracle: &'a AccountInfo<'a>,
     debt_bank: &'a AccountInfo<'a>,
     debt_price_oracle: &'a AccountInfo<'a>,
+    leg_accounts: &[MarginfiLegAccounts<'a>],
     solauto_position: &'b mut DeserializedAccount<SolautoPosition>,
     price_type: PriceType,
 ) -> ProgramResult {
     MarginfiClient::refresh_bank(marginfi_program, marginfi_group, supply_bank)?;
     MarginfiClient::refresh_bank(marginfi_program, marginfi_group, debt_bank)?;
+    for leg in leg_accounts.iter() {
+        MarginfiClient::refresh_bank(marginfi_program, marginfi_group, leg.bank)?;
+    }
 
     let marginfi_account =
         DeserializedAccount::<MarginfiAccount>::zerocopy(Some(marginfi_account))?.unwrap();
@@ -36,9 +41,23 @@ pub fn marginfi_refresh_accounts<'a, 'b>(
         supply_price_oracle,
         debt_bank,
         debt_price_oracle,
+        leg_accounts,
         price_type,
     )?;
 
     SolautoManager::refresh_position(&mut solauto_position.data, updated_state, Clock::get()?)?;
     ix_utils::update_data(solauto_position)
 }
+
+pub fn refresh_accounts<'a, 'b>(
+    lp_accounts: &dyn LendingProtocolAccounts<'a>,
+    solauto_position: &'b mut DeserializedAccount<SolautoPosition>,
+    price_type: PriceType,
+) -> ProgramResult {
+    lp_accounts.refresh_accounts()?;
+
+    let updated_state = lp_accounts.get_updated_state(price_type)?;
+
+    SolautoManager::refresh_position(&mut solauto_position.data, updated_state, Clock::get()?)?;
+    ix_utils::update_data(solauto_position)
+}
@@ -1,15 +1,26 @@
-use solana_program::{clock::Clock, entrypoint::ProgramResult, sysvar::Sysvar};
+use solana_program::{clock::Clock, entrypoint::ProgramResult, rent::Rent, sysvar::Sysvar};
 
 use crate::{
-    state::solauto_position::{SolautoPosition, SolautoSettingsParameters},
+    state::{
+        automation::DCASettings,
+        carry_guard::CarryGuard,
+        grid::Grid,
+        leverage_curve::LeverageCurve,
+        orders::{LimitOrder, LimitOrderInp, StopLossOrder, TakeProfitOrder, TrailingStopOrder},
+        solauto_position::{SolautoPosition, SolautoSettingsParameters},
+    },
     types::{
+        errors::SolautoError,
         instruction::{
-            accounts::{Context, UpdatePositionAccounts},
+            accounts::{
+                CancelDCAAccounts, CancelLimitOrderAccounts, Context, PlaceLimitOrderAccounts,
+                UpdatePositionAccounts, UpgradePositionAccounts,
+            },
             UpdatePositionData,
         },
-        shared::DeserializedAccount,
+        shared::{DeserializedAccount, SplTokenTransferArgs},
     },
-    utils::{ix_utils, validation_utils},
+    utils::{ix_utils, solana_utils, validation_utils},
 };
 
 pub fn update_position<'a>(
@@ -17,12 +28,197 @@ pub fn update_position<'a>(
     mut solauto_position: DeserializedAccount<'a, SolautoPosition>,
     new_data: UpdatePositionData,
 ) -> ProgramResult {
-    if new_data.settings.is_some() {
-        solauto_position.data.position.settings =
-            SolautoSettingsParameters::from(new_data.settings.unwrap());
+    if let Some(settings) = new_data.settings {
+        let settings = SolautoSettingsParameters::from(settings);
+        validation_utils::validate_settings_automation(
+            &settings,
+            Clock::get()?.unix_timestamp as u64,
+        )?;
+        solauto_position.data.position.settings = settings;
+    }
+
+    if let Some(rebalance_strategy) = new_data.rebalance_strategy {
+        solauto_position.data.position.rebalance_strategy = rebalance_strategy;
+    }
+
+    if let Some(max_price_divergence_bps) = new_data.max_price_divergence_bps {
+        solauto_position.data.position.max_price_divergence_bps = max_price_divergence_bps;
+    }
+
+    if let Some(leverage_curve) = &new_data.leverage_curve {
+        solauto_position.data.leverage_curve =
+            LeverageCurve::from(leverage_curve).ok_or(SolautoError::InvalidLeverageCurve)?;
+    }
+
+    if let Some(grid) = new_data.grid {
+        solauto_position.data.grid = Grid::from(
+            grid,
+            solauto_position.data.state.liq_utilization_rate_bps,
+            solauto_position.data.price_ratio(),
+        );
+    }
+
+    if let Some(carry_guard) = new_data.carry_guard {
+        let carry_guard = CarryGuard::from(carry_guard);
+        validation_utils::validate_carry_guard(&solauto_position.data, &carry_guard)?;
+        solauto_position.data.carry_guard = carry_guard;
+    }
+
+    if let Some(dca) = new_data.dca {
+        let dca = DCASettings::from(dca);
+        validation_utils::validate_dca_settings(
+            &solauto_position.data,
+            &dca,
+            Clock::get()?.unix_timestamp as u64,
+        )?;
+
+        if dca.dca_in() {
+            solana_utils::spl_token_transfer(
+                ctx.accounts.token_program,
+                SplTokenTransferArgs {
+                    source: ctx.accounts.signer_dca_ta.unwrap(),
+                    authority: ctx.accounts.signer,
+                    recipient: ctx.accounts.position_dca_ta.unwrap(),
+                    amount: dca.dca_in_base_unit,
+                    authority_seeds: None,
+                },
+            )?;
+        }
+
+        solauto_position.data.position.dca = dca;
+    }
+
+    if let Some(take_profit) = new_data.take_profit {
+        let take_profit = TakeProfitOrder::from(take_profit);
+        validation_utils::validate_take_profit_order(&solauto_position.data, &take_profit)?;
+        solauto_position.data.take_profit = take_profit;
+    }
+
+    if let Some(stop_loss) = new_data.stop_loss {
+        let stop_loss = StopLossOrder::from(stop_loss);
+        validation_utils::validate_stop_loss_order(&solauto_position.data, &stop_loss)?;
+        solauto_position.data.stop_loss = stop_loss;
+    }
+
+    if let Some(trailing_stop) = new_data.trailing_stop {
+        let trailing_stop = TrailingStopOrder::from(trailing_stop);
+        validation_utils::validate_trailing_stop_order(&solauto_position.data, &trailing_stop)?;
+        solauto_position.data.trailing_stop = trailing_stop;
+        solauto_position
+            .data
+            .state
+            .reset_supply_price_high_water_mark();
     }
 
     validation_utils::validate_position_settings(&solauto_position.data)?;
 
     ix_utils::update_data(&mut solauto_position)
 }
+
+pub fn cancel_dca<'a>(
+    ctx: Context<CancelDCAAccounts<'a>>,
+    mut solauto_position: DeserializedAccount<'a, SolautoPosition>,
+) -> ProgramResult {
+    let dca = solauto_position.data.position.dca;
+
+    if dca.dca_in() {
+        solana_utils::init_ata_if_needed(
+            ctx.accounts.token_program,
+            ctx.accounts.system_program,
+            ctx.accounts.signer,
+            ctx.accounts.signer,
+            ctx.accounts.signer_dca_ta.unwrap(),
+            ctx.accounts.dca_mint.unwrap(),
+        )?;
+
+        solana_utils::spl_token_transfer(
+            ctx.accounts.token_program,
+            SplTokenTransferArgs {
+                source: ctx.accounts.position_dca_ta.unwrap(),
+                authority: solauto_position.account_info,
+                recipient: ctx.accounts.signer_dca_ta.unwrap(),
+                amount: dca.dca_in_base_unit,
+                authority_seeds: Some(&solauto_position.data.seeds_with_bump()),
+            },
+        )?;
+    }
+
+    solauto_position.data.position.dca = DCASettings::default();
+
+    ix_utils::update_data(&mut solauto_position)
+}
+
+pub fn place_limit_order<'a>(
+    ctx: Context<PlaceLimitOrderAccounts<'a>>,
+    mut solauto_position: DeserializedAccount<'a, SolautoPosition>,
+    args: LimitOrderInp,
+) -> ProgramResult {
+    let limit_order = LimitOrder::from(args);
+    validation_utils::validate_limit_order(&solauto_position.data, &limit_order)?;
+
+    solana_utils::spl_token_transfer(
+        ctx.accounts.token_program,
+        SplTokenTransferArgs {
+            source: ctx.accounts.signer_order_ta,
+            authority: ctx.accounts.signer,
+            recipient: ctx.accounts.position_order_ta,
+            amount: limit_order.amount_base_unit,
+            authority_seeds: None,
+        },
+    )?;
+
+    solauto_position.data.limit_order = limit_order;
+
+    ix_utils::update_data(&mut solauto_position)
+}
+
+pub fn cancel_limit_order<'a>(
+    ctx: Context<CancelLimitOrderAccounts<'a>>,
+    mut solauto_position: DeserializedAccount<'a, SolautoPosition>,
+) -> ProgramResult {
+    solana_utils::init_ata_if_needed(
+        ctx.accounts.token_program,
+        ctx.accounts.system_program,
+        ctx.accounts.signer,
+        ctx.accounts.signer,
+        ctx.accounts.signer_order_ta,
+        ctx.accounts.order_mint,
+    )?;
+
+    solana_utils::spl_token_transfer(
+        ctx.accounts.token_program,
+        SplTokenTransferArgs {
+            source: ctx.accounts.position_order_ta,
+            authority: solauto_position.account_info,
+            recipient: ctx.accounts.signer_order_ta,
+            amount: solauto_position.data.limit_order.amount_base_unit,
+            authority_seeds: Some(&solauto_position.data.seeds_with_bump()),
+        },
+    )?;
+
+    solauto_position.data.limit_order = LimitOrder::default();
+
+    ix_utils::update_data(&mut solauto_position)
+}
+
+pub fn upgrade_position<'a>(
+    ctx: Context<UpgradePositionAccounts<'a>>,
+    mut solauto_position: DeserializedAccount<'a, SolautoPosition>,
+) -> ProgramResult {
+    let rent_exempt_lamports = Rent::get()?.minimum_balance(SolautoPosition::LEN);
+    let lamports = solauto_position.account_info.lamports();
+    if rent_exempt_lamports > lamports {
+        solana_utils::system_transfer(
+            ctx.accounts.signer,
+            solauto_position.account_info,
+            rent_exempt_lamports - lamports,
+            None,
+        )?;
+    }
+
+    solauto_position
+        .account_info
+        .realloc(SolautoPosition::LEN, true)?;
+
+    ix_utils::update_data(&mut solauto_position)
+}
@@ -1,11 +1,13 @@
 pub mod clients;
 pub mod constants;
 pub mod entrypoint;
+pub mod flash_loan;
 pub mod instructions;
 pub mod macros;
 pub mod processors;
 pub mod rebalance;
 pub mod state;
+pub mod swap;
 pub mod types;
 pub mod utils;
 
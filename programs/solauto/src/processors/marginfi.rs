@@ -1,21 +1,28 @@
+use marginfi_sdk::generated::accounts::MarginfiAccount;
 use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
 
 use crate::{
     check,
-    clients::marginfi::MarginfiClient,
-    instructions::{open_position, protocol_interaction, rebalance, refresh},
-    rebalance::utils::set_rebalance_ixs_data,
+    clients::marginfi::{MarginfiClient, MarginfiLegAccounts},
+    error_if,
+    instructions::{
+        account_transfer, harvest_emissions, open_position, protocol_interaction, rebalance,
+        refresh,
+    },
+    rebalance::utils::{select_rebalance_legs, set_rebalance_ixs_data},
     state::{referral_state::ReferralState, solauto_position::SolautoPosition},
     types::{
         errors::SolautoError,
         instruction::{
             accounts::{
-                MarginfiOpenPositionAccounts, MarginfiProtocolInteractionAccounts,
-                MarginfiRebalanceAccounts, MarginfiRefreshDataAccounts,
+                MarginfiExportAccountAccounts, MarginfiHarvestEmissionsAccounts,
+                MarginfiImportAccountAccounts, MarginfiOpenPositionAccounts,
+                MarginfiProtocolInteractionAccounts, MarginfiRebalanceAccounts,
+                MarginfiRefreshDataAccounts,
             },
             MarginfiOpenPositionData, RebalanceSettings, SolautoAction, SolautoStandardAccounts,
         },
-        shared::{DeserializedAccount, LendingPlatform, PriceType},
+        shared::{DeserializedAccount, LendingPlatform, PriceType, TokenType},
     },
     utils::*,
 };
@@ -137,6 +144,7 @@ pub fn process_marginfi_refresh_data<'a>(
         ctx.accounts.supply_price_oracle,
         ctx.accounts.debt_bank,
         ctx.accounts.debt_price_oracle,
+        &MarginfiLegAccounts::parse(ctx.remaining_accounts)?,
         &mut solauto_position,
         price_type,
     )
@@ -214,7 +222,217 @@ pub fn process_marginfi_rebalance<'a>(
         SolautoError::IncorrectAccounts
     );
 
-    let rebalance_step = set_rebalance_ixs_data(&mut std_accounts, &args)?;
+    select_rebalance_legs(&mut std_accounts.solauto_position.data, &args);
+    let rebalance_step = set_rebalance_ixs_data(&mut std_accounts, &args, accounts)?;
 
     rebalance::marginfi_rebalance(ctx, std_accounts, rebalance_step, args)
 }
+
+pub fn process_marginfi_harvest_emissions<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    msg!("Instruction: Marginfi harvest emissions");
+    let ctx = MarginfiHarvestEmissionsAccounts::context(accounts)?;
+    let solauto_position =
+        DeserializedAccount::<SolautoPosition>::zerocopy(Some(ctx.accounts.solauto_position))?
+            .unwrap();
+
+    let std_accounts = Box::new(SolautoStandardAccounts {
+        signer: ctx.accounts.signer,
+        lending_protocol: ctx.accounts.marginfi_program,
+        system_program: ctx.accounts.system_program,
+        token_program: ctx.accounts.token_program,
+        ata_program: None,
+        rent: None,
+        ixs_sysvar: Some(ctx.accounts.ixs_sysvar),
+        solauto_position,
+        solauto_fees_ta: None,
+        authority_referral_state: None,
+        referred_by_ta: None,
+    });
+    validation_utils::generic_instruction_validation(
+        &std_accounts,
+        LendingPlatform::Marginfi,
+        false,
+        true,
+    )?;
+    validation_utils::validate_lending_program_accounts_with_position(
+        LendingPlatform::Marginfi,
+        &std_accounts.solauto_position,
+        ctx.accounts.marginfi_account,
+        ctx.accounts.supply_bank,
+        ctx.accounts.debt_bank,
+    )?;
+    validation_utils::validate_token_account(
+        &std_accounts.solauto_position,
+        Some(ctx.accounts.position_supply_ta),
+        Some(TokenType::Supply),
+        None,
+    )?;
+
+    let position = &std_accounts.solauto_position.data;
+    let emissions_bank = ctx.accounts.emissions_bank.key;
+    check!(
+        emissions_bank == &position.position.lp_supply_account
+            || emissions_bank == &position.position.lp_debt_account
+            || position
+                .state
+                .additional_supply
+                .iter()
+                .chain(position.state.additional_debt.iter())
+                .any(|leg| leg.active() && &leg.lp_account == emissions_bank),
+        SolautoError::IncorrectAccounts
+    );
+    check!(
+        validation_utils::correct_token_account(
+            ctx.accounts.position_emissions_ta.key,
+            ctx.accounts.solauto_position.key,
+            ctx.accounts.emissions_mint.key,
+        ),
+        SolautoError::IncorrectAccounts
+    );
+
+    if position.harvest.active.val {
+        ix_utils::validate_harvest_emissions_instructions(ctx.accounts.ixs_sysvar, false)?;
+    } else if ctx.accounts.emissions_mint.key != &position.state.supply.mint {
+        ix_utils::validate_harvest_emissions_instructions(ctx.accounts.ixs_sysvar, true)?;
+    }
+
+    harvest_emissions::marginfi_harvest_emissions(ctx, std_accounts)
+}
+
+pub fn process_marginfi_import_account<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: MarginfiOpenPositionData,
+) -> ProgramResult {
+    msg!("Instruction: Marginfi import account");
+    let ctx = MarginfiImportAccountAccounts::context(accounts)?;
+
+    // Self-managed positions don't take authority over the Marginfi account
+    error_if!(
+        args.position_data.position_id == 0,
+        SolautoError::IncorrectAccounts
+    );
+
+    MarginfiClient::validate_imported_account(
+        &DeserializedAccount::<MarginfiAccount>::zerocopy(Some(ctx.accounts.marginfi_account))?
+            .unwrap(),
+        ctx.accounts.signer.key,
+        ctx.accounts.marginfi_group.key,
+        ctx.accounts.supply_bank.key,
+        ctx.accounts.debt_bank.key,
+    )?;
+
+    let (max_ltv, liq_threshold) = if cfg!(feature = "local") {
+        (0.65, 0.8)
+    } else {
+        MarginfiClient::get_max_ltv_and_liq_threshold(
+            ctx.accounts.supply_bank,
+            ctx.accounts.debt_bank,
+        )?
+    };
+
+    let solauto_position = solauto_utils::create_new_solauto_position(
+        ctx.accounts.signer,
+        ctx.accounts.solauto_position,
+        args.position_type,
+        args.position_data,
+        LendingPlatform::Marginfi,
+        ctx.accounts.supply_mint,
+        ctx.accounts.supply_bank,
+        ctx.accounts.debt_mint,
+        ctx.accounts.debt_bank,
+        ctx.accounts.marginfi_account,
+        ctx.accounts.marginfi_group,
+        max_ltv,
+        liq_threshold,
+    )?;
+    validation_utils::validate_position_settings(&solauto_position.data)?;
+
+    if let Some(referred_by_supply_ta) = ctx.accounts.referred_by_supply_ta {
+        solana_utils::init_ata_if_needed(
+            ctx.accounts.token_program,
+            ctx.accounts.system_program,
+            ctx.accounts.signer,
+            ctx.accounts.referred_by_state.unwrap(),
+            referred_by_supply_ta,
+            ctx.accounts.supply_mint,
+        )?;
+    }
+
+    if !cfg!(feature = "local") {
+        validation_utils::validate_lending_program_accounts_with_position(
+            LendingPlatform::Marginfi,
+            &solauto_position,
+            ctx.accounts.marginfi_account,
+            ctx.accounts.supply_bank,
+            ctx.accounts.debt_bank,
+        )?;
+    }
+
+    let std_accounts = Box::new(SolautoStandardAccounts {
+        signer: ctx.accounts.signer,
+        lending_protocol: ctx.accounts.marginfi_program,
+        system_program: ctx.accounts.system_program,
+        token_program: ctx.accounts.token_program,
+        ata_program: Some(ctx.accounts.ata_program),
+        rent: Some(ctx.accounts.rent),
+        ixs_sysvar: None,
+        solauto_position,
+        solauto_fees_ta: None,
+        authority_referral_state: DeserializedAccount::<ReferralState>::zerocopy(Some(
+            ctx.accounts.signer_referral_state,
+        ))?,
+        referred_by_ta: ctx.accounts.referred_by_supply_ta,
+    });
+    validation_utils::generic_instruction_validation(
+        &std_accounts,
+        LendingPlatform::Marginfi,
+        true,
+        true,
+    )?;
+
+    account_transfer::marginfi_import_account(ctx, std_accounts.solauto_position)
+}
+
+pub fn process_marginfi_export_account<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    msg!("Instruction: Marginfi export account");
+    let ctx = MarginfiExportAccountAccounts::context(accounts)?;
+    let solauto_position =
+        DeserializedAccount::<SolautoPosition>::zerocopy(Some(ctx.accounts.solauto_position))?
+            .unwrap();
+
+    validation_utils::validate_instruction(ctx.accounts.signer, &solauto_position, true, true)?;
+    validation_utils::validate_lending_program_account(
+        ctx.accounts.marginfi_program,
+        LendingPlatform::Marginfi,
+    )?;
+    validation_utils::validate_standard_programs(
+        Some(ctx.accounts.system_program),
+        Some(ctx.accounts.token_program),
+        Some(ctx.accounts.ata_program),
+        None,
+        None,
+    )?;
+
+    check!(
+        solauto_position.data.position.lending_platform == LendingPlatform::Marginfi
+            && ctx.accounts.marginfi_account.key == &solauto_position.data.position.lp_user_account,
+        SolautoError::IncorrectAccounts
+    );
+    error_if!(
+        solauto_position.data.rebalance.active(),
+        SolautoError::IncorrectInstructions
+    );
+
+    validation_utils::validate_token_accounts(
+        &solauto_position,
+        Some(ctx.accounts.position_supply_ta),
+        Some(ctx.accounts.position_debt_ta),
+    )?;
+    validation_utils::validate_token_accounts(
+        &solauto_position,
+        Some(ctx.accounts.signer_supply_ta),
+        Some(ctx.accounts.signer_debt_ta),
+    )?;
+
+    account_transfer::marginfi_export_account(ctx, solauto_position)
+}
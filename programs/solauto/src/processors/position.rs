@@ -1,18 +1,427 @@
 use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
 
 use crate::{
-    instructions::{close_position, update_position},
-    state::solauto_position::SolautoPosition,
+    check,
+    clients::parse_lending_protocol_accounts,
+    error_if,
+    instructions::{
+        close_position, migrate_position, open_position, protocol_interaction, rebalance, refresh,
+        update_position,
+    },
+    rebalance::utils::{select_rebalance_legs, set_rebalance_ixs_data},
+    state::{
+        orders::LimitOrderInp,
+        referral_state::ReferralState,
+        solauto_position::{LegacySolautoPosition, SolautoPosition},
+    },
+    swap::SwapCpi,
     types::{
+        errors::SolautoError,
         instruction::{
-            accounts::{ClosePositionAccounts, UpdatePositionAccounts},
-            UpdatePositionData,
+            accounts::{
+                CancelDCAAccounts, CancelLimitOrderAccounts, ClosePositionAccounts,
+                MigratePositionAccounts, OpenPositionAccounts, PlaceLimitOrderAccounts,
+                ProtocolInteractionAccounts, RebalanceAccounts, RefreshDataAccounts,
+                UpdatePositionAccounts, UpgradePositionAccounts,
+            },
+            MigratePositionData, OpenPositionData, RebalanceSettings, SolautoAction,
+            SolautoStandardAccounts, UpdatePositionData,
         },
-        shared::DeserializedAccount,
+        shared::{DeserializedAccount, LendingPlatform, PriceType},
     },
-    utils::validation_utils,
+    utils::*,
 };
 
+pub fn process_open_position_instruction<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: OpenPositionData,
+) -> ProgramResult {
+    msg!("Instruction: Open position");
+    let ctx = OpenPositionAccounts::context(accounts)?;
+    let lp_accounts = parse_lending_protocol_accounts(
+        args.lending_platform,
+        ctx.accounts.lending_program,
+        ctx.remaining_accounts,
+    )?;
+
+    let (max_ltv, liq_threshold) = if cfg!(feature = "local") {
+        (0.65, 0.8)
+    } else {
+        lp_accounts.get_max_ltv_and_liq_threshold()?
+    };
+
+    let solauto_position = solauto_utils::create_new_solauto_position(
+        ctx.accounts.signer,
+        ctx.accounts.solauto_position,
+        args.position_type,
+        args.position_data,
+        args.lending_platform,
+        ctx.accounts.supply_mint,
+        lp_accounts.lp_supply_account(),
+        ctx.accounts.debt_mint,
+        lp_accounts.lp_debt_account(),
+        lp_accounts.lp_user_account(),
+        lp_accounts.lp_pool_account(),
+        max_ltv,
+        liq_threshold,
+    )?;
+    if !solauto_position.data.self_managed.val {
+        validation_utils::validate_position_settings(&solauto_position.data)?;
+    }
+
+    if let Some(referred_by_supply_ta) = ctx.accounts.referred_by_supply_ta {
+        solana_utils::init_ata_if_needed(
+            ctx.accounts.token_program,
+            ctx.accounts.system_program,
+            ctx.accounts.signer,
+            ctx.accounts.referred_by_state.unwrap(),
+            referred_by_supply_ta,
+            ctx.accounts.supply_mint,
+        )?;
+    }
+
+    if !cfg!(feature = "local") {
+        validation_utils::validate_lending_program_accounts_with_position(
+            args.lending_platform,
+            &solauto_position,
+            lp_accounts.lp_user_account(),
+            lp_accounts.lp_supply_account(),
+            lp_accounts.lp_debt_account(),
+        )?;
+    }
+
+    let std_accounts = Box::new(SolautoStandardAccounts {
+        signer: ctx.accounts.signer,
+        lending_protocol: ctx.accounts.lending_program,
+        system_program: ctx.accounts.system_program,
+        token_program: ctx.accounts.token_program,
+        ata_program: Some(ctx.accounts.ata_program),
+        rent: Some(ctx.accounts.rent),
+        ixs_sysvar: None,
+        solauto_position,
+        solauto_fees_ta: None,
+        authority_referral_state: DeserializedAccount::<ReferralState>::zerocopy(Some(
+            ctx.accounts.signer_referral_state,
+        ))?,
+        referred_by_ta: ctx.accounts.referred_by_supply_ta,
+    });
+    validation_utils::generic_instruction_validation(
+        &std_accounts,
+        args.lending_platform,
+        true,
+        false,
+    )?;
+
+    open_position::open_position(ctx, std_accounts, lp_accounts)
+}
+
+pub fn process_refresh_data<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    price_type: PriceType,
+) -> ProgramResult {
+    msg!("Instruction: Refresh data");
+    let ctx = RefreshDataAccounts::context(accounts)?;
+    let mut solauto_position =
+        DeserializedAccount::<SolautoPosition>::zerocopy(Some(ctx.accounts.solauto_position))?
+            .unwrap();
+    let lending_platform = solauto_position.data.position.lending_platform;
+    let lp_accounts = parse_lending_protocol_accounts(
+        lending_platform,
+        ctx.accounts.lending_program,
+        ctx.remaining_accounts,
+    )?;
+
+    validation_utils::validate_instruction(ctx.accounts.signer, &solauto_position, false, false)?;
+
+    if !solauto_position.data.self_managed.val {
+        validation_utils::validate_lending_program_accounts_with_position(
+            lending_platform,
+            &solauto_position,
+            lp_accounts.lp_user_account(),
+            lp_accounts.lp_supply_account(),
+            lp_accounts.lp_debt_account(),
+        )?;
+    }
+
+    validation_utils::validate_lending_program_account(
+        ctx.accounts.lending_program,
+        lending_platform,
+    )?;
+
+    refresh::refresh_accounts(lp_accounts.as_ref(), &mut solauto_position, price_type)
+}
+
+pub fn process_interaction_instruction<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    action: SolautoAction,
+) -> ProgramResult {
+    msg!("Instruction: Protocol interaction");
+    let ctx = ProtocolInteractionAccounts::context(accounts)?;
+    let solauto_position =
+        DeserializedAccount::<SolautoPosition>::zerocopy(Some(ctx.accounts.solauto_position))?
+            .unwrap();
+    let lending_platform = solauto_position.data.position.lending_platform;
+    let lp_accounts = parse_lending_protocol_accounts(
+        lending_platform,
+        ctx.accounts.lending_program,
+        ctx.remaining_accounts,
+    )?;
+
+    let std_accounts = Box::new(SolautoStandardAccounts {
+        signer: ctx.accounts.signer,
+        lending_protocol: ctx.accounts.lending_program,
+        system_program: ctx.accounts.system_program,
+        token_program: ctx.accounts.token_program,
+        ata_program: Some(ctx.accounts.ata_program),
+        rent: Some(ctx.accounts.rent),
+        ixs_sysvar: Some(ctx.accounts.ixs_sysvar),
+        solauto_position,
+        solauto_fees_ta: None,
+        authority_referral_state: None,
+        referred_by_ta: None,
+    });
+    validation_utils::generic_instruction_validation(&std_accounts, lending_platform, true, false)?;
+
+    if !std_accounts.solauto_position.data.self_managed.val {
+        validation_utils::validate_lending_program_accounts_with_position(
+            lending_platform,
+            &std_accounts.solauto_position,
+            lp_accounts.lp_user_account(),
+            lp_accounts.lp_supply_account(),
+            lp_accounts.lp_debt_account(),
+        )?;
+    }
+
+    // Kamino only accepts a refresh at the top level of the transaction, the CPI refresh doesn't satisfy it
+    if lending_platform == LendingPlatform::Kamino {
+        ix_utils::validate_kamino_refresh_instructions(
+            ctx.accounts.ixs_sysvar,
+            lp_accounts.lp_user_account().key,
+        )?;
+    }
+
+    protocol_interaction::interaction(ctx, std_accounts, lp_accounts, action)
+}
+
+pub fn process_rebalance<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: RebalanceSettings,
+) -> ProgramResult {
+    msg!("Instruction: Rebalance");
+    let ctx = RebalanceAccounts::context(accounts)?;
+    let solauto_position =
+        DeserializedAccount::<SolautoPosition>::zerocopy(Some(ctx.accounts.solauto_position))?
+            .unwrap();
+    let lending_platform = solauto_position.data.position.lending_platform;
+
+    let swap_accounts_len = args
+        .swap_cpi
+        .as_ref()
+        .map_or(0, |swap_cpi| swap_cpi.accounts_len as usize);
+    check!(
+        swap_accounts_len <= ctx.remaining_accounts.len(),
+        SolautoError::IncorrectAccounts
+    );
+    let (lp_remaining_accounts, swap_accounts) = ctx
+        .remaining_accounts
+        .split_at(ctx.remaining_accounts.len() - swap_accounts_len);
+    let swap_cpi = if let Some(swap_cpi) = &args.swap_cpi {
+        Some(SwapCpi::from(swap_accounts, swap_cpi.data.clone())?)
+    } else {
+        None
+    };
+
+    let lp_accounts = parse_lending_protocol_accounts(
+        lending_platform,
+        ctx.accounts.lending_program,
+        lp_remaining_accounts,
+    )?;
+
+    let mut std_accounts = Box::new(SolautoStandardAccounts {
+        signer: ctx.accounts.signer,
+        lending_protocol: ctx.accounts.lending_program,
+        system_program: ctx.accounts.system_program,
+        token_program: ctx.accounts.token_program,
+        ata_program: None,
+        rent: None,
+        ixs_sysvar: Some(ctx.accounts.ixs_sysvar),
+        solauto_position,
+        solauto_fees_ta: ctx.accounts.solauto_fees_ta,
+        authority_referral_state: DeserializedAccount::<ReferralState>::zerocopy(Some(
+            ctx.accounts.authority_referral_state,
+        ))?,
+        referred_by_ta: ctx.accounts.referred_by_ta,
+    });
+    validation_utils::generic_instruction_validation(
+        &std_accounts,
+        lending_platform,
+        false,
+        false,
+    )?;
+
+    check!(
+        ctx.accounts.position_authority.is_none()
+            || &std_accounts.solauto_position.data.authority
+                == ctx.accounts.position_authority.unwrap().key,
+        SolautoError::IncorrectAccounts
+    );
+
+    // The selected legs decide which lending accounts the rebalance must be given
+    select_rebalance_legs(&mut std_accounts.solauto_position.data, &args);
+
+    if !std_accounts.solauto_position.data.self_managed.val {
+        validation_utils::validate_lending_program_accounts_with_position(
+            lending_platform,
+            &std_accounts.solauto_position,
+            lp_accounts.lp_user_account(),
+            lp_accounts.lp_supply_account(),
+            lp_accounts.lp_debt_account(),
+        )?;
+    }
+
+    // Kamino only accepts a refresh at the top level of the transaction, the CPI refresh doesn't satisfy it
+    if lending_platform == LendingPlatform::Kamino {
+        ix_utils::validate_kamino_refresh_instructions(
+            ctx.accounts.ixs_sysvar,
+            lp_accounts.lp_user_account().key,
+        )?;
+    }
+
+    let rebalance_step = set_rebalance_ixs_data(&mut std_accounts, &args, accounts)?;
+
+    rebalance::rebalance_position(
+        ctx,
+        std_accounts,
+        lp_accounts,
+        rebalance_step,
+        args,
+        swap_cpi,
+    )
+}
+
+pub fn process_migrate_position<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: MigratePositionData,
+) -> ProgramResult {
+    msg!("Instruction: Migrate position");
+    let ctx = MigratePositionAccounts::context(accounts)?;
+    let solauto_position =
+        DeserializedAccount::<SolautoPosition>::zerocopy(Some(ctx.accounts.solauto_position))?
+            .unwrap();
+    let lending_platform = solauto_position.data.position.lending_platform;
+
+    let source_accounts_len = args.source_accounts_len as usize;
+    check!(
+        source_accounts_len <= ctx.remaining_accounts.len(),
+        SolautoError::IncorrectAccounts
+    );
+    let (source_accounts, destination_accounts) =
+        ctx.remaining_accounts.split_at(source_accounts_len);
+    let source_lp_accounts = parse_lending_protocol_accounts(
+        lending_platform,
+        ctx.accounts.source_lending_program,
+        source_accounts,
+    )?;
+    let destination_lp_accounts = parse_lending_protocol_accounts(
+        args.lending_platform,
+        ctx.accounts.destination_lending_program,
+        destination_accounts,
+    )?;
+
+    let std_accounts = Box::new(SolautoStandardAccounts {
+        signer: ctx.accounts.signer,
+        lending_protocol: ctx.accounts.source_lending_program,
+        system_program: ctx.accounts.system_program,
+        token_program: ctx.accounts.token_program,
+        ata_program: Some(ctx.accounts.ata_program),
+        rent: Some(ctx.accounts.rent),
+        ixs_sysvar: Some(ctx.accounts.ixs_sysvar),
+        solauto_position,
+        solauto_fees_ta: None,
+        authority_referral_state: None,
+        referred_by_ta: None,
+    });
+    validation_utils::generic_instruction_validation(&std_accounts, lending_platform, true, true)?;
+    validation_utils::validate_lending_program_account(
+        ctx.accounts.destination_lending_program,
+        args.lending_platform,
+    )?;
+    validation_utils::validate_lending_program_accounts_with_position(
+        lending_platform,
+        &std_accounts.solauto_position,
+        source_lp_accounts.lp_user_account(),
+        source_lp_accounts.lp_supply_account(),
+        source_lp_accounts.lp_debt_account(),
+    )?;
+    validation_utils::validate_token_accounts(
+        &std_accounts.solauto_position,
+        Some(ctx.accounts.position_supply_ta),
+        Some(ctx.accounts.position_debt_ta),
+    )?;
+
+    let position = &std_accounts.solauto_position.data;
+    check!(
+        ctx.accounts.supply_mint.key == &position.state.supply.mint
+            && ctx.accounts.debt_mint.key == &position.state.debt.mint,
+        SolautoError::IncorrectAccounts
+    );
+    error_if!(
+        position.state.has_additional_legs(),
+        SolautoError::TooManyPositionLegs
+    );
+    error_if!(
+        position.rebalance.active(),
+        SolautoError::IncorrectInstructions
+    );
+    // DCA & limit order funds are sized against the current lending platform's state, and only move with the position
+    error_if!(
+        position.position.dca.is_active(),
+        SolautoError::InvalidDCASettings
+    );
+    error_if!(
+        position.limit_order.is_active(),
+        SolautoError::InvalidLimitOrder
+    );
+
+    if !cfg!(feature = "local") {
+        validation_utils::validate_migration_destination_accounts(
+            args.lending_platform,
+            ctx.accounts.destination_lending_program,
+            &std_accounts.solauto_position,
+            ctx.accounts.signer,
+            destination_lp_accounts.as_ref(),
+        )?;
+    }
+
+    // Kamino only accepts a refresh at the top level of the transaction, the CPI refresh doesn't satisfy it.
+    // An obligation the migration creates has nothing to refresh yet
+    if lending_platform == LendingPlatform::Kamino {
+        ix_utils::validate_kamino_refresh_instructions(
+            ctx.accounts.ixs_sysvar,
+            source_lp_accounts.lp_user_account().key,
+        )?;
+    }
+    if args.lending_platform == LendingPlatform::Kamino
+        && solana_utils::account_has_data(destination_lp_accounts.lp_user_account())
+    {
+        ix_utils::validate_kamino_refresh_instructions(
+            ctx.accounts.ixs_sysvar,
+            destination_lp_accounts.lp_user_account().key,
+        )?;
+    }
+
+    let flash_loan = ix_utils::get_migrate_position_flash_loan(ctx.accounts.ixs_sysvar, accounts)?;
+
+    migrate_position::migrate_position(
+        ctx,
+        std_accounts,
+        source_lp_accounts,
+        destination_lp_accounts,
+        args,
+        flash_loan,
+    )
+}
+
 pub fn process_update_position_instruction<'a>(
     accounts: &'a [AccountInfo<'a>],
     args: UpdatePositionData,
@@ -32,18 +441,84 @@ pub fn process_update_position_instruction<'a>(
         None,
     )?;
 
-    if args.dca.is_some() {
-        validation_utils::validate_token_account(
-            &solauto_position,
-            ctx.accounts.position_dca_ta,
-            Some(args.dca.as_ref().unwrap().token_type),
-            None,
-        )?;
+    if let Some(dca) = &args.dca {
+        if dca.dca_in_base_unit > 0 {
+            validation_utils::validate_escrow_token_accounts(
+                &solauto_position,
+                dca.token_type,
+                ctx.accounts.signer.key,
+                ctx.accounts.dca_mint,
+                ctx.accounts.position_dca_ta,
+                ctx.accounts.signer_dca_ta,
+            )?;
+        }
     }
 
     update_position::update_position(ctx, solauto_position, args)
 }
 
+pub fn process_place_limit_order<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: LimitOrderInp,
+) -> ProgramResult {
+    msg!("Instruction: Place limit order");
+    let ctx = PlaceLimitOrderAccounts::context(accounts)?;
+    let solauto_position =
+        DeserializedAccount::<SolautoPosition>::zerocopy(Some(ctx.accounts.solauto_position))?
+            .unwrap();
+
+    validation_utils::validate_instruction(ctx.accounts.signer, &solauto_position, true, true)?;
+    validation_utils::validate_standard_programs(
+        Some(ctx.accounts.system_program),
+        Some(ctx.accounts.token_program),
+        Some(ctx.accounts.ata_program),
+        None,
+        None,
+    )?;
+
+    validation_utils::validate_escrow_token_accounts(
+        &solauto_position,
+        args.token_type,
+        ctx.accounts.signer.key,
+        Some(ctx.accounts.order_mint),
+        Some(ctx.accounts.position_order_ta),
+        Some(ctx.accounts.signer_order_ta),
+    )?;
+
+    update_position::place_limit_order(ctx, solauto_position, args)
+}
+
+pub fn process_cancel_limit_order<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    msg!("Instruction: Cancel limit order");
+    let ctx = CancelLimitOrderAccounts::context(accounts)?;
+    let solauto_position =
+        DeserializedAccount::<SolautoPosition>::zerocopy(Some(ctx.accounts.solauto_position))?
+            .unwrap();
+
+    validation_utils::validate_instruction(ctx.accounts.signer, &solauto_position, true, true)?;
+    validation_utils::validate_standard_programs(
+        Some(ctx.accounts.system_program),
+        Some(ctx.accounts.token_program),
+        Some(ctx.accounts.ata_program),
+        None,
+        None,
+    )?;
+
+    let limit_order = &solauto_position.data.limit_order;
+    check!(limit_order.is_active(), SolautoError::InvalidLimitOrder);
+
+    validation_utils::validate_escrow_token_accounts(
+        &solauto_position,
+        limit_order.token_type,
+        ctx.accounts.signer.key,
+        Some(ctx.accounts.order_mint),
+        Some(ctx.accounts.position_order_ta),
+        Some(ctx.accounts.signer_order_ta),
+    )?;
+
+    update_position::cancel_limit_order(ctx, solauto_position)
+}
+
 pub fn process_close_position_instruction<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
     msg!("Instruction: Close position");
     let ctx = ClosePositionAccounts::context(accounts)?;
@@ -83,6 +558,68 @@ pub fn process_close_position_instruction<'a>(accounts: &'a [AccountInfo<'a>]) -
 }
 
 pub fn process_cancel_dca<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
-    // TODO
-    Ok(())
+    msg!("Instruction: Cancel DCA");
+    let ctx = CancelDCAAccounts::context(accounts)?;
+    let solauto_position =
+        DeserializedAccount::<SolautoPosition>::zerocopy(Some(ctx.accounts.solauto_position))?
+            .unwrap();
+
+    validation_utils::validate_instruction(ctx.accounts.signer, &solauto_position, true, true)?;
+    validation_utils::validate_standard_programs(
+        Some(ctx.accounts.system_program),
+        Some(ctx.accounts.token_program),
+        Some(ctx.accounts.ata_program),
+        None,
+        None,
+    )?;
+
+    let dca = &solauto_position.data.position.dca;
+    check!(dca.is_active(), SolautoError::InvalidDCASettings);
+
+    if dca.dca_in() {
+        validation_utils::validate_escrow_token_accounts(
+            &solauto_position,
+            dca.token_type,
+            ctx.accounts.signer.key,
+            ctx.accounts.dca_mint,
+            ctx.accounts.position_dca_ta,
+            ctx.accounts.signer_dca_ta,
+        )?;
+    }
+
+    update_position::cancel_dca(ctx, solauto_position)
+}
+
+pub fn process_upgrade_position<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    msg!("Instruction: Upgrade position");
+    let ctx = UpgradePositionAccounts::context(accounts)?;
+
+    check!(
+        ctx.accounts.solauto_position.owner == &crate::ID,
+        SolautoError::IncorrectAccounts
+    );
+    if ctx.accounts.solauto_position.data_len() != LegacySolautoPosition::LEN {
+        msg!("Solauto position is already on the current layout");
+        return Err(SolautoError::IncorrectAccounts.into());
+    }
+
+    let legacy_position = DeserializedAccount::<LegacySolautoPosition>::zerocopy(Some(
+        ctx.accounts.solauto_position,
+    ))?
+    .unwrap();
+    let solauto_position = DeserializedAccount {
+        account_info: ctx.accounts.solauto_position,
+        data: Box::new(SolautoPosition::from_legacy(&legacy_position.data)),
+    };
+
+    validation_utils::validate_instruction(ctx.accounts.signer, &solauto_position, false, false)?;
+    validation_utils::validate_standard_programs(
+        Some(ctx.accounts.system_program),
+        None,
+        None,
+        None,
+        None,
+    )?;
+
+    update_position::upgrade_position(ctx, solauto_position)
 }
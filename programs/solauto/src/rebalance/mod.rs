@@ -1,4 +1,5 @@
 pub mod rebalancer;
 pub mod rebalancer_tests;
 pub mod solauto_fees;
+pub mod strategies;
 pub mod utils;
@@ -6,29 +6,39 @@ use std::{
 use solana_program::{entrypoint::ProgramResult, program_error::ProgramError};
 
 use crate::{
-    check,
-    state::solauto_position::{
-        PositionTokenState, RebalanceData, SolautoPosition, TokenBalanceChangeType,
+    check, error_if,
+    state::{
+        orders::{LimitOrder, StopLossOrder, TakeProfitOrder, TrailingStopOrder},
+        solauto_position::{
+            PositionTokenState, RebalanceData, SolautoPosition, TokenBalanceChangeType,
+        },
     },
     types::{
         errors::SolautoError,
         instruction::RebalanceSettings,
         shared::{
             RebalanceDirection, RebalanceStep, SolautoRebalanceType, SwapType, TokenBalanceAmount,
+            TokenType,
         },
         solauto::{
             FromLendingPlatformAction, SolautoAccount, SolautoCpiAction,
             SolautoSplTokenTransferArgs,
         },
     },
-    utils::math_utils::{
-        calc_fee_amount, from_bps, from_rounded_usd_value, usd_value_to_base_unit,
+    utils::{
+        math_utils::{calc_fee_amount, from_bps, from_rounded_usd_value, usd_value_to_base_unit},
+        validation_utils,
     },
 };
 
 use super::{
     solauto_fees::SolautoFeesBps,
-    utils::{eligible_for_rebalance, get_rebalance_values},
+    strategies::get_rebalance_strategy,
+    utils::{
+        eligible_for_rebalance, full_unwind_triggered, get_deleverage_order_target_bps,
+        get_negative_carry_target_bps, get_rebalance_values, get_token_balance_change_source,
+        negative_carry, price_divergence_exceeded, TokenBalanceChangeSource,
+    },
 };
 
 pub struct TokenAccountData {
@@ -54,6 +64,7 @@ pub struct RebalancerData<'a> {
     pub solauto_position: SolautoPositionData<'a>,
     pub solauto_fees_bps: SolautoFeesBps,
     pub referred_by: bool,
+    pub curr_unix_timestamp: u64,
 }
 
 pub struct RebalanceResult {
@@ -101,17 +112,34 @@ impl<'a> Rebalancer<'a> {
         self.rebalance_data().values.rebalance_direction == RebalanceDirection::Boost
     }
 
+    fn fully_repaying(&self) -> bool {
+        !self.is_boost()
+            && (self.data.rebalance_args.target_liq_utilization_rate_bps == Some(0)
+                || self.rebalance_data().values.target_debt_usd == 0)
+    }
+
     fn set_rebalance_data(&mut self) -> ProgramResult {
         if self.rebalance_data().values_set() {
             return Ok(());
         }
 
+        if self
+            .data
+            .solauto_position
+            .data
+            .position
+            .settings
+            .update_from_automation(self.data.curr_unix_timestamp)
+        {
+            validation_utils::validate_position_settings(self.position_data())?;
+        }
+
         check!(
             self.data
                 .rebalance_args
                 .target_liq_utilization_rate_bps
                 .is_some()
-                || eligible_for_rebalance(self.position_data()),
+                || eligible_for_rebalance(self.position_data(), self.data.curr_unix_timestamp),
             SolautoError::InvalidRebalanceCondition
         );
 
@@ -119,11 +147,87 @@ impl<'a> Rebalancer<'a> {
             self.position_data(),
             &self.data.rebalance_args,
             &self.data.solauto_fees_bps,
+            self.data.curr_unix_timestamp,
         )?;
+        error_if!(
+            self.is_boost() && negative_carry(self.position_data()),
+            SolautoError::NegativeCarryBoost
+        );
+        error_if!(
+            self.is_boost() && price_divergence_exceeded(self.position_data()),
+            SolautoError::PriceDivergenceTooHigh
+        );
+
+        // Strategy state is only moved on when the rebalance heads to the strategy's own target
+        if self
+            .data
+            .rebalance_args
+            .target_liq_utilization_rate_bps
+            .is_none()
+            && !full_unwind_triggered(self.position_data())
+            && get_deleverage_order_target_bps(self.position_data()).is_none()
+            && get_negative_carry_target_bps(self.position_data()).is_none()
+        {
+            get_rebalance_strategy(self.position_data())
+                .record_rebalance(self.data.solauto_position.data);
+        }
+
+        let requires_token_balance_change = self
+            .rebalance_data()
+            .values
+            .token_balance_change
+            .requires_one();
+        // Take-profits and trailing stops are filled once, even when they only cut leverage without withdrawing
+        let deleverage_filled = requires_token_balance_change
+            || self
+                .data
+                .rebalance_args
+                .target_liq_utilization_rate_bps
+                .is_none();
+        match get_token_balance_change_source(self.position_data(), self.data.curr_unix_timestamp) {
+            Some(TokenBalanceChangeSource::TakeProfit) if deleverage_filled => {
+                self.data.solauto_position.data.take_profit = TakeProfitOrder::default();
+            }
+            Some(TokenBalanceChangeSource::TrailingStop) if deleverage_filled => {
+                self.data.solauto_position.data.trailing_stop = TrailingStopOrder::default();
+            }
+            Some(TokenBalanceChangeSource::StopLoss) if requires_token_balance_change => {
+                self.data.solauto_position.data.stop_loss = StopLossOrder::default();
+            }
+            Some(TokenBalanceChangeSource::LimitOrder) if requires_token_balance_change => {
+                self.data.solauto_position.data.limit_order = LimitOrder::default();
+            }
+            Some(TokenBalanceChangeSource::DCAIn | TokenBalanceChangeSource::DCAOut)
+                if requires_token_balance_change =>
+            {
+                self.complete_dca_period();
+            }
+            _ => {}
+        }
 
         Ok(())
     }
 
+    fn complete_dca_period(&mut self) {
+        let curr_unix_timestamp = self.data.curr_unix_timestamp;
+        let dca = &mut self.data.solauto_position.data.position.dca;
+        let dca_in_amount = dca.dca_in_period_amount(curr_unix_timestamp);
+        dca.complete_period(dca_in_amount, curr_unix_timestamp);
+    }
+
+    fn pre_swap_deposit_amount(&self) -> u64 {
+        let token_balance_change = self.rebalance_data().values.token_balance_change;
+        if token_balance_change.change_type != TokenBalanceChangeType::PreSwapDeposit {
+            return 0;
+        }
+
+        self.calc_additional_amount(
+            token_balance_change.amount_usd,
+            self.position_data().state.supply,
+            Some(self.position_supply_ta().balance),
+        )
+    }
+
     fn calc_additional_amount(
         &self,
         rounded_usd_value: u64,
@@ -156,10 +260,62 @@ impl<'a> Rebalancer<'a> {
             )
         };
 
-        let balance = ta.balance;
-        // Subtract current balances that are attributed to DCA / limit order in
+        // Subtract current balances that are escrowed for a DCA-in or a limit order
+        let dca = &self.position_data().position.dca;
+        let reserved_token_type = if self.is_boost() {
+            TokenType::Supply
+        } else {
+            TokenType::Debt
+        };
+        let reserved = if dca.dca_in() && dca.token_type == reserved_token_type {
+            dca.dca_in_base_unit
+        } else {
+            0
+        };
+
+        let reserved = reserved
+            + self
+                .position_data()
+                .limit_order
+                .reserved_amount(reserved_token_type);
+
+        (ta.balance.saturating_sub(reserved), account)
+    }
+
+    fn swap_output_ta_balance(&self) -> u64 {
+        if self.is_boost() {
+            self.position_supply_ta().balance
+        } else {
+            self.position_debt_ta().balance
+        }
+    }
+
+    /// Measures what the exact-in swap returned from the output token account's balance change around it
+    fn validate_swap_output(&self) -> ProgramResult {
+        let ixs = &self.rebalance_data().ixs;
+        let (swap_in_amount, swap_out_amount) =
+            if ixs.rebalance_type == SolautoRebalanceType::FLSwapThenRebalance {
+                // Before the swap the output token account only holds escrowed balances, which the dynamic balance leaves out
+                (
+                    self.data
+                        .rebalance_args
+                        .swap_in_amount_base_unit
+                        .unwrap_or(ixs.flash_loan_amount),
+                    self.get_dynamic_balance().0,
+                )
+            } else {
+                (
+                    ixs.swap_in_amount_base_unit,
+                    self.swap_output_ta_balance()
+                        .saturating_sub(ixs.pre_swap_output_balance),
+                )
+            };
 
-        (balance, account)
+        validation_utils::validate_swap_slippage(
+            self.position_data(),
+            swap_in_amount,
+            swap_out_amount,
+        )
     }
 
     fn transfer_to_authority_if_needed(&mut self, base_unit_amount: u64) {
@@ -209,18 +365,7 @@ impl<'a> Rebalancer<'a> {
             self.actions
                 .push(SolautoCpiAction::Deposit(base_unit_amount));
         } else {
-            let token_balance_amount = if self
-                .data
-                .rebalance_args
-                .target_liq_utilization_rate_bps
-                .is_some()
-                && self
-                    .data
-                    .rebalance_args
-                    .target_liq_utilization_rate_bps
-                    .unwrap()
-                    == 0
-            {
+            let token_balance_amount = if self.fully_repaying() {
                 TokenBalanceAmount::All
             } else {
                 TokenBalanceAmount::Some(min(
@@ -248,11 +393,7 @@ impl<'a> Rebalancer<'a> {
 
         let action = match token_balance_change.change_type {
             TokenBalanceChangeType::PreSwapDeposit => {
-                Some(SolautoCpiAction::Deposit(self.calc_additional_amount(
-                    token_balance_change.amount_usd,
-                    self.position_data().state.supply,
-                    Some(self.data.solauto_position.supply_ta.balance),
-                )))
+                Some(SolautoCpiAction::Deposit(self.pre_swap_deposit_amount()))
             }
             TokenBalanceChangeType::PostSwapDeposit => {
                 amount = self.calc_additional_amount(
@@ -269,21 +410,6 @@ impl<'a> Rebalancer<'a> {
                     },
                 ))
             }
-            TokenBalanceChangeType::PostRebalanceWithdrawDebtToken => {
-                amount = self.calc_additional_amount(
-                    token_balance_change.amount_usd,
-                    self.position_data().state.supply,
-                    Some(self.position_supply_ta().balance),
-                );
-                Some(SolautoCpiAction::SplTokenTransfer(
-                    SolautoSplTokenTransferArgs {
-                        amount,
-                        from_wallet: SolautoAccount::SolautoPosition,
-                        from_wallet_ta: SolautoAccount::SolautoPositionSupplyTa,
-                        to_wallet_ta: SolautoAccount::IntermediaryTa,
-                    },
-                ))
-            }
             _ => None,
         };
 
@@ -294,7 +420,7 @@ impl<'a> Rebalancer<'a> {
         amount
     }
 
-    fn get_additional_amount_after_swap(&mut self) -> u64 {
+    fn get_additional_amount_after_swap(&mut self, available_balance: u64) -> u64 {
         if !self
             .rebalance_data()
             .values
@@ -307,27 +433,13 @@ impl<'a> Rebalancer<'a> {
         let token_balance_change = self.rebalance_data().values.token_balance_change;
         let mut amount = 0;
 
+        // The supply token withdrawal is made once the rebalance has been finished, see withdraw_supply_to_authority_if_needed
         let action = match token_balance_change.change_type {
-            TokenBalanceChangeType::PostRebalanceWithdrawSupplyToken => {
-                amount = self.calc_additional_amount(
-                    token_balance_change.amount_usd,
-                    self.position_data().state.supply,
-                    None,
-                );
-                Some(SolautoCpiAction::SplTokenTransfer(
-                    SolautoSplTokenTransferArgs {
-                        amount,
-                        from_wallet: SolautoAccount::SolautoPosition,
-                        from_wallet_ta: SolautoAccount::SolautoPositionSupplyTa,
-                        to_wallet_ta: SolautoAccount::AuthoritySupplyTa, // TODO: what if this is native mint
-                    },
-                ))
-            }
             TokenBalanceChangeType::PostRebalanceWithdrawDebtToken => {
                 amount = self.calc_additional_amount(
                     token_balance_change.amount_usd,
                     self.position_data().state.debt,
-                    None,
+                    Some(available_balance),
                 );
                 Some(SolautoCpiAction::SplTokenTransfer(
                     SolautoSplTokenTransferArgs {
@@ -414,7 +526,7 @@ impl<'a> Rebalancer<'a> {
                 self.data.rebalance_args.swap_in_amount_base_unit.unwrap()
             } else {
                 check!(flash_loan_amount != 0, SolautoError::IncorrectInstructions);
-                let flash_loan_fee_bps = self.data.rebalance_args.flash_loan_fee_bps.unwrap_or(0);
+                let flash_loan_fee_bps = self.rebalance_data().ixs.flash_loan_fee_bps;
                 flash_loan_amount.add(
                     (flash_loan_amount as f64)
                         .mul(from_bps(flash_loan_fee_bps))
@@ -428,10 +540,36 @@ impl<'a> Rebalancer<'a> {
         Ok(())
     }
 
+    fn withdraw_supply_to_authority_if_needed(&mut self) {
+        let token_balance_change = self.rebalance_data().values.token_balance_change;
+        if token_balance_change.change_type
+            != TokenBalanceChangeType::PostRebalanceWithdrawSupplyToken
+        {
+            return;
+        }
+
+        let amount = if self.fully_repaying() {
+            TokenBalanceAmount::All
+        } else {
+            TokenBalanceAmount::Some(self.calc_additional_amount(
+                token_balance_change.amount_usd,
+                self.position_data().state.supply,
+                None,
+            ))
+        };
+        self.actions
+            .push(SolautoCpiAction::Withdraw(FromLendingPlatformAction {
+                amount,
+                to_wallet_ta: SolautoAccount::AuthoritySupplyTa, // TODO: what if this is native mint
+            }));
+    }
+
     fn finish_rebalance(&mut self, dynamic_balance: u64) -> ProgramResult {
         let amount_to_put_in_lp = self.payout_fees(dynamic_balance)?;
         self.put_liquidity_in_lp(amount_to_put_in_lp);
         self.repay_flash_loan_if_necessary()?;
+        // Taken out last so that the position is at its healthiest when the supply is withdrawn
+        self.withdraw_supply_to_authority_if_needed();
         Ok(())
     }
 
@@ -443,20 +581,39 @@ impl<'a> Rebalancer<'a> {
 
         if self.rebalance_data().ixs.swap_type == SwapType::ExactOut {
             let (dynamic_balance, _) = self.get_dynamic_balance();
-            self.finish_rebalance(dynamic_balance)?;
+            // The pre-swap deposit hasn't left the token account yet
+            self.finish_rebalance(dynamic_balance.saturating_sub(self.pre_swap_deposit_amount()))?;
             Ok(RebalanceResult { finished: true })
         } else {
             let amount_to_pull_from_lp = amount_to_swap - additional_amount_to_swap;
             self.pull_liquidity_from_lp(amount_to_pull_from_lp, SolautoAccount::IntermediaryTa);
+
+            // Leave out what this instruction moves out of the swap's output token account
+            let moved_out_of_output_ta = if self.is_boost() {
+                self.pre_swap_deposit_amount()
+            } else {
+                additional_amount_to_swap
+            };
+            let pre_swap_output_balance = self
+                .swap_output_ta_balance()
+                .saturating_sub(moved_out_of_output_ta);
+            let ixs = &mut self.data.solauto_position.data.rebalance.ixs;
+            ixs.swap_in_amount_base_unit = amount_to_swap;
+            ixs.pre_swap_output_balance = pre_swap_output_balance;
+
             Ok(RebalanceResult { finished: false })
         }
     }
 
     fn post_swap_rebalance(&mut self) -> Result<RebalanceResult, ProgramError> {
         self.set_rebalance_data()?;
+        if self.rebalance_data().ixs.swap_type == SwapType::ExactIn {
+            self.validate_swap_output()?;
+        }
 
-        let additional_amount_after_swap = self.get_additional_amount_after_swap();
         let (dynamic_balance, balance_ta) = self.get_dynamic_balance();
+        // Capped to the dynamic balance, as the swap output can fall short of the withdrawal
+        let additional_amount_after_swap = self.get_additional_amount_after_swap(dynamic_balance);
         let balance_leftover = dynamic_balance - additional_amount_after_swap;
 
         if self.rebalance_data().ixs.swap_type == SwapType::ExactOut {
@@ -3,6 +3,7 @@ use std::ops::{Div, Mul};
 use solana_program::pubkey::Pubkey;
 
 use crate::{
+    flash_loan::FlashLoan,
     state::solauto_position::{
         PositionData, PositionState, RebalanceData, SolautoPosition, SolautoSettingsParameters,
         SolautoSettingsParametersInp,
@@ -70,6 +71,8 @@ fn create_position<'a>(pos: &FakePosition<'a>) -> Box<SolautoPosition> {
             decimals: TEST_TOKEN_DECIMALS,
             market_price: SUPPLY_PRICE,
             borrow_fee_bps: None,
+            supply_apr_bps: 0,
+            borrow_apr_bps: 0,
         }),
     );
     update_token_state(
@@ -81,6 +84,8 @@ fn create_position<'a>(pos: &FakePosition<'a>) -> Box<SolautoPosition> {
             decimals: TEST_TOKEN_DECIMALS,
             market_price: DEBT_PRICE,
             borrow_fee_bps: Some(BORROW_FEE_BPS),
+            supply_apr_bps: 0,
+            borrow_apr_bps: 0,
         }),
     );
 
@@ -102,16 +107,17 @@ pub struct FakeRebalance<'a> {
     pos: &'a mut Box<SolautoPosition>,
     position_supply_ta_balance: Option<u64>,
     position_debt_ta_balance: Option<u64>,
-    rebalance_direction: RebalanceDirection,
 }
 
 fn create_rebalancer<'a>(
     data: FakeRebalance<'a>,
     rebalance_args: RebalanceSettings,
-    flash_loan_amount: Option<u64>,
+    flash_loan: Option<FlashLoan>,
 ) -> Rebalancer<'a> {
+    let flash_loan = flash_loan.unwrap_or_default();
     data.pos.rebalance.ixs.rebalance_type = rebalance_args.rebalance_type;
-    data.pos.rebalance.ixs.flash_loan_amount = flash_loan_amount.unwrap_or(0);
+    data.pos.rebalance.ixs.flash_loan_amount = flash_loan.amount;
+    data.pos.rebalance.ixs.flash_loan_fee_bps = flash_loan.fee_bps;
     data.pos.rebalance.ixs.active = PodBool::new(true);
     data.pos.rebalance.ixs.swap_type = rebalance_args.swap_type.unwrap_or(SwapType::default());
 
@@ -126,6 +132,7 @@ fn create_rebalancer<'a>(
         },
         solauto_fees_bps: solauto_fees,
         referred_by: false,
+        curr_unix_timestamp: 0,
     });
 
     rebalancer
@@ -393,32 +400,1117 @@ impl TaCreditor {
 }
 
 mod tests {
-    use std::ops::{Add, Div};
+    use std::ops::Div;
 
     use crate::{
+        rebalance::utils::eligible_for_rebalance,
+        state::{
+            automation::{AutomationSettingsInp, DCASettings, DCASettingsInp},
+            carry_guard::{CarryGuard, CarryGuardInp},
+            grid::{Grid, GridSettingsInp},
+            leverage_curve::{LeverageCurve, LeverageCurveBandInp, LeverageCurveInp},
+            orders::{
+                LimitOrder, LimitOrderInp, StopLossOrder, StopLossOrderInp, TakeProfitOrder,
+                TakeProfitOrderInp, TrailingStopOrder, TrailingStopOrderInp,
+            },
+        },
         types::{
-            shared::{RebalanceStep, SolautoRebalanceType, SwapType},
-            solauto::RebalanceFeesBps,
+            errors::SolautoError,
+            shared::{
+                RebalanceStep, RebalanceStrategyType, SolautoRebalanceType, SwapType, TokenType,
+            },
+            solauto::{FromLendingPlatformAction, RebalanceFeesBps},
+        },
+        utils::math_utils::{
+            get_debt_adjustment, get_max_boost_to_bps, get_max_repay_to_bps, to_rounded_usd_value,
         },
-        utils::math_utils::{get_debt_adjustment, get_max_boost_to_bps, get_max_repay_to_bps},
     };
 
-    use super::*;
+    use super::*;
+
+    #[test]
+    fn test_standard_rebalance_boost() {
+        let pos_values = PositionValues {
+            supply_usd: 100.0,
+            debt_usd: 25.0,
+        };
+        let rebalance_to = 3800;
+        let rebalance_direction = RebalanceDirection::Boost;
+
+        let settings = SolautoSettingsParametersInp {
+            boost_gap: 50,
+            boost_to_bps: rebalance_to,
+            repay_gap: 50,
+            repay_to_bps: get_max_repay_to_bps(MAX_LTV_BPS, LIQ_THRESHOLD_BPS),
+            ..Default::default()
+        };
+        let mut position = create_position(
+            &(FakePosition {
+                values: &pos_values,
+                settings,
+                max_ltv_bps: Some(MAX_LTV_BPS),
+                liq_threshold_bps: Some(LIQ_THRESHOLD_BPS),
+            }),
+        );
+        let debt_adjustment = get_debt_adjustment(
+            LIQ_THRESHOLD_BPS,
+            &pos_values,
+            rebalance_to,
+            &(RebalanceFeesBps {
+                solauto: SOLAUTO_FEE_BPS,
+                lp_borrow: BORROW_FEE_BPS,
+                flash_loan: 0,
+            }),
+        );
+        let rebalance_args = RebalanceSettings {
+            rebalance_type: SolautoRebalanceType::Regular,
+            target_liq_utilization_rate_bps: None,
+            swap_in_amount_base_unit: Some(to_base_unit(
+                debt_adjustment.debt_adjustment_usd.div(DEBT_PRICE),
+                TEST_TOKEN_DECIMALS,
+            )),
+            swap_type: Some(SwapType::ExactIn),
+            price_type: None,
+            swap_cpi: None,
+        };
+        let rebalancer = &mut create_rebalancer(
+            FakeRebalance {
+                pos: &mut position,
+                position_supply_ta_balance: None,
+                position_debt_ta_balance: None,
+            },
+            rebalance_args,
+            None,
+        );
+        let ta_creditor = &mut TaCreditor::new();
+
+        let res = rebalancer.rebalance(RebalanceStep::PreSwap);
+        assert!(res.is_ok());
+        apply_actions(rebalancer, ta_creditor);
+
+        perform_swap(rebalancer, ta_creditor, &rebalance_direction, true);
+
+        let res = rebalancer.rebalance(RebalanceStep::PostSwap);
+        assert!(res.is_ok());
+        apply_actions(rebalancer, ta_creditor);
+
+        validate_rebalance(rebalancer);
+    }
+
+    #[test]
+    fn test_dca_in_rebalance_boost() {
+        let pos_values = PositionValues {
+            supply_usd: 100.0,
+            debt_usd: 40.0,
+        };
+        let dca_in_base_unit = to_base_unit::<f64, u8, u64>(0.3, TEST_TOKEN_DECIMALS);
+        let period_usd = 10.0;
+        let rebalance_direction = RebalanceDirection::Boost;
+
+        let settings = SolautoSettingsParametersInp {
+            boost_gap: 50,
+            boost_to_bps: 3000,
+            repay_gap: 50,
+            repay_to_bps: get_max_repay_to_bps(MAX_LTV_BPS, LIQ_THRESHOLD_BPS),
+            ..Default::default()
+        };
+        let mut position = create_position(
+            &(FakePosition {
+                values: &pos_values,
+                settings,
+                max_ltv_bps: Some(MAX_LTV_BPS),
+                liq_threshold_bps: Some(LIQ_THRESHOLD_BPS),
+            }),
+        );
+        position.position.dca = DCASettings::from(DCASettingsInp {
+            automation: AutomationSettingsInp {
+                target_periods: 3,
+                periods_passed: 0,
+                unix_start_date: 0,
+                interval_seconds: 60 * 10,
+            },
+            dca_in_base_unit,
+            token_type: TokenType::Supply,
+        });
+
+        // The position is in between boost-from and repay-from, so the DCA period is what makes it eligible
+        let debt_adjustment = get_debt_adjustment(
+            LIQ_THRESHOLD_BPS,
+            &PositionValues {
+                supply_usd: pos_values.supply_usd + period_usd,
+                debt_usd: pos_values.debt_usd,
+            },
+            position.state.liq_utilization_rate_bps,
+            &(RebalanceFeesBps {
+                solauto: SOLAUTO_FEE_BPS,
+                lp_borrow: BORROW_FEE_BPS,
+                flash_loan: 0,
+            }),
+        );
+        let rebalance_args = RebalanceSettings {
+            rebalance_type: SolautoRebalanceType::Regular,
+            target_liq_utilization_rate_bps: None,
+            swap_in_amount_base_unit: Some(to_base_unit(
+                debt_adjustment.debt_adjustment_usd.div(DEBT_PRICE),
+                TEST_TOKEN_DECIMALS,
+            )),
+            swap_type: Some(SwapType::ExactIn),
+            price_type: None,
+            swap_cpi: None,
+        };
+        let rebalancer = &mut create_rebalancer(
+            FakeRebalance {
+                pos: &mut position,
+                position_supply_ta_balance: Some(dca_in_base_unit),
+                position_debt_ta_balance: None,
+            },
+            rebalance_args,
+            None,
+        );
+        let ta_creditor = &mut TaCreditor::new();
+
+        let res = rebalancer.rebalance(RebalanceStep::PreSwap);
+        assert!(res.is_ok());
+        apply_actions(rebalancer, ta_creditor);
+
+        perform_swap(rebalancer, ta_creditor, &rebalance_direction, true);
+
+        let res = rebalancer.rebalance(RebalanceStep::PostSwap);
+        assert!(res.is_ok());
+        apply_actions(rebalancer, ta_creditor);
+
+        validate_rebalance(rebalancer);
+
+        // The rest of the DCA stays in the position supply token account for the next periods
+        let remaining_base_unit = to_base_unit::<f64, u8, u64>(0.2, TEST_TOKEN_DECIMALS);
+        let dca = rebalancer.data.solauto_position.data.position.dca;
+        assert_eq!(dca.dca_in_base_unit, remaining_base_unit);
+        assert_eq!(dca.automation.periods_passed, 1);
+        assert_eq!(
+            rebalancer.data.solauto_position.supply_ta.balance,
+            remaining_base_unit
+        );
+    }
+
+    #[test]
+    fn test_limit_order_rebalance_boost() {
+        let pos_values = PositionValues {
+            supply_usd: 100.0,
+            debt_usd: 40.0,
+        };
+        let order_base_unit = to_base_unit::<f64, u8, u64>(0.2, TEST_TOKEN_DECIMALS);
+        let order_usd = 20.0;
+        let rebalance_direction = RebalanceDirection::Boost;
+
+        let settings = SolautoSettingsParametersInp {
+            boost_gap: 50,
+            boost_to_bps: 3000,
+            repay_gap: 50,
+            repay_to_bps: get_max_repay_to_bps(MAX_LTV_BPS, LIQ_THRESHOLD_BPS),
+            ..Default::default()
+        };
+        let mut position = create_position(
+            &(FakePosition {
+                values: &pos_values,
+                settings,
+                max_ltv_bps: Some(MAX_LTV_BPS),
+                liq_threshold_bps: Some(LIQ_THRESHOLD_BPS),
+            }),
+        );
+
+        // The supply price is above the trigger price, so the order doesn't make the position eligible yet
+        position.limit_order = LimitOrder::from(LimitOrderInp {
+            amount_base_unit: order_base_unit,
+            token_type: TokenType::Supply,
+            trigger_price_usd: to_rounded_usd_value(SUPPLY_PRICE - 5.0),
+            trigger_above: false,
+        });
+        assert!(!eligible_for_rebalance(&position, 0));
+
+        position.limit_order = LimitOrder::from(LimitOrderInp {
+            amount_base_unit: order_base_unit,
+            token_type: TokenType::Supply,
+            trigger_price_usd: to_rounded_usd_value(SUPPLY_PRICE),
+            trigger_above: false,
+        });
+        assert!(eligible_for_rebalance(&position, 0));
+
+        let debt_adjustment = get_debt_adjustment(
+            LIQ_THRESHOLD_BPS,
+            &PositionValues {
+                supply_usd: pos_values.supply_usd + order_usd,
+                debt_usd: pos_values.debt_usd,
+            },
+            position.state.liq_utilization_rate_bps,
+            &(RebalanceFeesBps {
+                solauto: SOLAUTO_FEE_BPS,
+                lp_borrow: BORROW_FEE_BPS,
+                flash_loan: 0,
+            }),
+        );
+        let rebalance_args = RebalanceSettings {
+            rebalance_type: SolautoRebalanceType::Regular,
+            target_liq_utilization_rate_bps: None,
+            swap_in_amount_base_unit: Some(to_base_unit(
+                debt_adjustment.debt_adjustment_usd.div(DEBT_PRICE),
+                TEST_TOKEN_DECIMALS,
+            )),
+            swap_type: Some(SwapType::ExactIn),
+            price_type: None,
+            swap_cpi: None,
+        };
+        let rebalancer = &mut create_rebalancer(
+            FakeRebalance {
+                pos: &mut position,
+                position_supply_ta_balance: Some(order_base_unit),
+                position_debt_ta_balance: None,
+            },
+            rebalance_args,
+            None,
+        );
+        let ta_creditor = &mut TaCreditor::new();
+
+        let res = rebalancer.rebalance(RebalanceStep::PreSwap);
+        assert!(res.is_ok());
+        apply_actions(rebalancer, ta_creditor);
+
+        perform_swap(rebalancer, ta_creditor, &rebalance_direction, true);
+
+        let res = rebalancer.rebalance(RebalanceStep::PostSwap);
+        assert!(res.is_ok());
+        apply_actions(rebalancer, ta_creditor);
+
+        validate_rebalance(rebalancer);
+
+        // The order is filled in full and the escrow is used up
+        assert!(!rebalancer
+            .data
+            .solauto_position
+            .data
+            .limit_order
+            .is_active());
+        assert_eq!(rebalancer.data.solauto_position.supply_ta.balance, 0);
+    }
+
+    #[test]
+    fn test_standard_rebalance_repay() {
+        let pos_values = PositionValues {
+            supply_usd: 100.0,
+            debt_usd: 50.0,
+        };
+        let rebalance_to = 5000;
+        let rebalance_direction = RebalanceDirection::Repay;
+
+        let settings = SolautoSettingsParametersInp {
+            boost_gap: 50,
+            boost_to_bps: 2000,
+            repay_gap: 50,
+            repay_to_bps: rebalance_to,
+            ..Default::default()
+        };
+        let mut position = create_position(
+            &(FakePosition {
+                values: &pos_values,
+                settings,
+                max_ltv_bps: Some(MAX_LTV_BPS),
+                liq_threshold_bps: Some(LIQ_THRESHOLD_BPS),
+            }),
+        );
+        let debt_adjustment = get_debt_adjustment(
+            LIQ_THRESHOLD_BPS,
+            &pos_values,
+            rebalance_to,
+            &(RebalanceFeesBps {
+                solauto: SOLAUTO_FEE_BPS,
+                lp_borrow: BORROW_FEE_BPS,
+                flash_loan: 0,
+            }),
+        );
+        let rebalance_args = RebalanceSettings {
+            rebalance_type: SolautoRebalanceType::Regular,
+            target_liq_utilization_rate_bps: None,
+            swap_in_amount_base_unit: Some(to_base_unit(
+                debt_adjustment.debt_adjustment_usd.abs().div(SUPPLY_PRICE),
+                TEST_TOKEN_DECIMALS,
+            )),
+            swap_type: Some(SwapType::ExactIn),
+            price_type: None,
+            swap_cpi: None,
+        };
+        let rebalancer = &mut create_rebalancer(
+            FakeRebalance {
+                pos: &mut position,
+                position_supply_ta_balance: None,
+                position_debt_ta_balance: None,
+            },
+            rebalance_args,
+            None,
+        );
+        let ta_creditor = &mut TaCreditor::new();
+
+        let res = rebalancer.rebalance(RebalanceStep::PreSwap);
+        assert!(res.is_ok());
+        apply_actions(rebalancer, ta_creditor);
+
+        perform_swap(rebalancer, ta_creditor, &rebalance_direction, true);
+
+        let res = rebalancer.rebalance(RebalanceStep::PostSwap);
+        assert!(res.is_ok());
+        apply_actions(rebalancer, ta_creditor);
+
+        validate_rebalance(rebalancer);
+    }
+
+    fn perform_dca_out_period(rebalancer: &mut Rebalancer, curr_unix_timestamp: u64) {
+        let rebalance_direction = RebalanceDirection::Repay;
+        let fees = RebalanceFeesBps {
+            solauto: SOLAUTO_FEE_BPS,
+            lp_borrow: BORROW_FEE_BPS,
+            flash_loan: 0,
+        };
+        let position = &rebalancer.data.solauto_position.data;
+        let dca = position.position.dca;
+        let supply_usd = position.state.supply_usd();
+        let debt_usd = position.state.debt_usd();
+
+        let net_worth_usd = supply_usd - debt_usd / (1.0 - from_bps(SOLAUTO_FEE_BPS));
+        let withdraw_usd = net_worth_usd
+            - dca.automation.updated_amount_from_automation(
+                net_worth_usd,
+                0.0,
+                curr_unix_timestamp,
+            );
+        let target_liq_utilization_rate_bps = dca.automation.updated_amount_from_automation(
+            position.state.liq_utilization_rate_bps,
+            0,
+            curr_unix_timestamp,
+        );
+        let debt_adjustment = get_debt_adjustment(
+            LIQ_THRESHOLD_BPS,
+            &PositionValues {
+                supply_usd: supply_usd - withdraw_usd,
+                debt_usd,
+            },
+            target_liq_utilization_rate_bps,
+            &fees,
+        );
+
+        rebalancer.data.curr_unix_timestamp = curr_unix_timestamp;
+        rebalancer.data.rebalance_args.swap_in_amount_base_unit = Some(to_base_unit(
+            debt_adjustment.debt_adjustment_usd.abs().div(SUPPLY_PRICE),
+            TEST_TOKEN_DECIMALS,
+        ));
+        let ta_creditor = &mut TaCreditor::new();
+
+        let res = rebalancer.rebalance(RebalanceStep::PreSwap);
+        assert!(res.is_ok());
+        apply_actions(rebalancer, ta_creditor);
+
+        perform_swap(rebalancer, ta_creditor, &rebalance_direction, true);
+
+        let res = rebalancer.rebalance(RebalanceStep::PostSwap);
+        assert!(res.is_ok());
+        apply_actions(rebalancer, ta_creditor);
+
+        assert_eq!(
+            round_to_decimals(
+                from_base_unit::<u64, u8, f64>(
+                    ta_creditor.authority_supply_ta.balance,
+                    TEST_TOKEN_DECIMALS
+                )
+                .mul(SUPPLY_PRICE),
+                4
+            ),
+            round_to_decimals(withdraw_usd, 4),
+            "Incorrect DCA-out withdrawal. Expected (left) vs. actual (right)"
+        );
+        validate_rebalance(rebalancer);
+        rebalancer.data.solauto_position.data.refresh_state();
+    }
+
+    #[test]
+    fn test_dca_out_rebalance() {
+        let pos_values = PositionValues {
+            supply_usd: 100.0,
+            debt_usd: 40.0,
+        };
+        let settings = SolautoSettingsParametersInp {
+            boost_gap: 50,
+            boost_to_bps: 3000,
+            repay_gap: 50,
+            repay_to_bps: get_max_repay_to_bps(MAX_LTV_BPS, LIQ_THRESHOLD_BPS),
+            ..Default::default()
+        };
+        let mut position = create_position(
+            &(FakePosition {
+                values: &pos_values,
+                settings,
+                max_ltv_bps: Some(MAX_LTV_BPS),
+                liq_threshold_bps: Some(LIQ_THRESHOLD_BPS),
+            }),
+        );
+        let interval_seconds = 60 * 10;
+        position.position.dca = DCASettings::from(DCASettingsInp {
+            automation: AutomationSettingsInp {
+                target_periods: 2,
+                periods_passed: 0,
+                unix_start_date: 0,
+                interval_seconds,
+            },
+            dca_in_base_unit: 0,
+            token_type: TokenType::Supply,
+        });
+
+        let rebalance_args = RebalanceSettings {
+            rebalance_type: SolautoRebalanceType::Regular,
+            target_liq_utilization_rate_bps: None,
+            swap_in_amount_base_unit: None,
+            swap_type: Some(SwapType::ExactIn),
+            price_type: None,
+            swap_cpi: None,
+        };
+        let rebalancer = &mut create_rebalancer(
+            FakeRebalance {
+                pos: &mut position,
+                position_supply_ta_balance: None,
+                position_debt_ta_balance: None,
+            },
+            rebalance_args,
+            None,
+        );
+
+        perform_dca_out_period(rebalancer, 0);
+        assert_eq!(
+            rebalancer
+                .data
+                .solauto_position
+                .data
+                .position
+                .dca
+                .automation
+                .periods_passed,
+            1
+        );
+
+        // The last period repays all debt & withdraws the rest of the supply
+        perform_dca_out_period(rebalancer, interval_seconds);
+        let position = &rebalancer.data.solauto_position.data;
+        assert!(!position.position.dca.is_active());
+        assert_eq!(position.state.debt.amount_used.base_unit, 0);
+        assert_eq!(position.state.supply.amount_used.base_unit, 0);
+    }
+
+    #[test]
+    fn test_dca_out_debt_withdrawal_capped_to_swap_output() {
+        let pos_values = PositionValues {
+            supply_usd: 100.0,
+            debt_usd: 40.0,
+        };
+        let settings = SolautoSettingsParametersInp {
+            boost_gap: 50,
+            boost_to_bps: 3000,
+            repay_gap: 50,
+            repay_to_bps: get_max_repay_to_bps(MAX_LTV_BPS, LIQ_THRESHOLD_BPS),
+            ..Default::default()
+        };
+        let mut position = create_position(
+            &(FakePosition {
+                values: &pos_values,
+                settings,
+                max_ltv_bps: Some(MAX_LTV_BPS),
+                liq_threshold_bps: Some(LIQ_THRESHOLD_BPS),
+            }),
+        );
+        position.position.dca = DCASettings::from(DCASettingsInp {
+            automation: AutomationSettingsInp {
+                target_periods: 2,
+                periods_passed: 0,
+                unix_start_date: 0,
+                interval_seconds: 60 * 10,
+            },
+            dca_in_base_unit: 0,
+            token_type: TokenType::Debt,
+        });
+
+        // Swaps $1 of supply, far less than the debt tokens the period withdraws
+        let rebalance_args = RebalanceSettings {
+            rebalance_type: SolautoRebalanceType::Regular,
+            target_liq_utilization_rate_bps: None,
+            swap_in_amount_base_unit: Some(to_base_unit(
+                1.0.div(SUPPLY_PRICE),
+                TEST_TOKEN_DECIMALS,
+            )),
+            swap_type: Some(SwapType::ExactIn),
+            price_type: None,
+            swap_cpi: None,
+        };
+        let rebalancer = &mut create_rebalancer(
+            FakeRebalance {
+                pos: &mut position,
+                position_supply_ta_balance: None,
+                position_debt_ta_balance: None,
+            },
+            rebalance_args,
+            None,
+        );
+        let ta_creditor = &mut TaCreditor::new();
+
+        let res = rebalancer.rebalance(RebalanceStep::PreSwap);
+        assert!(res.is_ok());
+        apply_actions(rebalancer, ta_creditor);
+
+        let swap_output = perform_swap(rebalancer, ta_creditor, &RebalanceDirection::Repay, true);
+
+        let res = rebalancer.rebalance(RebalanceStep::PostSwap);
+        assert!(res.is_ok());
+        apply_actions(rebalancer, ta_creditor);
+
+        assert_eq!(ta_creditor.authority_debt_ta.balance, swap_output);
+        assert_eq!(rebalancer.data.solauto_position.debt_ta.balance, 0);
+    }
+
+    // TODO: when token balance changes pre-swap are needed
+    // #[test]
+    // fn test_double_rebalance_fl_boost() {}
+
+    // #[test]
+    // fn test_double_rebalance_fl_repay() {}
+
+    #[test]
+    fn test_swap_then_rebalance_boost() {
+        let pos_values = PositionValues {
+            supply_usd: 100.0,
+            debt_usd: 0.0,
+        };
+        let rebalance_to = get_max_boost_to_bps(MAX_LTV_BPS, LIQ_THRESHOLD_BPS);
+        let rebalance_direction = RebalanceDirection::Boost;
+
+        let settings = SolautoSettingsParametersInp {
+            boost_gap: 50,
+            boost_to_bps: rebalance_to,
+            repay_gap: 50,
+            repay_to_bps: rebalance_to,
+            ..Default::default()
+        };
+        let mut position = create_position(
+            &(FakePosition {
+                values: &pos_values,
+                settings,
+                max_ltv_bps: Some(MAX_LTV_BPS),
+                liq_threshold_bps: Some(LIQ_THRESHOLD_BPS),
+            }),
+        );
+
+        let debt_adjustment = get_debt_adjustment(
+            LIQ_THRESHOLD_BPS,
+            &pos_values,
+            rebalance_to,
+            &(RebalanceFeesBps {
+                solauto: SOLAUTO_FEE_BPS,
+                lp_borrow: BORROW_FEE_BPS,
+                flash_loan: FLASH_LOAN_FEE_BPS,
+            }),
+        );
+        let flash_borrow = to_base_unit(
+            debt_adjustment.debt_adjustment_usd.abs().div(DEBT_PRICE),
+            TEST_TOKEN_DECIMALS,
+        );
+
+        let rebalance_args = RebalanceSettings {
+            rebalance_type: SolautoRebalanceType::FLSwapThenRebalance,
+            target_liq_utilization_rate_bps: None,
+            swap_in_amount_base_unit: Some(flash_borrow),
+            swap_type: Some(SwapType::ExactIn),
+            price_type: None,
+            swap_cpi: None,
+        };
+        let rebalancer = &mut create_rebalancer(
+            FakeRebalance {
+                pos: &mut position,
+                position_supply_ta_balance: None,
+                position_debt_ta_balance: None,
+            },
+            rebalance_args,
+            Some(FlashLoan {
+                amount: flash_borrow,
+                fee_bps: FLASH_LOAN_FEE_BPS,
+            }),
+        );
+        let ta_creditor = &mut TaCreditor::new();
+
+        credit_token_account(
+            rebalancer,
+            ta_creditor,
+            SolautoAccount::IntermediaryTa,
+            flash_borrow as i64,
+        );
+
+        perform_swap(rebalancer, ta_creditor, &rebalance_direction, true);
+
+        let res = rebalancer.rebalance(RebalanceStep::PostSwap);
+        assert!(res.is_ok());
+        apply_actions(rebalancer, ta_creditor);
+
+        validate_rebalance(rebalancer);
+    }
+
+    #[test]
+    fn test_swap_then_rebalance_repay() {
+        let pos_values = PositionValues {
+            supply_usd: 100.0,
+            debt_usd: 70.0,
+        };
+        let rebalance_to = 1000;
+        let rebalance_direction = RebalanceDirection::Repay;
+
+        let settings = SolautoSettingsParametersInp {
+            boost_gap: 50,
+            boost_to_bps: 3000,
+            repay_gap: 50,
+            repay_to_bps: get_max_repay_to_bps(MAX_LTV_BPS, LIQ_THRESHOLD_BPS),
+            ..Default::default()
+        };
+        let mut position = create_position(
+            &(FakePosition {
+                values: &pos_values,
+                settings,
+                max_ltv_bps: Some(MAX_LTV_BPS),
+                liq_threshold_bps: Some(LIQ_THRESHOLD_BPS),
+            }),
+        );
+
+        let debt_adjustment = get_debt_adjustment(
+            LIQ_THRESHOLD_BPS,
+            &pos_values,
+            rebalance_to,
+            &(RebalanceFeesBps {
+                solauto: SOLAUTO_FEE_BPS,
+                lp_borrow: BORROW_FEE_BPS,
+                flash_loan: FLASH_LOAN_FEE_BPS,
+            }),
+        );
+        let flash_borrow = to_base_unit(
+            debt_adjustment.debt_adjustment_usd.abs().div(SUPPLY_PRICE),
+            TEST_TOKEN_DECIMALS,
+        );
+
+        let rebalance_args = RebalanceSettings {
+            rebalance_type: SolautoRebalanceType::FLSwapThenRebalance,
+            target_liq_utilization_rate_bps: Some(rebalance_to),
+            swap_in_amount_base_unit: Some(flash_borrow),
+            swap_type: Some(SwapType::ExactIn),
+            price_type: None,
+            swap_cpi: None,
+        };
+        let rebalancer = &mut create_rebalancer(
+            FakeRebalance {
+                pos: &mut position,
+                position_supply_ta_balance: None,
+                position_debt_ta_balance: None,
+            },
+            rebalance_args,
+            Some(FlashLoan {
+                amount: flash_borrow,
+                fee_bps: FLASH_LOAN_FEE_BPS,
+            }),
+        );
+        let ta_creditor = &mut TaCreditor::new();
+
+        credit_token_account(
+            rebalancer,
+            ta_creditor,
+            SolautoAccount::IntermediaryTa,
+            flash_borrow as i64,
+        );
+
+        perform_swap(rebalancer, ta_creditor, &rebalance_direction, true);
+
+        let res = rebalancer.rebalance(RebalanceStep::PostSwap);
+        assert!(res.is_ok());
+        apply_actions(rebalancer, ta_creditor);
+
+        validate_rebalance(rebalancer);
+    }
+
+    // TODO: double rebalance with flash loan exact out swap will not work currently. What needs to change?
+
+    #[test]
+    fn test_rebalance_then_swap_repay() {
+        let pos_values = PositionValues {
+            supply_usd: 100.0,
+            debt_usd: 70.0,
+        };
+        let rebalance_to = 1000;
+        let rebalance_direction = RebalanceDirection::Repay;
+
+        let settings = SolautoSettingsParametersInp {
+            boost_gap: 50,
+            boost_to_bps: rebalance_to,
+            repay_gap: 50,
+            repay_to_bps: rebalance_to,
+            ..Default::default()
+        };
+        let mut position = create_position(
+            &(FakePosition {
+                values: &pos_values,
+                settings,
+                max_ltv_bps: Some(MAX_LTV_BPS),
+                liq_threshold_bps: Some(LIQ_THRESHOLD_BPS),
+            }),
+        );
+
+        let debt_adjustment = get_debt_adjustment(
+            LIQ_THRESHOLD_BPS,
+            &pos_values,
+            rebalance_to,
+            &(RebalanceFeesBps {
+                solauto: SOLAUTO_FEE_BPS,
+                lp_borrow: BORROW_FEE_BPS,
+                flash_loan: 0,
+            }),
+        );
+
+        println!("{}", debt_adjustment.debt_adjustment_usd.abs());
+        let rebalance_args = RebalanceSettings {
+            rebalance_type: SolautoRebalanceType::FLRebalanceThenSwap,
+            target_liq_utilization_rate_bps: None,
+            swap_in_amount_base_unit: Some(to_base_unit(
+                debt_adjustment.debt_adjustment_usd.abs().div(SUPPLY_PRICE),
+                TEST_TOKEN_DECIMALS,
+            )),
+            swap_type: Some(SwapType::ExactOut),
+            price_type: None,
+            swap_cpi: None,
+        };
+        let rebalancer = &mut create_rebalancer(
+            FakeRebalance {
+                pos: &mut position,
+                position_supply_ta_balance: None,
+                position_debt_ta_balance: None,
+            },
+            rebalance_args,
+            None,
+        );
+        let ta_creditor = &mut TaCreditor::new();
+
+        let flash_borrow = to_base_unit(
+            debt_adjustment.debt_adjustment_usd.abs().div(DEBT_PRICE),
+            TEST_TOKEN_DECIMALS,
+        );
+        credit_token_account(
+            rebalancer,
+            ta_creditor,
+            SolautoAccount::SolautoPositionDebtTa,
+            flash_borrow,
+        );
+
+        let res = rebalancer.rebalance(RebalanceStep::PreSwap);
+        assert!(res.is_ok());
+        apply_actions(rebalancer, ta_creditor);
+
+        validate_rebalance(rebalancer);
+
+        let swap_output_amount = perform_swap(rebalancer, ta_creditor, &rebalance_direction, false);
+
+        assert_eq!(
+            round_to_decimals(from_base_unit(swap_output_amount, TEST_TOKEN_DECIMALS), 4),
+            round_to_decimals(from_base_unit(flash_borrow as u64, TEST_TOKEN_DECIMALS), 4),
+            "Flash loan repayment (left) doesn't match expected (right)"
+        );
+    }
+
+    #[test]
+    fn test_target_liq_utilization_rate_rebalance() {
+        let pos_values = PositionValues {
+            supply_usd: 100.0,
+            debt_usd: 25.0,
+        };
+        let rebalance_to = 3500;
+        let rebalance_direction = RebalanceDirection::Boost;
+
+        let settings = SolautoSettingsParametersInp {
+            boost_gap: 50,
+            boost_to_bps: rebalance_to + 300,
+            repay_gap: 50,
+            repay_to_bps: get_max_repay_to_bps(MAX_LTV_BPS, LIQ_THRESHOLD_BPS),
+            ..Default::default()
+        };
+        let mut position = create_position(
+            &(FakePosition {
+                values: &pos_values,
+                settings,
+                max_ltv_bps: Some(MAX_LTV_BPS),
+                liq_threshold_bps: Some(LIQ_THRESHOLD_BPS),
+            }),
+        );
+        let debt_adjustment = get_debt_adjustment(
+            LIQ_THRESHOLD_BPS,
+            &pos_values,
+            rebalance_to,
+            &(RebalanceFeesBps {
+                solauto: SOLAUTO_FEE_BPS,
+                lp_borrow: BORROW_FEE_BPS,
+                flash_loan: 0,
+            }),
+        );
+        let rebalance_args = RebalanceSettings {
+            rebalance_type: SolautoRebalanceType::Regular,
+            target_liq_utilization_rate_bps: Some(rebalance_to),
+            swap_in_amount_base_unit: Some(to_base_unit(
+                debt_adjustment.debt_adjustment_usd.div(DEBT_PRICE),
+                TEST_TOKEN_DECIMALS,
+            )),
+            swap_type: Some(SwapType::ExactIn),
+            price_type: None,
+            swap_cpi: None,
+        };
+        let rebalancer = &mut create_rebalancer(
+            FakeRebalance {
+                pos: &mut position,
+                position_supply_ta_balance: None,
+                position_debt_ta_balance: None,
+            },
+            rebalance_args,
+            None,
+        );
+        let ta_creditor = &mut TaCreditor::new();
+
+        let res = rebalancer.rebalance(RebalanceStep::PreSwap);
+        assert!(res.is_ok());
+        apply_actions(rebalancer, ta_creditor);
+
+        perform_swap(rebalancer, ta_creditor, &rebalance_direction, true);
+
+        let res = rebalancer.rebalance(RebalanceStep::PostSwap);
+        assert!(res.is_ok());
+        apply_actions(rebalancer, ta_creditor);
+
+        validate_rebalance(rebalancer);
+    }
+
+    #[test]
+    fn test_take_profit_rebalance() {
+        let pos_values = PositionValues {
+            supply_usd: 100.0,
+            debt_usd: 60.0,
+        };
+        let target_liq_utilization_rate_bps = 5000;
+        let rebalance_direction = RebalanceDirection::Repay;
+        let settings = SolautoSettingsParametersInp {
+            boost_gap: 50,
+            boost_to_bps: 3000,
+            repay_gap: 50,
+            repay_to_bps: get_max_repay_to_bps(MAX_LTV_BPS, LIQ_THRESHOLD_BPS),
+            ..Default::default()
+        };
+        let mut position = create_position(
+            &(FakePosition {
+                values: &pos_values,
+                settings,
+                max_ltv_bps: Some(MAX_LTV_BPS),
+                liq_threshold_bps: Some(LIQ_THRESHOLD_BPS),
+            }),
+        );
+
+        position.take_profit = TakeProfitOrder::from(TakeProfitOrderInp {
+            trigger_price_usd: to_rounded_usd_value(SUPPLY_PRICE + 10.0),
+            target_liq_utilization_rate_bps,
+            withdraw_net_worth_bps: 1000,
+        });
+        assert!(!eligible_for_rebalance(&position, 0));
+
+        position.take_profit = TakeProfitOrder::from(TakeProfitOrderInp {
+            trigger_price_usd: to_rounded_usd_value(SUPPLY_PRICE),
+            target_liq_utilization_rate_bps,
+            withdraw_net_worth_bps: 1000,
+        });
+        assert!(eligible_for_rebalance(&position, 0));
+
+        let fees = RebalanceFeesBps {
+            solauto: SOLAUTO_FEE_BPS,
+            lp_borrow: BORROW_FEE_BPS,
+            flash_loan: 0,
+        };
+        let withdraw_usd =
+            (pos_values.supply_usd - pos_values.debt_usd / (1.0 - from_bps(SOLAUTO_FEE_BPS))) * 0.1;
+        let debt_adjustment = get_debt_adjustment(
+            LIQ_THRESHOLD_BPS,
+            &PositionValues {
+                supply_usd: pos_values.supply_usd - withdraw_usd,
+                debt_usd: pos_values.debt_usd,
+            },
+            target_liq_utilization_rate_bps,
+            &fees,
+        );
+        let rebalance_args = RebalanceSettings {
+            rebalance_type: SolautoRebalanceType::Regular,
+            target_liq_utilization_rate_bps: None,
+            swap_in_amount_base_unit: Some(to_base_unit(
+                debt_adjustment.debt_adjustment_usd.abs().div(SUPPLY_PRICE),
+                TEST_TOKEN_DECIMALS,
+            )),
+            swap_type: Some(SwapType::ExactIn),
+            price_type: None,
+            swap_cpi: None,
+        };
+        let rebalancer = &mut create_rebalancer(
+            FakeRebalance {
+                pos: &mut position,
+                position_supply_ta_balance: None,
+                position_debt_ta_balance: None,
+            },
+            rebalance_args,
+            None,
+        );
+        let ta_creditor = &mut TaCreditor::new();
+
+        let res = rebalancer.rebalance(RebalanceStep::PreSwap);
+        assert!(res.is_ok());
+        apply_actions(rebalancer, ta_creditor);
+
+        perform_swap(rebalancer, ta_creditor, &rebalance_direction, true);
+
+        let res = rebalancer.rebalance(RebalanceStep::PostSwap);
+        assert!(res.is_ok());
+        apply_actions(rebalancer, ta_creditor);
+
+        assert_eq!(
+            round_to_decimals(
+                from_base_unit::<u64, u8, f64>(
+                    ta_creditor.authority_supply_ta.balance,
+                    TEST_TOKEN_DECIMALS
+                )
+                .mul(SUPPLY_PRICE),
+                4
+            ),
+            round_to_decimals(withdraw_usd, 4),
+            "Incorrect take-profit withdrawal. Expected (left) vs. actual (right)"
+        );
+        validate_rebalance(rebalancer);
+
+        // The take-profit only fills once
+        assert!(!rebalancer
+            .data
+            .solauto_position
+            .data
+            .take_profit
+            .is_active());
+    }
+
+    #[test]
+    fn test_stop_loss_rebalance() {
+        let pos_values = PositionValues {
+            supply_usd: 100.0,
+            debt_usd: 40.0,
+        };
+        let rebalance_direction = RebalanceDirection::Repay;
+        let settings = SolautoSettingsParametersInp {
+            boost_gap: 50,
+            boost_to_bps: 3000,
+            repay_gap: 50,
+            repay_to_bps: get_max_repay_to_bps(MAX_LTV_BPS, LIQ_THRESHOLD_BPS),
+            ..Default::default()
+        };
+        let mut position = create_position(
+            &(FakePosition {
+                values: &pos_values,
+                settings,
+                max_ltv_bps: Some(MAX_LTV_BPS),
+                liq_threshold_bps: Some(LIQ_THRESHOLD_BPS),
+            }),
+        );
+
+        position.stop_loss = StopLossOrder::from(StopLossOrderInp {
+            trigger_price_usd: to_rounded_usd_value(SUPPLY_PRICE - 10.0),
+            min_net_worth_usd: to_rounded_usd_value(50.0),
+        });
+        assert!(!eligible_for_rebalance(&position, 0));
+
+        // Net worth is 60 USD, so raising the minimum net worth above it triggers the stop-loss
+        position.stop_loss = StopLossOrder::from(StopLossOrderInp {
+            trigger_price_usd: to_rounded_usd_value(SUPPLY_PRICE - 10.0),
+            min_net_worth_usd: to_rounded_usd_value(65.0),
+        });
+        assert!(eligible_for_rebalance(&position, 0));
+
+        let withdraw_usd =
+            pos_values.supply_usd - pos_values.debt_usd / (1.0 - from_bps(SOLAUTO_FEE_BPS));
+        let debt_adjustment = get_debt_adjustment(
+            LIQ_THRESHOLD_BPS,
+            &PositionValues {
+                supply_usd: pos_values.supply_usd - withdraw_usd,
+                debt_usd: pos_values.debt_usd,
+            },
+            0,
+            &(RebalanceFeesBps {
+                solauto: SOLAUTO_FEE_BPS,
+                lp_borrow: BORROW_FEE_BPS,
+                flash_loan: 0,
+            }),
+        );
+        let rebalance_args = RebalanceSettings {
+            rebalance_type: SolautoRebalanceType::Regular,
+            target_liq_utilization_rate_bps: None,
+            swap_in_amount_base_unit: Some(to_base_unit(
+                debt_adjustment.debt_adjustment_usd.abs().div(SUPPLY_PRICE),
+                TEST_TOKEN_DECIMALS,
+            )),
+            swap_type: Some(SwapType::ExactIn),
+            price_type: None,
+            swap_cpi: None,
+        };
+        let rebalancer = &mut create_rebalancer(
+            FakeRebalance {
+                pos: &mut position,
+                position_supply_ta_balance: None,
+                position_debt_ta_balance: None,
+            },
+            rebalance_args,
+            None,
+        );
+        let ta_creditor = &mut TaCreditor::new();
+
+        let res = rebalancer.rebalance(RebalanceStep::PreSwap);
+        assert!(res.is_ok());
+        apply_actions(rebalancer, ta_creditor);
+
+        perform_swap(rebalancer, ta_creditor, &rebalance_direction, true);
+
+        let res = rebalancer.rebalance(RebalanceStep::PostSwap);
+        assert!(res.is_ok());
+
+        // The debt is repaid in full, and the rest of the supply is withdrawn to the authority
+        assert!(rebalancer
+            .actions()
+            .iter()
+            .any(|action| matches!(action, SolautoCpiAction::Repay(TokenBalanceAmount::All))));
+        assert!(rebalancer.actions().iter().any(|action| matches!(
+            action,
+            SolautoCpiAction::Withdraw(FromLendingPlatformAction {
+                amount: TokenBalanceAmount::All,
+                to_wallet_ta: SolautoAccount::AuthoritySupplyTa,
+            })
+        )));
+        apply_actions(rebalancer, ta_creditor);
+        validate_rebalance(rebalancer);
+
+        assert!(!rebalancer.data.solauto_position.data.stop_loss.is_active());
+    }
 
     #[test]
-    fn test_standard_rebalance_boost() {
+    fn test_trailing_stop_rebalance() {
         let pos_values = PositionValues {
             supply_usd: 100.0,
-            debt_usd: 25.0,
+            debt_usd: 60.0,
         };
-        let rebalance_to = 3800;
-        let rebalance_direction = RebalanceDirection::Boost;
-
+        let target_liq_utilization_rate_bps = 5000;
+        let rebalance_direction = RebalanceDirection::Repay;
         let settings = SolautoSettingsParametersInp {
             boost_gap: 50,
-            boost_to_bps: rebalance_to,
+            boost_to_bps: 3000,
             repay_gap: 50,
             repay_to_bps: get_max_repay_to_bps(MAX_LTV_BPS, LIQ_THRESHOLD_BPS),
+            ..Default::default()
         };
         let mut position = create_position(
             &(FakePosition {
@@ -428,10 +1520,27 @@ mod tests {
                 liq_threshold_bps: Some(LIQ_THRESHOLD_BPS),
             }),
         );
+        position.trailing_stop = TrailingStopOrder::from(TrailingStopOrderInp {
+            trailing_distance_bps: 1000,
+            target_liq_utilization_rate_bps,
+        });
+
+        position.state.supply_price_high_water_mark_usd = to_rounded_usd_value(105.0);
+        assert!(!eligible_for_rebalance(&position, 0));
+
+        // The supply price is now more than 10% below its peak
+        position.state.supply_price_high_water_mark_usd = to_rounded_usd_value(120.0);
+        assert!(eligible_for_rebalance(&position, 0));
+
+        // Any supply leg can be in the supply slot once the position has others, so the trailing stop doesn't apply
+        let mut multi_leg_position = position.clone();
+        multi_leg_position.state.additional_supply[0].token.mint = Pubkey::new_unique();
+        assert!(!eligible_for_rebalance(&multi_leg_position, 0));
+
         let debt_adjustment = get_debt_adjustment(
             LIQ_THRESHOLD_BPS,
             &pos_values,
-            rebalance_to,
+            target_liq_utilization_rate_bps,
             &(RebalanceFeesBps {
                 solauto: SOLAUTO_FEE_BPS,
                 lp_borrow: BORROW_FEE_BPS,
@@ -442,19 +1551,18 @@ mod tests {
             rebalance_type: SolautoRebalanceType::Regular,
             target_liq_utilization_rate_bps: None,
             swap_in_amount_base_unit: Some(to_base_unit(
-                debt_adjustment.debt_adjustment_usd.div(DEBT_PRICE),
+                debt_adjustment.debt_adjustment_usd.abs().div(SUPPLY_PRICE),
                 TEST_TOKEN_DECIMALS,
             )),
-            flash_loan_fee_bps: None,
             swap_type: Some(SwapType::ExactIn),
             price_type: None,
+            swap_cpi: None,
         };
         let rebalancer = &mut create_rebalancer(
             FakeRebalance {
                 pos: &mut position,
                 position_supply_ta_balance: None,
                 position_debt_ta_balance: None,
-                rebalance_direction: rebalance_direction.clone(),
             },
             rebalance_args,
             None,
@@ -472,22 +1580,30 @@ mod tests {
         apply_actions(rebalancer, ta_creditor);
 
         validate_rebalance(rebalancer);
+        assert_eq!(ta_creditor.authority_supply_ta.balance, 0);
+        assert!(!rebalancer
+            .data
+            .solauto_position
+            .data
+            .trailing_stop
+            .is_active());
     }
 
     #[test]
-    fn test_standard_rebalance_repay() {
+    fn test_leverage_curve_rebalance_boost() {
         let pos_values = PositionValues {
             supply_usd: 100.0,
-            debt_usd: 50.0,
+            debt_usd: 40.0,
         };
-        let rebalance_to = 5000;
-        let rebalance_direction = RebalanceDirection::Repay;
+        let rebalance_to = 6000;
+        let rebalance_direction = RebalanceDirection::Boost;
 
         let settings = SolautoSettingsParametersInp {
             boost_gap: 50,
-            boost_to_bps: 2000,
+            boost_to_bps: 3000,
             repay_gap: 50,
-            repay_to_bps: rebalance_to,
+            repay_to_bps: get_max_repay_to_bps(MAX_LTV_BPS, LIQ_THRESHOLD_BPS),
+            ..Default::default()
         };
         let mut position = create_position(
             &(FakePosition {
@@ -497,6 +1613,25 @@ mod tests {
                 liq_threshold_bps: Some(LIQ_THRESHOLD_BPS),
             }),
         );
+        // The position sits within its own band
+        assert!(!eligible_for_rebalance(&position, 0));
+
+        // The supply / debt price ratio is 100, which falls into the middle band
+        let band = |min_price_ratio: f64, boost_to_bps: u16| LeverageCurveBandInp {
+            min_price_ratio: to_base_unit(min_price_ratio, TEST_TOKEN_DECIMALS),
+            boost_to_bps,
+            boost_gap: 500,
+            repay_to_bps: get_max_repay_to_bps(MAX_LTV_BPS, LIQ_THRESHOLD_BPS),
+            repay_gap: 50,
+        };
+        position.position.rebalance_strategy = RebalanceStrategyType::LeverageCurve;
+        position.leverage_curve = LeverageCurve::from(&LeverageCurveInp {
+            bands: vec![band(0.0, 7000), band(90.0, rebalance_to), band(150.0, 2000)],
+        })
+        .unwrap();
+        assert!(validation_utils::validate_position_settings(&position).is_ok());
+        assert!(eligible_for_rebalance(&position, 0));
+
         let debt_adjustment = get_debt_adjustment(
             LIQ_THRESHOLD_BPS,
             &pos_values,
@@ -511,19 +1646,18 @@ mod tests {
             rebalance_type: SolautoRebalanceType::Regular,
             target_liq_utilization_rate_bps: None,
             swap_in_amount_base_unit: Some(to_base_unit(
-                debt_adjustment.debt_adjustment_usd.abs().div(SUPPLY_PRICE),
+                debt_adjustment.debt_adjustment_usd.div(DEBT_PRICE),
                 TEST_TOKEN_DECIMALS,
             )),
-            flash_loan_fee_bps: None,
             swap_type: Some(SwapType::ExactIn),
             price_type: None,
+            swap_cpi: None,
         };
         let rebalancer = &mut create_rebalancer(
             FakeRebalance {
                 pos: &mut position,
                 position_supply_ta_balance: None,
                 position_debt_ta_balance: None,
-                rebalance_direction: rebalance_direction.clone(),
             },
             rebalance_args,
             None,
@@ -543,27 +1677,20 @@ mod tests {
         validate_rebalance(rebalancer);
     }
 
-    // TODO: when token balance changes pre-swap are needed
-    // #[test]
-    // fn test_double_rebalance_fl_boost() {}
-
-    // #[test]
-    // fn test_double_rebalance_fl_repay() {}
-
     #[test]
-    fn test_swap_then_rebalance_boost() {
+    fn test_grid_rebalance_step() {
         let pos_values = PositionValues {
             supply_usd: 100.0,
-            debt_usd: 0.0,
+            debt_usd: 40.0,
         };
-        let rebalance_to = get_max_boost_to_bps(MAX_LTV_BPS, LIQ_THRESHOLD_BPS);
         let rebalance_direction = RebalanceDirection::Boost;
 
         let settings = SolautoSettingsParametersInp {
             boost_gap: 50,
-            boost_to_bps: rebalance_to,
+            boost_to_bps: 3000,
             repay_gap: 50,
-            repay_to_bps: rebalance_to,
+            repay_to_bps: get_max_repay_to_bps(MAX_LTV_BPS, LIQ_THRESHOLD_BPS),
+            ..Default::default()
         };
         let mut position = create_position(
             &(FakePosition {
@@ -574,6 +1701,22 @@ mod tests {
             }),
         );
 
+        // The grid was anchored at a price ratio of 90 on the middle level, and the price ratio has since risen to 100
+        position.position.rebalance_strategy = RebalanceStrategyType::Grid;
+        position.grid = Grid::from(
+            GridSettingsInp {
+                lower_liq_utilization_rate_bps: 2000,
+                upper_liq_utilization_rate_bps: 6000,
+                level_count: 5,
+                price_step_bps: 1000,
+            },
+            4000,
+            Some(90.0),
+        );
+        assert!(validation_utils::validate_position_settings(&position).is_ok());
+        assert!(eligible_for_rebalance(&position, 0));
+
+        let rebalance_to = position.grid.level_bps(3);
         let debt_adjustment = get_debt_adjustment(
             LIQ_THRESHOLD_BPS,
             &pos_values,
@@ -581,40 +1724,34 @@ mod tests {
             &(RebalanceFeesBps {
                 solauto: SOLAUTO_FEE_BPS,
                 lp_borrow: BORROW_FEE_BPS,
-                flash_loan: FLASH_LOAN_FEE_BPS,
+                flash_loan: 0,
             }),
         );
-        let flash_borrow = to_base_unit(
-            debt_adjustment.debt_adjustment_usd.abs().div(DEBT_PRICE),
-            TEST_TOKEN_DECIMALS,
-        );
-
         let rebalance_args = RebalanceSettings {
-            rebalance_type: SolautoRebalanceType::FLSwapThenRebalance,
+            rebalance_type: SolautoRebalanceType::Regular,
             target_liq_utilization_rate_bps: None,
-            swap_in_amount_base_unit: Some(flash_borrow),
-            flash_loan_fee_bps: Some(FLASH_LOAN_FEE_BPS),
+            swap_in_amount_base_unit: Some(to_base_unit(
+                debt_adjustment.debt_adjustment_usd.div(DEBT_PRICE),
+                TEST_TOKEN_DECIMALS,
+            )),
             swap_type: Some(SwapType::ExactIn),
             price_type: None,
+            swap_cpi: None,
         };
         let rebalancer = &mut create_rebalancer(
             FakeRebalance {
                 pos: &mut position,
                 position_supply_ta_balance: None,
                 position_debt_ta_balance: None,
-                rebalance_direction: rebalance_direction.clone(),
             },
             rebalance_args,
-            Some(flash_borrow),
+            None,
         );
         let ta_creditor = &mut TaCreditor::new();
 
-        credit_token_account(
-            rebalancer,
-            ta_creditor,
-            SolautoAccount::IntermediaryTa,
-            flash_borrow as i64,
-        );
+        let res = rebalancer.rebalance(RebalanceStep::PreSwap);
+        assert!(res.is_ok());
+        apply_actions(rebalancer, ta_creditor);
 
         perform_swap(rebalancer, ta_creditor, &rebalance_direction, true);
 
@@ -623,22 +1760,25 @@ mod tests {
         apply_actions(rebalancer, ta_creditor);
 
         validate_rebalance(rebalancer);
+
+        // The executed level is recorded, with the anchor moved up by one step
+        let grid = rebalancer.data.solauto_position.data.grid;
+        assert!(grid.last_level == 3);
+        assert!(grid.next_step(100.0).is_none());
     }
 
     #[test]
-    fn test_swap_then_rebalance_repay() {
+    fn test_negative_carry_rebalance() {
         let pos_values = PositionValues {
             supply_usd: 100.0,
-            debt_usd: 70.0,
+            debt_usd: 40.0,
         };
-        let rebalance_to = 1000;
-        let rebalance_direction = RebalanceDirection::Repay;
-
         let settings = SolautoSettingsParametersInp {
-            boost_gap: 50,
-            boost_to_bps: 3000,
+            boost_gap: 500,
+            boost_to_bps: 6000,
             repay_gap: 50,
             repay_to_bps: get_max_repay_to_bps(MAX_LTV_BPS, LIQ_THRESHOLD_BPS),
+            ..Default::default()
         };
         let mut position = create_position(
             &(FakePosition {
@@ -648,7 +1788,47 @@ mod tests {
                 liq_threshold_bps: Some(LIQ_THRESHOLD_BPS),
             }),
         );
+        // Earning 1% on the supply while paying 10% on the debt
+        position.state.supply.supply_apr_bps = 100;
+        position.state.debt.borrow_apr_bps = 1000;
+        assert!(eligible_for_rebalance(&position, 0));
+
+        // Boosts are refused while the net carry is negative
+        position.carry_guard = CarryGuard::from(CarryGuardInp {
+            enabled: true,
+            deleverage_to_bps: 6000,
+        });
+        assert!(!eligible_for_rebalance(&position, 0));
+
+        let boost_args = RebalanceSettings {
+            rebalance_type: SolautoRebalanceType::Regular,
+            target_liq_utilization_rate_bps: Some(6000),
+            swap_in_amount_base_unit: Some(1),
+            swap_type: Some(SwapType::ExactIn),
+            price_type: None,
+            swap_cpi: None,
+        };
+        let rebalancer = &mut create_rebalancer(
+            FakeRebalance {
+                pos: &mut position,
+                position_supply_ta_balance: None,
+                position_debt_ta_balance: None,
+            },
+            boost_args,
+            None,
+        );
+        assert!(rebalancer.rebalance(RebalanceStep::PreSwap).is_err());
+        // The failed transaction doesn't keep its rebalance data
+        position.rebalance = RebalanceData::default();
+
+        position.carry_guard = CarryGuard::from(CarryGuardInp {
+            enabled: true,
+            deleverage_to_bps: 3000,
+        });
+        assert!(eligible_for_rebalance(&position, 0));
 
+        let rebalance_to = 3000;
+        let rebalance_direction = RebalanceDirection::Repay;
         let debt_adjustment = get_debt_adjustment(
             LIQ_THRESHOLD_BPS,
             &pos_values,
@@ -656,40 +1836,34 @@ mod tests {
             &(RebalanceFeesBps {
                 solauto: SOLAUTO_FEE_BPS,
                 lp_borrow: BORROW_FEE_BPS,
-                flash_loan: FLASH_LOAN_FEE_BPS,
+                flash_loan: 0,
             }),
         );
-        let flash_borrow = to_base_unit(
-            debt_adjustment.debt_adjustment_usd.abs().div(SUPPLY_PRICE),
-            TEST_TOKEN_DECIMALS,
-        );
-
         let rebalance_args = RebalanceSettings {
-            rebalance_type: SolautoRebalanceType::FLSwapThenRebalance,
-            target_liq_utilization_rate_bps: Some(rebalance_to),
-            swap_in_amount_base_unit: Some(flash_borrow),
-            flash_loan_fee_bps: Some(FLASH_LOAN_FEE_BPS),
+            rebalance_type: SolautoRebalanceType::Regular,
+            target_liq_utilization_rate_bps: None,
+            swap_in_amount_base_unit: Some(to_base_unit(
+                debt_adjustment.debt_adjustment_usd.abs().div(SUPPLY_PRICE),
+                TEST_TOKEN_DECIMALS,
+            )),
             swap_type: Some(SwapType::ExactIn),
             price_type: None,
+            swap_cpi: None,
         };
         let rebalancer = &mut create_rebalancer(
             FakeRebalance {
                 pos: &mut position,
                 position_supply_ta_balance: None,
                 position_debt_ta_balance: None,
-                rebalance_direction: rebalance_direction.clone(),
             },
             rebalance_args,
-            Some(flash_borrow),
+            None,
         );
         let ta_creditor = &mut TaCreditor::new();
 
-        credit_token_account(
-            rebalancer,
-            ta_creditor,
-            SolautoAccount::IntermediaryTa,
-            flash_borrow as i64,
-        );
+        let res = rebalancer.rebalance(RebalanceStep::PreSwap);
+        assert!(res.is_ok());
+        apply_actions(rebalancer, ta_creditor);
 
         perform_swap(rebalancer, ta_creditor, &rebalance_direction, true);
 
@@ -700,22 +1874,69 @@ mod tests {
         validate_rebalance(rebalancer);
     }
 
-    // TODO: double rebalance with flash loan exact out swap will not work currently. What needs to change?
+    #[test]
+    fn test_price_divergence_refuses_boost() {
+        let pos_values = PositionValues {
+            supply_usd: 100.0,
+            debt_usd: 40.0,
+        };
+        let settings = SolautoSettingsParametersInp {
+            boost_gap: 500,
+            boost_to_bps: 6000,
+            repay_gap: 50,
+            repay_to_bps: get_max_repay_to_bps(MAX_LTV_BPS, LIQ_THRESHOLD_BPS),
+            ..Default::default()
+        };
+        let mut position = create_position(
+            &(FakePosition {
+                values: &pos_values,
+                settings,
+                max_ltv_bps: Some(MAX_LTV_BPS),
+                liq_threshold_bps: Some(LIQ_THRESHOLD_BPS),
+            }),
+        );
+        position.position.max_price_divergence_bps = 500;
+        position.state.price_divergence_bps = 300;
+        assert!(eligible_for_rebalance(&position, 0));
+
+        position.state.price_divergence_bps = 800;
+        assert!(!eligible_for_rebalance(&position, 0));
+
+        let rebalance_args = RebalanceSettings {
+            rebalance_type: SolautoRebalanceType::Regular,
+            target_liq_utilization_rate_bps: Some(6000),
+            swap_in_amount_base_unit: Some(1),
+            swap_type: Some(SwapType::ExactIn),
+            price_type: None,
+            swap_cpi: None,
+        };
+        let rebalancer = &mut create_rebalancer(
+            FakeRebalance {
+                pos: &mut position,
+                position_supply_ta_balance: None,
+                position_debt_ta_balance: None,
+            },
+            rebalance_args,
+            None,
+        );
+        assert!(rebalancer.rebalance(RebalanceStep::PreSwap).is_err());
+    }
 
     #[test]
-    fn test_rebalance_then_swap_repay() {
+    fn test_swap_cpi_rebalance_repay() {
         let pos_values = PositionValues {
             supply_usd: 100.0,
-            debt_usd: 70.0,
+            debt_usd: 50.0,
         };
-        let rebalance_to = 1000;
+        let rebalance_to = 5000;
         let rebalance_direction = RebalanceDirection::Repay;
 
         let settings = SolautoSettingsParametersInp {
             boost_gap: 50,
-            boost_to_bps: rebalance_to,
+            boost_to_bps: 2000,
             repay_gap: 50,
             repay_to_bps: rebalance_to,
+            ..Default::default()
         };
         let mut position = create_position(
             &(FakePosition {
@@ -725,7 +1946,6 @@ mod tests {
                 liq_threshold_bps: Some(LIQ_THRESHOLD_BPS),
             }),
         );
-
         let debt_adjustment = get_debt_adjustment(
             LIQ_THRESHOLD_BPS,
             &pos_values,
@@ -736,71 +1956,57 @@ mod tests {
                 flash_loan: 0,
             }),
         );
-
-        println!("{}", debt_adjustment.debt_adjustment_usd.abs());
         let rebalance_args = RebalanceSettings {
-            rebalance_type: SolautoRebalanceType::FLRebalanceThenSwap,
+            rebalance_type: SolautoRebalanceType::SwapCpi,
             target_liq_utilization_rate_bps: None,
             swap_in_amount_base_unit: Some(to_base_unit(
                 debt_adjustment.debt_adjustment_usd.abs().div(SUPPLY_PRICE),
                 TEST_TOKEN_DECIMALS,
             )),
-            flash_loan_fee_bps: None,
-            swap_type: Some(SwapType::ExactOut),
+            swap_type: Some(SwapType::ExactIn),
             price_type: None,
+            swap_cpi: None,
         };
         let rebalancer = &mut create_rebalancer(
             FakeRebalance {
                 pos: &mut position,
                 position_supply_ta_balance: None,
                 position_debt_ta_balance: None,
-                rebalance_direction: rebalance_direction.clone(),
             },
             rebalance_args,
             None,
         );
         let ta_creditor = &mut TaCreditor::new();
 
-        let flash_borrow = to_base_unit(
-            debt_adjustment.debt_adjustment_usd.abs().div(DEBT_PRICE),
-            TEST_TOKEN_DECIMALS,
-        );
-        credit_token_account(
-            rebalancer,
-            ta_creditor,
-            SolautoAccount::SolautoPositionDebtTa,
-            flash_borrow,
-        );
-
+        // Both steps run in the same instruction, around the swap CPI
         let res = rebalancer.rebalance(RebalanceStep::PreSwap);
         assert!(res.is_ok());
         apply_actions(rebalancer, ta_creditor);
 
-        validate_rebalance(rebalancer);
+        perform_swap(rebalancer, ta_creditor, &rebalance_direction, true);
 
-        let swap_output_amount = perform_swap(rebalancer, ta_creditor, &rebalance_direction, false);
+        let res = rebalancer.rebalance(RebalanceStep::PostSwap);
+        assert!(res.is_ok());
+        apply_actions(rebalancer, ta_creditor);
 
-        assert_eq!(
-            round_to_decimals(from_base_unit(swap_output_amount, TEST_TOKEN_DECIMALS), 4),
-            round_to_decimals(from_base_unit(flash_borrow as u64, TEST_TOKEN_DECIMALS), 4),
-            "Flash loan repayment (left) doesn't match expected (right)"
-        );
+        validate_rebalance(rebalancer);
     }
 
     #[test]
-    fn test_target_liq_utilization_rate_rebalance() {
+    fn test_swap_slippage_refused() {
         let pos_values = PositionValues {
             supply_usd: 100.0,
             debt_usd: 25.0,
         };
-        let rebalance_to = 3500;
+        let rebalance_to = 3800;
         let rebalance_direction = RebalanceDirection::Boost;
 
         let settings = SolautoSettingsParametersInp {
             boost_gap: 50,
-            boost_to_bps: rebalance_to + 300,
+            boost_to_bps: rebalance_to,
             repay_gap: 50,
             repay_to_bps: get_max_repay_to_bps(MAX_LTV_BPS, LIQ_THRESHOLD_BPS),
+            ..Default::default()
         };
         let mut position = create_position(
             &(FakePosition {
@@ -822,37 +2028,51 @@ mod tests {
         );
         let rebalance_args = RebalanceSettings {
             rebalance_type: SolautoRebalanceType::Regular,
-            target_liq_utilization_rate_bps: Some(rebalance_to),
+            target_liq_utilization_rate_bps: None,
             swap_in_amount_base_unit: Some(to_base_unit(
                 debt_adjustment.debt_adjustment_usd.div(DEBT_PRICE),
                 TEST_TOKEN_DECIMALS,
             )),
-            flash_loan_fee_bps: None,
             swap_type: Some(SwapType::ExactIn),
             price_type: None,
+            swap_cpi: None,
         };
+        // Dust already sitting in the output token account isn't counted as swap output
         let rebalancer = &mut create_rebalancer(
             FakeRebalance {
                 pos: &mut position,
-                position_supply_ta_balance: None,
+                position_supply_ta_balance: Some(to_base_unit(0.5, TEST_TOKEN_DECIMALS)),
                 position_debt_ta_balance: None,
-                rebalance_direction: rebalance_direction.clone(),
             },
             rebalance_args,
             None,
         );
         let ta_creditor = &mut TaCreditor::new();
 
-        let res = rebalancer.rebalance(RebalanceStep::PreSwap);
-        assert!(res.is_ok());
+        assert!(rebalancer.rebalance(RebalanceStep::PreSwap).is_ok());
         apply_actions(rebalancer, ta_creditor);
 
-        perform_swap(rebalancer, ta_creditor, &rebalance_direction, true);
+        // The swap returns 8% less than the oracle prices imply
+        let output_amount = perform_swap(rebalancer, ta_creditor, &rebalance_direction, true);
+        credit_token_account(
+            rebalancer,
+            ta_creditor,
+            SolautoAccount::SolautoPositionSupplyTa,
+            -((output_amount as f64 * 0.08) as i64),
+        );
 
-        let res = rebalancer.rebalance(RebalanceStep::PostSwap);
-        assert!(res.is_ok());
-        apply_actions(rebalancer, ta_creditor);
+        assert_eq!(
+            rebalancer.rebalance(RebalanceStep::PostSwap).err(),
+            Some(SolautoError::SwapSlippageTooHigh.into())
+        );
 
-        validate_rebalance(rebalancer);
+        rebalancer
+            .data
+            .solauto_position
+            .data
+            .position
+            .settings
+            .max_slippage_bps = 1000;
+        assert!(rebalancer.rebalance(RebalanceStep::PostSwap).is_ok());
     }
 }
@@ -0,0 +1,28 @@
+use crate::state::solauto_position::{SolautoPosition, SolautoSettingsParameters};
+
+use super::RebalanceStrategy;
+
+/// Boosts to boost_to once the liq utilization rate falls to boost_from, and repays to repay_to once it rises to repay_from
+pub struct BandStrategy {}
+
+impl RebalanceStrategy for BandStrategy {
+    fn strategy_target_bps(&self, solauto_position: &SolautoPosition) -> Option<u16> {
+        get_band_target_bps(solauto_position, &solauto_position.position.settings)
+    }
+}
+
+/// The band logic for the given setting parameters, for strategies that pick between several bands
+pub fn get_band_target_bps(
+    solauto_position: &SolautoPosition,
+    settings: &SolautoSettingsParameters,
+) -> Option<u16> {
+    let liq_utilization_rate_bps = solauto_position.state.liq_utilization_rate_bps;
+
+    if liq_utilization_rate_bps >= solauto_position.repay_from_bps_for(settings) {
+        Some(settings.repay_to_bps)
+    } else if liq_utilization_rate_bps <= solauto_position.boost_from_bps_for(settings) {
+        Some(settings.boost_to_bps)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,37 @@
+use crate::state::solauto_position::SolautoPosition;
+
+use super::RebalanceStrategy;
+
+/// Moves the position one grid level at a time, one level up for every step the supply / debt price ratio rises and one
+/// level down for every step it falls. The position's own repay_from still guards against the liq utilization rate
+/// running away between price steps
+pub struct GridStrategy {}
+
+impl RebalanceStrategy for GridStrategy {
+    fn strategy_target_bps(&self, solauto_position: &SolautoPosition) -> Option<u16> {
+        let grid = &solauto_position.grid;
+        let grid_step = solauto_position
+            .price_ratio()
+            .and_then(|price_ratio| grid.next_step(price_ratio));
+
+        if let Some(grid_step) = grid_step {
+            Some(grid.level_bps(grid_step.level))
+        } else if solauto_position.state.liq_utilization_rate_bps
+            >= solauto_position.repay_from_bps()
+        {
+            Some(solauto_position.position.settings.repay_to_bps)
+        } else {
+            None
+        }
+    }
+
+    fn record_rebalance(&self, solauto_position: &mut SolautoPosition) {
+        let grid_step = solauto_position
+            .price_ratio()
+            .and_then(|price_ratio| solauto_position.grid.next_step(price_ratio));
+
+        if let Some(grid_step) = grid_step {
+            solauto_position.grid.record_step(grid_step);
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use crate::state::solauto_position::SolautoPosition;
+
+use super::{band::get_band_target_bps, RebalanceStrategy};
+
+/// Rebalances within the band of the position's leverage curve that matches the current supply / debt price ratio,
+/// in place of the position's own boost_to / repay_to band
+pub struct LeverageCurveStrategy {}
+
+impl RebalanceStrategy for LeverageCurveStrategy {
+    fn strategy_target_bps(&self, solauto_position: &SolautoPosition) -> Option<u16> {
+        let band = solauto_position
+            .leverage_curve
+            .band_for(solauto_position.price_ratio()?)?;
+
+        get_band_target_bps(solauto_position, &band.settings())
+    }
+}
@@ -0,0 +1,121 @@
+use solana_program::program_error::ProgramError;
+
+use crate::{
+    state::solauto_position::{SolautoPosition, TokenBalanceChange},
+    types::{errors::SolautoError, instruction::RebalanceSettings, shared::RebalanceStrategyType},
+};
+
+use super::{
+    solauto_fees::SolautoFeesBps,
+    utils::{
+        boosts_refused, full_unwind_triggered, get_deleverage_order_target_bps,
+        get_negative_carry_target_bps, get_token_balance_change, get_token_balance_change_source,
+    },
+};
+
+use self::{band::BandStrategy, grid::GridStrategy, leverage_curve::LeverageCurveStrategy};
+
+pub mod band;
+pub mod grid;
+pub mod leverage_curve;
+
+/// Decides when a position is rebalanced, which liq utilization rate it is moved to, and which tokens are moved in or out
+/// of it. Orders & DCA are handled the same way for every strategy by the provided methods, so a strategy only decides
+/// the liq utilization rate it wants the position at
+pub trait RebalanceStrategy {
+    /// The liq utilization rate the position should be moved to, or None if the strategy doesn't require a rebalance
+    fn strategy_target_bps(&self, solauto_position: &SolautoPosition) -> Option<u16>;
+
+    /// Records any strategy state once a rebalance towards the strategy's own target has been set
+    fn record_rebalance(&self, _solauto_position: &mut SolautoPosition) {}
+
+    fn eligible_for_rebalance(
+        &self,
+        solauto_position: &SolautoPosition,
+        curr_unix_timestamp: u64,
+    ) -> bool {
+        self.allowed_target_bps(solauto_position).is_some()
+            || get_negative_carry_target_bps(solauto_position).is_some()
+            || get_token_balance_change_source(solauto_position, curr_unix_timestamp).is_some()
+    }
+
+    /// The strategy's target, unless it is a boost while boosts are refused
+    fn allowed_target_bps(&self, solauto_position: &SolautoPosition) -> Option<u16> {
+        let target_bps = self.strategy_target_bps(solauto_position)?;
+        if boosts_refused(solauto_position)
+            && target_bps > solauto_position.state.liq_utilization_rate_bps
+        {
+            None
+        } else {
+            Some(target_bps)
+        }
+    }
+
+    fn target_liq_utilization_rate_bps(
+        &self,
+        solauto_position: &SolautoPosition,
+        rebalance_args: &RebalanceSettings,
+        token_balance_change: &Option<TokenBalanceChange>,
+    ) -> Result<u16, ProgramError> {
+        if let Some(target_bps) = rebalance_args.target_liq_utilization_rate_bps {
+            return Ok(target_bps);
+        }
+
+        if full_unwind_triggered(solauto_position) {
+            return Ok(0);
+        }
+
+        let strategy_target_bps = self.allowed_target_bps(solauto_position);
+        let deleverage_target_bps = match (
+            get_deleverage_order_target_bps(solauto_position),
+            get_negative_carry_target_bps(solauto_position),
+        ) {
+            (Some(order_target_bps), Some(carry_target_bps)) => {
+                Some(order_target_bps.min(carry_target_bps))
+            }
+            (order_target_bps, carry_target_bps) => order_target_bps.or(carry_target_bps),
+        };
+
+        // An order or the carry guard never leaves the position above the strategy's own target if that target is a repay
+        if let Some(order_target_bps) = deleverage_target_bps {
+            return Ok(match strategy_target_bps {
+                Some(target_bps)
+                    if target_bps < solauto_position.state.liq_utilization_rate_bps =>
+                {
+                    order_target_bps.min(target_bps)
+                }
+                _ => order_target_bps,
+            });
+        }
+
+        if let Some(target_bps) = strategy_target_bps {
+            return Ok(target_bps);
+        } else if token_balance_change.is_some() {
+            return Ok(solauto_position.state.liq_utilization_rate_bps);
+        }
+
+        Err(SolautoError::InvalidRebalanceCondition.into())
+    }
+
+    fn token_balance_change(
+        &self,
+        solauto_position: &SolautoPosition,
+        solauto_fees_bps: &SolautoFeesBps,
+        curr_unix_timestamp: u64,
+    ) -> Option<TokenBalanceChange> {
+        get_token_balance_change(
+            solauto_position,
+            solauto_fees_bps,
+            get_token_balance_change_source(solauto_position, curr_unix_timestamp),
+            curr_unix_timestamp,
+        )
+    }
+}
+
+pub fn get_rebalance_strategy(solauto_position: &SolautoPosition) -> Box<dyn RebalanceStrategy> {
+    match solauto_position.position.rebalance_strategy {
+        RebalanceStrategyType::Band => Box::new(BandStrategy {}),
+        RebalanceStrategyType::LeverageCurve => Box::new(LeverageCurveStrategy {}),
+        RebalanceStrategyType::Grid => Box::new(GridStrategy {}),
+    }
+}
@@ -1,6 +1,10 @@
-use solana_program::program_error::ProgramError;
+use std::cmp::min;
+
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
 
 use crate::{
+    check, error_if,
+    flash_loan::{detect_flash_loan, FlashLoan},
     state::solauto_position::{
         RebalanceInstructionData, RebalanceStateValues, SolautoPosition, TokenBalanceChange,
         TokenBalanceChangeType,
@@ -8,39 +12,59 @@ use crate::{
     types::{
         errors::SolautoError,
         instruction::{RebalanceSettings, SolautoStandardAccounts},
-        shared::{RebalanceDirection, RebalanceStep, SolautoRebalanceType, SwapType},
+        shared::{RebalanceDirection, RebalanceStep, SolautoRebalanceType, SwapType, TokenType},
         solauto::{PositionValues, RebalanceFeesBps},
     },
     utils::{
-        ix_utils::{
-            get_flash_borrow_ix_idx, get_marginfi_flash_loan_amount,
-            validate_rebalance_instructions,
+        ix_utils::{get_flash_borrow_ix_idx, validate_rebalance_instructions},
+        math_utils::{
+            base_unit_to_usd_value, from_bps, from_rounded_usd_value, get_debt_adjustment,
+            get_liq_utilization_rate_bps, to_rounded_usd_value,
         },
-        math_utils::{from_rounded_usd_value, get_debt_adjustment},
     },
 };
 
-use super::solauto_fees::SolautoFeesBps;
+use super::{solauto_fees::SolautoFeesBps, strategies::get_rebalance_strategy};
 
-pub fn set_rebalance_ixs_data(
-    std_accounts: &mut Box<SolautoStandardAccounts>,
+pub fn set_rebalance_ixs_data<'a>(
+    std_accounts: &mut Box<SolautoStandardAccounts<'a>>,
     args: &RebalanceSettings,
+    accounts: &'a [AccountInfo<'a>],
 ) -> Result<RebalanceStep, ProgramError> {
     let has_rebalance_data = std_accounts.solauto_position.data.rebalance.active();
 
     if !has_rebalance_data {
-        validate_rebalance_instructions(std_accounts, args.rebalance_type)?;
-
-        let fl_borrow_ix_idx = get_flash_borrow_ix_idx(std_accounts, args.rebalance_type)?;
-        let flash_loan_amount = if fl_borrow_ix_idx.is_some() {
-            get_marginfi_flash_loan_amount(std_accounts.ixs_sysvar.unwrap(), fl_borrow_ix_idx)?
+        if args.rebalance_type == SolautoRebalanceType::SwapCpi {
+            // There are no other instructions to validate, and without a flash loan the swap can only be exact-in
+            check!(
+                args.swap_type.unwrap_or_default() == SwapType::ExactIn,
+                SolautoError::IncorrectInstructions
+            );
         } else {
-            0
+            validate_rebalance_instructions(std_accounts, args.rebalance_type)?;
+        }
+
+        let flash_loan = match get_flash_borrow_ix_idx(std_accounts, args.rebalance_type)? {
+            Some(ix_idx) => detect_flash_loan(std_accounts.ixs_sysvar.unwrap(), ix_idx, accounts)?
+                .ok_or(SolautoError::IncorrectInstructions)?,
+            None => {
+                // Flash loan rebalances must be funded by a flash borrow of a supported provider
+                error_if!(
+                    matches!(
+                        args.rebalance_type,
+                        SolautoRebalanceType::DoubleRebalanceWithFL
+                            | SolautoRebalanceType::FLRebalanceThenSwap
+                            | SolautoRebalanceType::FLSwapThenRebalance
+                    ),
+                    SolautoError::IncorrectInstructions
+                );
+                FlashLoan::default()
+            }
         };
 
         std_accounts.solauto_position.data.rebalance.ixs = RebalanceInstructionData::from(
             args.rebalance_type,
-            flash_loan_amount,
+            flash_loan,
             args.swap_type.unwrap_or(SwapType::default()),
         );
     }
@@ -56,6 +80,7 @@ pub fn set_rebalance_ixs_data(
             SolautoRebalanceType::Regular
                 | SolautoRebalanceType::DoubleRebalanceWithFL
                 | SolautoRebalanceType::FLRebalanceThenSwap
+                | SolautoRebalanceType::SwapCpi
         ) {
         RebalanceStep::PreSwap
     } else {
@@ -65,45 +90,353 @@ pub fn set_rebalance_ixs_data(
     Ok(rebalance_step)
 }
 
-pub fn eligible_for_rebalance(solauto_position: &Box<SolautoPosition>) -> bool {
-    // TODO: DCA, limit orders, take profit, stop loss, etc.
+/// Chooses which supply & debt legs the rebalance acts on. Must be run before the lending platform accounts
+/// are validated against the position, since the provided accounts are expected to match the chosen legs
+pub fn select_rebalance_legs(
+    solauto_position: &mut Box<SolautoPosition>,
+    args: &RebalanceSettings,
+) {
+    if solauto_position.rebalance.active() || !solauto_position.state.has_additional_legs() {
+        return;
+    }
 
-    solauto_position.state.liq_utilization_rate_bps <= solauto_position.boost_from_bps()
-        || solauto_position.state.liq_utilization_rate_bps >= solauto_position.repay_from_bps()
+    if let Ok(target_liq_utilization_rate_bps) = get_rebalance_strategy(solauto_position)
+        .target_liq_utilization_rate_bps(solauto_position, args, &None)
+    {
+        let rebalance_direction =
+            get_rebalance_direction(solauto_position, target_liq_utilization_rate_bps);
+        solauto_position.select_rebalance_legs(rebalance_direction);
+    }
 }
 
-fn get_target_liq_utilization_rate_bps(
+pub fn eligible_for_rebalance(
     solauto_position: &Box<SolautoPosition>,
-    rebalance_args: &RebalanceSettings,
-    token_balance_change: &Option<TokenBalanceChange>,
-) -> Result<u16, ProgramError> {
-    if rebalance_args.target_liq_utilization_rate_bps.is_some() {
-        return Ok(rebalance_args.target_liq_utilization_rate_bps.unwrap());
+    curr_unix_timestamp: u64,
+) -> bool {
+    get_rebalance_strategy(solauto_position)
+        .eligible_for_rebalance(solauto_position, curr_unix_timestamp)
+}
+
+fn get_dca_in_base_unit_amount(
+    solauto_position: &SolautoPosition,
+    curr_unix_timestamp: u64,
+) -> u64 {
+    let dca = &solauto_position.position.dca;
+    // Debt-token DCA is moved into the swap during the pre-swap step, which this rebalance type doesn't have
+    if dca.token_type == TokenType::Debt
+        && solauto_position.rebalance.ixs.rebalance_type
+            == SolautoRebalanceType::FLSwapThenRebalance
+    {
+        return 0;
+    }
+
+    dca.dca_in_period_amount(curr_unix_timestamp)
+}
+
+fn dca_out_period_due(solauto_position: &SolautoPosition, curr_unix_timestamp: u64) -> bool {
+    let dca = &solauto_position.position.dca;
+    // Debt-token DCA-out is paid out of the swap output, which isn't available when the rebalance finishes before an exact-out swap
+    if dca.token_type == TokenType::Debt
+        && solauto_position.rebalance.ixs.swap_type == SwapType::ExactOut
+    {
+        return false;
+    }
+
+    dca.dca_out_period_due(curr_unix_timestamp)
+}
+
+fn limit_order_triggered(solauto_position: &SolautoPosition) -> bool {
+    let limit_order = &solauto_position.limit_order;
+    // Debt-token deposits are moved into the swap during the pre-swap step, which this rebalance type doesn't have
+    if limit_order.token_type == TokenType::Debt
+        && solauto_position.rebalance.ixs.rebalance_type
+            == SolautoRebalanceType::FLSwapThenRebalance
+    {
+        return false;
     }
 
-    if solauto_position.state.liq_utilization_rate_bps >= solauto_position.repay_from_bps() {
-        return Ok(solauto_position.position.settings.repay_to_bps);
-    } else if solauto_position.state.liq_utilization_rate_bps <= solauto_position.boost_from_bps() {
-        return Ok(solauto_position.position.settings.boost_to_bps);
-    } else if token_balance_change.is_some() {
-        // TODO: DCA, limit orders, take profit, stop loss, etc.
-        return Ok(solauto_position.state.liq_utilization_rate_bps);
+    limit_order.triggered(solauto_position.state.supply.market_price())
+}
+
+/// Order trigger prices are of the position's only supply mint. Once the position has other legs,
+/// a rebalance can move any of them into the supply slot, so the price triggers don't apply
+fn single_leg(solauto_position: &SolautoPosition) -> bool {
+    !solauto_position.state.has_additional_legs()
+}
+
+fn stop_loss_triggered(solauto_position: &SolautoPosition) -> bool {
+    let supply_usd = solauto_position.state.supply_usd();
+    // Without a supply price, only the net worth floor can trigger
+    let supply_price = if single_leg(solauto_position) {
+        solauto_position.state.supply.market_price()
+    } else {
+        0.0
+    };
+    supply_usd > 0.0
+        && solauto_position
+            .stop_loss
+            .triggered(supply_price, supply_usd - solauto_position.state.debt_usd())
+}
+
+fn trailing_stop_triggered(solauto_position: &SolautoPosition) -> bool {
+    let trailing_stop = &solauto_position.trailing_stop;
+    single_leg(solauto_position)
+        && solauto_position.state.supply_usd() > 0.0
+        && trailing_stop.triggered(
+            solauto_position.state.supply.market_price(),
+            solauto_position.state.supply_price_high_water_mark(),
+        )
+        && (trailing_stop.target_liq_utilization_rate_bps == 0
+            || solauto_position.state.liq_utilization_rate_bps
+                > trailing_stop.target_liq_utilization_rate_bps)
+}
+
+/// Whether a stop-loss or trailing stop requires the position to be fully unwound to its authority
+pub fn full_unwind_triggered(solauto_position: &SolautoPosition) -> bool {
+    stop_loss_triggered(solauto_position)
+        || (trailing_stop_triggered(solauto_position)
+            && solauto_position
+                .trailing_stop
+                .target_liq_utilization_rate_bps
+                == 0)
+}
+
+fn take_profit_triggered(solauto_position: &SolautoPosition) -> bool {
+    let take_profit = &solauto_position.take_profit;
+    single_leg(solauto_position)
+        && take_profit.triggered(solauto_position.state.supply.market_price())
+        && (take_profit.withdraw_net_worth_bps > 0
+            || solauto_position.state.liq_utilization_rate_bps
+                > take_profit.target_liq_utilization_rate_bps)
+}
+
+/// The liq utilization rate a triggered trailing stop or take-profit cuts the position to. These orders only ever cut leverage
+pub fn get_deleverage_order_target_bps(solauto_position: &SolautoPosition) -> Option<u16> {
+    let order_target_liq_utilization_rate_bps = if trailing_stop_triggered(solauto_position) {
+        solauto_position
+            .trailing_stop
+            .target_liq_utilization_rate_bps
+    } else if take_profit_triggered(solauto_position) {
+        solauto_position.take_profit.target_liq_utilization_rate_bps
+    } else {
+        return None;
+    };
+
+    Some(min(
+        order_target_liq_utilization_rate_bps,
+        solauto_position.state.liq_utilization_rate_bps,
+    ))
+}
+
+/// Whether the carry guard is on & the position pays more interest on its debt than it earns on its supply
+pub fn negative_carry(solauto_position: &SolautoPosition) -> bool {
+    solauto_position.carry_guard.is_active()
+        && solauto_position.state.debt_usd() > 0.0
+        && solauto_position.state.net_carry_usd() < 0.0
+}
+
+/// The liq utilization rate the carry guard deleverages the position to while its net carry is negative
+pub fn get_negative_carry_target_bps(solauto_position: &SolautoPosition) -> Option<u16> {
+    let deleverage_to_bps = solauto_position.carry_guard.deleverage_to_bps;
+    if negative_carry(solauto_position)
+        && solauto_position.state.liq_utilization_rate_bps > deleverage_to_bps
+    {
+        Some(deleverage_to_bps)
+    } else {
+        None
     }
+}
 
-    Err(SolautoError::InvalidRebalanceCondition.into())
+/// Whether the realtime & EMA prices loaded for the current rebalance diverge by more than the position allows
+pub fn price_divergence_exceeded(solauto_position: &SolautoPosition) -> bool {
+    let max_price_divergence_bps = solauto_position.position.max_price_divergence_bps;
+    max_price_divergence_bps > 0
+        && solauto_position.state.price_divergence_bps > max_price_divergence_bps
 }
 
-fn get_token_balance_change() -> Option<TokenBalanceChange> {
-    // TODO: DCA, limit orders, take profit, stop loss, etc.
-    None
+/// Whether any rebalance that adds leverage must be refused
+pub fn boosts_refused(solauto_position: &SolautoPosition) -> bool {
+    negative_carry(solauto_position) || price_divergence_exceeded(solauto_position)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TokenBalanceChangeSource {
+    StopLoss,
+    TrailingStop,
+    TakeProfit,
+    DCAOut,
+    LimitOrder,
+    DCAIn,
+}
+
+/// Which automation the token balance change of the current rebalance comes from. Only one is applied per rebalance,
+/// the rest are left for a later rebalance
+pub fn get_token_balance_change_source(
+    solauto_position: &SolautoPosition,
+    curr_unix_timestamp: u64,
+) -> Option<TokenBalanceChangeSource> {
+    if stop_loss_triggered(solauto_position) {
+        Some(TokenBalanceChangeSource::StopLoss)
+    } else if trailing_stop_triggered(solauto_position) {
+        Some(TokenBalanceChangeSource::TrailingStop)
+    } else if take_profit_triggered(solauto_position) {
+        Some(TokenBalanceChangeSource::TakeProfit)
+    } else if dca_out_period_due(solauto_position, curr_unix_timestamp) {
+        Some(TokenBalanceChangeSource::DCAOut)
+    } else if limit_order_triggered(solauto_position) {
+        Some(TokenBalanceChangeSource::LimitOrder)
+    } else if get_dca_in_base_unit_amount(solauto_position, curr_unix_timestamp) > 0 {
+        Some(TokenBalanceChangeSource::DCAIn)
+    } else {
+        None
+    }
+}
+
+fn get_rebalance_fees(
+    solauto_position: &SolautoPosition,
+    solauto_fees_bps: &SolautoFeesBps,
+    rebalance_direction: &RebalanceDirection,
+) -> RebalanceFeesBps {
+    RebalanceFeesBps {
+        solauto: solauto_fees_bps.fetch_fees(rebalance_direction).total,
+        lp_borrow: solauto_position.state.debt.borrow_fee_bps,
+        flash_loan: solauto_position.rebalance.ixs.flash_loan_fee_bps,
+    }
+}
+
+/// Net worth (in USD) that is left after the fees it would take to repay all debt
+fn get_withdrawable_net_worth_usd(
+    solauto_position: &SolautoPosition,
+    solauto_fees_bps: &SolautoFeesBps,
+) -> f64 {
+    let fees = get_rebalance_fees(
+        solauto_position,
+        solauto_fees_bps,
+        &RebalanceDirection::Repay,
+    );
+    let supply_usd_to_repay_debt = solauto_position.state.debt_usd()
+        * (1.0 + from_bps(fees.flash_loan))
+        / (1.0 - from_bps(fees.solauto));
+
+    (solauto_position.state.supply_usd() - supply_usd_to_repay_debt).max(0.0)
+}
+
+/// The slice of net worth (in USD) to withdraw in the current DCA-out period. Net worth is measured after the fees
+/// it would take to repay all debt, so that the last period fully unwinds the position
+fn get_dca_out_amount_usd(
+    solauto_position: &SolautoPosition,
+    solauto_fees_bps: &SolautoFeesBps,
+    curr_unix_timestamp: u64,
+) -> f64 {
+    let net_worth_usd = get_withdrawable_net_worth_usd(solauto_position, solauto_fees_bps);
+
+    net_worth_usd
+        - solauto_position
+            .position
+            .dca
+            .automation
+            .updated_amount_from_automation(net_worth_usd, 0.0, curr_unix_timestamp)
+}
+
+fn get_deposit_balance_change(
+    solauto_position: &SolautoPosition,
+    token_type: TokenType,
+    base_unit_amount: u64,
+) -> TokenBalanceChange {
+    let (change_type, token) = if token_type == TokenType::Supply {
+        (
+            TokenBalanceChangeType::PreSwapDeposit,
+            &solauto_position.state.supply,
+        )
+    } else {
+        (
+            TokenBalanceChangeType::PostSwapDeposit,
+            &solauto_position.state.debt,
+        )
+    };
+
+    TokenBalanceChange::from(
+        change_type,
+        to_rounded_usd_value(base_unit_to_usd_value(
+            base_unit_amount,
+            token.decimals,
+            token.market_price(),
+        )),
+    )
+}
+
+pub fn get_token_balance_change(
+    solauto_position: &SolautoPosition,
+    solauto_fees_bps: &SolautoFeesBps,
+    source: Option<TokenBalanceChangeSource>,
+    curr_unix_timestamp: u64,
+) -> Option<TokenBalanceChange> {
+    let dca = &solauto_position.position.dca;
+
+    match source? {
+        TokenBalanceChangeSource::StopLoss | TokenBalanceChangeSource::TrailingStop => {
+            if !full_unwind_triggered(solauto_position) {
+                return None;
+            }
+
+            // Everything that is left once all debt is repaid goes back to the authority
+            Some(TokenBalanceChange::from(
+                TokenBalanceChangeType::PostRebalanceWithdrawSupplyToken,
+                to_rounded_usd_value(get_withdrawable_net_worth_usd(
+                    solauto_position,
+                    solauto_fees_bps,
+                )),
+            ))
+        }
+        TokenBalanceChangeSource::TakeProfit => {
+            let withdraw_net_worth_bps = solauto_position.take_profit.withdraw_net_worth_bps;
+            if withdraw_net_worth_bps == 0 {
+                return None;
+            }
+
+            let net_worth_usd = get_withdrawable_net_worth_usd(solauto_position, solauto_fees_bps);
+            Some(TokenBalanceChange::from(
+                TokenBalanceChangeType::PostRebalanceWithdrawSupplyToken,
+                to_rounded_usd_value(net_worth_usd * from_bps(withdraw_net_worth_bps)),
+            ))
+        }
+        TokenBalanceChangeSource::DCAOut => {
+            let change_type = if dca.token_type == TokenType::Supply {
+                TokenBalanceChangeType::PostRebalanceWithdrawSupplyToken
+            } else {
+                TokenBalanceChangeType::PostRebalanceWithdrawDebtToken
+            };
+            Some(TokenBalanceChange::from(
+                change_type,
+                to_rounded_usd_value(get_dca_out_amount_usd(
+                    solauto_position,
+                    solauto_fees_bps,
+                    curr_unix_timestamp,
+                )),
+            ))
+        }
+        TokenBalanceChangeSource::LimitOrder => {
+            let limit_order = &solauto_position.limit_order;
+            Some(get_deposit_balance_change(
+                solauto_position,
+                limit_order.token_type,
+                limit_order.amount_base_unit,
+            ))
+        }
+        TokenBalanceChangeSource::DCAIn => Some(get_deposit_balance_change(
+            solauto_position,
+            dca.token_type,
+            get_dca_in_base_unit_amount(solauto_position, curr_unix_timestamp),
+        )),
+    }
 }
 
 fn get_adjusted_position_values(
     solauto_position: &Box<SolautoPosition>,
     token_balance_change: &Option<TokenBalanceChange>,
 ) -> PositionValues {
-    let mut supply_usd = solauto_position.state.supply.amount_used.usd_value();
-    let debt_usd = solauto_position.state.debt.amount_used.usd_value();
+    let mut supply_usd = solauto_position.state.supply_usd();
+    let debt_usd = solauto_position.state.debt_usd();
 
     if token_balance_change.is_some() {
         let tb = token_balance_change.as_ref().unwrap();
@@ -129,7 +462,17 @@ fn get_rebalance_direction(
     solauto_position: &Box<SolautoPosition>,
     target_ltv_bps: u16,
 ) -> RebalanceDirection {
-    if solauto_position.state.liq_utilization_rate_bps < target_ltv_bps {
+    get_rebalance_direction_from(
+        solauto_position.state.liq_utilization_rate_bps,
+        target_ltv_bps,
+    )
+}
+
+fn get_rebalance_direction_from(
+    liq_utilization_rate_bps: u16,
+    target_ltv_bps: u16,
+) -> RebalanceDirection {
+    if liq_utilization_rate_bps < target_ltv_bps {
         RebalanceDirection::Boost
     } else {
         RebalanceDirection::Repay
@@ -140,21 +483,81 @@ pub fn get_rebalance_values(
     solauto_position: &Box<SolautoPosition>,
     rebalance_args: &RebalanceSettings,
     solauto_fees_bps: &SolautoFeesBps,
+    curr_unix_timestamp: u64,
 ) -> Result<RebalanceStateValues, ProgramError> {
-    let token_balance_change = get_token_balance_change();
-    let target_liq_utilization_rate_bps = get_target_liq_utilization_rate_bps(
+    let strategy = get_rebalance_strategy(solauto_position);
+    let source = get_token_balance_change_source(solauto_position, curr_unix_timestamp);
+    let mut token_balance_change =
+        strategy.token_balance_change(solauto_position, solauto_fees_bps, curr_unix_timestamp);
+    let mut target_liq_utilization_rate_bps = strategy.target_liq_utilization_rate_bps(
         solauto_position,
         rebalance_args,
         &token_balance_change,
     )?;
-    let rebalance_direction =
-        get_rebalance_direction(solauto_position, target_liq_utilization_rate_bps);
+
+    let withdrawing = token_balance_change.is_some()
+        && matches!(
+            token_balance_change.unwrap().change_type,
+            TokenBalanceChangeType::PostRebalanceWithdrawSupplyToken
+                | TokenBalanceChangeType::PostRebalanceWithdrawDebtToken
+        );
+    if source == Some(TokenBalanceChangeSource::DCAOut)
+        && rebalance_args.target_liq_utilization_rate_bps.is_none()
+    {
+        // A DCA-out steps the liq utilization rate down to 0 over its remaining periods
+        target_liq_utilization_rate_bps = min(
+            target_liq_utilization_rate_bps,
+            solauto_position
+                .position
+                .dca
+                .automation
+                .updated_amount_from_automation(
+                    solauto_position.state.liq_utilization_rate_bps,
+                    0,
+                    curr_unix_timestamp,
+                ),
+        );
+    }
+
+    // Incoming tokens are only put to work on a boost, and outgoing tokens are only taken out on a repay.
+    // Otherwise the token balance change is left for a later rebalance
+    if token_balance_change.is_some() {
+        let adjusted = get_adjusted_position_values(solauto_position, &token_balance_change);
+        let adjusted_liq_utilization_rate_bps = get_liq_utilization_rate_bps(
+            adjusted.supply_usd,
+            adjusted.debt_usd,
+            from_bps(solauto_position.state.liq_threshold_bps),
+        );
+        let expected_direction = if withdrawing {
+            RebalanceDirection::Repay
+        } else {
+            RebalanceDirection::Boost
+        };
+        if get_rebalance_direction_from(
+            adjusted_liq_utilization_rate_bps,
+            target_liq_utilization_rate_bps,
+        ) != expected_direction
+        {
+            token_balance_change = None;
+            target_liq_utilization_rate_bps = strategy.target_liq_utilization_rate_bps(
+                solauto_position,
+                rebalance_args,
+                &None,
+            )?;
+        }
+    }
+
     let position = get_adjusted_position_values(solauto_position, &token_balance_change);
-    let fees = RebalanceFeesBps {
-        solauto: solauto_fees_bps.fetch_fees(&rebalance_direction).total,
-        lp_borrow: solauto_position.state.debt.borrow_fee_bps,
-        flash_loan: rebalance_args.flash_loan_fee_bps.unwrap_or(0),
+    let rebalance_direction = if token_balance_change.is_some() {
+        if withdrawing {
+            RebalanceDirection::Repay
+        } else {
+            RebalanceDirection::Boost
+        }
+    } else {
+        get_rebalance_direction(solauto_position, target_liq_utilization_rate_bps)
     };
+    let fees = get_rebalance_fees(solauto_position, solauto_fees_bps, &rebalance_direction);
 
     let debt_adjustment = get_debt_adjustment(
         solauto_position.state.liq_threshold_bps,
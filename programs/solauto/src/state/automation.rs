@@ -122,9 +122,50 @@ impl DCASettings {
         self.dca_in_base_unit > 0
     }
     #[inline(always)]
+    pub fn dca_out(&self) -> bool {
+        self.is_active() && !self.dca_in()
+    }
+    #[inline(always)]
     pub fn is_active(&self) -> bool {
         self.automation.is_active()
     }
+    #[inline(always)]
+    pub fn dca_out_period_due(&self, curr_unix_timestamp: u64) -> bool {
+        self.dca_out()
+            && self
+                .automation
+                .eligible_for_next_period(curr_unix_timestamp)
+    }
+    /// The DCA-in amount that is due, if the next period is eligible. Catches up on the periods that were missed
+    pub fn dca_in_period_amount(&self, curr_unix_timestamp: u64) -> u64 {
+        if !self.is_active()
+            || !self.dca_in()
+            || !self
+                .automation
+                .eligible_for_next_period(curr_unix_timestamp)
+        {
+            return 0;
+        }
+
+        let remaining = self.automation.updated_amount_from_automation(
+            self.dca_in_base_unit,
+            0,
+            curr_unix_timestamp,
+        );
+        self.dca_in_base_unit - remaining
+    }
+    /// Records the DCA-in amount that was used for the current period (0 for a DCA-out). Resets the DCA once the
+    /// last period has passed, or once a DCA-in has nothing left to add
+    pub fn complete_period(&mut self, dca_in_base_unit_used: u64, curr_unix_timestamp: u64) {
+        let dca_in = self.dca_in();
+        self.dca_in_base_unit = self.dca_in_base_unit.saturating_sub(dca_in_base_unit_used);
+        self.automation.periods_passed = self.automation.new_periods_passed(curr_unix_timestamp);
+        if self.automation.periods_passed >= self.automation.target_periods
+            || (dca_in && !self.dca_in())
+        {
+            *self = DCASettings::default();
+        }
+    }
 }
 
 mod tests {
@@ -157,6 +198,51 @@ mod tests {
         assert!(automation.new_periods_passed(5 * 5) == 4);
     }
 
+    #[test]
+    fn validate_dca_in_periods() {
+        let mut dca = DCASettings::from(DCASettingsInp {
+            automation: AutomationSettingsInp {
+                target_periods: 3,
+                periods_passed: 0,
+                unix_start_date: 0,
+                interval_seconds: 5,
+            },
+            dca_in_base_unit: 100,
+            token_type: TokenType::Debt,
+        });
+
+        assert!(dca.dca_in_period_amount(0) == 34);
+        dca.complete_period(34, 0);
+        assert!(dca.dca_in_base_unit == 66 && dca.automation.periods_passed == 1);
+        assert!(dca.dca_in_period_amount(4) == 0);
+
+        // A missed period is caught up on
+        assert!(dca.dca_in_period_amount(10) == 66);
+        dca.complete_period(66, 10);
+        assert!(!dca.is_active());
+    }
+
+    #[test]
+    fn validate_dca_out_periods() {
+        let mut dca = DCASettings::from(DCASettingsInp {
+            automation: AutomationSettingsInp {
+                target_periods: 2,
+                periods_passed: 0,
+                unix_start_date: 0,
+                interval_seconds: 5,
+            },
+            dca_in_base_unit: 0,
+            token_type: TokenType::Supply,
+        });
+
+        assert!(dca.dca_out() && dca.dca_out_period_due(0));
+        dca.complete_period(0, 0);
+        assert!(dca.dca_out() && !dca.dca_out_period_due(4));
+        assert!(dca.dca_out_period_due(5));
+        dca.complete_period(0, 5);
+        assert!(!dca.is_active());
+    }
+
     #[test]
     fn validate_updated_automation_value() {
         let mut automation = AutomationSettings::from(AutomationSettingsInp {
@@ -0,0 +1,39 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use shank::ShankType;
+
+use crate::types::shared::PodBool;
+
+#[derive(BorshDeserialize, Clone, Debug, Copy, Default)]
+pub struct CarryGuardInp {
+    /// Deleverage the position, and refuse boosts, while its net carry is negative. If false, the carry guard is removed
+    pub enabled: bool,
+    /// Liq utilization rate the position is deleveraged to once its net carry turns negative
+    pub deleverage_to_bps: u16,
+}
+
+#[repr(C, align(8))]
+#[derive(
+    ShankType, BorshSerialize, BorshDeserialize, Clone, Debug, Default, Copy, Pod, Zeroable,
+)]
+pub struct CarryGuard {
+    pub deleverage_to_bps: u16,
+    pub enabled: PodBool,
+    _padding1: [u8; 5],
+    _padding: [u8; 32],
+}
+
+impl CarryGuard {
+    pub fn from(args: CarryGuardInp) -> Self {
+        Self {
+            deleverage_to_bps: args.deleverage_to_bps,
+            enabled: PodBool::new(args.enabled),
+            _padding1: [0; 5],
+            _padding: [0; 32],
+        }
+    }
+    #[inline(always)]
+    pub fn is_active(&self) -> bool {
+        self.enabled.val
+    }
+}
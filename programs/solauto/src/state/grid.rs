@@ -0,0 +1,211 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use shank::ShankType;
+use std::cmp::min;
+
+use crate::utils::math_utils::{from_base_unit, from_bps, to_base_unit};
+
+use super::leverage_curve::PRICE_RATIO_DECIMALS;
+
+pub const MIN_GRID_LEVELS: u8 = 2;
+pub const MAX_GRID_LEVELS: u8 = 20;
+
+#[derive(BorshDeserialize, Clone, Debug, Copy, Default)]
+pub struct GridSettingsInp {
+    /// Liq utilization rate of the lowest grid level
+    pub lower_liq_utilization_rate_bps: u16,
+    /// Liq utilization rate of the highest grid level
+    pub upper_liq_utilization_rate_bps: u16,
+    /// Number of evenly spaced levels between (and including) the lower & upper liq utilization rates
+    pub level_count: u8,
+    /// How far (in bps) the supply / debt price ratio must move before the position is moved by one level
+    pub price_step_bps: u16,
+}
+
+/// A step of the grid that is due to be executed
+#[derive(Clone, Debug, Copy, PartialEq)]
+pub struct GridStep {
+    pub level: u8,
+    /// Denominated by 9 decimal places
+    pub anchor_price_ratio: u64,
+}
+
+#[repr(C, align(8))]
+#[derive(
+    ShankType, BorshSerialize, BorshDeserialize, Clone, Debug, Default, Copy, Pod, Zeroable,
+)]
+pub struct Grid {
+    pub lower_liq_utilization_rate_bps: u16,
+    pub upper_liq_utilization_rate_bps: u16,
+    pub price_step_bps: u16,
+    /// If this is 0, there is no grid
+    pub level_count: u8,
+    /// Grid level the position was last moved to
+    pub last_level: u8,
+    /// Supply / debt price ratio the last level was executed at. Denominated by 9 decimal places.
+    /// If this is 0, the grid hasn't been anchored to a price yet, which the next refresh does
+    pub anchor_price_ratio: u64,
+    _padding: [u8; 32],
+}
+
+impl Grid {
+    /// Starts the grid at the level closest to the given liq utilization rate, anchored at the given price ratio if known
+    pub fn from(
+        args: GridSettingsInp,
+        liq_utilization_rate_bps: u16,
+        price_ratio: Option<f64>,
+    ) -> Self {
+        let mut grid = Self {
+            lower_liq_utilization_rate_bps: args.lower_liq_utilization_rate_bps,
+            upper_liq_utilization_rate_bps: args.upper_liq_utilization_rate_bps,
+            price_step_bps: args.price_step_bps,
+            level_count: args.level_count,
+            last_level: 0,
+            anchor_price_ratio: 0,
+            _padding: [0; 32],
+        };
+        if let Some(price_ratio) = price_ratio {
+            grid.anchor(liq_utilization_rate_bps, price_ratio);
+        }
+        grid
+    }
+    /// Starts the grid at the level closest to the given liq utilization rate, with the given price ratio as its anchor
+    pub fn anchor(&mut self, liq_utilization_rate_bps: u16, price_ratio: f64) {
+        if price_ratio == 0.0 {
+            return;
+        }
+        self.last_level = self.nearest_level(liq_utilization_rate_bps);
+        self.anchor_price_ratio = to_base_unit(price_ratio, PRICE_RATIO_DECIMALS);
+    }
+    #[inline(always)]
+    pub fn is_active(&self) -> bool {
+        self.level_count > 0
+    }
+    #[inline(always)]
+    pub fn is_anchored(&self) -> bool {
+        self.anchor_price_ratio > 0
+    }
+    #[inline(always)]
+    pub fn anchor_price_ratio(&self) -> f64 {
+        from_base_unit(self.anchor_price_ratio, PRICE_RATIO_DECIMALS)
+    }
+    #[inline(always)]
+    fn level_spacing_bps(&self) -> f64 {
+        if self.level_count < MIN_GRID_LEVELS {
+            return 0.0;
+        }
+        self.upper_liq_utilization_rate_bps
+            .saturating_sub(self.lower_liq_utilization_rate_bps) as f64
+            / (self.level_count - 1) as f64
+    }
+    pub fn level_bps(&self, level: u8) -> u16 {
+        self.lower_liq_utilization_rate_bps + (self.level_spacing_bps() * level as f64) as u16
+    }
+    pub fn nearest_level(&self, liq_utilization_rate_bps: u16) -> u8 {
+        let level_spacing_bps = self.level_spacing_bps();
+        if level_spacing_bps == 0.0 {
+            return 0;
+        }
+        let offset_bps =
+            liq_utilization_rate_bps.saturating_sub(self.lower_liq_utilization_rate_bps) as f64;
+        min(
+            (offset_bps / level_spacing_bps).round() as u8,
+            self.level_count - 1,
+        )
+    }
+    /// The next level the position should be moved to at the given price ratio, if any. A price move of several steps
+    /// still only moves the position by one level, with the anchor moved by one step, so that no level is skipped.
+    /// At the lowest & highest level the position is brought back to that level. A grid that isn't anchored yet has no next level
+    pub fn next_step(&self, price_ratio: f64) -> Option<GridStep> {
+        if !self.is_active() || !self.is_anchored() || price_ratio == 0.0 {
+            return None;
+        }
+
+        let step = 1.0 + from_bps(self.price_step_bps);
+        let anchor_price_ratio = self.anchor_price_ratio();
+        if price_ratio >= anchor_price_ratio * step {
+            // A rising supply price lowers the liq utilization rate, so the position moves up a level
+            Some(GridStep {
+                level: min(self.last_level + 1, self.level_count - 1),
+                anchor_price_ratio: to_base_unit(anchor_price_ratio * step, PRICE_RATIO_DECIMALS),
+            })
+        } else if price_ratio <= anchor_price_ratio / step {
+            Some(GridStep {
+                level: self.last_level.saturating_sub(1),
+                anchor_price_ratio: to_base_unit(anchor_price_ratio / step, PRICE_RATIO_DECIMALS),
+            })
+        } else {
+            None
+        }
+    }
+    pub fn record_step(&mut self, step: GridStep) {
+        self.last_level = step.level;
+        self.anchor_price_ratio = step.anchor_price_ratio;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid_steps() {
+        let mut grid = Grid::from(
+            GridSettingsInp {
+                lower_liq_utilization_rate_bps: 2000,
+                upper_liq_utilization_rate_bps: 6000,
+                level_count: 5,
+                price_step_bps: 1000,
+            },
+            4100,
+            Some(100.0),
+        );
+        assert!(grid.last_level == 2);
+        assert!(
+            grid.level_bps(0) == 2000 && grid.level_bps(2) == 4000 && grid.level_bps(4) == 6000
+        );
+
+        assert!(grid.next_step(105.0).is_none());
+        assert!(grid.next_step(95.0).is_none());
+
+        // A move of several steps only moves one level
+        let step = grid.next_step(150.0).unwrap();
+        assert!(step.level == 3);
+        assert!(
+            step.anchor_price_ratio == to_base_unit::<f64, u8, u64>(110.0, PRICE_RATIO_DECIMALS)
+        );
+        grid.record_step(step);
+
+        let step = grid.next_step(150.0).unwrap();
+        assert!(step.level == 4);
+        grid.record_step(step);
+
+        // The highest level is kept, while the anchor keeps following the price
+        let step = grid.next_step(150.0).unwrap();
+        assert!(step.level == 4 && step.anchor_price_ratio > grid.anchor_price_ratio);
+        grid.record_step(step);
+
+        let step = grid.next_step(grid.anchor_price_ratio() / 1.1).unwrap();
+        assert!(step.level == 3);
+
+        let unanchored = Grid::from(
+            GridSettingsInp {
+                lower_liq_utilization_rate_bps: 2000,
+                upper_liq_utilization_rate_bps: 6000,
+                level_count: 5,
+                price_step_bps: 1000,
+            },
+            0,
+            None,
+        );
+        assert!(unanchored.next_step(100.0).is_none());
+        assert!(Grid::default().next_step(100.0).is_none());
+
+        // Anchoring starts the grid at the current level & price ratio, after which the price must move a step
+        let mut anchored = unanchored;
+        anchored.anchor(5900, 100.0);
+        assert!(anchored.last_level == 4);
+        assert!(anchored.next_step(105.0).is_none());
+        assert!(anchored.next_step(90.0).unwrap().level == 3);
+    }
+}
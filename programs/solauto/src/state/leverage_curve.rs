@@ -0,0 +1,141 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use shank::ShankType;
+
+use crate::utils::math_utils::from_base_unit;
+
+use super::solauto_position::{SolautoSettingsParameters, SolautoSettingsParametersInp};
+
+pub const MAX_LEVERAGE_CURVE_BANDS: usize = 4;
+pub const PRICE_RATIO_DECIMALS: u8 = 9;
+
+#[derive(BorshDeserialize, Clone, Debug, Copy, Default)]
+pub struct LeverageCurveBandInp {
+    /// Supply / debt price ratio from which this band applies. Denominated by 9 decimal places
+    pub min_price_ratio: u64,
+    pub boost_to_bps: u16,
+    pub boost_gap: u16,
+    pub repay_to_bps: u16,
+    pub repay_gap: u16,
+}
+
+#[derive(BorshDeserialize, Clone, Debug, Default)]
+pub struct LeverageCurveInp {
+    /// Bands in ascending order of their minimum price ratio. The first band must start at a price ratio of 0
+    pub bands: Vec<LeverageCurveBandInp>,
+}
+
+#[repr(C, align(8))]
+#[derive(
+    ShankType, BorshSerialize, BorshDeserialize, Clone, Debug, Default, Copy, Pod, Zeroable,
+)]
+pub struct LeverageCurveBand {
+    /// Supply / debt price ratio from which this band applies. Denominated by 9 decimal places
+    pub min_price_ratio: u64,
+    pub boost_to_bps: u16,
+    pub boost_gap: u16,
+    pub repay_to_bps: u16,
+    pub repay_gap: u16,
+}
+
+impl LeverageCurveBand {
+    pub fn from(args: LeverageCurveBandInp) -> Self {
+        Self {
+            min_price_ratio: args.min_price_ratio,
+            boost_to_bps: args.boost_to_bps,
+            boost_gap: args.boost_gap,
+            repay_to_bps: args.repay_to_bps,
+            repay_gap: args.repay_gap,
+        }
+    }
+    #[inline(always)]
+    pub fn min_price_ratio(&self) -> f64 {
+        from_base_unit(self.min_price_ratio, PRICE_RATIO_DECIMALS)
+    }
+    /// The band as setting parameters, so that it can be validated & rebalanced like the position's own settings
+    pub fn settings(&self) -> SolautoSettingsParameters {
+        SolautoSettingsParameters::from(SolautoSettingsParametersInp {
+            boost_to_bps: self.boost_to_bps,
+            boost_gap: self.boost_gap,
+            repay_to_bps: self.repay_to_bps,
+            repay_gap: self.repay_gap,
+            ..Default::default()
+        })
+    }
+}
+
+#[repr(C, align(8))]
+#[derive(
+    ShankType, BorshSerialize, BorshDeserialize, Clone, Debug, Default, Copy, Pod, Zeroable,
+)]
+pub struct LeverageCurve {
+    pub bands: [LeverageCurveBand; 4],
+    pub band_count: u8,
+    _padding: [u8; 7],
+}
+
+impl LeverageCurve {
+    /// Returns None if more bands are provided than can be stored
+    pub fn from(args: &LeverageCurveInp) -> Option<Self> {
+        if args.bands.len() > MAX_LEVERAGE_CURVE_BANDS {
+            return None;
+        }
+
+        let mut curve = Self::default();
+        for (idx, band) in args.bands.iter().enumerate() {
+            curve.bands[idx] = LeverageCurveBand::from(*band);
+        }
+        curve.band_count = args.bands.len() as u8;
+        Some(curve)
+    }
+    #[inline(always)]
+    pub fn is_active(&self) -> bool {
+        self.band_count > 0
+    }
+    #[inline(always)]
+    pub fn active_bands(&self) -> &[LeverageCurveBand] {
+        &self.bands[..self.band_count as usize]
+    }
+    /// The band with the highest minimum price ratio that the given price ratio has reached
+    pub fn band_for(&self, price_ratio: f64) -> Option<&LeverageCurveBand> {
+        self.active_bands()
+            .iter()
+            .rev()
+            .find(|band| price_ratio >= band.min_price_ratio())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::math_utils::to_base_unit;
+
+    fn band(min_price_ratio: f64, boost_to_bps: u16) -> LeverageCurveBandInp {
+        LeverageCurveBandInp {
+            min_price_ratio: to_base_unit(min_price_ratio, PRICE_RATIO_DECIMALS),
+            boost_to_bps,
+            boost_gap: 500,
+            repay_to_bps: boost_to_bps + 1000,
+            repay_gap: 500,
+        }
+    }
+
+    #[test]
+    fn test_leverage_curve_band_selection() {
+        let curve = LeverageCurve::from(&LeverageCurveInp {
+            bands: vec![band(0.0, 6000), band(120.0, 4500), band(180.0, 1500)],
+        })
+        .unwrap();
+
+        assert!(curve.band_for(100.0).unwrap().boost_to_bps == 6000);
+        assert!(curve.band_for(120.0).unwrap().boost_to_bps == 4500);
+        assert!(curve.band_for(179.9).unwrap().boost_to_bps == 4500);
+        assert!(curve.band_for(250.0).unwrap().boost_to_bps == 1500);
+
+        assert!(LeverageCurve::default().band_for(100.0).is_none());
+        assert!(LeverageCurve::from(&LeverageCurveInp {
+            bands: vec![band(0.0, 6000); MAX_LEVERAGE_CURVE_BANDS + 1],
+        })
+        .is_none());
+    }
+}
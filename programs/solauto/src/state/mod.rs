@@ -1,3 +1,7 @@
 pub mod automation;
+pub mod carry_guard;
+pub mod grid;
+pub mod leverage_curve;
+pub mod orders;
 pub mod referral_state;
 pub mod solauto_position;
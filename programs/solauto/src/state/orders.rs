@@ -0,0 +1,288 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use shank::ShankType;
+
+use crate::{
+    types::shared::{PodBool, TokenType},
+    utils::math_utils::{from_bps, from_rounded_usd_value},
+};
+
+#[derive(BorshDeserialize, Clone, Debug, Copy, Default)]
+pub struct LimitOrderInp {
+    /// Base unit amount of the deposit token to escrow in the position token account
+    pub amount_base_unit: u64,
+    /// Which of the position's tokens is deposited when the order fills
+    pub token_type: TokenType,
+    /// Supply token price (USD) that fills the order. Denominated by 9 decimal places
+    pub trigger_price_usd: u64,
+    /// Fill once the supply price rises to the trigger price, rather than falls to it
+    pub trigger_above: bool,
+}
+
+#[repr(C, align(8))]
+#[derive(
+    ShankType, BorshSerialize, BorshDeserialize, Clone, Debug, Default, Copy, Pod, Zeroable,
+)]
+pub struct LimitOrder {
+    /// Base unit amount escrowed in the position token account. If this is 0, there is no open order
+    pub amount_base_unit: u64,
+    /// Supply token price (USD) that fills the order. Denominated by 9 decimal places
+    pub trigger_price_usd: u64,
+    pub token_type: TokenType,
+    pub trigger_above: PodBool,
+    _padding1: [u8; 6],
+    _padding: [u8; 32],
+}
+
+impl LimitOrder {
+    pub fn from(args: LimitOrderInp) -> Self {
+        Self {
+            amount_base_unit: args.amount_base_unit,
+            trigger_price_usd: args.trigger_price_usd,
+            token_type: args.token_type,
+            trigger_above: PodBool::new(args.trigger_above),
+            _padding1: [0; 6],
+            _padding: [0; 32],
+        }
+    }
+    #[inline(always)]
+    pub fn is_active(&self) -> bool {
+        self.amount_base_unit > 0
+    }
+    #[inline(always)]
+    pub fn trigger_price(&self) -> f64 {
+        from_rounded_usd_value(self.trigger_price_usd)
+    }
+    /// Whether the given supply token price has crossed the trigger price
+    pub fn triggered(&self, supply_market_price: f64) -> bool {
+        if !self.is_active() || supply_market_price == 0.0 {
+            return false;
+        }
+
+        if self.trigger_above.val {
+            supply_market_price >= self.trigger_price()
+        } else {
+            supply_market_price <= self.trigger_price()
+        }
+    }
+    /// Base unit amount of the given token that is held in escrow for this order
+    #[inline(always)]
+    pub fn reserved_amount(&self, token_type: TokenType) -> u64 {
+        if self.is_active() && self.token_type == token_type {
+            self.amount_base_unit
+        } else {
+            0
+        }
+    }
+}
+
+#[derive(BorshDeserialize, Clone, Debug, Copy, Default)]
+pub struct TakeProfitOrderInp {
+    /// Supply token price (USD) at or above which profit is taken. Denominated by 9 decimal places.
+    /// If this is 0, the take-profit is removed
+    pub trigger_price_usd: u64,
+    /// Liq utilization rate the position is cut to once the order fills
+    pub target_liq_utilization_rate_bps: u16,
+    /// Share of the position's net worth to withdraw to the authority once the order fills
+    pub withdraw_net_worth_bps: u16,
+}
+
+#[repr(C, align(8))]
+#[derive(
+    ShankType, BorshSerialize, BorshDeserialize, Clone, Debug, Default, Copy, Pod, Zeroable,
+)]
+pub struct TakeProfitOrder {
+    /// Supply token price (USD) at or above which profit is taken. Denominated by 9 decimal places
+    pub trigger_price_usd: u64,
+    pub target_liq_utilization_rate_bps: u16,
+    pub withdraw_net_worth_bps: u16,
+    _padding1: [u8; 4],
+    _padding: [u8; 32],
+}
+
+impl TakeProfitOrder {
+    pub fn from(args: TakeProfitOrderInp) -> Self {
+        Self {
+            trigger_price_usd: args.trigger_price_usd,
+            target_liq_utilization_rate_bps: args.target_liq_utilization_rate_bps,
+            withdraw_net_worth_bps: args.withdraw_net_worth_bps,
+            _padding1: [0; 4],
+            _padding: [0; 32],
+        }
+    }
+    #[inline(always)]
+    pub fn is_active(&self) -> bool {
+        self.trigger_price_usd > 0
+    }
+    #[inline(always)]
+    pub fn trigger_price(&self) -> f64 {
+        from_rounded_usd_value(self.trigger_price_usd)
+    }
+    pub fn triggered(&self, supply_market_price: f64) -> bool {
+        self.is_active() && supply_market_price >= self.trigger_price()
+    }
+}
+
+#[derive(BorshDeserialize, Clone, Debug, Copy, Default)]
+pub struct StopLossOrderInp {
+    /// Supply token price (USD) at or below which the position is unwound. Denominated by 9 decimal places.
+    /// If this is 0, the price trigger is not used
+    pub trigger_price_usd: u64,
+    /// Net worth (USD) at or below which the position is unwound. Denominated by 9 decimal places.
+    /// If this is 0, the net worth trigger is not used
+    pub min_net_worth_usd: u64,
+}
+
+#[repr(C, align(8))]
+#[derive(
+    ShankType, BorshSerialize, BorshDeserialize, Clone, Debug, Default, Copy, Pod, Zeroable,
+)]
+pub struct StopLossOrder {
+    /// Supply token price (USD) at or below which the position is unwound. Denominated by 9 decimal places
+    pub trigger_price_usd: u64,
+    /// Net worth (USD) at or below which the position is unwound. Denominated by 9 decimal places
+    pub min_net_worth_usd: u64,
+    _padding: [u8; 32],
+}
+
+impl StopLossOrder {
+    pub fn from(args: StopLossOrderInp) -> Self {
+        Self {
+            trigger_price_usd: args.trigger_price_usd,
+            min_net_worth_usd: args.min_net_worth_usd,
+            _padding: [0; 32],
+        }
+    }
+    #[inline(always)]
+    pub fn is_active(&self) -> bool {
+        self.trigger_price_usd > 0 || self.min_net_worth_usd > 0
+    }
+    pub fn triggered(&self, supply_market_price: f64, net_worth_usd: f64) -> bool {
+        (self.trigger_price_usd > 0
+            && supply_market_price > 0.0
+            && supply_market_price <= from_rounded_usd_value(self.trigger_price_usd))
+            || (self.min_net_worth_usd > 0
+                && net_worth_usd <= from_rounded_usd_value(self.min_net_worth_usd))
+    }
+}
+
+#[derive(BorshDeserialize, Clone, Debug, Copy, Default)]
+pub struct TrailingStopOrderInp {
+    /// How far (in bps) the supply price can fall below its high-water mark before the order fills.
+    /// If this is 0, the trailing stop is removed
+    pub trailing_distance_bps: u16,
+    /// Liq utilization rate the position is cut to once the order fills. If this is 0, the position is fully unwound
+    pub target_liq_utilization_rate_bps: u16,
+}
+
+#[repr(C, align(8))]
+#[derive(
+    ShankType, BorshSerialize, BorshDeserialize, Clone, Debug, Default, Copy, Pod, Zeroable,
+)]
+pub struct TrailingStopOrder {
+    pub trailing_distance_bps: u16,
+    pub target_liq_utilization_rate_bps: u16,
+    _padding1: [u8; 4],
+    _padding: [u8; 32],
+}
+
+impl TrailingStopOrder {
+    pub fn from(args: TrailingStopOrderInp) -> Self {
+        Self {
+            trailing_distance_bps: args.trailing_distance_bps,
+            target_liq_utilization_rate_bps: args.target_liq_utilization_rate_bps,
+            _padding1: [0; 4],
+            _padding: [0; 32],
+        }
+    }
+    #[inline(always)]
+    pub fn is_active(&self) -> bool {
+        self.trailing_distance_bps > 0
+    }
+    pub fn triggered(&self, supply_market_price: f64, supply_price_high_water_mark: f64) -> bool {
+        self.is_active()
+            && supply_market_price > 0.0
+            && supply_market_price
+                <= supply_price_high_water_mark * (1.0 - from_bps(self.trailing_distance_bps))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::math_utils::to_rounded_usd_value;
+
+    #[test]
+    fn validate_limit_order_trigger() {
+        let order = LimitOrder::from(LimitOrderInp {
+            amount_base_unit: 1_000_000,
+            token_type: TokenType::Debt,
+            trigger_price_usd: to_rounded_usd_value(120.0),
+            trigger_above: false,
+        });
+        assert!(!order.triggered(125.0));
+        assert!(order.triggered(120.0));
+        assert!(order.triggered(110.0));
+        assert!(!order.triggered(0.0));
+        assert!(order.reserved_amount(TokenType::Debt) == 1_000_000);
+        assert!(order.reserved_amount(TokenType::Supply) == 0);
+
+        let order = LimitOrder::from(LimitOrderInp {
+            amount_base_unit: 1_000_000,
+            token_type: TokenType::Supply,
+            trigger_price_usd: to_rounded_usd_value(120.0),
+            trigger_above: true,
+        });
+        assert!(order.triggered(125.0));
+        assert!(!order.triggered(110.0));
+
+        assert!(!LimitOrder::default().triggered(110.0));
+    }
+
+    #[test]
+    fn validate_take_profit_trigger() {
+        let order = TakeProfitOrder::from(TakeProfitOrderInp {
+            trigger_price_usd: to_rounded_usd_value(150.0),
+            target_liq_utilization_rate_bps: 2000,
+            withdraw_net_worth_bps: 0,
+        });
+        assert!(!order.triggered(149.0));
+        assert!(order.triggered(150.0));
+        assert!(order.triggered(160.0));
+
+        assert!(!TakeProfitOrder::default().triggered(160.0));
+    }
+
+    #[test]
+    fn validate_stop_loss_trigger() {
+        let order = StopLossOrder::from(StopLossOrderInp {
+            trigger_price_usd: to_rounded_usd_value(80.0),
+            min_net_worth_usd: 0,
+        });
+        assert!(!order.triggered(90.0, 10.0));
+        assert!(order.triggered(80.0, 1000.0));
+        assert!(!order.triggered(0.0, 1000.0));
+
+        let order = StopLossOrder::from(StopLossOrderInp {
+            trigger_price_usd: 0,
+            min_net_worth_usd: to_rounded_usd_value(500.0),
+        });
+        assert!(!order.triggered(10.0, 600.0));
+        assert!(order.triggered(10.0, 500.0));
+
+        assert!(!StopLossOrder::default().triggered(10.0, 0.0));
+    }
+
+    #[test]
+    fn validate_trailing_stop_trigger() {
+        let order = TrailingStopOrder::from(TrailingStopOrderInp {
+            trailing_distance_bps: 1000,
+            target_liq_utilization_rate_bps: 0,
+        });
+        assert!(!order.triggered(91.0, 100.0));
+        assert!(order.triggered(90.0, 100.0));
+        assert!(!order.triggered(0.0, 100.0));
+
+        assert!(!TrailingStopOrder::default().triggered(10.0, 100.0));
+    }
+}
@@ -2,14 +2,25 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use bytemuck::{Pod, Zeroable};
 use shank::{ShankAccount, ShankType};
 use solana_program::{msg, pubkey::Pubkey};
-use std::{cmp::min, ops::Mul};
+use std::{
+    cmp::{max, min},
+    ops::Mul,
+};
 
 use crate::{
-    constants::USD_DECIMALS,
+    constants::{MAX_BASIS_POINTS, MAX_SWAP_SLIPPAGE_BPS, USD_DECIMALS},
     derive_pod_traits,
+    flash_loan::FlashLoan,
+    state::{
+        automation::{AutomationSettings, AutomationSettingsInp, DCASettings},
+        carry_guard::CarryGuard,
+        grid::Grid,
+        leverage_curve::LeverageCurve,
+        orders::{LimitOrder, StopLossOrder, TakeProfitOrder, TrailingStopOrder},
+    },
     types::shared::{
-        LendingPlatform, PodBool, PositionType, RebalanceDirection, SolautoRebalanceType, SwapType,
-        TokenType,
+        LendingPlatform, PodBool, PositionType, RebalanceDirection, RebalanceStrategyType,
+        SolautoRebalanceType, SwapType, TokenType,
     },
     utils::math_utils::{
         base_unit_to_usd_value, from_bps, from_rounded_usd_value, get_liq_utilization_rate_bps,
@@ -51,7 +62,10 @@ pub struct PositionTokenState {
     pub amount_can_be_used: TokenAmount,
     /// Denominated by 9 decimal places
     base_amount_market_price_usd: u64,
-    _padding2: [u8; 8],
+    /// Lending APR of the token's bank or reserve as of the last refresh
+    pub supply_apr_bps: u32,
+    /// Borrowing APR of the token's bank or reserve as of the last refresh
+    pub borrow_apr_bps: u32,
     _padding: [u8; 32],
 }
 
@@ -60,6 +74,14 @@ impl PositionTokenState {
     pub fn market_price(&self) -> f64 {
         from_rounded_usd_value(self.base_amount_market_price_usd)
     }
+    #[inline(always)]
+    pub fn supply_apr(&self) -> f64 {
+        self.supply_apr_bps as f64 / MAX_BASIS_POINTS as f64
+    }
+    #[inline(always)]
+    pub fn borrow_apr(&self) -> f64 {
+        self.borrow_apr_bps as f64 / MAX_BASIS_POINTS as f64
+    }
     fn update_usd_values(&mut self) {
         self.amount_used
             .update_usd_value(self.market_price(), self.decimals);
@@ -95,12 +117,34 @@ impl PositionTokenState {
     }
 }
 
+#[repr(C, align(8))]
+#[derive(ShankType, BorshSerialize, Clone, Debug, Default, Copy, Pod, Zeroable)]
+pub struct PositionLeg {
+    /// The lending platform account backing this leg (e.g. the Marginfi bank)
+    pub lp_account: Pubkey,
+    pub token: PositionTokenState,
+}
+
+impl PositionLeg {
+    #[inline(always)]
+    pub fn active(&self) -> bool {
+        self.token.mint != Pubkey::default()
+    }
+}
+
 #[derive(BorshDeserialize, Clone, Debug, Copy, Default)]
 pub struct SolautoSettingsParametersInp {
     pub boost_to_bps: u16,
     pub boost_gap: u16,
     pub repay_to_bps: u16,
     pub repay_gap: u16,
+    /// The boost_to_bps to gradually move to over the automation periods
+    pub target_boost_to_bps: Option<u16>,
+    /// The repay_to_bps to gradually move to over the automation periods
+    pub target_repay_to_bps: Option<u16>,
+    pub automation: Option<AutomationSettingsInp>,
+    /// Most a rebalance swap can return below the oracle-implied amount. Defaults to the program-wide max
+    pub max_slippage_bps: Option<u16>,
 }
 
 #[repr(C, align(8))]
@@ -116,7 +160,16 @@ pub struct SolautoSettingsParameters {
     pub repay_to_bps: u16,
     /// repay_gap basis points above repay_to_bps is the liquidation utilization rate at which to begin a rebalance
     pub repay_gap: u16,
-    _padding: [u32; 24],
+    /// The boost_to_bps that the automation is moving towards
+    pub target_boost_to_bps: u16,
+    /// The repay_to_bps that the automation is moving towards
+    pub target_repay_to_bps: u16,
+    /// Most a rebalance swap can return below the oracle-implied amount. 0 uses the program-wide max
+    pub max_slippage_bps: u16,
+    _padding1: [u8; 2],
+    /// Steps boost_to_bps & repay_to_bps towards their targets over time
+    pub automation: AutomationSettings,
+    _padding: [u8; 32],
 }
 
 impl SolautoSettingsParameters {
@@ -126,16 +179,71 @@ impl SolautoSettingsParameters {
             boost_gap: args.boost_gap,
             repay_to_bps: args.repay_to_bps,
             repay_gap: args.repay_gap,
-            _padding: [0; 24],
+            target_boost_to_bps: args.target_boost_to_bps.unwrap_or(args.boost_to_bps),
+            target_repay_to_bps: args.target_repay_to_bps.unwrap_or(args.repay_to_bps),
+            max_slippage_bps: args.max_slippage_bps.unwrap_or(0),
+            _padding1: [0; 2],
+            automation: args
+                .automation
+                .map(AutomationSettings::from)
+                .unwrap_or_default(),
+            _padding: [0; 32],
         }
     }
+    #[inline(always)]
+    pub fn max_slippage(&self) -> f64 {
+        let max_slippage_bps = if self.max_slippage_bps == 0 {
+            MAX_SWAP_SLIPPAGE_BPS
+        } else {
+            self.max_slippage_bps
+        };
+        max_slippage_bps as f64 / MAX_BASIS_POINTS as f64
+    }
+    /// The settings once the automation has finished
+    pub fn target_settings(&self) -> Self {
+        let mut target = *self;
+        target.boost_to_bps = self.target_boost_to_bps;
+        target.repay_to_bps = self.target_repay_to_bps;
+        target.automation = AutomationSettings::default();
+        target
+    }
+    /// Steps boost_to_bps & repay_to_bps towards their targets if the next automation period is due.
+    /// Returns whether the settings were updated
+    pub fn update_from_automation(&mut self, curr_unix_timestamp: u64) -> bool {
+        if !self.automation.is_active()
+            || !self
+                .automation
+                .eligible_for_next_period(curr_unix_timestamp)
+        {
+            return false;
+        }
+
+        self.boost_to_bps = self.automation.updated_amount_from_automation(
+            self.boost_to_bps,
+            self.target_boost_to_bps,
+            curr_unix_timestamp,
+        );
+        self.repay_to_bps = self.automation.updated_amount_from_automation(
+            self.repay_to_bps,
+            self.target_repay_to_bps,
+            curr_unix_timestamp,
+        );
+        self.automation.periods_passed = self.automation.new_periods_passed(curr_unix_timestamp);
+        if self.automation.periods_passed >= self.automation.target_periods {
+            *self = self.target_settings();
+        }
+
+        true
+    }
 }
 
 #[repr(C, align(8))]
 #[derive(ShankType, BorshSerialize, Clone, Debug, Default, Copy, Pod, Zeroable)]
 pub struct PositionState {
     pub liq_utilization_rate_bps: u16,
-    _padding1: [u8; 6],
+    /// Largest divergence between the realtime & EMA price of the supply or debt token, as of the last rebalance
+    pub price_divergence_bps: u16,
+    _padding1: [u8; 4],
     /// Denominated by 9 decimal places
     pub net_worth: TokenAmount,
 
@@ -146,20 +254,106 @@ pub struct PositionState {
     pub liq_threshold_bps: u16,
     _padding2: [u8; 4],
     pub last_refreshed: u64,
-    _padding: [u32; 2],
+    /// Total value of the Marginfi emissions harvested into the supply. Denominated by 9 decimal places
+    pub emissions_harvested_usd: u64,
+    /// Highest supply price seen since the trailing stop was last set. Denominated by 9 decimal places
+    pub supply_price_high_water_mark_usd: u64,
+
+    /// Supply legs other than `supply`, for positions backed by more than one collateral
+    pub additional_supply: [PositionLeg; 3],
+    /// Debt legs other than `debt`, for positions with more than one liability
+    pub additional_debt: [PositionLeg; 3],
+}
+
+impl PositionState {
+    pub fn supply_usd(&self) -> f64 {
+        self.supply.amount_used.usd_value()
+            + self
+                .additional_supply
+                .iter()
+                .filter(|leg| leg.active())
+                .map(|leg| leg.token.amount_used.usd_value())
+                .sum::<f64>()
+    }
+
+    pub fn debt_usd(&self) -> f64 {
+        self.debt.amount_used.usd_value()
+            + self
+                .additional_debt
+                .iter()
+                .filter(|leg| leg.active())
+                .map(|leg| leg.token.amount_used.usd_value())
+                .sum::<f64>()
+    }
+
+    /// Interest earned on the supply minus interest paid on the debt, in USD per year. Per unit of net worth this is
+    /// supply APR × leverage − borrow APR × (leverage − 1)
+    pub fn net_carry_usd(&self) -> f64 {
+        let supply_interest = self.supply.amount_used.usd_value() * self.supply.supply_apr()
+            + self
+                .additional_supply
+                .iter()
+                .filter(|leg| leg.active())
+                .map(|leg| leg.token.amount_used.usd_value() * leg.token.supply_apr())
+                .sum::<f64>();
+        let debt_interest = self.debt.amount_used.usd_value() * self.debt.borrow_apr()
+            + self
+                .additional_debt
+                .iter()
+                .filter(|leg| leg.active())
+                .map(|leg| leg.token.amount_used.usd_value() * leg.token.borrow_apr())
+                .sum::<f64>();
+        supply_interest - debt_interest
+    }
+
+    #[inline(always)]
+    pub fn supply_price_high_water_mark(&self) -> f64 {
+        from_rounded_usd_value(self.supply_price_high_water_mark_usd)
+    }
+    /// Starts tracking the high-water mark again from the current supply price
+    pub fn reset_supply_price_high_water_mark(&mut self) {
+        self.supply_price_high_water_mark_usd = self.supply.base_amount_market_price_usd;
+    }
+
+    pub fn has_additional_legs(&self) -> bool {
+        self.additional_supply.iter().any(|leg| leg.active())
+            || self.additional_debt.iter().any(|leg| leg.active())
+    }
+}
+
+/// Returns the index of the additional leg that ranks strictly higher than the primary leg, if any
+fn preferred_leg_idx(
+    primary: &PositionTokenState,
+    additional: &[PositionLeg],
+    rank: fn(&PositionTokenState) -> f64,
+) -> Option<usize> {
+    let mut best_value = rank(primary);
+    let mut best_idx = None;
+    for (idx, leg) in additional.iter().enumerate() {
+        if leg.active() && rank(&leg.token) > best_value {
+            best_value = rank(&leg.token);
+            best_idx = Some(idx);
+        }
+    }
+    best_idx
 }
 
 #[repr(C, align(8))]
 #[derive(ShankType, BorshSerialize, Clone, Debug, Default, Copy, Pod, Zeroable)]
 pub struct PositionData {
     pub lending_platform: LendingPlatform,
-    _padding1: [u8; 7],
+    /// Decides when & how the position is rebalanced
+    pub rebalance_strategy: RebalanceStrategyType,
+    /// Boosts are refused while the realtime & EMA prices diverge by more than this. If this is 0, the guard is off
+    pub max_price_divergence_bps: u16,
+    _padding1: [u8; 4],
     pub lp_user_account: Pubkey,
     pub lp_supply_account: Pubkey,
     pub lp_debt_account: Pubkey,
     pub settings: SolautoSettingsParameters,
     pub lp_pool_account: Pubkey,
-    _padding: [u32; 20],
+    /// DCA-in tokens are held in the position's supply or debt token account until their period is rebalanced into the position
+    pub dca: DCASettings,
 }
 
 #[repr(u8)]
@@ -184,6 +378,13 @@ pub struct TokenBalanceChange {
 }
 
 impl TokenBalanceChange {
+    pub fn from(change_type: TokenBalanceChangeType, amount_usd: u64) -> Self {
+        Self {
+            change_type,
+            _padding1: [0; 7],
+            amount_usd,
+        }
+    }
     pub fn requires_one(&self) -> bool {
         self.change_type != TokenBalanceChangeType::None
     }
@@ -231,23 +432,32 @@ pub struct RebalanceInstructionData {
     pub active: PodBool,
     pub rebalance_type: SolautoRebalanceType,
     pub swap_type: SwapType,
-    _padding1: [u8; 5],
+    _padding1: [u8; 1],
+    /// Read from the flash loan provider's accounts, rather than trusted from the instruction args
+    pub flash_loan_fee_bps: u16,
+    _padding2: [u8; 2],
     pub flash_loan_amount: u64,
-    _padding: [u32; 4],
+    /// What was sent into an exact-in swap, recorded by the pre-swap instruction
+    pub swap_in_amount_base_unit: u64,
+    /// Balance of the token account receiving the swap output, once the pre-swap instruction is done with it
+    pub pre_swap_output_balance: u64,
 }
 impl RebalanceInstructionData {
     pub fn from(
         rebalance_type: SolautoRebalanceType,
-        flash_loan_amount: u64,
+        flash_loan: FlashLoan,
         swap_type: SwapType,
     ) -> Self {
         Self {
             active: PodBool::new(true),
             rebalance_type,
             swap_type,
-            _padding1: [0; 5],
-            flash_loan_amount,
-            _padding: [0; 4],
+            _padding1: [0; 1],
+            flash_loan_fee_bps: flash_loan.fee_bps,
+            _padding2: [0; 2],
+            flash_loan_amount: flash_loan.amount,
+            swap_in_amount_base_unit: 0,
+            pre_swap_output_balance: 0,
         }
     }
 }
@@ -269,6 +479,25 @@ impl RebalanceData {
     }
 }
 
+#[repr(C, align(8))]
+#[derive(ShankType, BorshSerialize, Clone, Debug, Default, Copy, Pod, Zeroable)]
+pub struct HarvestData {
+    pub active: PodBool,
+    _padding1: [u8; 7],
+    /// Balance of the position supply token account before the emissions are swapped to the supply token
+    pub supply_ta_balance: u64,
+}
+
+impl HarvestData {
+    pub fn from(supply_ta_balance: u64) -> Self {
+        Self {
+            active: PodBool::new(true),
+            _padding1: [0; 7],
+            supply_ta_balance,
+        }
+    }
+}
+
 #[repr(C, align(8))]
 #[derive(ShankAccount, BorshSerialize, Clone, Debug, Copy, Pod, Zeroable)]
 pub struct SolautoPosition {
@@ -281,11 +510,26 @@ pub struct SolautoPosition {
     pub position: PositionData,
     pub state: PositionState,
     pub rebalance: RebalanceData,
-    _padding: [u32; 20],
+    pub harvest: HarvestData,
+    /// Escrowed deposit that is added to the position once the supply price crosses the trigger price
+    pub limit_order: LimitOrder,
+    /// Cuts leverage, and optionally withdraws part of the net worth, once the supply price reaches the trigger price
+    pub take_profit: TakeProfitOrder,
+    /// Fully unwinds the position to the authority once the supply price or net worth falls to its trigger
+    pub stop_loss: StopLossOrder,
+    /// Deleverages or unwinds the position once the supply price falls far enough below its high-water mark
+    pub trailing_stop: TrailingStopOrder,
+    /// Setting parameters per supply / debt price ratio, used by the leverage curve rebalance strategy
+    pub leverage_curve: LeverageCurve,
+    /// Grid levels & the last executed level, used by the grid rebalance strategy
+    pub grid: Grid,
+    /// Deleverages the position, and refuses boosts, while its net carry is negative
+    pub carry_guard: CarryGuard,
+    _padding: [u32; 2],
 }
 
 impl SolautoPosition {
-    pub const LEN: usize = 832;
+    pub const LEN: usize = 2064;
 
     pub fn new(
         position_id: u8,
@@ -306,7 +550,15 @@ impl SolautoPosition {
             position,
             state,
             rebalance: RebalanceData::default(),
-            _padding: [0; 20],
+            harvest: HarvestData::default(),
+            limit_order: LimitOrder::default(),
+            take_profit: TakeProfitOrder::default(),
+            stop_loss: StopLossOrder::default(),
+            trailing_stop: TrailingStopOrder::default(),
+            leverage_curve: LeverageCurve::default(),
+            grid: Grid::default(),
+            carry_guard: CarryGuard::default(),
+            _padding: [0; 2],
         }
     }
 
@@ -334,8 +586,14 @@ impl SolautoPosition {
     }
 
     pub fn refresh_state(&mut self) {
-        let supply_usd = self.state.supply.amount_used.usd_value();
-        let debt_usd = self.state.debt.amount_used.usd_value();
+        // The high-water mark only moves forward, until the trailing stop is set again
+        self.state.supply_price_high_water_mark_usd = max(
+            self.state.supply_price_high_water_mark_usd,
+            self.state.supply.base_amount_market_price_usd,
+        );
+
+        let supply_usd = self.state.supply_usd();
+        let debt_usd = self.state.debt_usd();
 
         self.state.liq_utilization_rate_bps = get_liq_utilization_rate_bps(
             supply_usd,
@@ -368,8 +626,8 @@ impl SolautoPosition {
             self.state.debt.update_usage(base_unit_amount_update);
         }
 
-        let supply_usd = self.state.supply.amount_used.usd_value();
-        let debt_usd = self.state.debt.amount_used.usd_value();
+        let supply_usd = self.state.supply_usd();
+        let debt_usd = self.state.debt_usd();
 
         if supply_usd > debt_usd {
             self.refresh_state();
@@ -378,6 +636,54 @@ impl SolautoPosition {
         }
     }
 
+    /// Moves the legs a rebalance in the given direction should act on into the `supply` & `debt` slots.
+    /// A boost grows the largest supply leg and borrows from the debt leg with the most liquidity available,
+    /// a repay shrinks the largest supply & debt legs.
+    pub fn select_rebalance_legs(&mut self, rebalance_direction: RebalanceDirection) {
+        let supply_rank: fn(&PositionTokenState) -> f64 = |token| token.amount_used.usd_value();
+        let debt_rank: fn(&PositionTokenState) -> f64 = match rebalance_direction {
+            RebalanceDirection::Boost => |token| token.amount_can_be_used.usd_value(),
+            _ => |token| token.amount_used.usd_value(),
+        };
+
+        if let Some(idx) = preferred_leg_idx(
+            &self.state.supply,
+            &self.state.additional_supply,
+            supply_rank,
+        ) {
+            let leg = &mut self.state.additional_supply[idx];
+            std::mem::swap(&mut self.state.supply, &mut leg.token);
+            std::mem::swap(&mut self.position.lp_supply_account, &mut leg.lp_account);
+            // The high-water mark is of the price of the mint in the supply slot
+            self.state.reset_supply_price_high_water_mark();
+            msg!("Rebalancing supply leg {}", self.state.supply.mint);
+        }
+
+        if let Some(idx) =
+            preferred_leg_idx(&self.state.debt, &self.state.additional_debt, debt_rank)
+        {
+            let leg = &mut self.state.additional_debt[idx];
+            std::mem::swap(&mut self.state.debt, &mut leg.token);
+            std::mem::swap(&mut self.position.lp_debt_account, &mut leg.lp_account);
+            msg!("Rebalancing debt leg {}", self.state.debt.mint);
+        }
+    }
+
+    pub fn record_emissions_harvest(&mut self, supply_base_unit_amount: u64) {
+        let harvested_usd = base_unit_to_usd_value(
+            supply_base_unit_amount,
+            self.state.supply.decimals,
+            self.state.supply.market_price(),
+        );
+        self.state.emissions_harvested_usd += to_rounded_usd_value(harvested_usd);
+        msg!(
+            "Harvested {} of {} (${}) from emissions",
+            supply_base_unit_amount,
+            self.state.supply.mint,
+            harvested_usd
+        );
+    }
+
     #[inline(always)]
     pub fn boost_to_bps(&self) -> u16 {
         min(
@@ -388,8 +694,17 @@ impl SolautoPosition {
 
     #[inline(always)]
     pub fn boost_from_bps(&self) -> u16 {
-        self.boost_to_bps()
-            .saturating_sub(self.position.settings.boost_gap)
+        self.boost_from_bps_for(&self.position.settings)
+    }
+
+    /// boost_from for the given setting parameters rather than the position's own
+    #[inline(always)]
+    pub fn boost_from_bps_for(&self, settings: &SolautoSettingsParameters) -> u16 {
+        min(
+            settings.boost_to_bps,
+            get_max_boost_to_bps(self.state.max_ltv_bps, self.state.liq_threshold_bps),
+        )
+        .saturating_sub(settings.boost_gap)
     }
 
     #[inline(always)]
@@ -402,11 +717,125 @@ impl SolautoPosition {
 
     #[inline(always)]
     pub fn repay_from_bps(&self) -> u16 {
+        self.repay_from_bps_for(&self.position.settings)
+    }
+
+    /// repay_from for the given setting parameters rather than the position's own
+    #[inline(always)]
+    pub fn repay_from_bps_for(&self, settings: &SolautoSettingsParameters) -> u16 {
         min(
-            self.position.settings.repay_to_bps + self.position.settings.repay_gap,
+            settings.repay_to_bps + settings.repay_gap,
             get_max_repay_from_bps(self.state.max_ltv_bps, self.state.liq_threshold_bps),
         )
     }
+
+    /// Supply / debt market price ratio, or None if the debt price isn't known yet
+    pub fn price_ratio(&self) -> Option<f64> {
+        let debt_price = self.state.debt.market_price();
+        if debt_price == 0.0 {
+            return None;
+        }
+
+        Some(self.state.supply.market_price() / debt_price)
+    }
+
+    /// The position in the current layout, with everything the legacy layout didn't hold at its defaults
+    pub fn from_legacy(legacy: &LegacySolautoPosition) -> Self {
+        let legacy_settings = &legacy.position.settings;
+        let mut position = PositionData::default();
+        position.lending_platform = legacy.position.lending_platform;
+        position.lp_user_account = legacy.position.lp_user_account;
+        position.lp_supply_account = legacy.position.lp_supply_account;
+        position.lp_debt_account = legacy.position.lp_debt_account;
+        position.lp_pool_account = legacy.position.lp_pool_account;
+        position.settings = SolautoSettingsParameters::from(SolautoSettingsParametersInp {
+            boost_to_bps: legacy_settings.boost_to_bps,
+            boost_gap: legacy_settings.boost_gap,
+            repay_to_bps: legacy_settings.repay_to_bps,
+            repay_gap: legacy_settings.repay_gap,
+            ..Default::default()
+        });
+
+        let mut state = PositionState::default();
+        state.liq_utilization_rate_bps = legacy.state.liq_utilization_rate_bps;
+        state.net_worth = legacy.state.net_worth;
+        state.supply = legacy.state.supply;
+        state.debt = legacy.state.debt;
+        state.max_ltv_bps = legacy.state.max_ltv_bps;
+        state.liq_threshold_bps = legacy.state.liq_threshold_bps;
+        state.last_refreshed = legacy.state.last_refreshed;
+
+        let mut solauto_position = Self::new(
+            legacy.position_id[0],
+            legacy.authority,
+            legacy.position_type,
+            position,
+            state,
+        );
+        solauto_position.bump = legacy.bump;
+        solauto_position.self_managed = legacy.self_managed;
+        solauto_position.rebalance = legacy.rebalance;
+        solauto_position
+    }
+}
+
+#[repr(C, align(8))]
+#[derive(Clone, Debug, Default, Copy, Pod, Zeroable)]
+pub struct LegacySolautoSettingsParameters {
+    pub boost_to_bps: u16,
+    pub boost_gap: u16,
+    pub repay_to_bps: u16,
+    pub repay_gap: u16,
+    _padding: [u32; 24],
+}
+
+#[repr(C, align(8))]
+#[derive(Clone, Debug, Default, Copy, Pod, Zeroable)]
+pub struct LegacyPositionData {
+    pub lending_platform: LendingPlatform,
+    _padding1: [u8; 7],
+    pub lp_user_account: Pubkey,
+    pub lp_supply_account: Pubkey,
+    pub lp_debt_account: Pubkey,
+    pub settings: LegacySolautoSettingsParameters,
+    pub lp_pool_account: Pubkey,
+    _padding: [u32; 20],
+}
+
+#[repr(C, align(8))]
+#[derive(Clone, Debug, Default, Copy, Pod, Zeroable)]
+pub struct LegacyPositionState {
+    pub liq_utilization_rate_bps: u16,
+    _padding1: [u8; 6],
+    pub net_worth: TokenAmount,
+    pub supply: PositionTokenState,
+    pub debt: PositionTokenState,
+    pub max_ltv_bps: u16,
+    pub liq_threshold_bps: u16,
+    _padding2: [u8; 4],
+    pub last_refreshed: u64,
+    _padding: [u32; 2],
+}
+
+/// Layout of the positions opened before the position account grew to `SolautoPosition::LEN`. The token states &
+/// rebalance data kept their layout, the fields added to them since took the place of zeroed padding
+#[repr(C, align(8))]
+#[derive(Clone, Debug, Copy, Pod, Zeroable)]
+pub struct LegacySolautoPosition {
+    bump: [u8; 1],
+    position_id: [u8; 1],
+    pub self_managed: PodBool,
+    pub position_type: PositionType,
+    _padding1: [u8; 4],
+    pub authority: Pubkey,
+    pub position: LegacyPositionData,
+    pub state: LegacyPositionState,
+    pub rebalance: RebalanceData,
+    _padding: [u32; 20],
+}
+
+impl LegacySolautoPosition {
+    pub const LEN: usize = 832;
 }
 
 mod tests {
@@ -427,4 +856,183 @@ mod tests {
         );
         assert!(std::mem::size_of_val(&solauto_position) == SolautoPosition::LEN);
     }
+
+    #[test]
+    fn test_from_legacy() {
+        assert!(std::mem::size_of::<LegacySolautoPosition>() == LegacySolautoPosition::LEN);
+
+        let authority = Pubkey::new_unique();
+        let (_, bump) = Pubkey::find_program_address(&[&[3], authority.as_ref()], &crate::ID);
+        let mut legacy = LegacySolautoPosition::zeroed();
+        legacy.bump = [bump];
+        legacy.position_id = [3];
+        legacy.position_type = PositionType::SafeLoan;
+        legacy.authority = authority;
+        legacy.position.lending_platform = LendingPlatform::Kamino;
+        legacy.position.lp_user_account = Pubkey::new_unique();
+        legacy.position.lp_pool_account = Pubkey::new_unique();
+        legacy.position.settings.boost_to_bps = 5000;
+        legacy.position.settings.boost_gap = 500;
+        legacy.position.settings.repay_to_bps = 7500;
+        legacy.position.settings.repay_gap = 250;
+        legacy.state.supply = token_state(Pubkey::new_unique(), 100_000_000, 0);
+        legacy.state.debt = token_state(Pubkey::new_unique(), 50_000_000, 10_000_000);
+        legacy.state.liq_threshold_bps = 8500;
+        legacy.state.last_refreshed = 1_700_000_000;
+        legacy.rebalance.ixs.flash_loan_amount = 123;
+
+        let position = SolautoPosition::from_legacy(&legacy);
+
+        assert!(position.seeds_with_bump() == vec![&[3], authority.as_ref(), &[bump]]);
+        assert!(!position.self_managed.val);
+        assert!(position.position_type == PositionType::SafeLoan);
+        assert!(position.position.lending_platform == LendingPlatform::Kamino);
+        assert!(position.position.lp_user_account == legacy.position.lp_user_account);
+        assert!(position.position.lp_pool_account == legacy.position.lp_pool_account);
+        assert!(position.position.settings.repay_to_bps == 7500);
+        assert!(position.position.settings.target_repay_to_bps == 7500);
+        assert!(position.state.supply.mint == legacy.state.supply.mint);
+        assert!(position.state.debt.amount_can_be_used.base_unit == 10_000_000);
+        assert!(position.state.liq_threshold_bps == 8500);
+        assert!(position.state.last_refreshed == 1_700_000_000);
+        assert!(position.rebalance.ixs.flash_loan_amount == 123);
+        assert!(!position.state.has_additional_legs());
+        assert!(!position.limit_order.is_active());
+    }
+
+    fn token_state(mint: Pubkey, amount_used: u64, amount_can_be_used: u64) -> PositionTokenState {
+        let mut token = PositionTokenState {
+            mint,
+            decimals: 6,
+            ..Default::default()
+        };
+        token.amount_used.base_unit = amount_used;
+        token.amount_can_be_used.base_unit = amount_can_be_used;
+        token.update_market_price(1.0);
+        token
+    }
+
+    #[test]
+    fn test_select_rebalance_legs() {
+        let mut state = PositionState {
+            supply: token_state(Pubkey::new_unique(), 100_000_000, 0),
+            debt: token_state(Pubkey::new_unique(), 50_000_000, 10_000_000),
+            ..Default::default()
+        };
+        state.additional_supply[0].lp_account = Pubkey::new_unique();
+        state.additional_supply[0].token = token_state(Pubkey::new_unique(), 300_000_000, 0);
+        state.additional_debt[1].lp_account = Pubkey::new_unique();
+        state.additional_debt[1].token = token_state(Pubkey::new_unique(), 20_000_000, 90_000_000);
+
+        assert!(state.supply_usd() == 400.0);
+        assert!(state.debt_usd() == 70.0);
+
+        let mut position = SolautoPosition::new(
+            1,
+            Pubkey::default(),
+            PositionType::default(),
+            PositionData::default(),
+            state,
+        );
+
+        let largest_supply = state.additional_supply[0];
+        let most_debt_liquidity = state.additional_debt[1];
+        position.state.supply_price_high_water_mark_usd = to_rounded_usd_value(2.0);
+        position.select_rebalance_legs(RebalanceDirection::Boost);
+        assert!(position.state.supply.mint == largest_supply.token.mint);
+        assert!(position.state.supply_price_high_water_mark() == 1.0);
+        assert!(position.position.lp_supply_account == largest_supply.lp_account);
+        assert!(position.state.additional_supply[0].token.mint == state.supply.mint);
+        assert!(position.state.debt.mint == most_debt_liquidity.token.mint);
+        assert!(position.position.lp_debt_account == most_debt_liquidity.lp_account);
+        assert!(position.state.supply_usd() == 400.0);
+
+        // The largest debt leg is the original one
+        position.select_rebalance_legs(RebalanceDirection::Repay);
+        assert!(position.state.supply.mint == largest_supply.token.mint);
+        assert!(position.state.debt.mint == state.debt.mint);
+        assert!(position.state.debt_usd() == 70.0);
+    }
+
+    #[test]
+    fn test_record_emissions_harvest() {
+        let mut state = PositionState {
+            supply: token_state(Pubkey::new_unique(), 100_000_000, 0),
+            ..Default::default()
+        };
+        state.supply.update_market_price(2.5);
+
+        let mut position = SolautoPosition::new(
+            1,
+            Pubkey::default(),
+            PositionType::default(),
+            PositionData::default(),
+            state,
+        );
+        position.record_emissions_harvest(4_000_000);
+        position.record_emissions_harvest(1_000_000);
+        assert!(position.state.emissions_harvested_usd == to_rounded_usd_value(12.5));
+    }
+
+    #[test]
+    fn test_supply_price_high_water_mark() {
+        let state = PositionState {
+            supply: token_state(Pubkey::new_unique(), 100_000_000, 0),
+            max_ltv_bps: 6400,
+            liq_threshold_bps: 8181,
+            ..Default::default()
+        };
+
+        let mut position = SolautoPosition::new(
+            1,
+            Pubkey::default(),
+            PositionType::default(),
+            PositionData::default(),
+            state,
+        );
+        position.refresh_state();
+        assert!(position.state.supply_price_high_water_mark() == 1.0);
+
+        position.state.supply.update_market_price(1.5);
+        position.refresh_state();
+        assert!(position.state.supply_price_high_water_mark() == 1.5);
+
+        // Only moves forward
+        position.state.supply.update_market_price(1.2);
+        position.refresh_state();
+        assert!(position.state.supply_price_high_water_mark() == 1.5);
+
+        position.state.reset_supply_price_high_water_mark();
+        assert!(position.state.supply_price_high_water_mark() == 1.2);
+    }
+
+    #[test]
+    fn test_settings_automation() {
+        let mut settings = SolautoSettingsParameters::from(SolautoSettingsParametersInp {
+            boost_to_bps: 4000,
+            boost_gap: 500,
+            repay_to_bps: 7500,
+            repay_gap: 500,
+            target_boost_to_bps: Some(7000),
+            target_repay_to_bps: None,
+            automation: Some(AutomationSettingsInp {
+                target_periods: 3,
+                periods_passed: 0,
+                unix_start_date: 0,
+                interval_seconds: 10,
+            }),
+            max_slippage_bps: None,
+        });
+
+        assert!(settings.update_from_automation(0));
+        assert!(settings.boost_to_bps == 5000 && settings.repay_to_bps == 7500);
+        assert!(!settings.update_from_automation(5));
+        assert!(settings.boost_to_bps == 5000);
+
+        // A missed period is caught up on
+        assert!(settings.update_from_automation(25));
+        assert!(settings.boost_to_bps == 7000);
+        assert!(!settings.automation.is_active());
+        assert!(!settings.update_from_automation(35));
+    }
 }
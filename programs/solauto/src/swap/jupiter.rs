@@ -0,0 +1,253 @@
+use borsh::BorshDeserialize;
+use jupiter_sdk::{
+    generated::instructions::{
+        ExactOutRouteInstructionArgs, RouteInstructionArgs,
+        SharedAccountsExactOutRouteInstructionArgs, SharedAccountsRouteInstructionArgs,
+    },
+    JUPITER_ID,
+};
+use solana_program::{msg, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{types::errors::SolautoError, utils::ix_utils::get_anchor_ix_discriminator};
+
+use super::{SwapData, SwapValidator};
+
+pub struct JupiterSwapValidator {}
+
+impl SwapValidator for JupiterSwapValidator {
+    fn program_id(&self) -> Pubkey {
+        JUPITER_ID
+    }
+
+    // Token ledger routes take their in-amount from the ledger, so it can't be validated
+    fn swap_ix_names(&self) -> Vec<&'static str> {
+        vec![
+            "route",
+            "shared_accounts_route",
+            "exact_out_route",
+            "shared_accounts_exact_out_route",
+        ]
+    }
+
+    fn accounts_len(&self) -> u16 {
+        9
+    }
+
+    fn decode_swap(&self, data: &[u8], accounts: &[Pubkey]) -> Result<SwapData, ProgramError> {
+        let discriminator = u64::from_le_bytes(data[0..8].try_into().expect("Should be 8 bytes"));
+        let args_data = &mut &data[8..];
+
+        // Routes without a shared program authority only have a separate destination account if one was provided
+        let destination_ta = |user_destination_idx: usize, destination_idx: usize| {
+            if accounts[destination_idx] != JUPITER_ID {
+                accounts[destination_idx]
+            } else {
+                accounts[user_destination_idx]
+            }
+        };
+
+        let swap = if discriminator == get_anchor_ix_discriminator("route") {
+            let args = RouteInstructionArgs::deserialize(args_data)
+                .map_err(|_| SolautoError::UnsupportedSwap)?;
+            SwapData {
+                exact_out: false,
+                in_amount: args.in_amount,
+                out_amount: args.quoted_out_amount,
+                platform_fee_bps: args.platform_fee_bps,
+                source_ta: accounts[2],
+                destination_ta: destination_ta(3, 4),
+                source_mint: None,
+                destination_mint: Some(accounts[5]),
+            }
+        } else if discriminator == get_anchor_ix_discriminator("shared_accounts_route") {
+            let args = SharedAccountsRouteInstructionArgs::deserialize(args_data)
+                .map_err(|_| SolautoError::UnsupportedSwap)?;
+            SwapData {
+                exact_out: false,
+                in_amount: args.in_amount,
+                out_amount: args.quoted_out_amount,
+                platform_fee_bps: args.platform_fee_bps,
+                source_ta: accounts[3],
+                destination_ta: accounts[6],
+                source_mint: Some(accounts[7]),
+                destination_mint: Some(accounts[8]),
+            }
+        } else if discriminator == get_anchor_ix_discriminator("exact_out_route") {
+            let args = ExactOutRouteInstructionArgs::deserialize(args_data)
+                .map_err(|_| SolautoError::UnsupportedSwap)?;
+            SwapData {
+                exact_out: true,
+                in_amount: args.quoted_in_amount,
+                out_amount: args.out_amount,
+                platform_fee_bps: args.platform_fee_bps,
+                source_ta: accounts[2],
+                destination_ta: destination_ta(3, 4),
+                source_mint: Some(accounts[5]),
+                destination_mint: Some(accounts[6]),
+            }
+        } else if discriminator == get_anchor_ix_discriminator("shared_accounts_exact_out_route") {
+            let args = SharedAccountsExactOutRouteInstructionArgs::deserialize(args_data)
+                .map_err(|_| SolautoError::UnsupportedSwap)?;
+            SwapData {
+                exact_out: true,
+                in_amount: args.quoted_in_amount,
+                out_amount: args.out_amount,
+                platform_fee_bps: args.platform_fee_bps,
+                source_ta: accounts[3],
+                destination_ta: accounts[6],
+                source_mint: Some(accounts[7]),
+                destination_mint: Some(accounts[8]),
+            }
+        } else {
+            msg!("Unsupported Jupiter route for a rebalance");
+            return Err(SolautoError::UnsupportedSwap.into());
+        };
+
+        Ok(swap)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use jupiter_sdk::generated::{
+        instructions::{RouteBuilder, SharedAccountsExactOutRouteBuilder},
+        types::{RoutePlanStep, Swap},
+    };
+    use solana_program::instruction::Instruction;
+
+    use super::*;
+    use crate::{
+        swap::{validate_swap_data, SwapRequirements},
+        types::shared::SwapType,
+    };
+
+    fn decode(ix: Instruction) -> SwapData {
+        let accounts: Vec<Pubkey> = ix.accounts.iter().map(|meta| meta.pubkey).collect();
+        JupiterSwapValidator {}
+            .decode_swap(&ix.data, &accounts)
+            .unwrap()
+    }
+
+    fn route_plan() -> Vec<RoutePlanStep> {
+        vec![RoutePlanStep {
+            swap: Swap::Whirlpool { a_to_b: true },
+            percent: 100,
+            input_index: 0,
+            output_index: 1,
+        }]
+    }
+
+    #[test]
+    fn test_validate_jup_route() {
+        let requirements = SwapRequirements {
+            input_mint: Pubkey::new_unique(),
+            output_mint: Pubkey::new_unique(),
+            source_ta: Pubkey::new_unique(),
+            destination_ta: Pubkey::new_unique(),
+            swap_type: SwapType::ExactIn,
+            swap_in_amount_base_unit: 1_000_000,
+        };
+        let route = |in_amount: u64, destination_ta: Pubkey, platform_fee_bps: u8| {
+            RouteBuilder::new()
+                .token_program(spl_token::ID)
+                .user_transfer_authority(Pubkey::new_unique())
+                .user_source_token_account(requirements.source_ta)
+                .user_destination_token_account(Pubkey::new_unique())
+                .destination_token_account(Some(destination_ta))
+                .destination_mint(requirements.output_mint)
+                .event_authority(Pubkey::new_unique())
+                .program(JUPITER_ID)
+                .route_plan(route_plan())
+                .in_amount(in_amount)
+                .quoted_out_amount(500)
+                .slippage_bps(50)
+                .platform_fee_bps(platform_fee_bps)
+                .instruction()
+        };
+
+        let swap = decode(route(1_000_000, requirements.destination_ta, 0));
+        assert_eq!(swap.destination_ta, requirements.destination_ta);
+        assert!(validate_swap_data(&swap, &requirements).is_ok());
+
+        assert_eq!(
+            validate_swap_data(
+                &decode(route(999_999, requirements.destination_ta, 0)),
+                &requirements
+            ),
+            Err(SolautoError::SwapAmountMismatch.into())
+        );
+        assert_eq!(
+            validate_swap_data(
+                &decode(route(1_000_000, Pubkey::new_unique(), 0)),
+                &requirements
+            ),
+            Err(SolautoError::SwapTokenAccountMismatch.into())
+        );
+        assert_eq!(
+            validate_swap_data(
+                &decode(route(1_000_000, requirements.destination_ta, 20)),
+                &requirements
+            ),
+            Err(SolautoError::SwapPlatformFee.into())
+        );
+    }
+
+    #[test]
+    fn test_validate_jup_shared_accounts_exact_out_route() {
+        let requirements = SwapRequirements {
+            input_mint: Pubkey::new_unique(),
+            output_mint: Pubkey::new_unique(),
+            source_ta: Pubkey::new_unique(),
+            destination_ta: Pubkey::new_unique(),
+            swap_type: SwapType::ExactOut,
+            swap_in_amount_base_unit: 1_000_000,
+        };
+        let route = |quoted_in_amount: u64, source_mint: Pubkey| {
+            SharedAccountsExactOutRouteBuilder::new()
+                .token_program(spl_token::ID)
+                .program_authority(Pubkey::new_unique())
+                .user_transfer_authority(Pubkey::new_unique())
+                .source_token_account(requirements.source_ta)
+                .program_source_token_account(Pubkey::new_unique())
+                .program_destination_token_account(Pubkey::new_unique())
+                .destination_token_account(requirements.destination_ta)
+                .source_mint(source_mint)
+                .destination_mint(requirements.output_mint)
+                .event_authority(Pubkey::new_unique())
+                .program(JUPITER_ID)
+                .id(0)
+                .route_plan(route_plan())
+                .out_amount(500)
+                .quoted_in_amount(quoted_in_amount)
+                .slippage_bps(50)
+                .platform_fee_bps(0)
+                .instruction()
+        };
+
+        let swap = decode(route(990_000, requirements.input_mint));
+        assert!(swap.exact_out);
+        assert!(validate_swap_data(&swap, &requirements).is_ok());
+
+        assert_eq!(
+            validate_swap_data(
+                &decode(route(1_000_001, requirements.input_mint)),
+                &requirements
+            ),
+            Err(SolautoError::SwapAmountMismatch.into())
+        );
+        assert_eq!(
+            validate_swap_data(&decode(route(990_000, Pubkey::new_unique())), &requirements),
+            Err(SolautoError::SwapMintMismatch.into())
+        );
+        assert_eq!(
+            validate_swap_data(
+                &swap,
+                &SwapRequirements {
+                    swap_type: SwapType::ExactIn,
+                    ..requirements
+                }
+            ),
+            Err(SolautoError::IncorrectInstructions.into())
+        );
+    }
+}
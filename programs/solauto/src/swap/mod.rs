@@ -0,0 +1,238 @@
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::instructions::load_current_index_checked,
+};
+
+use crate::{
+    check,
+    types::{
+        errors::SolautoError,
+        shared::{SolautoRebalanceType, SwapType},
+    },
+    utils::{
+        ix_utils::{
+            pick_ix_data, relative_ix_idx_skipping_refreshes, InstructionChecker, PickIxDataReq,
+        },
+        solana_utils::invoke_instruction,
+    },
+};
+
+use self::{jupiter::JupiterSwapValidator, whirlpool::WhirlpoolSwapValidator};
+
+pub mod jupiter;
+pub mod whirlpool;
+
+/// What the swap of a rebalance is required to look like
+pub struct SwapRequirements {
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub source_ta: Pubkey,
+    pub destination_ta: Pubkey,
+    pub swap_type: SwapType,
+    pub swap_in_amount_base_unit: u64,
+}
+
+/// A swap instruction decoded into what a rebalance is validated on
+#[derive(Debug, PartialEq)]
+pub struct SwapData {
+    pub exact_out: bool,
+    /// The in-amount of exact-in swaps, or the most exact-out swaps can spend
+    pub in_amount: u64,
+    /// The quoted (or minimum) out-amount of exact-in swaps, or the out-amount of exact-out swaps
+    pub out_amount: u64,
+    pub platform_fee_bps: u8,
+    pub source_ta: Pubkey,
+    pub destination_ta: Pubkey,
+    /// Only checked when the venue's instruction provides it. Otherwise the mints are implied by the token accounts
+    pub source_mint: Option<Pubkey>,
+    pub destination_mint: Option<Pubkey>,
+}
+
+/// A swap venue that a rebalance can swap through. Each venue decodes its own swap instructions, which are then
+/// validated against the rebalance the same way for every venue
+pub trait SwapValidator {
+    fn program_id(&self) -> Pubkey;
+
+    /// Names of the anchor instructions that can be decoded
+    fn swap_ix_names(&self) -> Vec<&'static str>;
+
+    /// Number of leading accounts that hold every account the swap is validated on
+    fn accounts_len(&self) -> u16;
+
+    fn decode_swap(&self, data: &[u8], accounts: &[Pubkey]) -> Result<SwapData, ProgramError>;
+
+    fn swap_checker<'a>(
+        &self,
+        ixs_sysvar: &'a AccountInfo<'a>,
+        current_ix_idx: u16,
+    ) -> InstructionChecker<'a> {
+        InstructionChecker::from_anchor(
+            ixs_sysvar,
+            vec![self.program_id()],
+            self.swap_ix_names(),
+            current_ix_idx,
+        )
+    }
+}
+
+pub fn get_swap_validators() -> Vec<Box<dyn SwapValidator>> {
+    vec![
+        Box::new(JupiterSwapValidator {}),
+        Box::new(WhirlpoolSwapValidator {}),
+    ]
+}
+
+/// Whether the instruction at the relative index is a swap of any of the supported venues
+pub fn swap_ix_matches<'a>(
+    ixs_sysvar: &'a AccountInfo<'a>,
+    current_ix_idx: u16,
+    relative_ix_idx: i16,
+) -> bool {
+    get_swap_validators().iter().any(|validator| {
+        validator
+            .swap_checker(ixs_sysvar, current_ix_idx)
+            .matches(relative_ix_idx)
+    })
+}
+
+pub fn validate_swap_data(swap: &SwapData, requirements: &SwapRequirements) -> ProgramResult {
+    check!(
+        swap.exact_out == (requirements.swap_type == SwapType::ExactOut),
+        SolautoError::IncorrectInstructions
+    );
+    check!(
+        swap.destination_mint.unwrap_or(requirements.output_mint) == requirements.output_mint
+            && swap.source_mint.unwrap_or(requirements.input_mint) == requirements.input_mint,
+        SolautoError::SwapMintMismatch
+    );
+    check!(
+        swap.source_ta == requirements.source_ta
+            && swap.destination_ta == requirements.destination_ta,
+        SolautoError::SwapTokenAccountMismatch
+    );
+    check!(swap.platform_fee_bps == 0, SolautoError::SwapPlatformFee);
+
+    // Exact-out swaps can't spend more than was pulled into the source token account, though they can spend less
+    let valid_in_amount = if swap.exact_out {
+        swap.in_amount <= requirements.swap_in_amount_base_unit
+    } else {
+        swap.in_amount == requirements.swap_in_amount_base_unit
+    };
+    check!(valid_in_amount, SolautoError::SwapAmountMismatch);
+
+    Ok(())
+}
+
+/// Decodes the swap of the rebalance with the validator of whichever venue it goes through, from the point of view
+/// of the first rebalance instruction, and validates it against the rebalance's mints, token accounts & swap-in amount
+pub fn validate_rebalance_swap<'a>(
+    ixs_sysvar: &'a AccountInfo<'a>,
+    rebalance_type: SolautoRebalanceType,
+    requirements: &SwapRequirements,
+) -> Result<SwapData, ProgramError> {
+    let current_ix_idx = load_current_index_checked(ixs_sysvar)?;
+    let nth_ix = if rebalance_type == SolautoRebalanceType::FLSwapThenRebalance {
+        -1
+    } else {
+        1
+    };
+    let relative_ix_idx = relative_ix_idx_skipping_refreshes(ixs_sysvar, current_ix_idx, nth_ix)
+        .ok_or(SolautoError::IncorrectInstructions)?;
+    let swap_ix_idx = ((current_ix_idx as i16) + relative_ix_idx) as usize;
+
+    let validators = get_swap_validators();
+    let accounts_len = validators
+        .iter()
+        .map(|validator| validator.accounts_len())
+        .max()
+        .unwrap_or(0);
+    let data = pick_ix_data(PickIxDataReq {
+        ixs_sysvar,
+        ix_idx: swap_ix_idx,
+        data_start_idx: Some(0),
+        data_len: None,
+        account_indices: Some((0..accounts_len).collect()),
+    })
+    .map_err(|_| SolautoError::IncorrectInstructions)?;
+
+    decode_and_validate_swap(
+        &validators,
+        &data.program_id,
+        &data.data,
+        &data.accounts,
+        requirements,
+    )
+}
+
+fn decode_and_validate_swap(
+    validators: &[Box<dyn SwapValidator>],
+    program_id: &Pubkey,
+    data: &[u8],
+    accounts: &[Pubkey],
+    requirements: &SwapRequirements,
+) -> Result<SwapData, ProgramError> {
+    let validator = validators
+        .iter()
+        .find(|validator| &validator.program_id() == program_id)
+        .ok_or(SolautoError::IncorrectInstructions)?;
+    check!(
+        data.len() >= 8 && accounts.len() >= validator.accounts_len() as usize,
+        SolautoError::UnsupportedSwap
+    );
+
+    let swap = validator.decode_swap(data, accounts)?;
+    validate_swap_data(&swap, requirements)?;
+
+    Ok(swap)
+}
+
+/// A swap that Solauto executes itself, through a CPI, in single-instruction rebalances
+pub struct SwapCpi<'a> {
+    /// The swap program, followed by the swap instruction's accounts
+    pub accounts: &'a [AccountInfo<'a>],
+    pub data: Vec<u8>,
+}
+
+impl<'a> SwapCpi<'a> {
+    pub fn from(accounts: &'a [AccountInfo<'a>], data: Vec<u8>) -> Result<Self, ProgramError> {
+        check!(!accounts.is_empty(), SolautoError::IncorrectAccounts);
+        Ok(Self { accounts, data })
+    }
+
+    fn program_id(&self) -> &Pubkey {
+        self.accounts[0].key
+    }
+
+    /// Validates the swap against the rebalance the same way as a swap instruction adjacent to the rebalance
+    pub fn validate(&self, requirements: &SwapRequirements) -> Result<SwapData, ProgramError> {
+        let accounts: Vec<Pubkey> = self.accounts[1..].iter().map(|acc| *acc.key).collect();
+        decode_and_validate_swap(
+            &get_swap_validators(),
+            self.program_id(),
+            &self.data,
+            &accounts,
+            requirements,
+        )
+    }
+
+    /// The swap is signed by the rebalance signer, never by the Solauto position
+    pub fn invoke(&self) -> ProgramResult {
+        let instruction = Instruction {
+            program_id: *self.program_id(),
+            accounts: self.accounts[1..]
+                .iter()
+                .map(|acc| AccountMeta {
+                    pubkey: *acc.key,
+                    is_signer: acc.is_signer,
+                    is_writable: acc.is_writable,
+                })
+                .collect(),
+            data: self.data.clone(),
+        };
+        invoke_instruction(&instruction, self.accounts, None)
+    }
+}
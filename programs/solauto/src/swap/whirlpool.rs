@@ -0,0 +1,171 @@
+use borsh::BorshDeserialize;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{constants::WHIRLPOOL_PROGRAM, types::errors::SolautoError};
+
+use super::{SwapData, SwapValidator};
+
+/// Args of the Whirlpool swap instruction: amount, other amount threshold, sqrt price limit, amount specified is
+/// input & a to b. Kept as a tuple so that it stays out of the Solauto IDL
+type WhirlpoolSwapArgs = (u64, u64, u128, bool, bool);
+
+/// Direct swaps through a single Orca Whirlpool. Accounts are laid out as in jupiter-sdk's `WhirlpoolSwap`,
+/// without its leading swap program
+pub struct WhirlpoolSwapValidator {}
+
+impl SwapValidator for WhirlpoolSwapValidator {
+    fn program_id(&self) -> Pubkey {
+        WHIRLPOOL_PROGRAM
+    }
+
+    fn swap_ix_names(&self) -> Vec<&'static str> {
+        vec!["swap"]
+    }
+
+    fn accounts_len(&self) -> u16 {
+        11
+    }
+
+    fn decode_swap(&self, data: &[u8], accounts: &[Pubkey]) -> Result<SwapData, ProgramError> {
+        let (amount, other_amount_threshold, _, amount_specified_is_input, a_to_b) =
+            WhirlpoolSwapArgs::deserialize(&mut &data[8..])
+                .map_err(|_| SolautoError::UnsupportedSwap)?;
+
+        let token_owner_account_a = accounts[3];
+        let token_owner_account_b = accounts[5];
+        let (source_ta, destination_ta) = if a_to_b {
+            (token_owner_account_a, token_owner_account_b)
+        } else {
+            (token_owner_account_b, token_owner_account_a)
+        };
+
+        // The threshold is the minimum out-amount of exact-in swaps, and the maximum in-amount of exact-out swaps
+        let exact_out = !amount_specified_is_input;
+        let (in_amount, out_amount) = if exact_out {
+            (other_amount_threshold, amount)
+        } else {
+            (amount, other_amount_threshold)
+        };
+
+        Ok(SwapData {
+            exact_out,
+            in_amount,
+            out_amount,
+            platform_fee_bps: 0,
+            source_ta,
+            destination_ta,
+            source_mint: None,
+            destination_mint: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshSerialize;
+    use jupiter_sdk::generated::instructions::WhirlpoolSwap;
+    use solana_program::instruction::Instruction;
+
+    use super::*;
+    use crate::{
+        swap::{validate_swap_data, SwapRequirements},
+        types::shared::SwapType,
+        utils::ix_utils::get_anchor_ix_discriminator,
+    };
+
+    fn whirlpool_swap(
+        token_owner_account_a: Pubkey,
+        token_owner_account_b: Pubkey,
+        args: WhirlpoolSwapArgs,
+    ) -> Instruction {
+        let mut ix = WhirlpoolSwap {
+            swap_program: WHIRLPOOL_PROGRAM,
+            token_program: spl_token::ID,
+            token_authority: Pubkey::new_unique(),
+            whirlpool: Pubkey::new_unique(),
+            token_owner_account_a,
+            token_vault_a: Pubkey::new_unique(),
+            token_owner_account_b,
+            token_vault_b: Pubkey::new_unique(),
+            tick_array0: Pubkey::new_unique(),
+            tick_array1: Pubkey::new_unique(),
+            tick_array2: Pubkey::new_unique(),
+            oracle: Pubkey::new_unique(),
+        }
+        .instruction();
+        ix.accounts.remove(0);
+        ix.program_id = WHIRLPOOL_PROGRAM;
+        ix.data = get_anchor_ix_discriminator("swap").to_le_bytes().to_vec();
+        ix.data.extend(args.try_to_vec().unwrap());
+        ix
+    }
+
+    fn decode(ix: Instruction) -> SwapData {
+        let accounts: Vec<Pubkey> = ix.accounts.iter().map(|meta| meta.pubkey).collect();
+        WhirlpoolSwapValidator {}
+            .decode_swap(&ix.data, &accounts)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_validate_whirlpool_swap() {
+        let requirements = SwapRequirements {
+            input_mint: Pubkey::new_unique(),
+            output_mint: Pubkey::new_unique(),
+            source_ta: Pubkey::new_unique(),
+            destination_ta: Pubkey::new_unique(),
+            swap_type: SwapType::ExactIn,
+            swap_in_amount_base_unit: 1_000_000,
+        };
+        let swap = |amount: u64, amount_specified_is_input: bool, a_to_b: bool| {
+            let args: WhirlpoolSwapArgs = (amount, 500, 0, amount_specified_is_input, a_to_b);
+            if a_to_b {
+                decode(whirlpool_swap(
+                    requirements.source_ta,
+                    requirements.destination_ta,
+                    args,
+                ))
+            } else {
+                decode(whirlpool_swap(
+                    requirements.destination_ta,
+                    requirements.source_ta,
+                    args,
+                ))
+            }
+        };
+
+        assert!(validate_swap_data(&swap(1_000_000, true, true), &requirements).is_ok());
+        assert!(validate_swap_data(&swap(1_000_000, true, false), &requirements).is_ok());
+
+        assert_eq!(
+            validate_swap_data(&swap(999_999, true, true), &requirements),
+            Err(SolautoError::SwapAmountMismatch.into())
+        );
+        assert_eq!(
+            validate_swap_data(&swap(1_000_000, false, true), &requirements),
+            Err(SolautoError::IncorrectInstructions.into())
+        );
+
+        let exact_out = swap(1_000_000, false, true);
+        assert_eq!(exact_out.in_amount, 500);
+        assert_eq!(exact_out.out_amount, 1_000_000);
+        assert!(validate_swap_data(
+            &exact_out,
+            &SwapRequirements {
+                swap_type: SwapType::ExactOut,
+                ..requirements
+            }
+        )
+        .is_ok());
+        assert_eq!(
+            validate_swap_data(
+                &swap(1_000_000, true, true),
+                &SwapRequirements {
+                    source_ta: Pubkey::new_unique(),
+                    ..requirements
+                }
+            ),
+            Err(SolautoError::SwapTokenAccountMismatch.into())
+        );
+    }
+}
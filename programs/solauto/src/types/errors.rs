@@ -33,6 +33,48 @@ pub enum SolautoError {
     InvalidRebalanceMade,
     #[error("Cannot provide a target liquidation utilization rate if the instruction is not signed by the position authority")]
     NonAuthorityProvidedTargetLTV,
+    #[error("The position has more supply or debt legs than a Solauto position can track")]
+    TooManyPositionLegs,
+    #[error("Invalid limit order provided")]
+    InvalidLimitOrder,
+    #[error("Invalid take-profit order provided")]
+    InvalidTakeProfitOrder,
+    #[error("Invalid stop-loss order provided")]
+    InvalidStopLossOrder,
+    #[error("Invalid trailing stop order provided")]
+    InvalidTrailingStopOrder,
+    #[error("Invalid leverage curve provided")]
+    InvalidLeverageCurve,
+    #[error("Invalid grid settings provided")]
+    InvalidGridSettings,
+    #[error("Invalid carry guard provided")]
+    InvalidCarryGuard,
+    #[error("Boosts are refused while the position's net carry is negative")]
+    NegativeCarryBoost,
+    #[error("Invalid max price divergence provided")]
+    InvalidMaxPriceDivergence,
+    #[error("Boosts are refused while the realtime & EMA prices diverge by more than the position allows")]
+    PriceDivergenceTooHigh,
+    #[error("The oracle price is older than the maximum price age")]
+    StaleOraclePrice,
+    #[error("The oracle confidence interval is too wide relative to the price")]
+    OracleConfidenceTooWide,
+    #[error("The oracle reported a price that isn't positive")]
+    InvalidOraclePrice,
+    #[error("The swap isn't through a supported venue or route, or its instruction data couldn't be decoded")]
+    UnsupportedSwap,
+    #[error("The swap's input or output mint doesn't match the rebalance")]
+    SwapMintMismatch,
+    #[error("The swap's source or destination token account doesn't match the rebalance")]
+    SwapTokenAccountMismatch,
+    #[error("The swap takes a platform fee")]
+    SwapPlatformFee,
+    #[error("The swap's in-amount doesn't match the rebalance swap-in amount")]
+    SwapAmountMismatch,
+    #[error("Invalid max slippage param")]
+    InvalidMaxSlippage,
+    #[error("The swap returned less than the oracle prices imply, by more than the max slippage")]
+    SwapSlippageTooHigh,
 }
 
 impl From<SolautoError> for ProgramError {
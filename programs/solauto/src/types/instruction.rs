@@ -5,6 +5,10 @@ use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
 use crate::{
     state::{
         automation::DCASettingsInp,
+        carry_guard::CarryGuardInp,
+        grid::GridSettingsInp,
+        leverage_curve::LeverageCurveInp,
+        orders::{LimitOrderInp, StopLossOrderInp, TakeProfitOrderInp, TrailingStopOrderInp},
         referral_state::ReferralState,
         solauto_position::{SolautoPosition, SolautoSettingsParametersInp},
     },
@@ -168,9 +172,189 @@ pub enum Instruction {
     #[account(mut, optional, name = "vault_debt_ta")]
     #[account(mut, optional, name = "debt_vault_authority")]
     MarginfiRebalance(RebalanceSettings),
+
+    /// Open a new Solauto position on any supported lending platform.
+    /// The lending platform accounts are provided as remaining accounts, in the order expected by the platform's account parser
+    #[account(signer, name = "signer")]
+    #[account(name = "lending_program")]
+    #[account(name = "system_program")]
+    #[account(name = "token_program")]
+    #[account(name = "ata_program")]
+    #[account(name = "rent")]
+    #[account(name = "signer_referral_state")]
+    #[account(optional, name = "referred_by_state")]
+    #[account(mut, optional, name = "referred_by_supply_ta")]
+    #[account(mut, name = "solauto_position")]
+    #[account(name = "supply_mint")]
+    #[account(mut, name = "position_supply_ta")]
+    #[account(name = "debt_mint")]
+    #[account(mut, name = "position_debt_ta")]
+    #[account(mut, optional, name = "signer_debt_ta")]
+    OpenPosition(OpenPositionData),
+
+    /// Refresh the lending platform accounts & position data. The lending platform is read from the position.
+    /// The lending platform accounts are provided as remaining accounts
+    #[account(signer, name = "signer")]
+    #[account(name = "lending_program")]
+    #[account(mut, name = "solauto_position")]
+    RefreshData(PriceType),
+
+    /// Lending platform interaction. Can only be invoked by the authority of the position.
+    /// The lending platform is read from the position, its accounts are provided as remaining accounts
+    #[account(signer, name = "signer")]
+    #[account(name = "lending_program")]
+    #[account(name = "system_program")]
+    #[account(name = "token_program")]
+    #[account(name = "ata_program")]
+    #[account(name = "rent")]
+    #[account(name = "ixs_sysvar")]
+    #[account(mut, name = "solauto_position")]
+    #[account(optional, name = "supply_mint")]
+    #[account(mut, optional, name = "position_supply_ta")]
+    #[account(optional, name = "debt_mint")]
+    #[account(mut, optional, name = "position_debt_ta")]
+    ProtocolInteraction(SolautoAction),
+
+    /// Rebalance the position, can be invoked by the authority or Solauto manager.
+    /// The lending platform is read from the position, its accounts are provided as remaining accounts.
+    /// With the SwapCpi rebalance type, the swap accounts follow them, and the whole rebalance is done in this instruction
+    #[account(signer, name = "signer")]
+    #[account(name = "lending_program")]
+    #[account(name = "system_program")]
+    #[account(name = "token_program")]
+    #[account(name = "ixs_sysvar")]
+    #[account(mut, optional, name = "solauto_fees_ta")]
+    #[account(name = "authority_referral_state")]
+    #[account(mut, optional, name = "referred_by_ta")]
+    #[account(mut, optional, name = "position_authority")]
+    #[account(mut, name = "solauto_position")]
+    #[account(optional, mut, name = "intermediary_ta")]
+    #[account(name = "supply_mint")]
+    #[account(mut, name = "position_supply_ta")]
+    #[account(mut, optional, name = "authority_supply_ta")]
+    #[account(name = "debt_mint")]
+    #[account(mut, name = "position_debt_ta")]
+    #[account(mut, optional, name = "authority_debt_ta")]
+    Rebalance(RebalanceSettings),
+
+    /// Settle & withdraw the Marginfi emissions earned on one of the position's banks, and deposit them into the supply.
+    /// Can only be invoked on positions that are not self-managed.
+    /// If the emissions mint isn't the supply mint, the emissions are moved to the intermediary token account, and this instruction must be followed
+    /// by a Jupiter swap into the position supply token account, and then by this instruction again to deposit the swap output
+    #[account(signer, name = "signer")]
+    #[account(name = "marginfi_program")]
+    #[account(name = "system_program")]
+    #[account(name = "token_program")]
+    #[account(name = "ixs_sysvar")]
+    #[account(mut, name = "solauto_position")]
+    #[account(name = "marginfi_group")]
+    #[account(mut, name = "marginfi_account")]
+    #[account(mut, name = "emissions_bank")]
+    #[account(name = "emissions_mint")]
+    #[account(name = "emissions_auth")]
+    #[account(mut, name = "emissions_vault")]
+    #[account(mut, name = "position_emissions_ta")]
+    #[account(mut, optional, name = "intermediary_ta")]
+    #[account(mut, name = "supply_bank")]
+    #[account(mut, name = "position_supply_ta")]
+    #[account(mut, name = "vault_supply_ta")]
+    #[account(name = "debt_bank")]
+    MarginfiHarvestEmissions,
+
+    /// Move the position to another lending platform, keeping the same Solauto position. Can only be invoked by the authority of the position,
+    /// on positions that are not self-managed. Must be preceded by a flash borrow of the debt token into the intermediary token account,
+    /// which is repaid with the debt borrowed on the new lending platform.
+    /// The current lending platform accounts are provided as remaining accounts, followed by the new lending platform accounts
+    #[account(signer, name = "signer")]
+    #[account(name = "source_lending_program")]
+    #[account(name = "destination_lending_program")]
+    #[account(name = "system_program")]
+    #[account(name = "token_program")]
+    #[account(name = "ata_program")]
+    #[account(name = "rent")]
+    #[account(name = "ixs_sysvar")]
+    #[account(mut, name = "solauto_position")]
+    #[account(mut, name = "intermediary_ta")]
+    #[account(name = "supply_mint")]
+    #[account(mut, name = "position_supply_ta")]
+    #[account(name = "debt_mint")]
+    #[account(mut, name = "position_debt_ta")]
+    MigratePosition(MigratePositionData),
+
+    /// Open a Solauto position on top of an existing Marginfi account of the signer, moving its authority to the Solauto position.
+    /// The Marginfi account may only hold a supply balance on the supply bank and a debt balance on the debt bank.
+    /// The position cannot be self-managed
+    #[account(signer, name = "signer")]
+    #[account(name = "marginfi_program")]
+    #[account(name = "system_program")]
+    #[account(name = "token_program")]
+    #[account(name = "ata_program")]
+    #[account(name = "rent")]
+    #[account(name = "signer_referral_state")]
+    #[account(optional, name = "referred_by_state")]
+    #[account(mut, optional, name = "referred_by_supply_ta")]
+    #[account(mut, name = "solauto_position")]
+    #[account(name = "marginfi_group")]
+    #[account(mut, name = "marginfi_account")]
+    #[account(name = "supply_mint")]
+    #[account(mut, name = "supply_bank")]
+    #[account(name = "supply_price_oracle")]
+    #[account(mut, name = "position_supply_ta")]
+    #[account(name = "debt_mint")]
+    #[account(mut, name = "debt_bank")]
+    #[account(name = "debt_price_oracle")]
+    #[account(mut, name = "position_debt_ta")]
+    MarginfiImportAccount(MarginfiOpenPositionData),
+
+    /// Hand the Marginfi account of the position back to the position authority, then close the Solauto position.
+    /// Can only be invoked by the authority of the position
+    #[account(signer, name = "signer")]
+    #[account(name = "marginfi_program")]
+    #[account(name = "system_program")]
+    #[account(name = "token_program")]
+    #[account(name = "ata_program")]
+    #[account(mut, name = "solauto_position")]
+    #[account(name = "marginfi_group")]
+    #[account(mut, name = "marginfi_account")]
+    #[account(mut, name = "position_supply_ta")]
+    #[account(mut, name = "signer_supply_ta")]
+    #[account(mut, name = "position_debt_ta")]
+    #[account(mut, name = "signer_debt_ta")]
+    MarginfiExportAccount,
+
+    /// Escrow tokens in the position token account, to be deposited into the position once the supply price crosses the trigger price.
+    /// Can only be invoked by the authority of the position
+    #[account(signer, name = "signer")]
+    #[account(name = "system_program")]
+    #[account(name = "token_program")]
+    #[account(name = "ata_program")]
+    #[account(mut, name = "solauto_position")]
+    #[account(name = "order_mint")]
+    #[account(mut, name = "position_order_ta")]
+    #[account(mut, name = "signer_order_ta")]
+    PlaceLimitOrder(LimitOrderInp),
+
+    /// Cancel the open limit order on a Solauto position and return the escrowed tokens to the position authority
+    #[account(signer, name = "signer")]
+    #[account(name = "system_program")]
+    #[account(name = "token_program")]
+    #[account(name = "ata_program")]
+    #[account(mut, name = "solauto_position")]
+    #[account(name = "order_mint")]
+    #[account(mut, name = "position_order_ta")]
+    #[account(mut, name = "signer_order_ta")]
+    CancelLimitOrder,
+
+    /// Reallocate a Solauto position opened with the legacy account layout to the current layout, topping up its rent from the signer.
+    /// Can be invoked by the authority of the position or the Solauto manager
+    #[account(mut, signer, name = "signer")]
+    #[account(name = "system_program")]
+    #[account(mut, name = "solauto_position")]
+    UpgradePosition,
 }
 
-pub const SOLAUTO_REBALANCE_IX_DISCRIMINATORS: [u8; 1] = [9];
+pub const SOLAUTO_REBALANCE_IX_DISCRIMINATORS: [u8; 2] = [9, 13];
+pub const SOLAUTO_HARVEST_EMISSIONS_IX_DISCRIMINATOR: u8 = 14;
 
 #[derive(BorshDeserialize, Clone, Debug)]
 pub struct UpdateReferralStatesArgs {
@@ -180,20 +364,51 @@ pub struct UpdateReferralStatesArgs {
     pub address_lookup_table: Option<Pubkey>,
 }
 
+#[derive(BorshDeserialize, Clone, Debug)]
+pub struct MigratePositionData {
+    /// The lending platform to move the position to
+    pub lending_platform: LendingPlatform,
+    /// Number of remaining accounts that belong to the current lending platform, the rest belong to the new lending platform
+    pub source_accounts_len: u8,
+}
+
 #[derive(BorshDeserialize, Clone, Debug)]
 pub struct MarginfiOpenPositionData {
     pub position_type: PositionType,
     pub position_data: UpdatePositionData,
 }
 
+#[derive(BorshDeserialize, Clone, Debug)]
+pub struct OpenPositionData {
+    pub lending_platform: LendingPlatform,
+    pub position_type: PositionType,
+    pub position_data: UpdatePositionData,
+}
+
 #[derive(BorshDeserialize, Clone, Debug)]
 pub struct UpdatePositionData {
     /// ID of the Solauto position
     pub position_id: u8,
     /// Setting parameters for the position
     pub settings: Option<SolautoSettingsParametersInp>,
+    /// Strategy that decides when & how the position is rebalanced
+    pub rebalance_strategy: Option<RebalanceStrategyType>,
+    /// Largest divergence between the realtime & EMA prices at which the position can still be boosted. 0 turns the guard off
+    pub max_price_divergence_bps: Option<u16>,
+    /// Setting parameters per supply / debt price ratio, for the leverage curve strategy
+    pub leverage_curve: Option<LeverageCurveInp>,
+    /// Grid levels for the grid strategy, restarting the grid from the level closest to the current liq utilization rate
+    pub grid: Option<GridSettingsInp>,
+    /// Deleverage on, and refuse boosts while, a negative net carry between the supply & borrow APRs
+    pub carry_guard: Option<CarryGuardInp>,
     /// New DCA data to initiate on the position
     pub dca: Option<DCASettingsInp>,
+    /// Take-profit order to set on the position, replacing any existing one
+    pub take_profit: Option<TakeProfitOrderInp>,
+    /// Stop-loss order to set on the position, replacing any existing one
+    pub stop_loss: Option<StopLossOrderInp>,
+    /// Trailing stop order to set on the position, replacing any existing one and resetting the high-water mark
+    pub trailing_stop: Option<TrailingStopOrderInp>,
 }
 
 #[derive(BorshDeserialize, Clone, Debug, PartialEq)]
@@ -215,9 +430,18 @@ pub struct RebalanceSettings {
     pub swap_in_amount_base_unit: Option<u64>,
     /// Target liq utilization rate. Only used/allowed if signed by the position authority.
     pub target_liq_utilization_rate_bps: Option<u16>,
-    pub flash_loan_fee_bps: Option<u16>,
     pub price_type: Option<PriceType>,
     pub swap_type: Option<SwapType>,
+    /// The swap to execute through a CPI. Only used/allowed by the SwapCpi rebalance type of the generic rebalance instruction
+    pub swap_cpi: Option<SwapCpiData>,
+}
+
+#[derive(BorshDeserialize, Clone, Debug, ShankType)]
+pub struct SwapCpiData {
+    /// Number of remaining accounts, at the end, that belong to the swap. The first of them is the swap program
+    pub accounts_len: u8,
+    /// Instruction data of the swap, e.g. a Jupiter route
+    pub data: Vec<u8>,
 }
 
 pub struct SolautoStandardAccounts<'a> {
@@ -2,7 +2,11 @@ use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
 };
 
-use super::{instruction::SolautoStandardAccounts, shared::TokenBalanceAmount};
+use super::{
+    errors::SolautoError,
+    instruction::SolautoStandardAccounts,
+    shared::{PriceType, RefreshStateProps, TokenBalanceAmount},
+};
 
 #[derive(Clone)]
 pub struct LendingProtocolTokenAccounts<'a> {
@@ -53,3 +57,71 @@ pub trait LendingProtocolClient<'a> {
         std_accounts: &'c Box<SolautoStandardAccounts<'a>>,
     ) -> ProgramResult;
 }
+
+/// Reads the platform-specific accounts of the generic position instructions, in order, from the instruction's remaining accounts.
+/// Optional accounts that aren't provided are passed as the Solauto program id, same as optional instruction accounts
+pub struct LendingProtocolAccountsParser<'a> {
+    accounts: &'a [AccountInfo<'a>],
+    index: usize,
+}
+
+impl<'a> LendingProtocolAccountsParser<'a> {
+    pub fn from(accounts: &'a [AccountInfo<'a>]) -> Self {
+        Self { accounts, index: 0 }
+    }
+
+    pub fn next_account(&mut self) -> Result<&'a AccountInfo<'a>, ProgramError> {
+        let account = self
+            .accounts
+            .get(self.index)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        self.index += 1;
+        Ok(account)
+    }
+
+    pub fn next_optional_account(&mut self) -> Result<Option<&'a AccountInfo<'a>>, ProgramError> {
+        let account = self.next_account()?;
+        if account.key == &crate::ID {
+            Ok(None)
+        } else {
+            Ok(Some(account))
+        }
+    }
+
+    /// The accounts following the ones that have been read
+    pub fn remaining(&self) -> &'a [AccountInfo<'a>] {
+        &self.accounts[self.index.min(self.accounts.len())..]
+    }
+}
+
+pub fn required_account<'a>(
+    account: Option<&'a AccountInfo<'a>>,
+) -> Result<&'a AccountInfo<'a>, ProgramError> {
+    account.ok_or(SolautoError::IncorrectAccounts.into())
+}
+
+/// The platform-specific accounts of a position, used by the generic position instructions.
+/// Each lending platform client module implements this, and registers its parser in `clients::parse_lending_protocol_accounts`
+pub trait LendingProtocolAccounts<'a> {
+    fn lp_pool_account(&self) -> &'a AccountInfo<'a>;
+    fn lp_user_account(&self) -> &'a AccountInfo<'a>;
+    fn lp_supply_account(&self) -> &'a AccountInfo<'a>;
+    fn lp_debt_account(&self) -> &'a AccountInfo<'a>;
+    /// The lending platform's supply & debt liquidity vaults
+    fn protocol_tas(&self) -> (Option<&'a AccountInfo<'a>>, Option<&'a AccountInfo<'a>>);
+    fn get_max_ltv_and_liq_threshold(&self) -> Result<(f64, f64), ProgramError>;
+    /// Create the lending platform user account(s) of the position, if they don't exist yet
+    fn initialize(&self, std_accounts: &SolautoStandardAccounts<'a>) -> ProgramResult;
+    /// Refresh the lending platform accounts so that they reflect the current interest & prices
+    fn refresh_accounts(&self) -> ProgramResult;
+    fn get_updated_state(&self, price_type: PriceType) -> Result<RefreshStateProps, ProgramError>;
+    fn load_prices(&self, price_type: PriceType) -> Result<(f64, f64), ProgramError>;
+    /// Prices of the position's additional supply & debt legs, which only Marginfi positions have
+    fn load_leg_prices(&self, price_type: PriceType) -> Result<Vec<f64>, ProgramError>;
+    fn client(
+        &self,
+        signer: &'a AccountInfo<'a>,
+        supply_tas: LendingProtocolTokenAccounts<'a>,
+        debt_tas: LendingProtocolTokenAccounts<'a>,
+    ) -> Result<Box<dyn LendingProtocolClient<'a> + 'a>, ProgramError>;
+}
@@ -21,6 +21,8 @@ use super::errors::SolautoError;
 pub enum LendingPlatform {
     #[default]
     Marginfi,
+    Kamino,
+    Save,
 }
 derive_pod_traits!(LendingPlatform);
 
@@ -33,6 +35,19 @@ pub enum PositionType {
 }
 derive_pod_traits!(PositionType);
 
+#[repr(u8)]
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, ShankType, Default, PartialEq, Copy)]
+pub enum RebalanceStrategyType {
+    /// Boost & repay within the boost_to / repay_to band of the setting parameters
+    #[default]
+    Band,
+    /// Boost & repay within the band of the leverage curve that matches the current supply / debt price ratio
+    LeverageCurve,
+    /// Step the position one grid level at a time as the supply / debt price ratio moves
+    Grid,
+}
+derive_pod_traits!(RebalanceStrategyType);
+
 #[repr(u8)]
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, ShankType, Default, PartialEq, Copy)]
 pub enum TokenType {
@@ -78,6 +93,8 @@ pub enum SolautoRebalanceType {
     DoubleRebalanceWithFL,
     FLSwapThenRebalance,
     FLRebalanceThenSwap,
+    /// A single instruction that swaps through a CPI between its pre-swap & post-swap steps
+    SwapCpi,
 }
 derive_pod_traits!(SolautoRebalanceType);
 
@@ -98,6 +115,16 @@ pub struct RefreshedTokenState {
     pub amount_can_be_used: u64,
     pub market_price: f64,
     pub borrow_fee_bps: Option<u16>,
+    /// Current lending APR of the token's bank or reserve, 0 if the lending platform's rate curve isn't read
+    pub supply_apr_bps: u32,
+    /// Current borrowing APR of the token's bank or reserve, 0 if the lending platform's rate curve isn't read
+    pub borrow_apr_bps: u32,
+}
+
+#[derive(Debug)]
+pub struct RefreshedLegState {
+    pub lp_account: Pubkey,
+    pub token: RefreshedTokenState,
 }
 
 #[derive(Debug)]
@@ -106,6 +133,8 @@ pub struct RefreshStateProps {
     pub liq_threshold: f64,
     pub supply: RefreshedTokenState,
     pub debt: RefreshedTokenState,
+    pub additional_supply: Vec<RefreshedLegState>,
+    pub additional_debt: Vec<RefreshedLegState>,
 }
 
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
@@ -153,10 +182,17 @@ pub struct DeserializedAccount<'a, T> {
 impl<'a, T: AnyBitPattern> DeserializedAccount<'a, T> {
     pub fn zerocopy(account: Option<&'a AccountInfo<'a>>) -> Result<Option<Self>, ProgramError> {
         match account {
-            Some(account_info) => Ok(Some(Self {
-                account_info,
-                data: Box::new(*bytemuck::from_bytes::<T>(&account_info.data.borrow())),
-            })),
+            Some(account_info) => {
+                let data =
+                    *bytemuck::try_from_bytes::<T>(&account_info.data.borrow()).map_err(|_| {
+                        msg!("Failed to deserialize account data");
+                        SolautoError::FailedAccountDeserialization
+                    })?;
+                Ok(Some(Self {
+                    account_info,
+                    data: Box::new(data),
+                }))
+            }
             None => Ok(None),
         }
     }
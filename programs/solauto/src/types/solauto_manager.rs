@@ -3,14 +3,16 @@ use std::collections::HashMap;
 use math_utils::to_bps;
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program_error::ProgramError,
+    program_error::ProgramError, sysvar::Sysvar,
 };
+use spl_associated_token_account::get_associated_token_address;
 
 use super::{
     instruction::{RebalanceSettings, SolautoAction, SolautoStandardAccounts},
     lending_protocol::{LendingProtocolClient, LendingProtocolTokenAccounts},
     shared::{
-        RebalanceStep, RefreshStateProps, SplTokenTransferArgs, TokenBalanceAmount, TokenType,
+        RebalanceDirection, RebalanceStep, RefreshStateProps, SplTokenTransferArgs, SwapType,
+        TokenBalanceAmount, TokenType,
     },
     solauto::{SolautoAccount, SolautoCpiAction},
 };
@@ -21,7 +23,8 @@ use crate::{
         rebalancer::{Rebalancer, RebalancerData, SolautoPositionData, TokenAccountData},
         solauto_fees::SolautoFeesBps,
     },
-    state::solauto_position::{RebalanceData, SolautoPosition},
+    state::solauto_position::{RebalanceData, SolautoPosition, TokenBalanceChangeType},
+    swap::{validate_rebalance_swap, SwapCpi, SwapRequirements},
     types::errors::SolautoError,
     utils::*,
 };
@@ -178,11 +181,15 @@ impl<'a> SolautoManager<'a> {
         map.get(acc).unwrap().unwrap()
     }
 
-    fn get_rebalancer(&mut self, rebalance_args: RebalanceSettings) -> Rebalancer {
+    fn get_rebalancer(
+        &mut self,
+        rebalance_args: RebalanceSettings,
+    ) -> Result<Rebalancer<'_>, ProgramError> {
         let position_supply_ta = self.get_token_account_data(self.accounts.supply.position_ta);
         let position_debt_ta = self.get_token_account_data(self.accounts.debt.position_ta);
+        let curr_unix_timestamp = Clock::get()?.unix_timestamp as u64;
 
-        Rebalancer::new(RebalancerData {
+        Ok(Rebalancer::new(RebalancerData {
             rebalance_args,
             solauto_position: SolautoPositionData {
                 data: &mut self.std_accounts.solauto_position.data,
@@ -198,10 +205,11 @@ impl<'a> SolautoManager<'a> {
                     .unwrap()
                     .data
                     .is_referred(),
-        })
+            curr_unix_timestamp,
+        }))
     }
 
-    fn execute_cpi_actions(&mut self, actions: Vec<SolautoCpiAction>) -> ProgramResult {
+    pub fn execute_cpi_actions(&mut self, actions: Vec<SolautoCpiAction>) -> ProgramResult {
         let owned_seeds: Vec<Vec<u8>> = self
             .std_accounts
             .solauto_position
@@ -260,10 +268,18 @@ impl<'a> SolautoManager<'a> {
 
         solauto_utils::update_token_state(&mut solauto_position.state.supply, &updated_data.supply);
         solauto_utils::update_token_state(&mut solauto_position.state.debt, &updated_data.debt);
+        solauto_utils::update_position_legs(
+            &mut solauto_position.state.additional_supply,
+            &updated_data.additional_supply,
+        )?;
+        solauto_utils::update_position_legs(
+            &mut solauto_position.state.additional_debt,
+            &updated_data.additional_debt,
+        )?;
 
         solauto_position.state.net_worth.base_unit = math_utils::net_worth_base_amount(
-            solauto_position.state.supply.amount_used.usd_value(),
-            solauto_position.state.debt.amount_used.usd_value(),
+            solauto_position.state.supply_usd(),
+            solauto_position.state.debt_usd(),
             solauto_position.state.supply.market_price(),
             solauto_position.state.supply.decimals,
         );
@@ -275,6 +291,16 @@ impl<'a> SolautoManager<'a> {
         solauto_position.refresh_state();
         solauto_position.state.last_refreshed = clock.unix_timestamp as u64;
 
+        // A grid set before the position's prices were known is anchored once they are
+        if solauto_position.grid.is_active() && !solauto_position.grid.is_anchored() {
+            if let Some(price_ratio) = solauto_position.price_ratio() {
+                let liq_utilization_rate_bps = solauto_position.state.liq_utilization_rate_bps;
+                solauto_position
+                    .grid
+                    .anchor(liq_utilization_rate_bps, price_ratio);
+            }
+        }
+
         Ok(())
     }
 
@@ -299,6 +325,39 @@ impl<'a> SolautoManager<'a> {
         Ok(())
     }
 
+    /// Tokens taken out of the position by a rebalance can only go to the position authority's token account
+    fn validate_authority_token_account(&self) -> ProgramResult {
+        let solauto_position = &self.std_accounts.solauto_position.data;
+        let (authority_ta, mint) = match solauto_position
+            .rebalance
+            .values
+            .token_balance_change
+            .change_type
+        {
+            TokenBalanceChangeType::PostRebalanceWithdrawSupplyToken => (
+                self.accounts.supply.authority_ta,
+                &solauto_position.state.supply.mint,
+            ),
+            TokenBalanceChangeType::PostRebalanceWithdrawDebtToken => (
+                self.accounts.debt.authority_ta,
+                &solauto_position.state.debt.mint,
+            ),
+            _ => return Ok(()),
+        };
+
+        check!(
+            authority_ta.is_some()
+                && validation_utils::correct_token_account(
+                    authority_ta.unwrap().key,
+                    &solauto_position.authority,
+                    mint
+                ),
+            SolautoError::IncorrectAccounts
+        );
+
+        Ok(())
+    }
+
     fn validate_fee_token_accounts(&self) -> ProgramResult {
         let mints = vec![
             self.std_accounts.solauto_position.data.state.supply.mint,
@@ -336,20 +395,126 @@ impl<'a> SolautoManager<'a> {
         Ok(())
     }
 
+    /// The rebalance's swap, through whichever supported venue is adjacent, must spend exactly what was pulled into the signer's intermediary token account,
+    /// and send its output to the position, or to the signer repaying the flash loan when it's an exact-out swap.
+    /// Exact-out swaps run after the rebalance has finished, so their slippage is bounded here by the swap-in amount
+    fn validate_swap(
+        &self,
+        rebalance_args: &RebalanceSettings,
+        swap_cpi: Option<&SwapCpi<'a>>,
+    ) -> ProgramResult {
+        let solauto_position = &self.std_accounts.solauto_position.data;
+        let signer = self.std_accounts.signer.key;
+        let (input_mint, output_mint, position_output_ta) =
+            if solauto_position.rebalance.values.rebalance_direction == RebalanceDirection::Boost {
+                (
+                    solauto_position.state.debt.mint,
+                    solauto_position.state.supply.mint,
+                    self.accounts.supply.position_ta,
+                )
+            } else {
+                (
+                    solauto_position.state.supply.mint,
+                    solauto_position.state.debt.mint,
+                    self.accounts.debt.position_ta,
+                )
+            };
+
+        let intermediary_ta = self
+            .accounts
+            .intermediary_ta
+            .ok_or(SolautoError::IncorrectAccounts)?;
+        check!(
+            validation_utils::correct_token_account(intermediary_ta.key, signer, &input_mint),
+            SolautoError::SwapTokenAccountMismatch
+        );
+
+        let swap_type = solauto_position.rebalance.ixs.swap_type;
+        let destination_ta = if swap_type == SwapType::ExactOut {
+            get_associated_token_address(signer, &output_mint)
+        } else {
+            *position_output_ta
+                .ok_or(SolautoError::IncorrectAccounts)?
+                .key
+        };
+
+        let swap_in_amount_base_unit = rebalance_args
+            .swap_in_amount_base_unit
+            .ok_or(SolautoError::IncorrectInstructions)?;
+        let requirements = SwapRequirements {
+            input_mint,
+            output_mint,
+            source_ta: *intermediary_ta.key,
+            destination_ta,
+            swap_type,
+            swap_in_amount_base_unit,
+        };
+        let swap = if let Some(swap_cpi) = swap_cpi {
+            swap_cpi.validate(&requirements)?
+        } else {
+            validate_rebalance_swap(
+                self.std_accounts.ixs_sysvar.unwrap(),
+                solauto_position.rebalance.ixs.rebalance_type,
+                &requirements,
+            )?
+        };
+
+        if swap.exact_out {
+            validation_utils::validate_swap_slippage(
+                solauto_position,
+                swap_in_amount_base_unit,
+                swap.out_amount,
+            )?;
+        }
+
+        Ok(())
+    }
+
     pub fn rebalance(
         &mut self,
         rebalance_args: RebalanceSettings,
         rebalance_step: RebalanceStep,
+    ) -> ProgramResult {
+        self.rebalance_step(rebalance_args, rebalance_step, None)
+    }
+
+    /// Runs both rebalance steps in one instruction, swapping through a CPI in between. Token account balances
+    /// are read again by the post-swap step, so it sees the swap output
+    pub fn rebalance_with_swap_cpi(
+        &mut self,
+        rebalance_args: RebalanceSettings,
+        swap_cpi: &SwapCpi<'a>,
+    ) -> ProgramResult {
+        self.rebalance_step(
+            rebalance_args.clone(),
+            RebalanceStep::PreSwap,
+            Some(swap_cpi),
+        )?;
+        swap_cpi.invoke()?;
+        self.rebalance_step(rebalance_args, RebalanceStep::PostSwap, Some(swap_cpi))
+    }
+
+    fn rebalance_step(
+        &mut self,
+        rebalance_args: RebalanceSettings,
+        rebalance_step: RebalanceStep,
+        swap_cpi: Option<&SwapCpi<'a>>,
     ) -> ProgramResult {
         self.validate_fee_token_accounts()?;
 
+        // The swap is validated by the instruction that starts the rebalance, once its direction is known
+        let first_rebalance_ix = !self.position_data().rebalance.values_set();
         let (actions, finished) = {
-            let mut rebalancer = self.get_rebalancer(rebalance_args.clone());
+            let mut rebalancer = self.get_rebalancer(rebalance_args.clone())?;
             let rebalance_result = rebalancer.rebalance(rebalance_step)?;
             let actions = rebalancer.actions().clone();
             (actions, rebalance_result.finished)
         };
 
+        if first_rebalance_ix {
+            self.validate_swap(&rebalance_args, swap_cpi)?;
+        }
+        self.validate_authority_token_account()?;
         self.execute_cpi_actions(actions)?;
 
         if finished {
@@ -1,6 +1,6 @@
-use borsh::{BorshDeserialize, BorshSerialize};
+use borsh::BorshSerialize;
 use jupiter_sdk::JUPITER_ID;
-use marginfi_sdk::generated::instructions::LendingAccountBorrowInstructionArgs;
+use kamino_sdk::{instructions::REFRESH_OBLIGATION_DISCRIMINATOR, KAMINO_ID};
 use solana_program::{
     account_info::AccountInfo,
     entrypoint::ProgramResult,
@@ -17,13 +17,16 @@ use solana_program::{
 
 use super::solana_utils::invoke_instruction;
 use crate::{
-    check,
-    constants::{MARGINFI_PROD_PROGRAM, MARGINFI_STAGING_PROGRAM},
-    error_if,
+    check, error_if,
+    flash_loan::{detect_flash_loan, FlashLoan},
     state::solauto_position::SolautoPosition,
+    swap::swap_ix_matches,
     types::{
         errors::SolautoError,
-        instruction::{SolautoStandardAccounts, SOLAUTO_REBALANCE_IX_DISCRIMINATORS},
+        instruction::{
+            SolautoStandardAccounts, SOLAUTO_HARVEST_EMISSIONS_IX_DISCRIMINATOR,
+            SOLAUTO_REBALANCE_IX_DISCRIMINATORS,
+        },
         shared::{DeserializedAccount, SolautoRebalanceType},
     },
 };
@@ -69,7 +72,7 @@ pub struct PickIxDataResp {
     pub accounts: Vec<Pubkey>,
 }
 
-fn pick_ix_data(req: PickIxDataReq) -> Result<PickIxDataResp, SanitizeError> {
+pub fn pick_ix_data(req: PickIxDataReq) -> Result<PickIxDataResp, SanitizeError> {
     let PickIxDataReq {
         ixs_sysvar,
         ix_idx,
@@ -120,24 +123,6 @@ fn pick_ix_data(req: PickIxDataReq) -> Result<PickIxDataResp, SanitizeError> {
     })
 }
 
-pub fn get_marginfi_flash_loan_amount<'a>(
-    ixs_sysvar: &'a AccountInfo<'a>,
-    ix_idx: Option<usize>,
-) -> Result<u64, ProgramError> {
-    let data = pick_ix_data(PickIxDataReq {
-        ixs_sysvar,
-        ix_idx: ix_idx.unwrap(),
-        data_start_idx: Some(8),
-        data_len: Some(8),
-        account_indices: Some(vec![4]),
-    })
-    .expect("Should retrieve flash loan amount");
-
-    let args = LendingAccountBorrowInstructionArgs::deserialize(&mut data.data.as_slice())?;
-
-    return Ok(args.amount);
-}
-
 pub fn get_anchor_ix_discriminator(instruction_name: &str) -> u64 {
     let concatenated = format!("global:{}", instruction_name.to_lowercase());
     let mut sighash = [0u8; 8];
@@ -226,19 +211,91 @@ impl<'a> InstructionChecker<'a> {
     pub fn matches(&self, relative_ix_idx: i16) -> bool {
         let discriminator_len = if self.anchor_program { 8 } else { 1 };
 
-        let data = pick_ix_data(PickIxDataReq {
+        // Instruction data too short to hold the discriminator can't match
+        let data = match pick_ix_data(PickIxDataReq {
             ixs_sysvar: self.ixs_sysvar,
             ix_idx: ((self.curr_ix_idx as i16) + relative_ix_idx) as usize,
             data_start_idx: Some(0),
             data_len: Some(discriminator_len),
             account_indices: None,
-        })
-        .expect("Should work");
+        }) {
+            Ok(data) => data,
+            Err(_) => return false,
+        };
 
         return self.ix_matches(data.program_id, &data.data);
     }
 }
 
+fn jupiter_swap_checker<'a>(
+    ixs_sysvar: &'a AccountInfo<'a>,
+    current_ix_idx: u16,
+) -> InstructionChecker<'a> {
+    InstructionChecker::from_anchor(
+        ixs_sysvar,
+        vec![JUPITER_ID],
+        vec![
+            "route",
+            "shared_accounts_route",
+            "route_with_token_ledger",
+            "shared_accounts_route_with_token_ledger",
+            "exact_out_route",
+            "shared_accounts_exact_out_route",
+        ],
+        current_ix_idx,
+    )
+}
+
+fn kamino_refresh_checker<'a>(
+    ixs_sysvar: &'a AccountInfo<'a>,
+    current_ix_idx: u16,
+) -> InstructionChecker<'a> {
+    InstructionChecker::from_anchor(
+        ixs_sysvar,
+        vec![KAMINO_ID],
+        vec![
+            "refresh_reserve",
+            "refresh_obligation",
+            "refresh_obligation_farms_for_reserve",
+        ],
+        current_ix_idx,
+    )
+}
+
+fn num_instructions(ixs_sysvar: &AccountInfo) -> usize {
+    let data = ixs_sysvar
+        .try_borrow_data()
+        .expect("Should retrieve IXS sysvar data");
+    u16::from_le_bytes([data[0], data[1]]) as usize
+}
+
+/// Index, relative to the current instruction, of the nth instruction after it (or before it if negative), not counting
+/// the top-level refresh instructions Kamino requires around its deposit, withdraw, borrow & repay instructions.
+/// Returns None if the transaction has no such instruction
+pub fn relative_ix_idx_skipping_refreshes<'a>(
+    ixs_sysvar: &'a AccountInfo<'a>,
+    current_ix_idx: u16,
+    nth_ix: i16,
+) -> Option<i16> {
+    let kamino_refresh = kamino_refresh_checker(ixs_sysvar, current_ix_idx);
+    let num_ixs = num_instructions(ixs_sysvar) as i16;
+
+    let mut relative_ix_idx = 0;
+    let mut ixs_left = nth_ix.abs();
+    while ixs_left > 0 {
+        relative_ix_idx += nth_ix.signum();
+        let ix_idx = (current_ix_idx as i16) + relative_ix_idx;
+        if ix_idx < 0 || ix_idx >= num_ixs {
+            return None;
+        }
+        if !kamino_refresh.matches(relative_ix_idx) {
+            ixs_left -= 1;
+        }
+    }
+
+    Some(relative_ix_idx)
+}
+
 pub fn validate_rebalance_instructions(
     std_accounts: &Box<SolautoStandardAccounts>,
     rebalance_type: SolautoRebalanceType,
@@ -257,30 +314,105 @@ pub fn validate_rebalance_instructions(
         Some(SOLAUTO_REBALANCE_IX_DISCRIMINATORS.to_vec()),
         current_ix_idx,
     );
-    let jup_swap = InstructionChecker::from_anchor(
+    let swap = |nth_ix: i16| match relative_ix_idx_skipping_refreshes(
         ixs_sysvar,
-        vec![JUPITER_ID],
-        vec![
-            "route",
-            "shared_accounts_route",
-            "route_with_token_ledger",
-            "shared_accounts_route_with_token_ledger",
-            "exact_out_route",
-            "shared_accounts_exact_out_route",
-        ],
         current_ix_idx,
-    );
+        nth_ix,
+    ) {
+        Some(relative_ix_idx) => swap_ix_matches(ixs_sysvar, current_ix_idx, relative_ix_idx),
+        None => false,
+    };
+    let rebalance = |nth_ix: i16| match relative_ix_idx_skipping_refreshes(
+        ixs_sysvar,
+        current_ix_idx,
+        nth_ix,
+    ) {
+        Some(relative_ix_idx) => solauto_rebalance.matches(relative_ix_idx),
+        None => false,
+    };
 
+    // Kamino positions have refresh instructions before each rebalance instruction, which aren't counted
     let next_ix = 1;
     let ix_2_after = 2;
     let prev_ix = -1;
 
     let valid_ixs = match rebalance_type {
         SolautoRebalanceType::Regular | SolautoRebalanceType::DoubleRebalanceWithFL => {
-            jup_swap.matches(next_ix) && solauto_rebalance.matches(ix_2_after)
+            swap(next_ix) && rebalance(ix_2_after)
         }
-        SolautoRebalanceType::FLSwapThenRebalance => jup_swap.matches(prev_ix),
-        SolautoRebalanceType::FLRebalanceThenSwap => jup_swap.matches(next_ix),
+        SolautoRebalanceType::FLSwapThenRebalance => swap(prev_ix),
+        SolautoRebalanceType::FLRebalanceThenSwap => swap(next_ix),
+        // Swaps within the rebalance instruction itself, so it has no adjacent instructions
+        SolautoRebalanceType::SwapCpi => false,
+    };
+
+    check!(valid_ixs, SolautoError::IncorrectInstructions);
+
+    Ok(())
+}
+
+/// Validates that the Kamino refresh instructions directly before the current instruction refresh the obligation,
+/// as klend requires at the top level of the transaction before each deposit, withdraw, borrow & repay
+pub fn validate_kamino_refresh_instructions<'a>(
+    ixs_sysvar: &'a AccountInfo<'a>,
+    obligation: &Pubkey,
+) -> ProgramResult {
+    let current_ix_idx = load_current_index_checked(ixs_sysvar)?;
+    error_if!(
+        get_stack_height() > TRANSACTION_LEVEL_STACK_HEIGHT,
+        SolautoError::InstructionIsCPI
+    );
+
+    let kamino_refresh = kamino_refresh_checker(ixs_sysvar, current_ix_idx);
+    let mut relative_ix_idx: i16 = -1;
+    let mut obligation_refreshed = false;
+    while !obligation_refreshed
+        && (current_ix_idx as i16) + relative_ix_idx >= 0
+        && kamino_refresh.matches(relative_ix_idx)
+    {
+        let refresh_ix = pick_ix_data(PickIxDataReq {
+            ixs_sysvar,
+            ix_idx: ((current_ix_idx as i16) + relative_ix_idx) as usize,
+            data_start_idx: Some(0),
+            data_len: Some(8),
+            account_indices: Some(vec![1]),
+        })
+        .map_err(|_| SolautoError::IncorrectInstructions)?;
+
+        obligation_refreshed = refresh_ix.data == REFRESH_OBLIGATION_DISCRIMINATOR
+            && refresh_ix.accounts.first() == Some(obligation);
+        relative_ix_idx -= 1;
+    }
+
+    check!(obligation_refreshed, SolautoError::IncorrectInstructions);
+
+    Ok(())
+}
+
+/// Validates the harvest emissions, Jupiter swap, harvest emissions instruction set,
+/// from the point of view of the first (pre-swap) or second (post-swap) harvest instruction
+pub fn validate_harvest_emissions_instructions<'a>(
+    ixs_sysvar: &'a AccountInfo<'a>,
+    pre_swap: bool,
+) -> ProgramResult {
+    let current_ix_idx = load_current_index_checked(ixs_sysvar)?;
+    error_if!(
+        get_stack_height() > TRANSACTION_LEVEL_STACK_HEIGHT,
+        SolautoError::InstructionIsCPI
+    );
+
+    let solauto_harvest = InstructionChecker::from(
+        ixs_sysvar,
+        vec![crate::ID],
+        Some(vec![SOLAUTO_HARVEST_EMISSIONS_IX_DISCRIMINATOR]),
+        current_ix_idx,
+    );
+    let jup_swap = jupiter_swap_checker(ixs_sysvar, current_ix_idx);
+
+    let valid_ixs = if pre_swap {
+        jup_swap.matches(1) && solauto_harvest.matches(2)
+    } else {
+        current_ix_idx > 0 && jup_swap.matches(-1)
     };
 
     check!(valid_ixs, SolautoError::IncorrectInstructions);
@@ -288,6 +420,31 @@ pub fn validate_rebalance_instructions(
     Ok(())
 }
 
+/// Validates that the migrate position instruction directly follows a flash borrow, or the Kamino refresh instructions
+/// that follow it, and returns its flash loan
+pub fn get_migrate_position_flash_loan<'a>(
+    ixs_sysvar: &'a AccountInfo<'a>,
+    accounts: &'a [AccountInfo<'a>],
+) -> Result<FlashLoan, ProgramError> {
+    let current_ix_idx = load_current_index_checked(ixs_sysvar)?;
+    error_if!(
+        get_stack_height() > TRANSACTION_LEVEL_STACK_HEIGHT,
+        SolautoError::InstructionIsCPI
+    );
+    let relative_ix_idx = relative_ix_idx_skipping_refreshes(ixs_sysvar, current_ix_idx, -1);
+    check!(
+        relative_ix_idx.is_some(),
+        SolautoError::IncorrectInstructions
+    );
+
+    let flash_borrow_ix_idx = (current_ix_idx as i16) + relative_ix_idx.unwrap();
+    let flash_loan = detect_flash_loan(ixs_sysvar, flash_borrow_ix_idx as usize, accounts)?;
+    check!(flash_loan.is_some(), SolautoError::IncorrectInstructions);
+
+    Ok(flash_loan.unwrap())
+}
+
+/// The index of the flash borrow that funds a rebalance of the type, if it's funded by one
 pub fn get_flash_borrow_ix_idx(
     std_accounts: &Box<SolautoStandardAccounts>,
     rebalance_type: SolautoRebalanceType,
@@ -295,40 +452,157 @@ pub fn get_flash_borrow_ix_idx(
     let ixs_sysvar = std_accounts.ixs_sysvar.unwrap();
     let current_ix_idx = load_current_index_checked(ixs_sysvar)?;
 
-    let prev_ix = -1;
-    let ix_2_before = -2;
+    let ixs_before = match rebalance_type {
+        SolautoRebalanceType::DoubleRebalanceWithFL | SolautoRebalanceType::FLRebalanceThenSwap => {
+            1
+        }
+        SolautoRebalanceType::FLSwapThenRebalance => 2,
+        _ => return Ok(None),
+    };
 
-    let marginfi_borrow = InstructionChecker::from_anchor(
-        ixs_sysvar,
-        vec![MARGINFI_PROD_PROGRAM, MARGINFI_STAGING_PROGRAM],
-        vec!["lending_account_borrow"],
-        current_ix_idx,
-    );
+    Ok(
+        relative_ix_idx_skipping_refreshes(ixs_sysvar, current_ix_idx, -ixs_before)
+            .map(|relative_ix_idx| ((current_ix_idx as i16) + relative_ix_idx) as usize),
+    )
+}
 
-    let borrow_ix_idx = match rebalance_type {
-        SolautoRebalanceType::DoubleRebalanceWithFL => {
-            if current_ix_idx > 0 && marginfi_borrow.matches(prev_ix) {
-                Some(((current_ix_idx as i16) + prev_ix) as usize)
-            } else {
-                None
-            }
-        }
-        SolautoRebalanceType::FLSwapThenRebalance => {
-            if current_ix_idx > 1 && marginfi_borrow.matches(ix_2_before) {
-                Some(((current_ix_idx as i16) + ix_2_before) as usize)
-            } else {
-                None
-            }
-        }
-        SolautoRebalanceType::FLRebalanceThenSwap => {
-            if current_ix_idx > 0 && marginfi_borrow.matches(prev_ix) {
-                Some(((current_ix_idx as i16) + prev_ix) as usize)
-            } else {
-                None
-            }
-        }
-        _ => None,
+#[cfg(test)]
+pub(crate) mod tests {
+    use kamino_sdk::instructions::{refresh_obligation, refresh_reserve, ReserveOracles};
+    use solana_program::{
+        instruction::AccountMeta,
+        sysvar::instructions::{
+            construct_instructions_data, store_current_index, BorrowedAccountMeta,
+            BorrowedInstruction,
+        },
     };
 
-    Ok(borrow_ix_idx)
+    use super::*;
+
+    pub(crate) fn fake_ixs_sysvar(
+        ixs: &[Instruction],
+        current_ix_idx: u16,
+    ) -> &'static AccountInfo<'static> {
+        let borrowed_ixs: Vec<BorrowedInstruction> = ixs
+            .iter()
+            .map(|ix| BorrowedInstruction {
+                program_id: &ix.program_id,
+                accounts: ix
+                    .accounts
+                    .iter()
+                    .map(|meta| BorrowedAccountMeta {
+                        pubkey: &meta.pubkey,
+                        is_signer: meta.is_signer,
+                        is_writable: meta.is_writable,
+                    })
+                    .collect(),
+                data: &ix.data,
+            })
+            .collect();
+        let mut data = construct_instructions_data(&borrowed_ixs);
+        store_current_index(&mut data, current_ix_idx);
+
+        let key = Box::leak(Box::new(solana_program::sysvar::instructions::ID));
+        let owner = Box::leak(Box::new(solana_program::sysvar::ID));
+        let lamports = Box::leak(Box::new(0u64));
+        let data = Box::leak(data.into_boxed_slice());
+        Box::leak(Box::new(AccountInfo::new(
+            key, false, false, lamports, data, owner, false, 0,
+        )))
+    }
+
+    fn kamino_refresh_ixs(supply_reserve: &Pubkey, debt_reserve: &Pubkey) -> Vec<Instruction> {
+        let lending_market = Pubkey::new_unique();
+        vec![
+            refresh_reserve(supply_reserve, &lending_market, ReserveOracles::default()),
+            refresh_reserve(debt_reserve, &lending_market, ReserveOracles::default()),
+            refresh_obligation(
+                &lending_market,
+                &Pubkey::new_unique(),
+                &[*supply_reserve, *debt_reserve],
+            ),
+        ]
+    }
+
+    /// Compute budget, refreshes, rebalance, swap, refreshes, rebalance
+    fn kamino_rebalance_ixs() -> Vec<Instruction> {
+        let supply_reserve = Pubkey::new_unique();
+        let debt_reserve = Pubkey::new_unique();
+        let rebalance_ix = Instruction::new_with_bytes(
+            crate::ID,
+            &[SOLAUTO_REBALANCE_IX_DISCRIMINATORS[1]],
+            vec![AccountMeta::new(Pubkey::new_unique(), false)],
+        );
+        let swap_ix = Instruction::new_with_bytes(
+            JUPITER_ID,
+            &get_anchor_ix_discriminator("route").to_le_bytes(),
+            vec![AccountMeta::new(Pubkey::new_unique(), false)],
+        );
+
+        let mut ixs = vec![Instruction::new_with_bytes(
+            Pubkey::new_unique(),
+            &[2, 0, 0, 0, 0],
+            vec![],
+        )];
+        ixs.extend(kamino_refresh_ixs(&supply_reserve, &debt_reserve));
+        ixs.push(rebalance_ix.clone());
+        ixs.push(swap_ix);
+        ixs.extend(kamino_refresh_ixs(&supply_reserve, &debt_reserve));
+        ixs.push(rebalance_ix);
+        ixs
+    }
+
+    #[test]
+    fn test_skips_kamino_refreshes() {
+        let ixs = kamino_rebalance_ixs();
+
+        let ixs_sysvar = fake_ixs_sysvar(&ixs, 4);
+        assert_eq!(
+            relative_ix_idx_skipping_refreshes(ixs_sysvar, 4, 1),
+            Some(1)
+        );
+        assert_eq!(
+            relative_ix_idx_skipping_refreshes(ixs_sysvar, 4, 2),
+            Some(5)
+        );
+        assert_eq!(relative_ix_idx_skipping_refreshes(ixs_sysvar, 4, 3), None);
+        assert_eq!(
+            relative_ix_idx_skipping_refreshes(ixs_sysvar, 4, -1),
+            Some(-4)
+        );
+        assert_eq!(relative_ix_idx_skipping_refreshes(ixs_sysvar, 4, -2), None);
+        assert!(swap_ix_matches(ixs_sysvar, 4, 1));
+
+        let ixs_sysvar = fake_ixs_sysvar(&ixs, 9);
+        assert_eq!(
+            relative_ix_idx_skipping_refreshes(ixs_sysvar, 9, -1),
+            Some(-4)
+        );
+        assert_eq!(
+            relative_ix_idx_skipping_refreshes(ixs_sysvar, 9, -2),
+            Some(-5)
+        );
+    }
+
+    #[test]
+    fn test_validate_kamino_refresh_instructions() {
+        let ixs = kamino_rebalance_ixs();
+        let obligation = ixs[3].accounts[1].pubkey;
+
+        assert!(
+            validate_kamino_refresh_instructions(fake_ixs_sysvar(&ixs, 4), &obligation).is_ok()
+        );
+        assert!(validate_kamino_refresh_instructions(
+            fake_ixs_sysvar(&ixs, 4),
+            &Pubkey::new_unique()
+        )
+        .is_err());
+        // The swap directly precedes the second rebalance's refreshes, not a rebalance
+        assert!(
+            validate_kamino_refresh_instructions(fake_ixs_sysvar(&ixs, 5), &obligation).is_err()
+        );
+        assert!(
+            validate_kamino_refresh_instructions(fake_ixs_sysvar(&ixs, 0), &obligation).is_err()
+        );
+    }
 }
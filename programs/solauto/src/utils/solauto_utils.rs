@@ -7,21 +7,31 @@ use spl_token::state::{Account as TokenAccount, Mint};
 use super::{
     math_utils::to_bps,
     solana_utils::{account_has_data, init_account},
+    validation_utils,
 };
 use crate::{
     check,
     constants::WSOL_MINT,
+    error_if,
     state::{
+        automation::DCASettings,
+        carry_guard::CarryGuard,
+        grid::Grid,
+        leverage_curve::LeverageCurve,
+        orders::{StopLossOrder, TakeProfitOrder, TrailingStopOrder},
         referral_state::ReferralState,
         solauto_position::{
-            PositionData, PositionState, PositionTokenState, SolautoPosition,
+            PositionData, PositionLeg, PositionState, PositionTokenState, SolautoPosition,
             SolautoSettingsParameters,
         },
     },
     types::{
         errors::SolautoError,
         instruction::UpdatePositionData,
-        shared::{DeserializedAccount, LendingPlatform, PositionType, RefreshedTokenState},
+        shared::{
+            DeserializedAccount, LendingPlatform, PositionType, RefreshedLegState,
+            RefreshedTokenState,
+        },
     },
 };
 
@@ -75,26 +85,122 @@ pub fn create_new_solauto_position<'a>(
 
         let mut position_data = PositionData::default();
         position_data.lending_platform = lending_platform;
+        position_data.rebalance_strategy =
+            update_position_data.rebalance_strategy.unwrap_or_default();
+        position_data.max_price_divergence_bps =
+            update_position_data.max_price_divergence_bps.unwrap_or(0);
         position_data.settings =
             SolautoSettingsParameters::from(*update_position_data.settings.as_ref().unwrap());
+        validation_utils::validate_settings_automation(
+            &position_data.settings,
+            Clock::get()?.unix_timestamp as u64,
+        )?;
         position_data.lp_user_account = *lp_user_account.key;
         position_data.lp_supply_account = *lp_supply_account.key;
         position_data.lp_debt_account = *lp_debt_account.key;
         position_data.lp_pool_account = *lp_pool_account.key;
 
-        Box::new(SolautoPosition::new(
+        let mut solauto_position = Box::new(SolautoPosition::new(
             update_position_data.position_id,
             *signer.key,
             position_type,
             position_data,
             state,
-        ))
+        ));
+
+        if let Some(leverage_curve) = &update_position_data.leverage_curve {
+            solauto_position.leverage_curve =
+                LeverageCurve::from(leverage_curve).ok_or(SolautoError::InvalidLeverageCurve)?;
+        }
+
+        if let Some(grid) = update_position_data.grid {
+            solauto_position.grid = Grid::from(grid, 0, None);
+        }
+
+        if let Some(carry_guard) = update_position_data.carry_guard {
+            let carry_guard = CarryGuard::from(carry_guard);
+            validation_utils::validate_carry_guard(&solauto_position, &carry_guard)?;
+            solauto_position.carry_guard = carry_guard;
+        }
+
+        if let Some(dca) = update_position_data.dca {
+            let dca = DCASettings::from(dca);
+            // There is nothing to DCA out of yet
+            error_if!(!dca.dca_in(), SolautoError::InvalidDCASettings);
+            validation_utils::validate_dca_settings(
+                &solauto_position,
+                &dca,
+                Clock::get()?.unix_timestamp as u64,
+            )?;
+            solauto_position.position.dca = dca;
+        }
+
+        if let Some(take_profit) = update_position_data.take_profit {
+            let take_profit = TakeProfitOrder::from(take_profit);
+            validation_utils::validate_take_profit_order(&solauto_position, &take_profit)?;
+            solauto_position.take_profit = take_profit;
+        }
+
+        if let Some(stop_loss) = update_position_data.stop_loss {
+            let stop_loss = StopLossOrder::from(stop_loss);
+            validation_utils::validate_stop_loss_order(&solauto_position, &stop_loss)?;
+            solauto_position.stop_loss = stop_loss;
+        }
+
+        if let Some(trailing_stop) = update_position_data.trailing_stop {
+            let trailing_stop = TrailingStopOrder::from(trailing_stop);
+            validation_utils::validate_trailing_stop_order(&solauto_position, &trailing_stop)?;
+            solauto_position.trailing_stop = trailing_stop;
+        }
+
+        solauto_position
     } else {
+        error_if!(
+            update_position_data.dca.is_some(),
+            SolautoError::InvalidDCASettings
+        );
+        error_if!(
+            update_position_data.take_profit.is_some(),
+            SolautoError::InvalidTakeProfitOrder
+        );
+        error_if!(
+            update_position_data.stop_loss.is_some(),
+            SolautoError::InvalidStopLossOrder
+        );
+        error_if!(
+            update_position_data.trailing_stop.is_some(),
+            SolautoError::InvalidTrailingStopOrder
+        );
+        error_if!(
+            update_position_data.rebalance_strategy.is_some(),
+            SolautoError::IncorrectInstructions
+        );
+        error_if!(
+            update_position_data.leverage_curve.is_some(),
+            SolautoError::InvalidLeverageCurve
+        );
+        error_if!(
+            update_position_data.grid.is_some(),
+            SolautoError::InvalidGridSettings
+        );
+        error_if!(
+            update_position_data.carry_guard.is_some(),
+            SolautoError::InvalidCarryGuard
+        );
+        error_if!(
+            update_position_data.max_price_divergence_bps.is_some(),
+            SolautoError::InvalidMaxPriceDivergence
+        );
+
+        // Self-managed positions still record the lending platform so that the generic instructions can dispatch on it
+        let mut position_data = PositionData::default();
+        position_data.lending_platform = lending_platform;
+
         Box::new(SolautoPosition::new(
             0,
             *signer.key,
             position_type,
-            PositionData::default(),
+            position_data,
             PositionState::default(),
         ))
     };
@@ -189,6 +295,29 @@ pub fn update_token_state(token_state: &mut PositionTokenState, token_data: &Ref
     token_state.amount_can_be_used.base_unit = token_data.amount_can_be_used;
     token_state.update_market_price(token_data.market_price);
     token_state.borrow_fee_bps = token_data.borrow_fee_bps.unwrap_or(0);
+    token_state.supply_apr_bps = token_data.supply_apr_bps;
+    token_state.borrow_apr_bps = token_data.borrow_apr_bps;
+}
+
+pub fn update_position_legs(
+    legs: &mut [PositionLeg],
+    legs_data: &[RefreshedLegState],
+) -> Result<(), ProgramError> {
+    check!(
+        legs_data.len() <= legs.len(),
+        SolautoError::TooManyPositionLegs
+    );
+
+    for (idx, leg) in legs.iter_mut().enumerate() {
+        *leg = PositionLeg::default();
+        if let Some(leg_data) = legs_data.get(idx) {
+            leg.lp_account = leg_data.lp_account;
+            leg.token.mint = leg_data.token.mint;
+            update_token_state(&mut leg.token, &leg_data.token);
+        }
+    }
+
+    Ok(())
 }
 
 pub fn safe_unpack_token_account<'a>(
@@ -201,3 +330,12 @@ pub fn safe_unpack_token_account<'a>(
         Ok(None)
     }
 }
+
+pub fn get_token_account_balance<'a>(
+    token_account: &'a AccountInfo<'a>,
+) -> Result<u64, ProgramError> {
+    Ok(safe_unpack_token_account(Some(token_account))?
+        .ok_or(SolautoError::IncorrectAccounts)?
+        .data
+        .amount)
+}
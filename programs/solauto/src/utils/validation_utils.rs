@@ -1,6 +1,8 @@
 use std::ops::Div;
 
+use kamino_sdk::KAMINO_ID;
 use marginfi_sdk::generated::accounts::{Bank, MarginfiAccount};
+use save_sdk::SAVE_ID;
 use solana_program::{
     account_info::AccountInfo,
     entrypoint::ProgramResult,
@@ -15,26 +17,36 @@ use spl_token::ID as token_program_id;
 
 use crate::{
     check,
+    clients::{kamino::KaminoClient, save::SaveClient},
     constants::{
-        MARGINFI_PROD_PROGRAM, MARGINFI_STAGING_PROGRAM, MIN_BOOST_GAP_BPS, MIN_REPAY_GAP_BPS,
-        SOLAUTO_MANAGER,
+        MARGINFI_PROD_PROGRAM, MARGINFI_STAGING_PROGRAM, MAX_BASIS_POINTS, MAX_SWAP_SLIPPAGE_BPS,
+        MIN_BOOST_GAP_BPS, MIN_REPAY_GAP_BPS, SOLAUTO_MANAGER,
     },
     error_if,
     state::{
-        automation::AutomationSettings, referral_state::ReferralState,
-        solauto_position::SolautoPosition,
+        automation::{AutomationSettings, DCASettings},
+        carry_guard::CarryGuard,
+        grid::{MAX_GRID_LEVELS, MIN_GRID_LEVELS},
+        orders::{LimitOrder, StopLossOrder, TakeProfitOrder, TrailingStopOrder},
+        referral_state::ReferralState,
+        solauto_position::{SolautoPosition, SolautoSettingsParameters},
     },
     types::{
         errors::SolautoError,
         instruction::SolautoStandardAccounts,
-        shared::{DeserializedAccount, LendingPlatform, TokenType},
+        lending_protocol::LendingProtocolAccounts,
+        shared::{
+            DeserializedAccount, LendingPlatform, RebalanceDirection, RebalanceStrategyType,
+            TokenType,
+        },
     },
-    utils::math_utils::from_rounded_usd_value,
+    utils::math_utils::{base_unit_to_usd_value, from_rounded_usd_value},
 };
 
 use super::{
     math_utils::{get_max_boost_to_bps, get_max_repay_from_bps, get_max_repay_to_bps},
-    solauto_utils::safe_unpack_token_account,
+    solana_utils::account_has_data,
+    solauto_utils::{get_owner, safe_unpack_token_account},
 };
 
 pub fn generic_instruction_validation(
@@ -115,6 +127,107 @@ pub fn validate_instruction(
 }
 
 pub fn validate_position_settings(solauto_position: &SolautoPosition) -> ProgramResult {
+    let settings = &solauto_position.position.settings;
+    validate_settings(solauto_position, settings)?;
+    if settings.automation.is_active() {
+        // The settings being moved towards must be valid too
+        validate_settings(solauto_position, &settings.target_settings())?;
+    }
+
+    check!(
+        solauto_position.position.max_price_divergence_bps < MAX_BASIS_POINTS,
+        SolautoError::InvalidMaxPriceDivergence
+    );
+    // Kamino reserves hold a single price, so there is no realtime & EMA divergence to guard against
+    error_if!(
+        solauto_position.position.max_price_divergence_bps > 0
+            && solauto_position.position.lending_platform == LendingPlatform::Kamino,
+        SolautoError::InvalidMaxPriceDivergence
+    );
+    check!(
+        settings.max_slippage_bps <= MAX_SWAP_SLIPPAGE_BPS,
+        SolautoError::InvalidMaxSlippage
+    );
+
+    let leverage_curve = &solauto_position.leverage_curve;
+    check!(
+        leverage_curve.is_active()
+            || solauto_position.position.rebalance_strategy != RebalanceStrategyType::LeverageCurve,
+        SolautoError::InvalidLeverageCurve
+    );
+    if leverage_curve.is_active() {
+        validate_leverage_curve(solauto_position)?;
+    }
+
+    let grid = &solauto_position.grid;
+    check!(
+        grid.is_active()
+            || solauto_position.position.rebalance_strategy != RebalanceStrategyType::Grid,
+        SolautoError::InvalidGridSettings
+    );
+    if grid.is_active() {
+        validate_grid(solauto_position)?;
+    }
+
+    Ok(())
+}
+
+fn validate_grid(solauto_position: &SolautoPosition) -> ProgramResult {
+    let grid = &solauto_position.grid;
+    let max_repay_to_bps = get_max_repay_to_bps(
+        solauto_position.state.max_ltv_bps,
+        solauto_position.state.liq_threshold_bps,
+    );
+
+    check!(
+        grid.level_count >= MIN_GRID_LEVELS && grid.level_count <= MAX_GRID_LEVELS,
+        SolautoError::InvalidGridSettings
+    );
+    check!(
+        grid.lower_liq_utilization_rate_bps < grid.upper_liq_utilization_rate_bps
+            && grid.upper_liq_utilization_rate_bps <= max_repay_to_bps,
+        SolautoError::InvalidGridSettings
+    );
+    // The highest level must sit below repay_from, otherwise every step up would also trigger a repay
+    check!(
+        grid.upper_liq_utilization_rate_bps < solauto_position.repay_from_bps(),
+        SolautoError::InvalidGridSettings
+    );
+    check!(
+        grid.price_step_bps > 0 && grid.price_step_bps < MAX_BASIS_POINTS,
+        SolautoError::InvalidGridSettings
+    );
+    check!(
+        grid.last_level < grid.level_count,
+        SolautoError::InvalidGridSettings
+    );
+
+    Ok(())
+}
+
+fn validate_leverage_curve(solauto_position: &SolautoPosition) -> ProgramResult {
+    let bands = solauto_position.leverage_curve.active_bands();
+
+    // Every price ratio must fall into a band
+    check!(
+        bands[0].min_price_ratio == 0
+            && bands
+                .windows(2)
+                .all(|pair| pair[0].min_price_ratio < pair[1].min_price_ratio),
+        SolautoError::InvalidLeverageCurve
+    );
+
+    for band in bands.iter() {
+        validate_settings(solauto_position, &band.settings())?;
+    }
+
+    Ok(())
+}
+
+fn validate_settings(
+    solauto_position: &SolautoPosition,
+    settings: &SolautoSettingsParameters,
+) -> ProgramResult {
     let max_boost_to_bps = get_max_boost_to_bps(
         solauto_position.state.max_ltv_bps,
         solauto_position.state.liq_threshold_bps,
@@ -128,34 +241,57 @@ pub fn validate_position_settings(solauto_position: &SolautoPosition) -> Program
         solauto_position.state.liq_threshold_bps,
     );
 
-    let data = &solauto_position.position;
     check!(
-        data.settings.repay_to_bps >= data.settings.boost_to_bps,
+        settings.repay_to_bps >= settings.boost_to_bps,
         SolautoError::InvalidRepayToSetting
     );
     check!(
-        data.settings.boost_to_bps <= max_boost_to_bps,
+        settings.boost_to_bps <= max_boost_to_bps,
         SolautoError::InvalidBoostToSetting
     );
     check!(
-        data.settings.repay_gap >= MIN_REPAY_GAP_BPS,
+        settings.repay_gap >= MIN_REPAY_GAP_BPS,
         SolautoError::InvalidRepayGapSetting
     );
     check!(
-        data.settings.boost_gap >= MIN_BOOST_GAP_BPS,
+        settings.boost_gap >= MIN_BOOST_GAP_BPS,
         SolautoError::InvalidBoostGapSetting
     );
     check!(
-        data.settings.repay_to_bps <= max_repay_to_bps,
+        settings.repay_to_bps <= max_repay_to_bps,
         SolautoError::InvalidRepayToSetting
     );
     check!(
-        data.settings.repay_to_bps + data.settings.repay_gap <= max_repay_from_bps,
+        settings.repay_to_bps + settings.repay_gap <= max_repay_from_bps,
         SolautoError::InvalidRepayFromSetting
     );
     Ok(())
 }
 
+/// Validates new setting parameters that gradually move boost_to_bps & repay_to_bps to their targets
+pub fn validate_settings_automation(
+    settings: &SolautoSettingsParameters,
+    current_unix_timestamp: u64,
+) -> ProgramResult {
+    if !settings.automation.is_active() {
+        return Ok(());
+    }
+
+    if settings.automation.periods_passed != 0 {
+        msg!("New setting parameter automation can't have periods passed");
+        return Err(SolautoError::InvalidAutomationData.into());
+    }
+
+    if settings.target_boost_to_bps == settings.boost_to_bps
+        && settings.target_repay_to_bps == settings.repay_to_bps
+    {
+        msg!("Setting parameter automation must have a target boost_to or repay_to");
+        return Err(SolautoError::InvalidAutomationData.into());
+    }
+
+    validate_automation_settings(&settings.automation, current_unix_timestamp)
+}
+
 pub fn validate_automation_settings(
     automation: &AutomationSettings,
     current_unix_timestamp: u64,
@@ -186,6 +322,134 @@ pub fn validate_automation_settings(
     Ok(())
 }
 
+pub fn validate_dca_settings(
+    solauto_position: &SolautoPosition,
+    dca: &DCASettings,
+    current_unix_timestamp: u64,
+) -> ProgramResult {
+    let invalid_params = |error_msg| {
+        msg!(error_msg);
+        Err(SolautoError::InvalidDCASettings.into())
+    };
+
+    if solauto_position.position.dca.is_active() {
+        return invalid_params("The position already has an active DCA");
+    }
+
+    if !dca.is_active() || dca.automation.periods_passed != 0 {
+        return invalid_params("A new DCA must have target periods and no periods passed");
+    }
+
+    validate_automation_settings(&dca.automation, current_unix_timestamp)
+}
+
+pub fn validate_limit_order(
+    solauto_position: &SolautoPosition,
+    limit_order: &LimitOrder,
+) -> ProgramResult {
+    let invalid_params = |error_msg| {
+        msg!(error_msg);
+        Err(SolautoError::InvalidLimitOrder.into())
+    };
+
+    if solauto_position.limit_order.is_active() {
+        return invalid_params("The position already has an open limit order");
+    }
+
+    if !limit_order.is_active() || limit_order.trigger_price_usd == 0 {
+        return invalid_params("A limit order must have an amount and a trigger price");
+    }
+
+    Ok(())
+}
+
+pub fn validate_carry_guard(
+    solauto_position: &SolautoPosition,
+    carry_guard: &CarryGuard,
+) -> ProgramResult {
+    if !carry_guard.is_active() {
+        return Ok(());
+    }
+
+    let max_repay_to_bps = get_max_repay_to_bps(
+        solauto_position.state.max_ltv_bps,
+        solauto_position.state.liq_threshold_bps,
+    );
+    check!(
+        carry_guard.deleverage_to_bps <= max_repay_to_bps,
+        SolautoError::InvalidCarryGuard
+    );
+
+    Ok(())
+}
+
+pub fn validate_trailing_stop_order(
+    solauto_position: &SolautoPosition,
+    trailing_stop: &TrailingStopOrder,
+) -> ProgramResult {
+    if !trailing_stop.is_active() {
+        return Ok(());
+    }
+
+    // The high-water mark follows whichever supply leg a rebalance selects
+    error_if!(
+        solauto_position.state.has_additional_legs(),
+        SolautoError::TooManyPositionLegs
+    );
+
+    let max_repay_to_bps = get_max_repay_to_bps(
+        solauto_position.state.max_ltv_bps,
+        solauto_position.state.liq_threshold_bps,
+    );
+    check!(
+        trailing_stop.trailing_distance_bps < 10000
+            && trailing_stop.target_liq_utilization_rate_bps <= max_repay_to_bps,
+        SolautoError::InvalidTrailingStopOrder
+    );
+
+    Ok(())
+}
+
+pub fn validate_take_profit_order(
+    solauto_position: &SolautoPosition,
+    take_profit: &TakeProfitOrder,
+) -> ProgramResult {
+    if !take_profit.is_active() {
+        return Ok(());
+    }
+
+    // The trigger price is of the position's only supply mint
+    error_if!(
+        solauto_position.state.has_additional_legs(),
+        SolautoError::TooManyPositionLegs
+    );
+
+    let max_repay_to_bps = get_max_repay_to_bps(
+        solauto_position.state.max_ltv_bps,
+        solauto_position.state.liq_threshold_bps,
+    );
+    check!(
+        take_profit.target_liq_utilization_rate_bps <= max_repay_to_bps
+            && take_profit.withdraw_net_worth_bps < 10000,
+        SolautoError::InvalidTakeProfitOrder
+    );
+
+    Ok(())
+}
+
+pub fn validate_stop_loss_order(
+    solauto_position: &SolautoPosition,
+    stop_loss: &StopLossOrder,
+) -> ProgramResult {
+    // The trigger price is of the position's only supply mint, the net worth floor applies to any position
+    error_if!(
+        stop_loss.trigger_price_usd > 0 && solauto_position.state.has_additional_legs(),
+        SolautoError::TooManyPositionLegs
+    );
+
+    Ok(())
+}
+
 pub fn validate_lending_program_account(
     program: &AccountInfo,
     lending_platform: LendingPlatform,
@@ -197,6 +461,12 @@ pub fn validate_lending_program_account(
                 SolautoError::IncorrectAccounts
             );
         }
+        LendingPlatform::Kamino => {
+            check!(*program.key == KAMINO_ID, SolautoError::IncorrectAccounts);
+        }
+        LendingPlatform::Save => {
+            check!(*program.key == SAVE_ID, SolautoError::IncorrectAccounts);
+        }
     }
     // We don't need to check more than this, as lending protocols have their own account checks and will fail during CPI if there is an issue with the provided accounts
     Ok(())
@@ -275,6 +545,42 @@ pub fn validate_marginfi_bank<'a>(
     Ok(())
 }
 
+pub fn validate_kamino_reserve<'a>(
+    kamino_reserve: &'a AccountInfo<'a>,
+    mint: &Pubkey,
+    lending_market: &Pubkey,
+) -> ProgramResult {
+    if mint == &Pubkey::default() {
+        return Ok(());
+    }
+
+    let reserve = KaminoClient::load_reserve(kamino_reserve)?;
+    check!(
+        &reserve.data.liquidity.mint_pubkey == mint
+            && &reserve.data.lending_market == lending_market,
+        SolautoError::IncorrectAccounts
+    );
+
+    Ok(())
+}
+
+pub fn validate_save_reserve<'a>(
+    save_reserve: &'a AccountInfo<'a>,
+    mint: &Pubkey,
+) -> ProgramResult {
+    if mint == &Pubkey::default() {
+        return Ok(());
+    }
+
+    let reserve = SaveClient::load_reserve(save_reserve)?;
+    check!(
+        &reserve.data.liquidity_mint == mint,
+        SolautoError::IncorrectAccounts
+    );
+
+    Ok(())
+}
+
 pub fn validate_lending_program_accounts_with_position<'a>(
     lending_platform: LendingPlatform,
     solauto_position: &DeserializedAccount<SolautoPosition>,
@@ -296,6 +602,110 @@ pub fn validate_lending_program_accounts_with_position<'a>(
             validate_marginfi_bank(lp_supply_account, &supply_mint)?;
             validate_marginfi_bank(lp_debt_account, &debt_mint)?;
         }
+        LendingPlatform::Kamino => {
+            // The obligation isn't created yet when opening a position, in which case it will be of the position's market
+            let lending_market = if account_has_data(lp_user_account) {
+                KaminoClient::load_obligation(lp_user_account)?
+                    .data
+                    .lending_market
+            } else {
+                solauto_position.data.position.lp_pool_account
+            };
+            validate_kamino_reserve(lp_supply_account, supply_mint, &lending_market)?;
+            validate_kamino_reserve(lp_debt_account, debt_mint, &lending_market)?;
+        }
+        LendingPlatform::Save => {
+            validate_save_reserve(lp_supply_account, supply_mint)?;
+            validate_save_reserve(lp_debt_account, debt_mint)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates the accounts of the lending platform a position migrates to, before they replace the position's own.
+/// The destination account is either created by the migration, or already belongs to the position's owner in the same pool
+pub fn validate_migration_destination_accounts<'a>(
+    lending_platform: LendingPlatform,
+    lending_program: &'a AccountInfo<'a>,
+    solauto_position: &DeserializedAccount<'a, SolautoPosition>,
+    signer: &'a AccountInfo<'a>,
+    lp_accounts: &dyn LendingProtocolAccounts<'a>,
+) -> ProgramResult {
+    let supply_mint = &solauto_position.data.state.supply.mint;
+    let debt_mint = &solauto_position.data.state.debt.mint;
+    let owner = get_owner(solauto_position, signer).key;
+    let lp_user_account = lp_accounts.lp_user_account();
+    let lp_supply_account = lp_accounts.lp_supply_account();
+    let lp_debt_account = lp_accounts.lp_debt_account();
+    let lending_pool = lp_accounts.lp_pool_account().key;
+
+    check!(
+        lp_user_account.key != &solauto_position.data.position.lp_user_account,
+        SolautoError::IncorrectAccounts
+    );
+    let existing_account = account_has_data(lp_user_account);
+    error_if!(
+        existing_account && lp_user_account.owner != lending_program.key,
+        SolautoError::IncorrectAccounts
+    );
+
+    match lending_platform {
+        LendingPlatform::Marginfi => {
+            for bank in [lp_supply_account, lp_debt_account] {
+                let bank = DeserializedAccount::<Bank>::zerocopy(Some(bank))?.unwrap();
+                check!(
+                    &bank.data.group == lending_pool,
+                    SolautoError::IncorrectAccounts
+                );
+            }
+            validate_marginfi_bank(lp_supply_account, supply_mint)?;
+            validate_marginfi_bank(lp_debt_account, debt_mint)?;
+
+            if existing_account {
+                let marginfi_account =
+                    DeserializedAccount::<MarginfiAccount>::zerocopy(Some(lp_user_account))?
+                        .unwrap();
+                check!(
+                    &marginfi_account.data.group == lending_pool
+                        && &marginfi_account.data.authority == owner,
+                    SolautoError::IncorrectAccounts
+                );
+            }
+        }
+        LendingPlatform::Kamino => {
+            validate_kamino_reserve(lp_supply_account, supply_mint, lending_pool)?;
+            validate_kamino_reserve(lp_debt_account, debt_mint, lending_pool)?;
+
+            if existing_account {
+                let obligation = KaminoClient::load_obligation(lp_user_account)?;
+                check!(
+                    &obligation.data.lending_market == lending_pool
+                        && &obligation.data.owner == owner,
+                    SolautoError::IncorrectAccounts
+                );
+            }
+        }
+        LendingPlatform::Save => {
+            for reserve in [lp_supply_account, lp_debt_account] {
+                let reserve = SaveClient::load_reserve(reserve)?;
+                check!(
+                    &reserve.data.lending_market == lending_pool,
+                    SolautoError::IncorrectAccounts
+                );
+            }
+            validate_save_reserve(lp_supply_account, supply_mint)?;
+            validate_save_reserve(lp_debt_account, debt_mint)?;
+
+            if existing_account {
+                let obligation = SaveClient::load_obligation(lp_user_account)?;
+                check!(
+                    &obligation.data.lending_market == lending_pool
+                        && &obligation.data.owner == owner,
+                    SolautoError::IncorrectAccounts
+                );
+            }
+        }
     }
 
     Ok(())
@@ -357,6 +767,38 @@ pub fn validate_token_account<'a>(
     Ok(())
 }
 
+/// Escrowed tokens (DCA-in and limit orders) are held in the position's associated token account for the token, and
+/// are sent from or refunded to the signer's associated token account
+pub fn validate_escrow_token_accounts<'a>(
+    solauto_position: &DeserializedAccount<'a, SolautoPosition>,
+    token_type: TokenType,
+    signer: &Pubkey,
+    mint: Option<&'a AccountInfo<'a>>,
+    position_ta: Option<&'a AccountInfo<'a>>,
+    signer_ta: Option<&'a AccountInfo<'a>>,
+) -> ProgramResult {
+    check!(
+        mint.is_some() && position_ta.is_some() && signer_ta.is_some(),
+        SolautoError::IncorrectAccounts
+    );
+
+    let mint_key = if token_type == TokenType::Supply {
+        &solauto_position.data.state.supply.mint
+    } else {
+        &solauto_position.data.state.debt.mint
+    };
+
+    check!(
+        mint.unwrap().key == mint_key
+            && position_ta.unwrap().key
+                == &get_associated_token_address(solauto_position.account_info.key, mint_key)
+            && signer_ta.unwrap().key == &get_associated_token_address(signer, mint_key),
+        SolautoError::IncorrectAccounts
+    );
+
+    Ok(())
+}
+
 pub fn token_account_owned_by<'a>(
     token_account: &'a AccountInfo<'a>,
     expected_owner: &Pubkey,
@@ -418,6 +860,42 @@ pub fn validate_no_active_balances<'a>(
             SolautoError::IncorrectAccounts
         );
 
+        Ok(())
+    } else if lending_platform == LendingPlatform::Kamino {
+        let obligation = KaminoClient::load_obligation(lp_user_account)?;
+
+        check!(
+            obligation
+                .data
+                .deposits
+                .iter()
+                .all(|deposit| deposit.deposited_amount == 0)
+                && obligation
+                    .data
+                    .borrows
+                    .iter()
+                    .all(|borrow| u128::from_le_bytes(borrow.borrowed_amount_sf) == 0),
+            SolautoError::IncorrectAccounts
+        );
+
+        Ok(())
+    } else if lending_platform == LendingPlatform::Save {
+        let obligation = SaveClient::load_obligation(lp_user_account)?;
+
+        check!(
+            obligation
+                .data
+                .deposits
+                .iter()
+                .all(|deposit| deposit.deposited_amount == 0)
+                && obligation
+                    .data
+                    .borrows
+                    .iter()
+                    .all(|borrow| borrow.borrowed_amount_wads == 0),
+            SolautoError::IncorrectAccounts
+        );
+
         Ok(())
     } else {
         msg!("Lending platform not yet supported");
@@ -426,8 +904,8 @@ pub fn validate_no_active_balances<'a>(
 }
 
 pub fn validate_rebalance(solauto_position: &SolautoPosition) -> ProgramResult {
-    let curr_supply_usd = solauto_position.state.supply.amount_used.usd_value();
-    let curr_debt_usd = solauto_position.state.debt.amount_used.usd_value();
+    let curr_supply_usd = solauto_position.state.supply_usd();
+    let curr_debt_usd = solauto_position.state.debt_usd();
 
     let target_supply_usd =
         from_rounded_usd_value(solauto_position.rebalance.values.target_supply_usd);
@@ -455,6 +933,37 @@ pub fn validate_rebalance(solauto_position: &SolautoPosition) -> ProgramResult {
     Ok(())
 }
 
+/// Rejects a rebalance whose swap returned less than the oracle prices imply, by more than the position's max slippage
+pub fn validate_swap_slippage(
+    solauto_position: &SolautoPosition,
+    swap_in_base_unit: u64,
+    swap_out_base_unit: u64,
+) -> ProgramResult {
+    let (input, output) =
+        if solauto_position.rebalance.values.rebalance_direction == RebalanceDirection::Boost {
+            (&solauto_position.state.debt, &solauto_position.state.supply)
+        } else {
+            (&solauto_position.state.supply, &solauto_position.state.debt)
+        };
+
+    let swap_in_usd =
+        base_unit_to_usd_value(swap_in_base_unit, input.decimals, input.market_price());
+    if swap_in_usd == 0.0 {
+        return Ok(());
+    }
+    let swap_out_usd =
+        base_unit_to_usd_value(swap_out_base_unit, output.decimals, output.market_price());
+
+    let slippage = 1.0 - swap_out_usd.div(swap_in_usd);
+    msg!("Swap slippage against oracle prices: {}", slippage);
+    error_if!(
+        slippage > solauto_position.position.settings.max_slippage(),
+        SolautoError::SwapSlippageTooHigh
+    );
+
+    Ok(())
+}
+
 pub fn correct_token_account(token_account: &Pubkey, wallet: &Pubkey, mint: &Pubkey) -> bool {
     token_account == &get_associated_token_address(wallet, mint)
 }
@@ -494,7 +1003,9 @@ pub fn value_match_with_threshold(value: f64, target_value: f64, threshold: f64)
 mod tests {
     use crate::{
         state::{
-            automation::{AutomationSettings, AutomationSettingsInp},
+            automation::{AutomationSettings, AutomationSettingsInp, DCASettingsInp},
+            grid::{Grid, GridSettingsInp},
+            leverage_curve::{LeverageCurve, LeverageCurveBandInp, LeverageCurveInp},
             solauto_position::{
                 PositionData, PositionState, SolautoSettingsParameters,
                 SolautoSettingsParametersInp,
@@ -589,6 +1100,192 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_invalid_max_price_divergence() {
+        let mut position_data = PositionData::default();
+        position_data.settings = SolautoSettingsParameters::from(SolautoSettingsParametersInp {
+            boost_to_bps: 5000,
+            boost_gap: 500,
+            repay_to_bps: 7000,
+            repay_gap: 500,
+            ..Default::default()
+        });
+        position_data.max_price_divergence_bps = 300;
+
+        let mut position_state = PositionState::default();
+        position_state.max_ltv_bps = 6500;
+        position_state.liq_threshold_bps = 8000;
+
+        let validate = |lending_platform: LendingPlatform| {
+            let mut position_data = position_data;
+            position_data.lending_platform = lending_platform;
+            validate_position_settings(&SolautoPosition::new(
+                1,
+                Pubkey::default(),
+                PositionType::default(),
+                position_data,
+                position_state,
+            ))
+        };
+        assert!(validate(LendingPlatform::Marginfi).is_ok());
+        assert!(validate(LendingPlatform::Save).is_ok());
+        assert_eq!(
+            validate(LendingPlatform::Kamino),
+            Err(SolautoError::InvalidMaxPriceDivergence.into())
+        );
+    }
+
+    #[test]
+    fn test_invalid_settings_automation() {
+        let current_timestamp = 100;
+        let settings_args = SolautoSettingsParametersInp {
+            boost_to_bps: 4000,
+            boost_gap: 500,
+            repay_to_bps: 7500,
+            repay_gap: 500,
+            target_boost_to_bps: Some(7000),
+            target_repay_to_bps: None,
+            automation: Some(AutomationSettingsInp {
+                target_periods: 5,
+                periods_passed: 0,
+                unix_start_date: current_timestamp,
+                interval_seconds: 60 * 60 * 24,
+            }),
+            max_slippage_bps: None,
+        };
+        let settings = SolautoSettingsParameters::from(settings_args);
+        assert!(validate_settings_automation(&settings, current_timestamp).is_ok());
+
+        let no_target = SolautoSettingsParameters::from(SolautoSettingsParametersInp {
+            target_boost_to_bps: None,
+            ..settings_args
+        });
+        assert!(validate_settings_automation(&no_target, current_timestamp).is_err());
+
+        // Moving towards a boost_to above repay_to
+        test_position_settings(
+            SolautoSettingsParameters::from(SolautoSettingsParametersInp {
+                target_boost_to_bps: Some(8000),
+                ..settings_args
+            }),
+            8000,
+        );
+    }
+
+    #[test]
+    fn test_invalid_leverage_curve() {
+        let mut position_data = PositionData::default();
+        position_data.lending_platform = LendingPlatform::Marginfi;
+        position_data.settings = SolautoSettingsParameters::from(SolautoSettingsParametersInp {
+            boost_to_bps: 4000,
+            boost_gap: 500,
+            repay_to_bps: 7500,
+            repay_gap: 500,
+            ..Default::default()
+        });
+        position_data.rebalance_strategy = RebalanceStrategyType::LeverageCurve;
+
+        let mut position_state = PositionState::default();
+        position_state.max_ltv_bps = 6500;
+        position_state.liq_threshold_bps = 8000;
+
+        let mut solauto_position = SolautoPosition::new(
+            1,
+            Pubkey::default(),
+            PositionType::default(),
+            position_data,
+            position_state,
+        );
+        let band = |min_price_ratio: u64, boost_to_bps: u16| LeverageCurveBandInp {
+            min_price_ratio,
+            boost_to_bps,
+            boost_gap: 500,
+            repay_to_bps: 7500,
+            repay_gap: 500,
+        };
+        let mut set_curve = |bands: Vec<LeverageCurveBandInp>| {
+            solauto_position.leverage_curve =
+                LeverageCurve::from(&LeverageCurveInp { bands }).unwrap();
+            validate_position_settings(&solauto_position)
+        };
+
+        // The strategy needs a curve
+        assert!(set_curve(vec![]).is_err());
+        assert!(set_curve(vec![band(0, 6000), band(120_000_000_000, 4000)]).is_ok());
+        // Price ratios below the first band aren't covered
+        assert!(set_curve(vec![band(1, 6000), band(120_000_000_000, 4000)]).is_err());
+        // Bands must be in ascending order
+        assert!(set_curve(vec![band(0, 6000), band(0, 4000)]).is_err());
+        // Each band must be valid setting parameters
+        assert!(set_curve(vec![band(0, 8000)]).is_err());
+    }
+
+    #[test]
+    fn test_invalid_grid_settings() {
+        let mut position_data = PositionData::default();
+        position_data.lending_platform = LendingPlatform::Marginfi;
+        position_data.settings = SolautoSettingsParameters::from(SolautoSettingsParametersInp {
+            boost_to_bps: 4000,
+            boost_gap: 500,
+            repay_to_bps: 7500,
+            repay_gap: 500,
+            ..Default::default()
+        });
+        position_data.rebalance_strategy = RebalanceStrategyType::Grid;
+
+        let mut position_state = PositionState::default();
+        position_state.max_ltv_bps = 6500;
+        position_state.liq_threshold_bps = 8000;
+
+        let mut solauto_position = SolautoPosition::new(
+            1,
+            Pubkey::default(),
+            PositionType::default(),
+            position_data,
+            position_state,
+        );
+        let grid_settings = GridSettingsInp {
+            lower_liq_utilization_rate_bps: 2000,
+            upper_liq_utilization_rate_bps: 6000,
+            level_count: 5,
+            price_step_bps: 500,
+        };
+        let mut set_grid = |args: GridSettingsInp| {
+            solauto_position.grid = Grid::from(args, 0, None);
+            validate_position_settings(&solauto_position)
+        };
+
+        // The strategy needs a grid
+        assert!(set_grid(GridSettingsInp::default()).is_err());
+        assert!(set_grid(grid_settings).is_ok());
+        assert!(set_grid(GridSettingsInp {
+            level_count: 1,
+            ..grid_settings
+        })
+        .is_err());
+        assert!(set_grid(GridSettingsInp {
+            level_count: MAX_GRID_LEVELS + 1,
+            ..grid_settings
+        })
+        .is_err());
+        assert!(set_grid(GridSettingsInp {
+            lower_liq_utilization_rate_bps: 6000,
+            ..grid_settings
+        })
+        .is_err());
+        // The highest level must sit below repay_from
+        assert!(set_grid(GridSettingsInp {
+            upper_liq_utilization_rate_bps: 8000,
+            ..grid_settings
+        })
+        .is_err());
+        assert!(set_grid(GridSettingsInp {
+            price_step_bps: 0,
+            ..grid_settings
+        })
+        .is_err());
+    }
+
     fn test_automation_settings(current_timestamp: u64, automation_settings: AutomationSettings) {
         let result = validate_automation_settings(&automation_settings, current_timestamp);
         assert!(result.is_err());
@@ -628,4 +1325,50 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn test_invalid_dca_settings() {
+        let current_timestamp = 100;
+        let default_dca_args = DCASettingsInp {
+            automation: AutomationSettingsInp {
+                unix_start_date: current_timestamp,
+                interval_seconds: 60 * 60 * 24,
+                target_periods: 5,
+                periods_passed: 0,
+            },
+            dca_in_base_unit: 1000,
+            token_type: TokenType::Debt,
+        };
+        let mut solauto_position = SolautoPosition::new(
+            1,
+            Pubkey::default(),
+            PositionType::default(),
+            PositionData::default(),
+            PositionState::default(),
+        );
+
+        let dca = DCASettings::from(default_dca_args);
+        assert!(validate_dca_settings(&solauto_position, &dca, current_timestamp).is_ok());
+
+        let dca_out = DCASettings::from(DCASettingsInp {
+            dca_in_base_unit: 0,
+            ..default_dca_args
+        });
+        assert!(validate_dca_settings(&solauto_position, &dca_out, current_timestamp).is_ok());
+
+        let invalid_dcas = [DCASettingsInp {
+            automation: AutomationSettingsInp {
+                periods_passed: 1,
+                ..default_dca_args.automation
+            },
+            ..default_dca_args
+        }];
+        for dca_args in invalid_dcas {
+            let dca = DCASettings::from(dca_args);
+            assert!(validate_dca_settings(&solauto_position, &dca, current_timestamp).is_err());
+        }
+
+        solauto_position.position.dca = dca;
+        assert!(validate_dca_settings(&solauto_position, &dca, current_timestamp).is_err());
+    }
 }
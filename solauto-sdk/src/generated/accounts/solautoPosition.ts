@@ -28,6 +28,16 @@ import {
   u8,
 } from '@metaplex-foundation/umi/serializers';
 import {
+  CarryGuard,
+  CarryGuardArgs,
+  Grid,
+  GridArgs,
+  HarvestData,
+  HarvestDataArgs,
+  LeverageCurve,
+  LeverageCurveArgs,
+  LimitOrder,
+  LimitOrderArgs,
   PodBool,
   PodBoolArgs,
   PositionData,
@@ -38,11 +48,25 @@ import {
   PositionTypeArgs,
   RebalanceData,
   RebalanceDataArgs,
+  StopLossOrder,
+  StopLossOrderArgs,
+  TakeProfitOrder,
+  TakeProfitOrderArgs,
+  TrailingStopOrder,
+  TrailingStopOrderArgs,
+  getCarryGuardSerializer,
+  getGridSerializer,
+  getHarvestDataSerializer,
+  getLeverageCurveSerializer,
+  getLimitOrderSerializer,
   getPodBoolSerializer,
   getPositionDataSerializer,
   getPositionStateSerializer,
   getPositionTypeSerializer,
   getRebalanceDataSerializer,
+  getStopLossOrderSerializer,
+  getTakeProfitOrderSerializer,
+  getTrailingStopOrderSerializer,
 } from '../types';
 
 export type SolautoPosition = Account<SolautoPositionAccountData>;
@@ -57,6 +81,14 @@ export type SolautoPositionAccountData = {
   position: PositionData;
   state: PositionState;
   rebalance: RebalanceData;
+  harvest: HarvestData;
+  limitOrder: LimitOrder;
+  takeProfit: TakeProfitOrder;
+  stopLoss: StopLossOrder;
+  trailingStop: TrailingStopOrder;
+  leverageCurve: LeverageCurve;
+  grid: Grid;
+  carryGuard: CarryGuard;
   padding: Array<number>;
 };
 
@@ -70,6 +102,14 @@ export type SolautoPositionAccountDataArgs = {
   position: PositionDataArgs;
   state: PositionStateArgs;
   rebalance: RebalanceDataArgs;
+  harvest: HarvestDataArgs;
+  limitOrder: LimitOrderArgs;
+  takeProfit: TakeProfitOrderArgs;
+  stopLoss: StopLossOrderArgs;
+  trailingStop: TrailingStopOrderArgs;
+  leverageCurve: LeverageCurveArgs;
+  grid: GridArgs;
+  carryGuard: CarryGuardArgs;
   padding: Array<number>;
 };
 
@@ -88,7 +128,15 @@ export function getSolautoPositionAccountDataSerializer(): Serializer<
       ['position', getPositionDataSerializer()],
       ['state', getPositionStateSerializer()],
       ['rebalance', getRebalanceDataSerializer()],
-      ['padding', array(u32(), { size: 20 })],
+      ['harvest', getHarvestDataSerializer()],
+      ['limitOrder', getLimitOrderSerializer()],
+      ['takeProfit', getTakeProfitOrderSerializer()],
+      ['stopLoss', getStopLossOrderSerializer()],
+      ['trailingStop', getTrailingStopOrderSerializer()],
+      ['leverageCurve', getLeverageCurveSerializer()],
+      ['grid', getGridSerializer()],
+      ['carryGuard', getCarryGuardSerializer()],
+      ['padding', array(u32(), { size: 2 })],
     ],
     { description: 'SolautoPositionAccountData' }
   ) as Serializer<SolautoPositionAccountDataArgs, SolautoPositionAccountData>;
@@ -177,6 +225,14 @@ export function getSolautoPositionGpaBuilder(
       position: PositionDataArgs;
       state: PositionStateArgs;
       rebalance: RebalanceDataArgs;
+      harvest: HarvestDataArgs;
+      limitOrder: LimitOrderArgs;
+      takeProfit: TakeProfitOrderArgs;
+      stopLoss: StopLossOrderArgs;
+      trailingStop: TrailingStopOrderArgs;
+      leverageCurve: LeverageCurveArgs;
+      grid: GridArgs;
+      carryGuard: CarryGuardArgs;
       padding: Array<number>;
     }>({
       bump: [0, array(u8(), { size: 1 })],
@@ -186,9 +242,17 @@ export function getSolautoPositionGpaBuilder(
       padding1: [4, array(u8(), { size: 4 })],
       authority: [8, publicKeySerializer()],
       position: [40, getPositionDataSerializer()],
-      state: [360, getPositionStateSerializer()],
-      rebalance: [648, getRebalanceDataSerializer()],
-      padding: [752, array(u32(), { size: 20 })],
+      state: [376, getPositionStateSerializer()],
+      rebalance: [1584, getRebalanceDataSerializer()],
+      harvest: [1688, getHarvestDataSerializer()],
+      limitOrder: [1704, getLimitOrderSerializer()],
+      takeProfit: [1760, getTakeProfitOrderSerializer()],
+      stopLoss: [1808, getStopLossOrderSerializer()],
+      trailingStop: [1856, getTrailingStopOrderSerializer()],
+      leverageCurve: [1896, getLeverageCurveSerializer()],
+      grid: [1968, getGridSerializer()],
+      carryGuard: [2016, getCarryGuardSerializer()],
+      padding: [2056, array(u32(), { size: 2 })],
     })
     .deserializeUsing<SolautoPosition>((account) =>
       deserializeSolautoPosition(account)
@@ -196,5 +260,5 @@ export function getSolautoPositionGpaBuilder(
 }
 
 export function getSolautoPositionSize(): number {
-  return 832;
+  return 2064;
 }
@@ -236,6 +236,319 @@ nameToErrorMap.set(
   NonAuthorityProvidedTargetLTVError
 );
 
+/** TooManyPositionLegs: The position has more supply or debt legs than a Solauto position can track */
+export class TooManyPositionLegsError extends ProgramError {
+  override readonly name: string = 'TooManyPositionLegs';
+
+  readonly code: number = 0xf; // 15
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The position has more supply or debt legs than a Solauto position can track',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0xf, TooManyPositionLegsError);
+nameToErrorMap.set('TooManyPositionLegs', TooManyPositionLegsError);
+
+/** InvalidLimitOrder: Invalid limit order provided */
+export class InvalidLimitOrderError extends ProgramError {
+  override readonly name: string = 'InvalidLimitOrder';
+
+  readonly code: number = 0x10; // 16
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid limit order provided', program, cause);
+  }
+}
+codeToErrorMap.set(0x10, InvalidLimitOrderError);
+nameToErrorMap.set('InvalidLimitOrder', InvalidLimitOrderError);
+
+/** InvalidTakeProfitOrder: Invalid take-profit order provided */
+export class InvalidTakeProfitOrderError extends ProgramError {
+  override readonly name: string = 'InvalidTakeProfitOrder';
+
+  readonly code: number = 0x11; // 17
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid take-profit order provided', program, cause);
+  }
+}
+codeToErrorMap.set(0x11, InvalidTakeProfitOrderError);
+nameToErrorMap.set('InvalidTakeProfitOrder', InvalidTakeProfitOrderError);
+
+/** InvalidStopLossOrder: Invalid stop-loss order provided */
+export class InvalidStopLossOrderError extends ProgramError {
+  override readonly name: string = 'InvalidStopLossOrder';
+
+  readonly code: number = 0x12; // 18
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid stop-loss order provided', program, cause);
+  }
+}
+codeToErrorMap.set(0x12, InvalidStopLossOrderError);
+nameToErrorMap.set('InvalidStopLossOrder', InvalidStopLossOrderError);
+
+/** InvalidTrailingStopOrder: Invalid trailing stop order provided */
+export class InvalidTrailingStopOrderError extends ProgramError {
+  override readonly name: string = 'InvalidTrailingStopOrder';
+
+  readonly code: number = 0x13; // 19
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid trailing stop order provided', program, cause);
+  }
+}
+codeToErrorMap.set(0x13, InvalidTrailingStopOrderError);
+nameToErrorMap.set('InvalidTrailingStopOrder', InvalidTrailingStopOrderError);
+
+/** InvalidLeverageCurve: Invalid leverage curve provided */
+export class InvalidLeverageCurveError extends ProgramError {
+  override readonly name: string = 'InvalidLeverageCurve';
+
+  readonly code: number = 0x14; // 20
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid leverage curve provided', program, cause);
+  }
+}
+codeToErrorMap.set(0x14, InvalidLeverageCurveError);
+nameToErrorMap.set('InvalidLeverageCurve', InvalidLeverageCurveError);
+
+/** InvalidGridSettings: Invalid grid settings provided */
+export class InvalidGridSettingsError extends ProgramError {
+  override readonly name: string = 'InvalidGridSettings';
+
+  readonly code: number = 0x15; // 21
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid grid settings provided', program, cause);
+  }
+}
+codeToErrorMap.set(0x15, InvalidGridSettingsError);
+nameToErrorMap.set('InvalidGridSettings', InvalidGridSettingsError);
+
+/** InvalidCarryGuard: Invalid carry guard provided */
+export class InvalidCarryGuardError extends ProgramError {
+  override readonly name: string = 'InvalidCarryGuard';
+
+  readonly code: number = 0x16; // 22
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid carry guard provided', program, cause);
+  }
+}
+codeToErrorMap.set(0x16, InvalidCarryGuardError);
+nameToErrorMap.set('InvalidCarryGuard', InvalidCarryGuardError);
+
+/** NegativeCarryBoost: Boosts are refused while the position's net carry is negative */
+export class NegativeCarryBoostError extends ProgramError {
+  override readonly name: string = 'NegativeCarryBoost';
+
+  readonly code: number = 0x17; // 23
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      "Boosts are refused while the position's net carry is negative",
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17, NegativeCarryBoostError);
+nameToErrorMap.set('NegativeCarryBoost', NegativeCarryBoostError);
+
+/** InvalidMaxPriceDivergence: Invalid max price divergence provided */
+export class InvalidMaxPriceDivergenceError extends ProgramError {
+  override readonly name: string = 'InvalidMaxPriceDivergence';
+
+  readonly code: number = 0x18; // 24
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid max price divergence provided', program, cause);
+  }
+}
+codeToErrorMap.set(0x18, InvalidMaxPriceDivergenceError);
+nameToErrorMap.set('InvalidMaxPriceDivergence', InvalidMaxPriceDivergenceError);
+
+/** PriceDivergenceTooHigh: Boosts are refused while the realtime & EMA prices diverge by more than the position allows */
+export class PriceDivergenceTooHighError extends ProgramError {
+  override readonly name: string = 'PriceDivergenceTooHigh';
+
+  readonly code: number = 0x19; // 25
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Boosts are refused while the realtime & EMA prices diverge by more than the position allows',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x19, PriceDivergenceTooHighError);
+nameToErrorMap.set('PriceDivergenceTooHigh', PriceDivergenceTooHighError);
+
+/** StaleOraclePrice: The oracle price is older than the maximum price age */
+export class StaleOraclePriceError extends ProgramError {
+  override readonly name: string = 'StaleOraclePrice';
+
+  readonly code: number = 0x1a; // 26
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The oracle price is older than the maximum price age',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x1a, StaleOraclePriceError);
+nameToErrorMap.set('StaleOraclePrice', StaleOraclePriceError);
+
+/** OracleConfidenceTooWide: The oracle confidence interval is too wide relative to the price */
+export class OracleConfidenceTooWideError extends ProgramError {
+  override readonly name: string = 'OracleConfidenceTooWide';
+
+  readonly code: number = 0x1b; // 27
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The oracle confidence interval is too wide relative to the price',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x1b, OracleConfidenceTooWideError);
+nameToErrorMap.set('OracleConfidenceTooWide', OracleConfidenceTooWideError);
+
+/** InvalidOraclePrice: The oracle reported a price that isn't positive */
+export class InvalidOraclePriceError extends ProgramError {
+  override readonly name: string = 'InvalidOraclePrice';
+
+  readonly code: number = 0x1c; // 28
+
+  constructor(program: Program, cause?: Error) {
+    super("The oracle reported a price that isn't positive", program, cause);
+  }
+}
+codeToErrorMap.set(0x1c, InvalidOraclePriceError);
+nameToErrorMap.set('InvalidOraclePrice', InvalidOraclePriceError);
+
+/** UnsupportedSwap: The swap isn't through a supported venue or route, or its instruction data couldn't be decoded */
+export class UnsupportedSwapError extends ProgramError {
+  override readonly name: string = 'UnsupportedSwap';
+
+  readonly code: number = 0x1d; // 29
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      "The swap isn't through a supported venue or route, or its instruction data couldn't be decoded",
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x1d, UnsupportedSwapError);
+nameToErrorMap.set('UnsupportedSwap', UnsupportedSwapError);
+
+/** SwapMintMismatch: The swap's input or output mint doesn't match the rebalance */
+export class SwapMintMismatchError extends ProgramError {
+  override readonly name: string = 'SwapMintMismatch';
+
+  readonly code: number = 0x1e; // 30
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      "The swap's input or output mint doesn't match the rebalance",
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x1e, SwapMintMismatchError);
+nameToErrorMap.set('SwapMintMismatch', SwapMintMismatchError);
+
+/** SwapTokenAccountMismatch: The swap's source or destination token account doesn't match the rebalance */
+export class SwapTokenAccountMismatchError extends ProgramError {
+  override readonly name: string = 'SwapTokenAccountMismatch';
+
+  readonly code: number = 0x1f; // 31
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      "The swap's source or destination token account doesn't match the rebalance",
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x1f, SwapTokenAccountMismatchError);
+nameToErrorMap.set('SwapTokenAccountMismatch', SwapTokenAccountMismatchError);
+
+/** SwapPlatformFee: The swap takes a platform fee */
+export class SwapPlatformFeeError extends ProgramError {
+  override readonly name: string = 'SwapPlatformFee';
+
+  readonly code: number = 0x20; // 32
+
+  constructor(program: Program, cause?: Error) {
+    super('The swap takes a platform fee', program, cause);
+  }
+}
+codeToErrorMap.set(0x20, SwapPlatformFeeError);
+nameToErrorMap.set('SwapPlatformFee', SwapPlatformFeeError);
+
+/** SwapAmountMismatch: The swap's in-amount doesn't match the rebalance swap-in amount */
+export class SwapAmountMismatchError extends ProgramError {
+  override readonly name: string = 'SwapAmountMismatch';
+
+  readonly code: number = 0x21; // 33
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      "The swap's in-amount doesn't match the rebalance swap-in amount",
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x21, SwapAmountMismatchError);
+nameToErrorMap.set('SwapAmountMismatch', SwapAmountMismatchError);
+
+/** InvalidMaxSlippage: Invalid max slippage param */
+export class InvalidMaxSlippageError extends ProgramError {
+  override readonly name: string = 'InvalidMaxSlippage';
+
+  readonly code: number = 0x22; // 34
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid max slippage param', program, cause);
+  }
+}
+codeToErrorMap.set(0x22, InvalidMaxSlippageError);
+nameToErrorMap.set('InvalidMaxSlippage', InvalidMaxSlippageError);
+
+/** SwapSlippageTooHigh: The swap returned less than the oracle prices imply, by more than the max slippage */
+export class SwapSlippageTooHighError extends ProgramError {
+  override readonly name: string = 'SwapSlippageTooHigh';
+
+  readonly code: number = 0x23; // 35
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The swap returned less than the oracle prices imply, by more than the max slippage',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x23, SwapSlippageTooHighError);
+nameToErrorMap.set('SwapSlippageTooHigh', SwapSlippageTooHighError);
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
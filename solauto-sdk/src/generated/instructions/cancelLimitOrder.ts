@@ -0,0 +1,164 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type CancelLimitOrderInstructionAccounts = {
+  signer: Signer;
+  systemProgram?: PublicKey | Pda;
+  tokenProgram?: PublicKey | Pda;
+  ataProgram?: PublicKey | Pda;
+  solautoPosition: PublicKey | Pda;
+  orderMint: PublicKey | Pda;
+  positionOrderTa: PublicKey | Pda;
+  signerOrderTa: PublicKey | Pda;
+};
+
+// Data.
+export type CancelLimitOrderInstructionData = { discriminator: number };
+
+export type CancelLimitOrderInstructionDataArgs = {};
+
+export function getCancelLimitOrderInstructionDataSerializer(): Serializer<
+  CancelLimitOrderInstructionDataArgs,
+  CancelLimitOrderInstructionData
+> {
+  return mapSerializer<
+    CancelLimitOrderInstructionDataArgs,
+    any,
+    CancelLimitOrderInstructionData
+  >(
+    struct<CancelLimitOrderInstructionData>([['discriminator', u8()]], {
+      description: 'CancelLimitOrderInstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 19 })
+  ) as Serializer<
+    CancelLimitOrderInstructionDataArgs,
+    CancelLimitOrderInstructionData
+  >;
+}
+
+// Instruction.
+export function cancelLimitOrder(
+  context: Pick<Context, 'programs'>,
+  input: CancelLimitOrderInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'solauto',
+    'AutoyKBRaHSBHy9RsmXCZMy6nNFAg5FYijrvZyQcNLV'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    signer: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.signer ?? null,
+    },
+    systemProgram: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    tokenProgram: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    ataProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.ataProgram ?? null,
+    },
+    solautoPosition: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.solautoPosition ?? null,
+    },
+    orderMint: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.orderMint ?? null,
+    },
+    positionOrderTa: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.positionOrderTa ?? null,
+    },
+    signerOrderTa: {
+      index: 7,
+      isWritable: true as boolean,
+      value: input.signerOrderTa ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.ataProgram.value) {
+    resolvedAccounts.ataProgram.value = context.programs.getPublicKey(
+      'splAssociatedToken',
+      'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+    );
+    resolvedAccounts.ataProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getCancelLimitOrderInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
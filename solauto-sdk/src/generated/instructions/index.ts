@@ -7,12 +7,23 @@
  */
 
 export * from './cancelDCA';
+export * from './cancelLimitOrder';
 export * from './claimReferralFees';
 export * from './closePosition';
 export * from './convertReferralFees';
+export * from './marginfiExportAccount';
+export * from './marginfiHarvestEmissions';
+export * from './marginfiImportAccount';
 export * from './marginfiOpenPosition';
 export * from './marginfiProtocolInteraction';
 export * from './marginfiRebalance';
 export * from './marginfiRefreshData';
+export * from './migratePosition';
+export * from './openPosition';
+export * from './placeLimitOrder';
+export * from './protocolInteraction';
+export * from './rebalance';
+export * from './refreshData';
 export * from './updatePosition';
 export * from './updateReferralStates';
+export * from './upgradePosition';
@@ -0,0 +1,189 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type MarginfiExportAccountInstructionAccounts = {
+  signer: Signer;
+  marginfiProgram: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+  tokenProgram?: PublicKey | Pda;
+  ataProgram?: PublicKey | Pda;
+  solautoPosition: PublicKey | Pda;
+  marginfiGroup: PublicKey | Pda;
+  marginfiAccount: PublicKey | Pda;
+  positionSupplyTa: PublicKey | Pda;
+  signerSupplyTa: PublicKey | Pda;
+  positionDebtTa: PublicKey | Pda;
+  signerDebtTa: PublicKey | Pda;
+};
+
+// Data.
+export type MarginfiExportAccountInstructionData = { discriminator: number };
+
+export type MarginfiExportAccountInstructionDataArgs = {};
+
+export function getMarginfiExportAccountInstructionDataSerializer(): Serializer<
+  MarginfiExportAccountInstructionDataArgs,
+  MarginfiExportAccountInstructionData
+> {
+  return mapSerializer<
+    MarginfiExportAccountInstructionDataArgs,
+    any,
+    MarginfiExportAccountInstructionData
+  >(
+    struct<MarginfiExportAccountInstructionData>([['discriminator', u8()]], {
+      description: 'MarginfiExportAccountInstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 17 })
+  ) as Serializer<
+    MarginfiExportAccountInstructionDataArgs,
+    MarginfiExportAccountInstructionData
+  >;
+}
+
+// Instruction.
+export function marginfiExportAccount(
+  context: Pick<Context, 'programs'>,
+  input: MarginfiExportAccountInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'solauto',
+    'AutoyKBRaHSBHy9RsmXCZMy6nNFAg5FYijrvZyQcNLV'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    signer: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.signer ?? null,
+    },
+    marginfiProgram: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.marginfiProgram ?? null,
+    },
+    systemProgram: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    tokenProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    ataProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.ataProgram ?? null,
+    },
+    solautoPosition: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.solautoPosition ?? null,
+    },
+    marginfiGroup: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.marginfiGroup ?? null,
+    },
+    marginfiAccount: {
+      index: 7,
+      isWritable: true as boolean,
+      value: input.marginfiAccount ?? null,
+    },
+    positionSupplyTa: {
+      index: 8,
+      isWritable: true as boolean,
+      value: input.positionSupplyTa ?? null,
+    },
+    signerSupplyTa: {
+      index: 9,
+      isWritable: true as boolean,
+      value: input.signerSupplyTa ?? null,
+    },
+    positionDebtTa: {
+      index: 10,
+      isWritable: true as boolean,
+      value: input.positionDebtTa ?? null,
+    },
+    signerDebtTa: {
+      index: 11,
+      isWritable: true as boolean,
+      value: input.signerDebtTa ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.ataProgram.value) {
+    resolvedAccounts.ataProgram.value = context.programs.getPublicKey(
+      'splAssociatedToken',
+      'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+    );
+    resolvedAccounts.ataProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getMarginfiExportAccountInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,218 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type MarginfiHarvestEmissionsInstructionAccounts = {
+  signer: Signer;
+  marginfiProgram: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+  tokenProgram?: PublicKey | Pda;
+  ixsSysvar: PublicKey | Pda;
+  solautoPosition: PublicKey | Pda;
+  marginfiGroup: PublicKey | Pda;
+  marginfiAccount: PublicKey | Pda;
+  emissionsBank: PublicKey | Pda;
+  emissionsMint: PublicKey | Pda;
+  emissionsAuth: PublicKey | Pda;
+  emissionsVault: PublicKey | Pda;
+  positionEmissionsTa: PublicKey | Pda;
+  intermediaryTa?: PublicKey | Pda;
+  supplyBank: PublicKey | Pda;
+  positionSupplyTa: PublicKey | Pda;
+  vaultSupplyTa: PublicKey | Pda;
+  debtBank: PublicKey | Pda;
+};
+
+// Data.
+export type MarginfiHarvestEmissionsInstructionData = { discriminator: number };
+
+export type MarginfiHarvestEmissionsInstructionDataArgs = {};
+
+export function getMarginfiHarvestEmissionsInstructionDataSerializer(): Serializer<
+  MarginfiHarvestEmissionsInstructionDataArgs,
+  MarginfiHarvestEmissionsInstructionData
+> {
+  return mapSerializer<
+    MarginfiHarvestEmissionsInstructionDataArgs,
+    any,
+    MarginfiHarvestEmissionsInstructionData
+  >(
+    struct<MarginfiHarvestEmissionsInstructionData>([['discriminator', u8()]], {
+      description: 'MarginfiHarvestEmissionsInstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 14 })
+  ) as Serializer<
+    MarginfiHarvestEmissionsInstructionDataArgs,
+    MarginfiHarvestEmissionsInstructionData
+  >;
+}
+
+// Instruction.
+export function marginfiHarvestEmissions(
+  context: Pick<Context, 'programs'>,
+  input: MarginfiHarvestEmissionsInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'solauto',
+    'AutoyKBRaHSBHy9RsmXCZMy6nNFAg5FYijrvZyQcNLV'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    signer: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.signer ?? null,
+    },
+    marginfiProgram: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.marginfiProgram ?? null,
+    },
+    systemProgram: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    tokenProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    ixsSysvar: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.ixsSysvar ?? null,
+    },
+    solautoPosition: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.solautoPosition ?? null,
+    },
+    marginfiGroup: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.marginfiGroup ?? null,
+    },
+    marginfiAccount: {
+      index: 7,
+      isWritable: true as boolean,
+      value: input.marginfiAccount ?? null,
+    },
+    emissionsBank: {
+      index: 8,
+      isWritable: true as boolean,
+      value: input.emissionsBank ?? null,
+    },
+    emissionsMint: {
+      index: 9,
+      isWritable: false as boolean,
+      value: input.emissionsMint ?? null,
+    },
+    emissionsAuth: {
+      index: 10,
+      isWritable: false as boolean,
+      value: input.emissionsAuth ?? null,
+    },
+    emissionsVault: {
+      index: 11,
+      isWritable: true as boolean,
+      value: input.emissionsVault ?? null,
+    },
+    positionEmissionsTa: {
+      index: 12,
+      isWritable: true as boolean,
+      value: input.positionEmissionsTa ?? null,
+    },
+    intermediaryTa: {
+      index: 13,
+      isWritable: true as boolean,
+      value: input.intermediaryTa ?? null,
+    },
+    supplyBank: {
+      index: 14,
+      isWritable: true as boolean,
+      value: input.supplyBank ?? null,
+    },
+    positionSupplyTa: {
+      index: 15,
+      isWritable: true as boolean,
+      value: input.positionSupplyTa ?? null,
+    },
+    vaultSupplyTa: {
+      index: 16,
+      isWritable: true as boolean,
+      value: input.vaultSupplyTa ?? null,
+    },
+    debtBank: {
+      index: 17,
+      isWritable: false as boolean,
+      value: input.debtBank ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getMarginfiHarvestEmissionsInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,262 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  MarginfiOpenPositionData,
+  MarginfiOpenPositionDataArgs,
+  getMarginfiOpenPositionDataSerializer,
+} from '../types';
+
+// Accounts.
+export type MarginfiImportAccountInstructionAccounts = {
+  signer: Signer;
+  marginfiProgram: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+  tokenProgram?: PublicKey | Pda;
+  ataProgram?: PublicKey | Pda;
+  rent?: PublicKey | Pda;
+  signerReferralState: PublicKey | Pda;
+  referredByState?: PublicKey | Pda;
+  referredBySupplyTa?: PublicKey | Pda;
+  solautoPosition: PublicKey | Pda;
+  marginfiGroup: PublicKey | Pda;
+  marginfiAccount: PublicKey | Pda;
+  supplyMint: PublicKey | Pda;
+  supplyBank: PublicKey | Pda;
+  supplyPriceOracle: PublicKey | Pda;
+  positionSupplyTa: PublicKey | Pda;
+  debtMint: PublicKey | Pda;
+  debtBank: PublicKey | Pda;
+  debtPriceOracle: PublicKey | Pda;
+  positionDebtTa: PublicKey | Pda;
+};
+
+// Data.
+export type MarginfiImportAccountInstructionData = {
+  discriminator: number;
+  marginfiOpenPositionData: MarginfiOpenPositionData;
+};
+
+export type MarginfiImportAccountInstructionDataArgs = {
+  marginfiOpenPositionData: MarginfiOpenPositionDataArgs;
+};
+
+export function getMarginfiImportAccountInstructionDataSerializer(): Serializer<
+  MarginfiImportAccountInstructionDataArgs,
+  MarginfiImportAccountInstructionData
+> {
+  return mapSerializer<
+    MarginfiImportAccountInstructionDataArgs,
+    any,
+    MarginfiImportAccountInstructionData
+  >(
+    struct<MarginfiImportAccountInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['marginfiOpenPositionData', getMarginfiOpenPositionDataSerializer()],
+      ],
+      { description: 'MarginfiImportAccountInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 16 })
+  ) as Serializer<
+    MarginfiImportAccountInstructionDataArgs,
+    MarginfiImportAccountInstructionData
+  >;
+}
+
+// Args.
+export type MarginfiImportAccountInstructionArgs =
+  MarginfiImportAccountInstructionDataArgs;
+
+// Instruction.
+export function marginfiImportAccount(
+  context: Pick<Context, 'programs'>,
+  input: MarginfiImportAccountInstructionAccounts &
+    MarginfiImportAccountInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'solauto',
+    'AutoyKBRaHSBHy9RsmXCZMy6nNFAg5FYijrvZyQcNLV'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    signer: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.signer ?? null,
+    },
+    marginfiProgram: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.marginfiProgram ?? null,
+    },
+    systemProgram: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    tokenProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    ataProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.ataProgram ?? null,
+    },
+    rent: { index: 5, isWritable: false as boolean, value: input.rent ?? null },
+    signerReferralState: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.signerReferralState ?? null,
+    },
+    referredByState: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.referredByState ?? null,
+    },
+    referredBySupplyTa: {
+      index: 8,
+      isWritable: true as boolean,
+      value: input.referredBySupplyTa ?? null,
+    },
+    solautoPosition: {
+      index: 9,
+      isWritable: true as boolean,
+      value: input.solautoPosition ?? null,
+    },
+    marginfiGroup: {
+      index: 10,
+      isWritable: false as boolean,
+      value: input.marginfiGroup ?? null,
+    },
+    marginfiAccount: {
+      index: 11,
+      isWritable: true as boolean,
+      value: input.marginfiAccount ?? null,
+    },
+    supplyMint: {
+      index: 12,
+      isWritable: false as boolean,
+      value: input.supplyMint ?? null,
+    },
+    supplyBank: {
+      index: 13,
+      isWritable: true as boolean,
+      value: input.supplyBank ?? null,
+    },
+    supplyPriceOracle: {
+      index: 14,
+      isWritable: false as boolean,
+      value: input.supplyPriceOracle ?? null,
+    },
+    positionSupplyTa: {
+      index: 15,
+      isWritable: true as boolean,
+      value: input.positionSupplyTa ?? null,
+    },
+    debtMint: {
+      index: 16,
+      isWritable: false as boolean,
+      value: input.debtMint ?? null,
+    },
+    debtBank: {
+      index: 17,
+      isWritable: true as boolean,
+      value: input.debtBank ?? null,
+    },
+    debtPriceOracle: {
+      index: 18,
+      isWritable: false as boolean,
+      value: input.debtPriceOracle ?? null,
+    },
+    positionDebtTa: {
+      index: 19,
+      isWritable: true as boolean,
+      value: input.positionDebtTa ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: MarginfiImportAccountInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.ataProgram.value) {
+    resolvedAccounts.ataProgram.value = context.programs.getPublicKey(
+      'splAssociatedToken',
+      'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+    );
+    resolvedAccounts.ataProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.rent.value) {
+    resolvedAccounts.rent.value = publicKey(
+      'SysvarRent111111111111111111111111111111111'
+    );
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getMarginfiImportAccountInstructionDataSerializer().serialize(
+    resolvedArgs as MarginfiImportAccountInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -27,12 +27,9 @@ import {
   getAccountMetasAndSigners,
 } from '../shared';
 import {
-  PositionType,
-  PositionTypeArgs,
-  UpdatePositionData,
-  UpdatePositionDataArgs,
-  getPositionTypeSerializer,
-  getUpdatePositionDataSerializer,
+  MarginfiOpenPositionData,
+  MarginfiOpenPositionDataArgs,
+  getMarginfiOpenPositionDataSerializer,
 } from '../types';
 
 // Accounts.
@@ -61,13 +58,11 @@ export type MarginfiOpenPositionInstructionAccounts = {
 // Data.
 export type MarginfiOpenPositionInstructionData = {
   discriminator: number;
-  positionType: PositionType;
-  positionData: UpdatePositionData;
+  marginfiOpenPositionData: MarginfiOpenPositionData;
 };
 
 export type MarginfiOpenPositionInstructionDataArgs = {
-  positionType: PositionTypeArgs;
-  positionData: UpdatePositionDataArgs;
+  marginfiOpenPositionData: MarginfiOpenPositionDataArgs;
 };
 
 export function getMarginfiOpenPositionInstructionDataSerializer(): Serializer<
@@ -82,8 +77,7 @@ export function getMarginfiOpenPositionInstructionDataSerializer(): Serializer<
     struct<MarginfiOpenPositionInstructionData>(
       [
         ['discriminator', u8()],
-        ['positionType', getPositionTypeSerializer()],
-        ['positionData', getUpdatePositionDataSerializer()],
+        ['marginfiOpenPositionData', getMarginfiOpenPositionDataSerializer()],
       ],
       { description: 'MarginfiOpenPositionInstructionData' }
     ),
@@ -8,8 +8,6 @@
 
 import {
   Context,
-  Option,
-  OptionOrNullable,
   Pda,
   PublicKey,
   Signer,
@@ -19,10 +17,7 @@ import {
 import {
   Serializer,
   mapSerializer,
-  option,
   struct,
-  u16,
-  u64,
   u8,
 } from '@metaplex-foundation/umi/serializers';
 import {
@@ -31,15 +26,9 @@ import {
   getAccountMetasAndSigners,
 } from '../shared';
 import {
-  PriceType,
-  PriceTypeArgs,
-  SolautoRebalanceType,
-  SolautoRebalanceTypeArgs,
-  SwapType,
-  SwapTypeArgs,
-  getPriceTypeSerializer,
-  getSolautoRebalanceTypeSerializer,
-  getSwapTypeSerializer,
+  RebalanceSettings,
+  RebalanceSettingsArgs,
+  getRebalanceSettingsSerializer,
 } from '../types';
 
 // Accounts.
@@ -74,21 +63,11 @@ export type MarginfiRebalanceInstructionAccounts = {
 // Data.
 export type MarginfiRebalanceInstructionData = {
   discriminator: number;
-  rebalanceType: SolautoRebalanceType;
-  swapInAmountBaseUnit: Option<bigint>;
-  targetLiqUtilizationRateBps: Option<number>;
-  flashLoanFeeBps: Option<number>;
-  priceType: Option<PriceType>;
-  swapType: Option<SwapType>;
+  rebalanceSettings: RebalanceSettings;
 };
 
 export type MarginfiRebalanceInstructionDataArgs = {
-  rebalanceType: SolautoRebalanceTypeArgs;
-  swapInAmountBaseUnit: OptionOrNullable<number | bigint>;
-  targetLiqUtilizationRateBps: OptionOrNullable<number>;
-  flashLoanFeeBps: OptionOrNullable<number>;
-  priceType: OptionOrNullable<PriceTypeArgs>;
-  swapType: OptionOrNullable<SwapTypeArgs>;
+  rebalanceSettings: RebalanceSettingsArgs;
 };
 
 export function getMarginfiRebalanceInstructionDataSerializer(): Serializer<
@@ -103,12 +82,7 @@ export function getMarginfiRebalanceInstructionDataSerializer(): Serializer<
     struct<MarginfiRebalanceInstructionData>(
       [
         ['discriminator', u8()],
-        ['rebalanceType', getSolautoRebalanceTypeSerializer()],
-        ['swapInAmountBaseUnit', option(u64())],
-        ['targetLiqUtilizationRateBps', option(u16())],
-        ['flashLoanFeeBps', option(u16())],
-        ['priceType', option(getPriceTypeSerializer())],
-        ['swapType', option(getSwapTypeSerializer())],
+        ['rebalanceSettings', getRebalanceSettingsSerializer()],
       ],
       { description: 'MarginfiRebalanceInstructionData' }
     ),
@@ -0,0 +1,227 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  LendingPlatform,
+  LendingPlatformArgs,
+  getLendingPlatformSerializer,
+} from '../types';
+
+// Accounts.
+export type MigratePositionInstructionAccounts = {
+  signer: Signer;
+  sourceLendingProgram: PublicKey | Pda;
+  destinationLendingProgram: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+  tokenProgram?: PublicKey | Pda;
+  ataProgram?: PublicKey | Pda;
+  rent?: PublicKey | Pda;
+  ixsSysvar: PublicKey | Pda;
+  solautoPosition: PublicKey | Pda;
+  intermediaryTa: PublicKey | Pda;
+  supplyMint: PublicKey | Pda;
+  positionSupplyTa: PublicKey | Pda;
+  debtMint: PublicKey | Pda;
+  positionDebtTa: PublicKey | Pda;
+};
+
+// Data.
+export type MigratePositionInstructionData = {
+  discriminator: number;
+  lendingPlatform: LendingPlatform;
+  sourceAccountsLen: number;
+};
+
+export type MigratePositionInstructionDataArgs = {
+  lendingPlatform: LendingPlatformArgs;
+  sourceAccountsLen: number;
+};
+
+export function getMigratePositionInstructionDataSerializer(): Serializer<
+  MigratePositionInstructionDataArgs,
+  MigratePositionInstructionData
+> {
+  return mapSerializer<
+    MigratePositionInstructionDataArgs,
+    any,
+    MigratePositionInstructionData
+  >(
+    struct<MigratePositionInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['lendingPlatform', getLendingPlatformSerializer()],
+        ['sourceAccountsLen', u8()],
+      ],
+      { description: 'MigratePositionInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 15 })
+  ) as Serializer<
+    MigratePositionInstructionDataArgs,
+    MigratePositionInstructionData
+  >;
+}
+
+// Args.
+export type MigratePositionInstructionArgs = MigratePositionInstructionDataArgs;
+
+// Instruction.
+export function migratePosition(
+  context: Pick<Context, 'programs'>,
+  input: MigratePositionInstructionAccounts & MigratePositionInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'solauto',
+    'AutoyKBRaHSBHy9RsmXCZMy6nNFAg5FYijrvZyQcNLV'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    signer: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.signer ?? null,
+    },
+    sourceLendingProgram: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.sourceLendingProgram ?? null,
+    },
+    destinationLendingProgram: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.destinationLendingProgram ?? null,
+    },
+    systemProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    tokenProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    ataProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.ataProgram ?? null,
+    },
+    rent: { index: 6, isWritable: false as boolean, value: input.rent ?? null },
+    ixsSysvar: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.ixsSysvar ?? null,
+    },
+    solautoPosition: {
+      index: 8,
+      isWritable: true as boolean,
+      value: input.solautoPosition ?? null,
+    },
+    intermediaryTa: {
+      index: 9,
+      isWritable: true as boolean,
+      value: input.intermediaryTa ?? null,
+    },
+    supplyMint: {
+      index: 10,
+      isWritable: false as boolean,
+      value: input.supplyMint ?? null,
+    },
+    positionSupplyTa: {
+      index: 11,
+      isWritable: true as boolean,
+      value: input.positionSupplyTa ?? null,
+    },
+    debtMint: {
+      index: 12,
+      isWritable: false as boolean,
+      value: input.debtMint ?? null,
+    },
+    positionDebtTa: {
+      index: 13,
+      isWritable: true as boolean,
+      value: input.positionDebtTa ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: MigratePositionInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.ataProgram.value) {
+    resolvedAccounts.ataProgram.value = context.programs.getPublicKey(
+      'splAssociatedToken',
+      'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+    );
+    resolvedAccounts.ataProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.rent.value) {
+    resolvedAccounts.rent.value = publicKey(
+      'SysvarRent111111111111111111111111111111111'
+    );
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getMigratePositionInstructionDataSerializer().serialize(
+    resolvedArgs as MigratePositionInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,239 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  LendingPlatform,
+  LendingPlatformArgs,
+  PositionType,
+  PositionTypeArgs,
+  UpdatePositionData,
+  UpdatePositionDataArgs,
+  getLendingPlatformSerializer,
+  getPositionTypeSerializer,
+  getUpdatePositionDataSerializer,
+} from '../types';
+
+// Accounts.
+export type OpenPositionInstructionAccounts = {
+  signer: Signer;
+  lendingProgram: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+  tokenProgram?: PublicKey | Pda;
+  ataProgram?: PublicKey | Pda;
+  rent?: PublicKey | Pda;
+  signerReferralState: PublicKey | Pda;
+  referredByState?: PublicKey | Pda;
+  referredBySupplyTa?: PublicKey | Pda;
+  solautoPosition: PublicKey | Pda;
+  supplyMint: PublicKey | Pda;
+  positionSupplyTa: PublicKey | Pda;
+  debtMint: PublicKey | Pda;
+  positionDebtTa: PublicKey | Pda;
+  signerDebtTa?: PublicKey | Pda;
+};
+
+// Data.
+export type OpenPositionInstructionData = {
+  discriminator: number;
+  lendingPlatform: LendingPlatform;
+  positionType: PositionType;
+  positionData: UpdatePositionData;
+};
+
+export type OpenPositionInstructionDataArgs = {
+  lendingPlatform: LendingPlatformArgs;
+  positionType: PositionTypeArgs;
+  positionData: UpdatePositionDataArgs;
+};
+
+export function getOpenPositionInstructionDataSerializer(): Serializer<
+  OpenPositionInstructionDataArgs,
+  OpenPositionInstructionData
+> {
+  return mapSerializer<
+    OpenPositionInstructionDataArgs,
+    any,
+    OpenPositionInstructionData
+  >(
+    struct<OpenPositionInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['lendingPlatform', getLendingPlatformSerializer()],
+        ['positionType', getPositionTypeSerializer()],
+        ['positionData', getUpdatePositionDataSerializer()],
+      ],
+      { description: 'OpenPositionInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 10 })
+  ) as Serializer<OpenPositionInstructionDataArgs, OpenPositionInstructionData>;
+}
+
+// Args.
+export type OpenPositionInstructionArgs = OpenPositionInstructionDataArgs;
+
+// Instruction.
+export function openPosition(
+  context: Pick<Context, 'programs'>,
+  input: OpenPositionInstructionAccounts & OpenPositionInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'solauto',
+    'AutoyKBRaHSBHy9RsmXCZMy6nNFAg5FYijrvZyQcNLV'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    signer: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.signer ?? null,
+    },
+    lendingProgram: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.lendingProgram ?? null,
+    },
+    systemProgram: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    tokenProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    ataProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.ataProgram ?? null,
+    },
+    rent: { index: 5, isWritable: false as boolean, value: input.rent ?? null },
+    signerReferralState: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.signerReferralState ?? null,
+    },
+    referredByState: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.referredByState ?? null,
+    },
+    referredBySupplyTa: {
+      index: 8,
+      isWritable: true as boolean,
+      value: input.referredBySupplyTa ?? null,
+    },
+    solautoPosition: {
+      index: 9,
+      isWritable: true as boolean,
+      value: input.solautoPosition ?? null,
+    },
+    supplyMint: {
+      index: 10,
+      isWritable: false as boolean,
+      value: input.supplyMint ?? null,
+    },
+    positionSupplyTa: {
+      index: 11,
+      isWritable: true as boolean,
+      value: input.positionSupplyTa ?? null,
+    },
+    debtMint: {
+      index: 12,
+      isWritable: false as boolean,
+      value: input.debtMint ?? null,
+    },
+    positionDebtTa: {
+      index: 13,
+      isWritable: true as boolean,
+      value: input.positionDebtTa ?? null,
+    },
+    signerDebtTa: {
+      index: 14,
+      isWritable: true as boolean,
+      value: input.signerDebtTa ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: OpenPositionInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.ataProgram.value) {
+    resolvedAccounts.ataProgram.value = context.programs.getPublicKey(
+      'splAssociatedToken',
+      'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+    );
+    resolvedAccounts.ataProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.rent.value) {
+    resolvedAccounts.rent.value = publicKey(
+      'SysvarRent111111111111111111111111111111111'
+    );
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getOpenPositionInstructionDataSerializer().serialize(
+    resolvedArgs as OpenPositionInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
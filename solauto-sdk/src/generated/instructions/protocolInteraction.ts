@@ -0,0 +1,214 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  SolautoAction,
+  SolautoActionArgs,
+  getSolautoActionSerializer,
+} from '../types';
+
+// Accounts.
+export type ProtocolInteractionInstructionAccounts = {
+  signer: Signer;
+  lendingProgram: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+  tokenProgram?: PublicKey | Pda;
+  ataProgram?: PublicKey | Pda;
+  rent?: PublicKey | Pda;
+  ixsSysvar: PublicKey | Pda;
+  solautoPosition: PublicKey | Pda;
+  supplyMint?: PublicKey | Pda;
+  positionSupplyTa?: PublicKey | Pda;
+  debtMint?: PublicKey | Pda;
+  positionDebtTa?: PublicKey | Pda;
+};
+
+// Data.
+export type ProtocolInteractionInstructionData = {
+  discriminator: number;
+  solautoAction: SolautoAction;
+};
+
+export type ProtocolInteractionInstructionDataArgs = {
+  solautoAction: SolautoActionArgs;
+};
+
+export function getProtocolInteractionInstructionDataSerializer(): Serializer<
+  ProtocolInteractionInstructionDataArgs,
+  ProtocolInteractionInstructionData
+> {
+  return mapSerializer<
+    ProtocolInteractionInstructionDataArgs,
+    any,
+    ProtocolInteractionInstructionData
+  >(
+    struct<ProtocolInteractionInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['solautoAction', getSolautoActionSerializer()],
+      ],
+      { description: 'ProtocolInteractionInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 12 })
+  ) as Serializer<
+    ProtocolInteractionInstructionDataArgs,
+    ProtocolInteractionInstructionData
+  >;
+}
+
+// Args.
+export type ProtocolInteractionInstructionArgs =
+  ProtocolInteractionInstructionDataArgs;
+
+// Instruction.
+export function protocolInteraction(
+  context: Pick<Context, 'programs'>,
+  input: ProtocolInteractionInstructionAccounts &
+    ProtocolInteractionInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'solauto',
+    'AutoyKBRaHSBHy9RsmXCZMy6nNFAg5FYijrvZyQcNLV'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    signer: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.signer ?? null,
+    },
+    lendingProgram: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.lendingProgram ?? null,
+    },
+    systemProgram: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    tokenProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    ataProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.ataProgram ?? null,
+    },
+    rent: { index: 5, isWritable: false as boolean, value: input.rent ?? null },
+    ixsSysvar: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.ixsSysvar ?? null,
+    },
+    solautoPosition: {
+      index: 7,
+      isWritable: true as boolean,
+      value: input.solautoPosition ?? null,
+    },
+    supplyMint: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.supplyMint ?? null,
+    },
+    positionSupplyTa: {
+      index: 9,
+      isWritable: true as boolean,
+      value: input.positionSupplyTa ?? null,
+    },
+    debtMint: {
+      index: 10,
+      isWritable: false as boolean,
+      value: input.debtMint ?? null,
+    },
+    positionDebtTa: {
+      index: 11,
+      isWritable: true as boolean,
+      value: input.positionDebtTa ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: ProtocolInteractionInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.ataProgram.value) {
+    resolvedAccounts.ataProgram.value = context.programs.getPublicKey(
+      'splAssociatedToken',
+      'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+    );
+    resolvedAccounts.ataProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.rent.value) {
+    resolvedAccounts.rent.value = publicKey(
+      'SysvarRent111111111111111111111111111111111'
+    );
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getProtocolInteractionInstructionDataSerializer().serialize(
+    resolvedArgs as ProtocolInteractionInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
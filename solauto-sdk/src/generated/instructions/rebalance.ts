@@ -0,0 +1,230 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  RebalanceSettings,
+  RebalanceSettingsArgs,
+  getRebalanceSettingsSerializer,
+} from '../types';
+
+// Accounts.
+export type RebalanceInstructionAccounts = {
+  signer: Signer;
+  lendingProgram: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+  tokenProgram?: PublicKey | Pda;
+  ixsSysvar: PublicKey | Pda;
+  solautoFeesTa?: PublicKey | Pda;
+  authorityReferralState: PublicKey | Pda;
+  referredByTa?: PublicKey | Pda;
+  positionAuthority?: PublicKey | Pda;
+  solautoPosition: PublicKey | Pda;
+  intermediaryTa?: PublicKey | Pda;
+  supplyMint: PublicKey | Pda;
+  positionSupplyTa: PublicKey | Pda;
+  authoritySupplyTa?: PublicKey | Pda;
+  debtMint: PublicKey | Pda;
+  positionDebtTa: PublicKey | Pda;
+  authorityDebtTa?: PublicKey | Pda;
+};
+
+// Data.
+export type RebalanceInstructionData = {
+  discriminator: number;
+  rebalanceSettings: RebalanceSettings;
+};
+
+export type RebalanceInstructionDataArgs = {
+  rebalanceSettings: RebalanceSettingsArgs;
+};
+
+export function getRebalanceInstructionDataSerializer(): Serializer<
+  RebalanceInstructionDataArgs,
+  RebalanceInstructionData
+> {
+  return mapSerializer<
+    RebalanceInstructionDataArgs,
+    any,
+    RebalanceInstructionData
+  >(
+    struct<RebalanceInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['rebalanceSettings', getRebalanceSettingsSerializer()],
+      ],
+      { description: 'RebalanceInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 13 })
+  ) as Serializer<RebalanceInstructionDataArgs, RebalanceInstructionData>;
+}
+
+// Args.
+export type RebalanceInstructionArgs = RebalanceInstructionDataArgs;
+
+// Instruction.
+export function rebalance(
+  context: Pick<Context, 'programs'>,
+  input: RebalanceInstructionAccounts & RebalanceInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'solauto',
+    'AutoyKBRaHSBHy9RsmXCZMy6nNFAg5FYijrvZyQcNLV'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    signer: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.signer ?? null,
+    },
+    lendingProgram: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.lendingProgram ?? null,
+    },
+    systemProgram: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    tokenProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    ixsSysvar: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.ixsSysvar ?? null,
+    },
+    solautoFeesTa: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.solautoFeesTa ?? null,
+    },
+    authorityReferralState: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.authorityReferralState ?? null,
+    },
+    referredByTa: {
+      index: 7,
+      isWritable: true as boolean,
+      value: input.referredByTa ?? null,
+    },
+    positionAuthority: {
+      index: 8,
+      isWritable: true as boolean,
+      value: input.positionAuthority ?? null,
+    },
+    solautoPosition: {
+      index: 9,
+      isWritable: true as boolean,
+      value: input.solautoPosition ?? null,
+    },
+    intermediaryTa: {
+      index: 10,
+      isWritable: true as boolean,
+      value: input.intermediaryTa ?? null,
+    },
+    supplyMint: {
+      index: 11,
+      isWritable: false as boolean,
+      value: input.supplyMint ?? null,
+    },
+    positionSupplyTa: {
+      index: 12,
+      isWritable: true as boolean,
+      value: input.positionSupplyTa ?? null,
+    },
+    authoritySupplyTa: {
+      index: 13,
+      isWritable: true as boolean,
+      value: input.authoritySupplyTa ?? null,
+    },
+    debtMint: {
+      index: 14,
+      isWritable: false as boolean,
+      value: input.debtMint ?? null,
+    },
+    positionDebtTa: {
+      index: 15,
+      isWritable: true as boolean,
+      value: input.positionDebtTa ?? null,
+    },
+    authorityDebtTa: {
+      index: 16,
+      isWritable: true as boolean,
+      value: input.authorityDebtTa ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: RebalanceInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getRebalanceInstructionDataSerializer().serialize(
+    resolvedArgs as RebalanceInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,124 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import { PriceType, PriceTypeArgs, getPriceTypeSerializer } from '../types';
+
+// Accounts.
+export type RefreshDataInstructionAccounts = {
+  signer: Signer;
+  lendingProgram: PublicKey | Pda;
+  solautoPosition: PublicKey | Pda;
+};
+
+// Data.
+export type RefreshDataInstructionData = {
+  discriminator: number;
+  priceType: PriceType;
+};
+
+export type RefreshDataInstructionDataArgs = { priceType: PriceTypeArgs };
+
+export function getRefreshDataInstructionDataSerializer(): Serializer<
+  RefreshDataInstructionDataArgs,
+  RefreshDataInstructionData
+> {
+  return mapSerializer<
+    RefreshDataInstructionDataArgs,
+    any,
+    RefreshDataInstructionData
+  >(
+    struct<RefreshDataInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['priceType', getPriceTypeSerializer()],
+      ],
+      { description: 'RefreshDataInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 11 })
+  ) as Serializer<RefreshDataInstructionDataArgs, RefreshDataInstructionData>;
+}
+
+// Args.
+export type RefreshDataInstructionArgs = RefreshDataInstructionDataArgs;
+
+// Instruction.
+export function refreshData(
+  context: Pick<Context, 'programs'>,
+  input: RefreshDataInstructionAccounts & RefreshDataInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'solauto',
+    'AutoyKBRaHSBHy9RsmXCZMy6nNFAg5FYijrvZyQcNLV'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    signer: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.signer ?? null,
+    },
+    lendingProgram: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.lendingProgram ?? null,
+    },
+    solautoPosition: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.solautoPosition ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: RefreshDataInstructionArgs = { ...input };
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getRefreshDataInstructionDataSerializer().serialize(
+    resolvedArgs as RefreshDataInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,120 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type UpgradePositionInstructionAccounts = {
+  signer: Signer;
+  systemProgram?: PublicKey | Pda;
+  solautoPosition: PublicKey | Pda;
+};
+
+// Data.
+export type UpgradePositionInstructionData = { discriminator: number };
+
+export type UpgradePositionInstructionDataArgs = {};
+
+export function getUpgradePositionInstructionDataSerializer(): Serializer<
+  UpgradePositionInstructionDataArgs,
+  UpgradePositionInstructionData
+> {
+  return mapSerializer<
+    UpgradePositionInstructionDataArgs,
+    any,
+    UpgradePositionInstructionData
+  >(
+    struct<UpgradePositionInstructionData>([['discriminator', u8()]], {
+      description: 'UpgradePositionInstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 20 })
+  ) as Serializer<
+    UpgradePositionInstructionDataArgs,
+    UpgradePositionInstructionData
+  >;
+}
+
+// Instruction.
+export function upgradePosition(
+  context: Pick<Context, 'programs'>,
+  input: UpgradePositionInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'solauto',
+    'AutoyKBRaHSBHy9RsmXCZMy6nNFAg5FYijrvZyQcNLV'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    signer: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.signer ?? null,
+    },
+    systemProgram: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    solautoPosition: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.solautoPosition ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getUpgradePositionInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,46 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  array,
+  bytes,
+  struct,
+  u16,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { PodBool, PodBoolArgs, getPodBoolSerializer } from '.';
+
+export type CarryGuard = {
+  deleverageToBps: number;
+  enabled: PodBool;
+  padding1: Array<number>;
+  padding: Uint8Array;
+};
+
+export type CarryGuardArgs = {
+  deleverageToBps: number;
+  enabled: PodBoolArgs;
+  padding1: Array<number>;
+  padding: Uint8Array;
+};
+
+export function getCarryGuardSerializer(): Serializer<
+  CarryGuardArgs,
+  CarryGuard
+> {
+  return struct<CarryGuard>(
+    [
+      ['deleverageToBps', u16()],
+      ['enabled', getPodBoolSerializer()],
+      ['padding1', array(u8(), { size: 5 })],
+      ['padding', bytes({ size: 32 })],
+    ],
+    { description: 'CarryGuard' }
+  ) as Serializer<CarryGuardArgs, CarryGuard>;
+}
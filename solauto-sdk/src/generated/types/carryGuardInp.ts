@@ -0,0 +1,31 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  bool,
+  struct,
+  u16,
+} from '@metaplex-foundation/umi/serializers';
+
+export type CarryGuardInp = { enabled: boolean; deleverageToBps: number };
+
+export type CarryGuardInpArgs = CarryGuardInp;
+
+export function getCarryGuardInpSerializer(): Serializer<
+  CarryGuardInpArgs,
+  CarryGuardInp
+> {
+  return struct<CarryGuardInp>(
+    [
+      ['enabled', bool()],
+      ['deleverageToBps', u16()],
+    ],
+    { description: 'CarryGuardInp' }
+  ) as Serializer<CarryGuardInpArgs, CarryGuardInp>;
+}
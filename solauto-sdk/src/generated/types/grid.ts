@@ -0,0 +1,51 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  bytes,
+  struct,
+  u16,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+export type Grid = {
+  lowerLiqUtilizationRateBps: number;
+  upperLiqUtilizationRateBps: number;
+  priceStepBps: number;
+  levelCount: number;
+  lastLevel: number;
+  anchorPriceRatio: bigint;
+  padding: Uint8Array;
+};
+
+export type GridArgs = {
+  lowerLiqUtilizationRateBps: number;
+  upperLiqUtilizationRateBps: number;
+  priceStepBps: number;
+  levelCount: number;
+  lastLevel: number;
+  anchorPriceRatio: number | bigint;
+  padding: Uint8Array;
+};
+
+export function getGridSerializer(): Serializer<GridArgs, Grid> {
+  return struct<Grid>(
+    [
+      ['lowerLiqUtilizationRateBps', u16()],
+      ['upperLiqUtilizationRateBps', u16()],
+      ['priceStepBps', u16()],
+      ['levelCount', u8()],
+      ['lastLevel', u8()],
+      ['anchorPriceRatio', u64()],
+      ['padding', bytes({ size: 32 })],
+    ],
+    { description: 'Grid' }
+  ) as Serializer<GridArgs, Grid>;
+}
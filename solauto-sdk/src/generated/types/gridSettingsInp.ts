@@ -0,0 +1,38 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  struct,
+  u16,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+export type GridSettingsInp = {
+  lowerLiqUtilizationRateBps: number;
+  upperLiqUtilizationRateBps: number;
+  levelCount: number;
+  priceStepBps: number;
+};
+
+export type GridSettingsInpArgs = GridSettingsInp;
+
+export function getGridSettingsInpSerializer(): Serializer<
+  GridSettingsInpArgs,
+  GridSettingsInp
+> {
+  return struct<GridSettingsInp>(
+    [
+      ['lowerLiqUtilizationRateBps', u16()],
+      ['upperLiqUtilizationRateBps', u16()],
+      ['levelCount', u8()],
+      ['priceStepBps', u16()],
+    ],
+    { description: 'GridSettingsInp' }
+  ) as Serializer<GridSettingsInpArgs, GridSettingsInp>;
+}
@@ -0,0 +1,42 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  array,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { PodBool, PodBoolArgs, getPodBoolSerializer } from '.';
+
+export type HarvestData = {
+  active: PodBool;
+  padding1: Array<number>;
+  supplyTaBalance: bigint;
+};
+
+export type HarvestDataArgs = {
+  active: PodBoolArgs;
+  padding1: Array<number>;
+  supplyTaBalance: number | bigint;
+};
+
+export function getHarvestDataSerializer(): Serializer<
+  HarvestDataArgs,
+  HarvestData
+> {
+  return struct<HarvestData>(
+    [
+      ['active', getPodBoolSerializer()],
+      ['padding1', array(u8(), { size: 7 })],
+      ['supplyTaBalance', u64()],
+    ],
+    { description: 'HarvestData' }
+  ) as Serializer<HarvestDataArgs, HarvestData>;
+}
@@ -8,11 +8,23 @@
 
 export * from './automationSettings';
 export * from './automationSettingsInp';
+export * from './carryGuard';
+export * from './carryGuardInp';
 export * from './dCASettings';
 export * from './dCASettingsInp';
+export * from './grid';
+export * from './gridSettingsInp';
+export * from './harvestData';
 export * from './lendingPlatform';
+export * from './leverageCurve';
+export * from './leverageCurveBand';
+export * from './leverageCurveBandInp';
+export * from './leverageCurveInp';
+export * from './limitOrder';
+export * from './marginfiOpenPositionData';
 export * from './podBool';
 export * from './positionData';
+export * from './positionLeg';
 export * from './positionState';
 export * from './positionTokenState';
 export * from './positionType';
@@ -20,16 +32,25 @@ export * from './priceType';
 export * from './rebalanceData';
 export * from './rebalanceDirection';
 export * from './rebalanceInstructionData';
+export * from './rebalanceSettings';
 export * from './rebalanceStateValues';
 export * from './rebalanceStep';
+export * from './rebalanceStrategyType';
 export * from './solautoAction';
 export * from './solautoRebalanceType';
 export * from './solautoSettingsParameters';
 export * from './solautoSettingsParametersInp';
+export * from './stopLossOrder';
+export * from './stopLossOrderInp';
+export * from './swapCpiData';
 export * from './swapType';
+export * from './takeProfitOrder';
+export * from './takeProfitOrderInp';
 export * from './tokenAmount';
 export * from './tokenBalanceAmount';
 export * from './tokenBalanceChange';
 export * from './tokenBalanceChangeType';
 export * from './tokenType';
+export * from './trailingStopOrder';
+export * from './trailingStopOrderInp';
 export * from './updatePositionData';
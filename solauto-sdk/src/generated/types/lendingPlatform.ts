@@ -10,6 +10,8 @@ import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
 
 export enum LendingPlatform {
   Marginfi,
+  Kamino,
+  Save,
 }
 
 export type LendingPlatformArgs = LendingPlatform;
@@ -0,0 +1,45 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  array,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  LeverageCurveBand,
+  LeverageCurveBandArgs,
+  getLeverageCurveBandSerializer,
+} from '.';
+
+export type LeverageCurve = {
+  bands: Array<LeverageCurveBand>;
+  bandCount: number;
+  padding: Array<number>;
+};
+
+export type LeverageCurveArgs = {
+  bands: Array<LeverageCurveBandArgs>;
+  bandCount: number;
+  padding: Array<number>;
+};
+
+export function getLeverageCurveSerializer(): Serializer<
+  LeverageCurveArgs,
+  LeverageCurve
+> {
+  return struct<LeverageCurve>(
+    [
+      ['bands', array(getLeverageCurveBandSerializer(), { size: 4 })],
+      ['bandCount', u8()],
+      ['padding', array(u8(), { size: 7 })],
+    ],
+    { description: 'LeverageCurve' }
+  ) as Serializer<LeverageCurveArgs, LeverageCurve>;
+}
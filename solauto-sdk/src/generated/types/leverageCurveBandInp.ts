@@ -0,0 +1,46 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  struct,
+  u16,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+export type LeverageCurveBandInp = {
+  minPriceRatio: bigint;
+  boostToBps: number;
+  boostGap: number;
+  repayToBps: number;
+  repayGap: number;
+};
+
+export type LeverageCurveBandInpArgs = {
+  minPriceRatio: number | bigint;
+  boostToBps: number;
+  boostGap: number;
+  repayToBps: number;
+  repayGap: number;
+};
+
+export function getLeverageCurveBandInpSerializer(): Serializer<
+  LeverageCurveBandInpArgs,
+  LeverageCurveBandInp
+> {
+  return struct<LeverageCurveBandInp>(
+    [
+      ['minPriceRatio', u64()],
+      ['boostToBps', u16()],
+      ['boostGap', u16()],
+      ['repayToBps', u16()],
+      ['repayGap', u16()],
+    ],
+    { description: 'LeverageCurveBandInp' }
+  ) as Serializer<LeverageCurveBandInpArgs, LeverageCurveBandInp>;
+}
@@ -0,0 +1,32 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  array,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  LeverageCurveBandInp,
+  LeverageCurveBandInpArgs,
+  getLeverageCurveBandInpSerializer,
+} from '.';
+
+export type LeverageCurveInp = { bands: Array<LeverageCurveBandInp> };
+
+export type LeverageCurveInpArgs = { bands: Array<LeverageCurveBandInpArgs> };
+
+export function getLeverageCurveInpSerializer(): Serializer<
+  LeverageCurveInpArgs,
+  LeverageCurveInp
+> {
+  return struct<LeverageCurveInp>(
+    [['bands', array(getLeverageCurveBandInpSerializer())]],
+    { description: 'LeverageCurveInp' }
+  ) as Serializer<LeverageCurveInpArgs, LeverageCurveInp>;
+}
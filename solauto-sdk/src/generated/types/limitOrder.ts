@@ -0,0 +1,59 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  array,
+  bytes,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  PodBool,
+  PodBoolArgs,
+  TokenType,
+  TokenTypeArgs,
+  getPodBoolSerializer,
+  getTokenTypeSerializer,
+} from '.';
+
+export type LimitOrder = {
+  amountBaseUnit: bigint;
+  triggerPriceUsd: bigint;
+  tokenType: TokenType;
+  triggerAbove: PodBool;
+  padding1: Array<number>;
+  padding: Uint8Array;
+};
+
+export type LimitOrderArgs = {
+  amountBaseUnit: number | bigint;
+  triggerPriceUsd: number | bigint;
+  tokenType: TokenTypeArgs;
+  triggerAbove: PodBoolArgs;
+  padding1: Array<number>;
+  padding: Uint8Array;
+};
+
+export function getLimitOrderSerializer(): Serializer<
+  LimitOrderArgs,
+  LimitOrder
+> {
+  return struct<LimitOrder>(
+    [
+      ['amountBaseUnit', u64()],
+      ['triggerPriceUsd', u64()],
+      ['tokenType', getTokenTypeSerializer()],
+      ['triggerAbove', getPodBoolSerializer()],
+      ['padding1', array(u8(), { size: 6 })],
+      ['padding', bytes({ size: 32 })],
+    ],
+    { description: 'LimitOrder' }
+  ) as Serializer<LimitOrderArgs, LimitOrder>;
+}
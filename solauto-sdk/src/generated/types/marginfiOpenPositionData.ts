@@ -0,0 +1,40 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, struct } from '@metaplex-foundation/umi/serializers';
+import {
+  PositionType,
+  PositionTypeArgs,
+  UpdatePositionData,
+  UpdatePositionDataArgs,
+  getPositionTypeSerializer,
+  getUpdatePositionDataSerializer,
+} from '.';
+
+export type MarginfiOpenPositionData = {
+  positionType: PositionType;
+  positionData: UpdatePositionData;
+};
+
+export type MarginfiOpenPositionDataArgs = {
+  positionType: PositionTypeArgs;
+  positionData: UpdatePositionDataArgs;
+};
+
+export function getMarginfiOpenPositionDataSerializer(): Serializer<
+  MarginfiOpenPositionDataArgs,
+  MarginfiOpenPositionData
+> {
+  return struct<MarginfiOpenPositionData>(
+    [
+      ['positionType', getPositionTypeSerializer()],
+      ['positionData', getUpdatePositionDataSerializer()],
+    ],
+    { description: 'MarginfiOpenPositionData' }
+  ) as Serializer<MarginfiOpenPositionDataArgs, MarginfiOpenPositionData>;
+}
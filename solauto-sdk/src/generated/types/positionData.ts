@@ -12,38 +12,48 @@ import {
   array,
   publicKey as publicKeySerializer,
   struct,
-  u32,
+  u16,
   u8,
 } from '@metaplex-foundation/umi/serializers';
 import {
+  DCASettings,
+  DCASettingsArgs,
   LendingPlatform,
   LendingPlatformArgs,
+  RebalanceStrategyType,
+  RebalanceStrategyTypeArgs,
   SolautoSettingsParameters,
   SolautoSettingsParametersArgs,
+  getDCASettingsSerializer,
   getLendingPlatformSerializer,
+  getRebalanceStrategyTypeSerializer,
   getSolautoSettingsParametersSerializer,
 } from '.';
 
 export type PositionData = {
   lendingPlatform: LendingPlatform;
+  rebalanceStrategy: RebalanceStrategyType;
+  maxPriceDivergenceBps: number;
   padding1: Array<number>;
   lpUserAccount: PublicKey;
   lpSupplyAccount: PublicKey;
   lpDebtAccount: PublicKey;
   settings: SolautoSettingsParameters;
   lpPoolAccount: PublicKey;
-  padding: Array<number>;
+  dca: DCASettings;
 };
 
 export type PositionDataArgs = {
   lendingPlatform: LendingPlatformArgs;
+  rebalanceStrategy: RebalanceStrategyTypeArgs;
+  maxPriceDivergenceBps: number;
   padding1: Array<number>;
   lpUserAccount: PublicKey;
   lpSupplyAccount: PublicKey;
   lpDebtAccount: PublicKey;
   settings: SolautoSettingsParametersArgs;
   lpPoolAccount: PublicKey;
-  padding: Array<number>;
+  dca: DCASettingsArgs;
 };
 
 export function getPositionDataSerializer(): Serializer<
@@ -53,13 +63,15 @@ export function getPositionDataSerializer(): Serializer<
   return struct<PositionData>(
     [
       ['lendingPlatform', getLendingPlatformSerializer()],
-      ['padding1', array(u8(), { size: 7 })],
+      ['rebalanceStrategy', getRebalanceStrategyTypeSerializer()],
+      ['maxPriceDivergenceBps', u16()],
+      ['padding1', array(u8(), { size: 4 })],
       ['lpUserAccount', publicKeySerializer()],
       ['lpSupplyAccount', publicKeySerializer()],
       ['lpDebtAccount', publicKeySerializer()],
       ['settings', getSolautoSettingsParametersSerializer()],
       ['lpPoolAccount', publicKeySerializer()],
-      ['padding', array(u32(), { size: 20 })],
+      ['dca', getDCASettingsSerializer()],
     ],
     { description: 'PositionData' }
   ) as Serializer<PositionDataArgs, PositionData>;
@@ -0,0 +1,39 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  PositionTokenState,
+  PositionTokenStateArgs,
+  getPositionTokenStateSerializer,
+} from '.';
+
+export type PositionLeg = { lpAccount: PublicKey; token: PositionTokenState };
+
+export type PositionLegArgs = {
+  lpAccount: PublicKey;
+  token: PositionTokenStateArgs;
+};
+
+export function getPositionLegSerializer(): Serializer<
+  PositionLegArgs,
+  PositionLeg
+> {
+  return struct<PositionLeg>(
+    [
+      ['lpAccount', publicKeySerializer()],
+      ['token', getPositionTokenStateSerializer()],
+    ],
+    { description: 'PositionLeg' }
+  ) as Serializer<PositionLegArgs, PositionLeg>;
+}
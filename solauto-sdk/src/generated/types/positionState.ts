@@ -11,21 +11,24 @@ import {
   array,
   struct,
   u16,
-  u32,
   u64,
   u8,
 } from '@metaplex-foundation/umi/serializers';
 import {
+  PositionLeg,
+  PositionLegArgs,
   PositionTokenState,
   PositionTokenStateArgs,
   TokenAmount,
   TokenAmountArgs,
+  getPositionLegSerializer,
   getPositionTokenStateSerializer,
   getTokenAmountSerializer,
 } from '.';
 
 export type PositionState = {
   liqUtilizationRateBps: number;
+  priceDivergenceBps: number;
   padding1: Array<number>;
   netWorth: TokenAmount;
   supply: PositionTokenState;
@@ -34,11 +37,15 @@ export type PositionState = {
   liqThresholdBps: number;
   padding2: Array<number>;
   lastRefreshed: bigint;
-  padding: Array<number>;
+  emissionsHarvestedUsd: bigint;
+  supplyPriceHighWaterMarkUsd: bigint;
+  additionalSupply: Array<PositionLeg>;
+  additionalDebt: Array<PositionLeg>;
 };
 
 export type PositionStateArgs = {
   liqUtilizationRateBps: number;
+  priceDivergenceBps: number;
   padding1: Array<number>;
   netWorth: TokenAmountArgs;
   supply: PositionTokenStateArgs;
@@ -47,7 +54,10 @@ export type PositionStateArgs = {
   liqThresholdBps: number;
   padding2: Array<number>;
   lastRefreshed: number | bigint;
-  padding: Array<number>;
+  emissionsHarvestedUsd: number | bigint;
+  supplyPriceHighWaterMarkUsd: number | bigint;
+  additionalSupply: Array<PositionLegArgs>;
+  additionalDebt: Array<PositionLegArgs>;
 };
 
 export function getPositionStateSerializer(): Serializer<
@@ -57,7 +67,8 @@ export function getPositionStateSerializer(): Serializer<
   return struct<PositionState>(
     [
       ['liqUtilizationRateBps', u16()],
-      ['padding1', array(u8(), { size: 6 })],
+      ['priceDivergenceBps', u16()],
+      ['padding1', array(u8(), { size: 4 })],
       ['netWorth', getTokenAmountSerializer()],
       ['supply', getPositionTokenStateSerializer()],
       ['debt', getPositionTokenStateSerializer()],
@@ -65,7 +76,10 @@ export function getPositionStateSerializer(): Serializer<
       ['liqThresholdBps', u16()],
       ['padding2', array(u8(), { size: 4 })],
       ['lastRefreshed', u64()],
-      ['padding', array(u32(), { size: 2 })],
+      ['emissionsHarvestedUsd', u64()],
+      ['supplyPriceHighWaterMarkUsd', u64()],
+      ['additionalSupply', array(getPositionLegSerializer(), { size: 3 })],
+      ['additionalDebt', array(getPositionLegSerializer(), { size: 3 })],
     ],
     { description: 'PositionState' }
   ) as Serializer<PositionStateArgs, PositionState>;
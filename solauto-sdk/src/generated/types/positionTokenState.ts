@@ -14,6 +14,7 @@ import {
   publicKey as publicKeySerializer,
   struct,
   u16,
+  u32,
   u64,
   u8,
 } from '@metaplex-foundation/umi/serializers';
@@ -27,7 +28,8 @@ export type PositionTokenState = {
   amountUsed: TokenAmount;
   amountCanBeUsed: TokenAmount;
   baseAmountMarketPriceUsd: bigint;
-  padding2: Array<number>;
+  supplyAprBps: number;
+  borrowAprBps: number;
   padding: Uint8Array;
 };
 
@@ -39,7 +41,8 @@ export type PositionTokenStateArgs = {
   amountUsed: TokenAmountArgs;
   amountCanBeUsed: TokenAmountArgs;
   baseAmountMarketPriceUsd: number | bigint;
-  padding2: Array<number>;
+  supplyAprBps: number;
+  borrowAprBps: number;
   padding: Uint8Array;
 };
 
@@ -56,7 +59,8 @@ export function getPositionTokenStateSerializer(): Serializer<
       ['amountUsed', getTokenAmountSerializer()],
       ['amountCanBeUsed', getTokenAmountSerializer()],
       ['baseAmountMarketPriceUsd', u64()],
-      ['padding2', array(u8(), { size: 8 })],
+      ['supplyAprBps', u32()],
+      ['borrowAprBps', u32()],
       ['padding', bytes({ size: 32 })],
     ],
     { description: 'PositionTokenState' }
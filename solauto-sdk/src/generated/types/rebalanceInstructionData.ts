@@ -10,7 +10,7 @@ import {
   Serializer,
   array,
   struct,
-  u32,
+  u16,
   u64,
   u8,
 } from '@metaplex-foundation/umi/serializers';
@@ -31,8 +31,11 @@ export type RebalanceInstructionData = {
   rebalanceType: SolautoRebalanceType;
   swapType: SwapType;
   padding1: Array<number>;
+  flashLoanFeeBps: number;
+  padding2: Array<number>;
   flashLoanAmount: bigint;
-  padding: Array<number>;
+  swapInAmountBaseUnit: bigint;
+  preSwapOutputBalance: bigint;
 };
 
 export type RebalanceInstructionDataArgs = {
@@ -40,8 +43,11 @@ export type RebalanceInstructionDataArgs = {
   rebalanceType: SolautoRebalanceTypeArgs;
   swapType: SwapTypeArgs;
   padding1: Array<number>;
+  flashLoanFeeBps: number;
+  padding2: Array<number>;
   flashLoanAmount: number | bigint;
-  padding: Array<number>;
+  swapInAmountBaseUnit: number | bigint;
+  preSwapOutputBalance: number | bigint;
 };
 
 export function getRebalanceInstructionDataSerializer(): Serializer<
@@ -53,9 +59,12 @@ export function getRebalanceInstructionDataSerializer(): Serializer<
       ['active', getPodBoolSerializer()],
       ['rebalanceType', getSolautoRebalanceTypeSerializer()],
       ['swapType', getSwapTypeSerializer()],
-      ['padding1', array(u8(), { size: 5 })],
+      ['padding1', array(u8(), { size: 1 })],
+      ['flashLoanFeeBps', u16()],
+      ['padding2', array(u8(), { size: 2 })],
       ['flashLoanAmount', u64()],
-      ['padding', array(u32(), { size: 4 })],
+      ['swapInAmountBaseUnit', u64()],
+      ['preSwapOutputBalance', u64()],
     ],
     { description: 'RebalanceInstructionData' }
   ) as Serializer<RebalanceInstructionDataArgs, RebalanceInstructionData>;
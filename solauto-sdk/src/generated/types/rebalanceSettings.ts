@@ -0,0 +1,65 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Option, OptionOrNullable } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  option,
+  struct,
+  u16,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  PriceType,
+  PriceTypeArgs,
+  SolautoRebalanceType,
+  SolautoRebalanceTypeArgs,
+  SwapCpiData,
+  SwapCpiDataArgs,
+  SwapType,
+  SwapTypeArgs,
+  getPriceTypeSerializer,
+  getSolautoRebalanceTypeSerializer,
+  getSwapCpiDataSerializer,
+  getSwapTypeSerializer,
+} from '.';
+
+export type RebalanceSettings = {
+  rebalanceType: SolautoRebalanceType;
+  swapInAmountBaseUnit: Option<bigint>;
+  targetLiqUtilizationRateBps: Option<number>;
+  priceType: Option<PriceType>;
+  swapType: Option<SwapType>;
+  swapCpi: Option<SwapCpiData>;
+};
+
+export type RebalanceSettingsArgs = {
+  rebalanceType: SolautoRebalanceTypeArgs;
+  swapInAmountBaseUnit: OptionOrNullable<number | bigint>;
+  targetLiqUtilizationRateBps: OptionOrNullable<number>;
+  priceType: OptionOrNullable<PriceTypeArgs>;
+  swapType: OptionOrNullable<SwapTypeArgs>;
+  swapCpi: OptionOrNullable<SwapCpiDataArgs>;
+};
+
+export function getRebalanceSettingsSerializer(): Serializer<
+  RebalanceSettingsArgs,
+  RebalanceSettings
+> {
+  return struct<RebalanceSettings>(
+    [
+      ['rebalanceType', getSolautoRebalanceTypeSerializer()],
+      ['swapInAmountBaseUnit', option(u64())],
+      ['targetLiqUtilizationRateBps', option(u16())],
+      ['priceType', option(getPriceTypeSerializer())],
+      ['swapType', option(getSwapTypeSerializer())],
+      ['swapCpi', option(getSwapCpiDataSerializer())],
+    ],
+    { description: 'RebalanceSettings' }
+  ) as Serializer<RebalanceSettingsArgs, RebalanceSettings>;
+}
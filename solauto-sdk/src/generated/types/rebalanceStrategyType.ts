@@ -0,0 +1,26 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+export enum RebalanceStrategyType {
+  Band,
+  LeverageCurve,
+  Grid,
+}
+
+export type RebalanceStrategyTypeArgs = RebalanceStrategyType;
+
+export function getRebalanceStrategyTypeSerializer(): Serializer<
+  RebalanceStrategyTypeArgs,
+  RebalanceStrategyType
+> {
+  return scalarEnum<RebalanceStrategyType>(RebalanceStrategyType, {
+    description: 'RebalanceStrategyType',
+  }) as Serializer<RebalanceStrategyTypeArgs, RebalanceStrategyType>;
+}
@@ -13,6 +13,7 @@ export enum SolautoRebalanceType {
   DoubleRebalanceWithFL,
   FLSwapThenRebalance,
   FLRebalanceThenSwap,
+  SwapCpi,
 }
 
 export type SolautoRebalanceTypeArgs = SolautoRebalanceType;
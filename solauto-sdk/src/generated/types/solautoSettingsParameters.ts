@@ -9,20 +9,42 @@
 import {
   Serializer,
   array,
+  bytes,
   struct,
   u16,
-  u32,
+  u8,
 } from '@metaplex-foundation/umi/serializers';
+import {
+  AutomationSettings,
+  AutomationSettingsArgs,
+  getAutomationSettingsSerializer,
+} from '.';
 
 export type SolautoSettingsParameters = {
   boostToBps: number;
   boostGap: number;
   repayToBps: number;
   repayGap: number;
-  padding: Array<number>;
+  targetBoostToBps: number;
+  targetRepayToBps: number;
+  maxSlippageBps: number;
+  padding1: Array<number>;
+  automation: AutomationSettings;
+  padding: Uint8Array;
 };
 
-export type SolautoSettingsParametersArgs = SolautoSettingsParameters;
+export type SolautoSettingsParametersArgs = {
+  boostToBps: number;
+  boostGap: number;
+  repayToBps: number;
+  repayGap: number;
+  targetBoostToBps: number;
+  targetRepayToBps: number;
+  maxSlippageBps: number;
+  padding1: Array<number>;
+  automation: AutomationSettingsArgs;
+  padding: Uint8Array;
+};
 
 export function getSolautoSettingsParametersSerializer(): Serializer<
   SolautoSettingsParametersArgs,
@@ -34,7 +56,12 @@ export function getSolautoSettingsParametersSerializer(): Serializer<
       ['boostGap', u16()],
       ['repayToBps', u16()],
       ['repayGap', u16()],
-      ['padding', array(u32(), { size: 24 })],
+      ['targetBoostToBps', u16()],
+      ['targetRepayToBps', u16()],
+      ['maxSlippageBps', u16()],
+      ['padding1', array(u8(), { size: 2 })],
+      ['automation', getAutomationSettingsSerializer()],
+      ['padding', bytes({ size: 32 })],
     ],
     { description: 'SolautoSettingsParameters' }
   ) as Serializer<SolautoSettingsParametersArgs, SolautoSettingsParameters>;
@@ -6,16 +6,40 @@
  * @see https://github.com/metaplex-foundation/kinobi
  */
 
-import { Serializer, struct, u16 } from '@metaplex-foundation/umi/serializers';
+import { Option, OptionOrNullable } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  option,
+  struct,
+  u16,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  AutomationSettingsInp,
+  AutomationSettingsInpArgs,
+  getAutomationSettingsInpSerializer,
+} from '.';
 
 export type SolautoSettingsParametersInp = {
   boostToBps: number;
   boostGap: number;
   repayToBps: number;
   repayGap: number;
+  targetBoostToBps: Option<number>;
+  targetRepayToBps: Option<number>;
+  automation: Option<AutomationSettingsInp>;
+  maxSlippageBps: Option<number>;
 };
 
-export type SolautoSettingsParametersInpArgs = SolautoSettingsParametersInp;
+export type SolautoSettingsParametersInpArgs = {
+  boostToBps: number;
+  boostGap: number;
+  repayToBps: number;
+  repayGap: number;
+  targetBoostToBps: OptionOrNullable<number>;
+  targetRepayToBps: OptionOrNullable<number>;
+  automation: OptionOrNullable<AutomationSettingsInpArgs>;
+  maxSlippageBps: OptionOrNullable<number>;
+};
 
 export function getSolautoSettingsParametersInpSerializer(): Serializer<
   SolautoSettingsParametersInpArgs,
@@ -27,6 +51,10 @@ export function getSolautoSettingsParametersInpSerializer(): Serializer<
       ['boostGap', u16()],
       ['repayToBps', u16()],
       ['repayGap', u16()],
+      ['targetBoostToBps', option(u16())],
+      ['targetRepayToBps', option(u16())],
+      ['automation', option(getAutomationSettingsInpSerializer())],
+      ['maxSlippageBps', option(u16())],
     ],
     { description: 'SolautoSettingsParametersInp' }
   ) as Serializer<
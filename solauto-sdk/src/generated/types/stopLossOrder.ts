@@ -0,0 +1,40 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  bytes,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+export type StopLossOrder = {
+  triggerPriceUsd: bigint;
+  minNetWorthUsd: bigint;
+  padding: Uint8Array;
+};
+
+export type StopLossOrderArgs = {
+  triggerPriceUsd: number | bigint;
+  minNetWorthUsd: number | bigint;
+  padding: Uint8Array;
+};
+
+export function getStopLossOrderSerializer(): Serializer<
+  StopLossOrderArgs,
+  StopLossOrder
+> {
+  return struct<StopLossOrder>(
+    [
+      ['triggerPriceUsd', u64()],
+      ['minNetWorthUsd', u64()],
+      ['padding', bytes({ size: 32 })],
+    ],
+    { description: 'StopLossOrder' }
+  ) as Serializer<StopLossOrderArgs, StopLossOrder>;
+}
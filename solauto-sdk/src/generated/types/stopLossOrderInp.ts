@@ -0,0 +1,32 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, struct, u64 } from '@metaplex-foundation/umi/serializers';
+
+export type StopLossOrderInp = {
+  triggerPriceUsd: bigint;
+  minNetWorthUsd: bigint;
+};
+
+export type StopLossOrderInpArgs = {
+  triggerPriceUsd: number | bigint;
+  minNetWorthUsd: number | bigint;
+};
+
+export function getStopLossOrderInpSerializer(): Serializer<
+  StopLossOrderInpArgs,
+  StopLossOrderInp
+> {
+  return struct<StopLossOrderInp>(
+    [
+      ['triggerPriceUsd', u64()],
+      ['minNetWorthUsd', u64()],
+    ],
+    { description: 'StopLossOrderInp' }
+  ) as Serializer<StopLossOrderInpArgs, StopLossOrderInp>;
+}
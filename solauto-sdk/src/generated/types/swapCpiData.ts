@@ -0,0 +1,32 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  bytes,
+  struct,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+export type SwapCpiData = { accountsLen: number; data: Uint8Array };
+
+export type SwapCpiDataArgs = SwapCpiData;
+
+export function getSwapCpiDataSerializer(): Serializer<
+  SwapCpiDataArgs,
+  SwapCpiData
+> {
+  return struct<SwapCpiData>(
+    [
+      ['accountsLen', u8()],
+      ['data', bytes({ size: u32() })],
+    ],
+    { description: 'SwapCpiData' }
+  ) as Serializer<SwapCpiDataArgs, SwapCpiData>;
+}
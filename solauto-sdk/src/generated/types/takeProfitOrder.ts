@@ -0,0 +1,49 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  array,
+  bytes,
+  struct,
+  u16,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+export type TakeProfitOrder = {
+  triggerPriceUsd: bigint;
+  targetLiqUtilizationRateBps: number;
+  withdrawNetWorthBps: number;
+  padding1: Array<number>;
+  padding: Uint8Array;
+};
+
+export type TakeProfitOrderArgs = {
+  triggerPriceUsd: number | bigint;
+  targetLiqUtilizationRateBps: number;
+  withdrawNetWorthBps: number;
+  padding1: Array<number>;
+  padding: Uint8Array;
+};
+
+export function getTakeProfitOrderSerializer(): Serializer<
+  TakeProfitOrderArgs,
+  TakeProfitOrder
+> {
+  return struct<TakeProfitOrder>(
+    [
+      ['triggerPriceUsd', u64()],
+      ['targetLiqUtilizationRateBps', u16()],
+      ['withdrawNetWorthBps', u16()],
+      ['padding1', array(u8(), { size: 4 })],
+      ['padding', bytes({ size: 32 })],
+    ],
+    { description: 'TakeProfitOrder' }
+  ) as Serializer<TakeProfitOrderArgs, TakeProfitOrder>;
+}
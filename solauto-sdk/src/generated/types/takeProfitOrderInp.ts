@@ -0,0 +1,40 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  struct,
+  u16,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+export type TakeProfitOrderInp = {
+  triggerPriceUsd: bigint;
+  targetLiqUtilizationRateBps: number;
+  withdrawNetWorthBps: number;
+};
+
+export type TakeProfitOrderInpArgs = {
+  triggerPriceUsd: number | bigint;
+  targetLiqUtilizationRateBps: number;
+  withdrawNetWorthBps: number;
+};
+
+export function getTakeProfitOrderInpSerializer(): Serializer<
+  TakeProfitOrderInpArgs,
+  TakeProfitOrderInp
+> {
+  return struct<TakeProfitOrderInp>(
+    [
+      ['triggerPriceUsd', u64()],
+      ['targetLiqUtilizationRateBps', u16()],
+      ['withdrawNetWorthBps', u16()],
+    ],
+    { description: 'TakeProfitOrderInp' }
+  ) as Serializer<TakeProfitOrderInpArgs, TakeProfitOrderInp>;
+}
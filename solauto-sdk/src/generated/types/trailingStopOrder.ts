@@ -0,0 +1,40 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  array,
+  bytes,
+  struct,
+  u16,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+export type TrailingStopOrder = {
+  trailingDistanceBps: number;
+  targetLiqUtilizationRateBps: number;
+  padding1: Array<number>;
+  padding: Uint8Array;
+};
+
+export type TrailingStopOrderArgs = TrailingStopOrder;
+
+export function getTrailingStopOrderSerializer(): Serializer<
+  TrailingStopOrderArgs,
+  TrailingStopOrder
+> {
+  return struct<TrailingStopOrder>(
+    [
+      ['trailingDistanceBps', u16()],
+      ['targetLiqUtilizationRateBps', u16()],
+      ['padding1', array(u8(), { size: 4 })],
+      ['padding', bytes({ size: 32 })],
+    ],
+    { description: 'TrailingStopOrder' }
+  ) as Serializer<TrailingStopOrderArgs, TrailingStopOrder>;
+}
@@ -0,0 +1,29 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, struct, u16 } from '@metaplex-foundation/umi/serializers';
+
+export type TrailingStopOrderInp = {
+  trailingDistanceBps: number;
+  targetLiqUtilizationRateBps: number;
+};
+
+export type TrailingStopOrderInpArgs = TrailingStopOrderInp;
+
+export function getTrailingStopOrderInpSerializer(): Serializer<
+  TrailingStopOrderInpArgs,
+  TrailingStopOrderInp
+> {
+  return struct<TrailingStopOrderInp>(
+    [
+      ['trailingDistanceBps', u16()],
+      ['targetLiqUtilizationRateBps', u16()],
+    ],
+    { description: 'TrailingStopOrderInp' }
+  ) as Serializer<TrailingStopOrderInpArgs, TrailingStopOrderInp>;
+}
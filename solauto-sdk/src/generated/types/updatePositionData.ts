@@ -11,27 +11,65 @@ import {
   Serializer,
   option,
   struct,
+  u16,
   u8,
 } from '@metaplex-foundation/umi/serializers';
 import {
+  CarryGuardInp,
+  CarryGuardInpArgs,
   DCASettingsInp,
   DCASettingsInpArgs,
+  GridSettingsInp,
+  GridSettingsInpArgs,
+  LeverageCurveInp,
+  LeverageCurveInpArgs,
+  RebalanceStrategyType,
+  RebalanceStrategyTypeArgs,
   SolautoSettingsParametersInp,
   SolautoSettingsParametersInpArgs,
+  StopLossOrderInp,
+  StopLossOrderInpArgs,
+  TakeProfitOrderInp,
+  TakeProfitOrderInpArgs,
+  TrailingStopOrderInp,
+  TrailingStopOrderInpArgs,
+  getCarryGuardInpSerializer,
   getDCASettingsInpSerializer,
+  getGridSettingsInpSerializer,
+  getLeverageCurveInpSerializer,
+  getRebalanceStrategyTypeSerializer,
   getSolautoSettingsParametersInpSerializer,
+  getStopLossOrderInpSerializer,
+  getTakeProfitOrderInpSerializer,
+  getTrailingStopOrderInpSerializer,
 } from '.';
 
 export type UpdatePositionData = {
   positionId: number;
   settings: Option<SolautoSettingsParametersInp>;
+  rebalanceStrategy: Option<RebalanceStrategyType>;
+  maxPriceDivergenceBps: Option<number>;
+  leverageCurve: Option<LeverageCurveInp>;
+  grid: Option<GridSettingsInp>;
+  carryGuard: Option<CarryGuardInp>;
   dca: Option<DCASettingsInp>;
+  takeProfit: Option<TakeProfitOrderInp>;
+  stopLoss: Option<StopLossOrderInp>;
+  trailingStop: Option<TrailingStopOrderInp>;
 };
 
 export type UpdatePositionDataArgs = {
   positionId: number;
   settings: OptionOrNullable<SolautoSettingsParametersInpArgs>;
+  rebalanceStrategy: OptionOrNullable<RebalanceStrategyTypeArgs>;
+  maxPriceDivergenceBps: OptionOrNullable<number>;
+  leverageCurve: OptionOrNullable<LeverageCurveInpArgs>;
+  grid: OptionOrNullable<GridSettingsInpArgs>;
+  carryGuard: OptionOrNullable<CarryGuardInpArgs>;
   dca: OptionOrNullable<DCASettingsInpArgs>;
+  takeProfit: OptionOrNullable<TakeProfitOrderInpArgs>;
+  stopLoss: OptionOrNullable<StopLossOrderInpArgs>;
+  trailingStop: OptionOrNullable<TrailingStopOrderInpArgs>;
 };
 
 export function getUpdatePositionDataSerializer(): Serializer<
@@ -42,7 +80,15 @@ export function getUpdatePositionDataSerializer(): Serializer<
     [
       ['positionId', u8()],
       ['settings', option(getSolautoSettingsParametersInpSerializer())],
+      ['rebalanceStrategy', option(getRebalanceStrategyTypeSerializer())],
+      ['maxPriceDivergenceBps', option(u16())],
+      ['leverageCurve', option(getLeverageCurveInpSerializer())],
+      ['grid', option(getGridSettingsInpSerializer())],
+      ['carryGuard', option(getCarryGuardInpSerializer())],
       ['dca', option(getDCASettingsInpSerializer())],
+      ['takeProfit', option(getTakeProfitOrderInpSerializer())],
+      ['stopLoss', option(getStopLossOrderInpSerializer())],
+      ['trailingStop', option(getTrailingStopOrderInpSerializer())],
     ],
     { description: 'UpdatePositionData' }
   ) as Serializer<UpdatePositionDataArgs, UpdatePositionData>;
@@ -49,7 +49,8 @@ export class RebalanceTxBuilder {
     private client: SolautoClient,
     private targetLiqUtilizationRateBps?: number,
     private optimizeSize?: boolean,
-    private bpsDistanceFromRebalance?: number
+    private bpsDistanceFromRebalance?: number,
+    private swapCpi?: boolean
   ) {}
 
   private shouldProceedWithRebalance() {
@@ -249,7 +250,9 @@ export class RebalanceTxBuilder {
             : SolautoRebalanceType.FLSwapThenRebalance;
       }
     } else {
-      this.rebalanceType = SolautoRebalanceType.Regular;
+      this.rebalanceType = this.swapCpi
+        ? SolautoRebalanceType.SwapCpi
+        : SolautoRebalanceType.Regular;
     }
   }
 
@@ -367,6 +370,17 @@ export class RebalanceTxBuilder {
       consoleLog("Weighted values", supply, debt);
     }
 
+    if (this.rebalanceType === SolautoRebalanceType.SwapCpi) {
+      tx = tx.add([
+        setupIx,
+        this.client.swapCpiRebalanceIx(rebalanceDetails, swapIx),
+      ]);
+      return {
+        tx,
+        lookupTableAddresses,
+      };
+    }
+
     const firstRebalance = this.client.rebalanceIx(
       RebalanceStep.PreSwap,
       rebalanceDetails
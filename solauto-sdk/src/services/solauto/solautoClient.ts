@@ -540,4 +540,9 @@ export abstract class SolautoClient extends ReferralStateManager {
     rebalanceStep: RebalanceStep,
     data: RebalanceDetails
   ): TransactionBuilder;
+
+  abstract swapCpiRebalanceIx(
+    data: RebalanceDetails,
+    swapIx: TransactionBuilder
+  ): TransactionBuilder;
 }
@@ -29,6 +29,7 @@ import {
   marginfiProtocolInteraction,
   marginfiRebalance,
   marginfiRefreshData,
+  rebalance,
 } from "../../generated";
 import {
   getAllMarginfiAccountsByAuthority,
@@ -211,11 +212,21 @@ export class SolautoMarginfiClient extends SolautoClient {
       debtBank: publicKey(this.marginfiDebtAccounts.bank),
       positionDebtTa: publicKey(this.positionDebtTa),
       signerDebtTa: signerDebtTa,
-      positionType: positionType ?? PositionType.Leverage,
-      positionData: {
-        positionId: this.pos.positionId,
-        settings: settings ?? null,
-        dca: dca ?? null,
+      marginfiOpenPositionData: {
+        positionType: positionType ?? PositionType.Leverage,
+        positionData: {
+          positionId: this.pos.positionId,
+          settings: settings ?? null,
+          rebalanceStrategy: null,
+          maxPriceDivergenceBps: null,
+          leverageCurve: null,
+          grid: null,
+          carryGuard: null,
+          dca: dca ?? null,
+          takeProfit: null,
+          stopLoss: null,
+          trailingStop: null,
+        },
       },
     });
   }
@@ -475,20 +486,104 @@ export class SolautoMarginfiClient extends SolautoClient {
         : undefined,
       vaultDebtTa: publicKey(this.marginfiDebtAccounts.liquidityVault),
       debtVaultAuthority: publicKey(this.marginfiDebtAccounts.vaultAuthority),
-      rebalanceType: data.rebalanceType,
-      targetLiqUtilizationRateBps: data.targetLiqUtilizationRateBps ?? null,
-      swapInAmountBaseUnit: isFirstRebalance
-        ? parseInt(data.swapQuote.inAmount)
-        : null,
-      swapType:
-        data.swapQuote.swapMode === "ExactOut" && isFirstRebalance
-          ? SwapType.ExactOut
-          : null,
-      priceType: isFirstRebalance ? data.priceType : null,
-      flashLoanFeeBps:
-        data.flashLoan?.flFeeBps && isFirstRebalance
-          ? data.flashLoan.flFeeBps
+      rebalanceSettings: {
+        rebalanceType: data.rebalanceType,
+        targetLiqUtilizationRateBps: data.targetLiqUtilizationRateBps ?? null,
+        swapInAmountBaseUnit: isFirstRebalance
+          ? parseInt(data.swapQuote.inAmount)
           : null,
+        swapType:
+          data.swapQuote.swapMode === "ExactOut" && isFirstRebalance
+            ? SwapType.ExactOut
+            : null,
+        priceType: isFirstRebalance ? data.priceType : null,
+        swapCpi: null,
+      },
     });
   }
+
+  swapCpiRebalanceIx(
+    data: RebalanceDetails,
+    swapIx: TransactionBuilder
+  ): TransactionBuilder {
+    const swap = swapIx.getInstructions()[0];
+    const addAuthorityTas =
+      this.pos.selfManaged || data.values.tokenBalanceChange !== undefined;
+
+    return rebalance(this.umi, {
+      signer: this.signer,
+      lendingProgram: publicKey(this.mfiAccounts.program),
+      ixsSysvar: publicKey(SYSVAR_INSTRUCTIONS_PUBKEY),
+      solautoFeesTa: publicKey(
+        data.values.rebalanceDirection === RebalanceDirection.Boost
+          ? this.solautoFeesSupplyTa
+          : this.solautoFeesDebtTa
+      ),
+      authorityReferralState: publicKey(this.referralState),
+      referredByTa: this.referredByState
+        ? publicKey(
+            data.values.rebalanceDirection === RebalanceDirection.Boost
+              ? this.referredBySupplyTa()!
+              : this.referredByDebtTa()!
+          )
+        : undefined,
+      positionAuthority:
+        data.values.tokenBalanceChange !== undefined
+          ? publicKey(this.authority)
+          : undefined,
+      solautoPosition: publicKey(this.pos.publicKey),
+      intermediaryTa: publicKey(
+        getTokenAccount(
+          toWeb3JsPublicKey(this.signer.publicKey),
+          new PublicKey(data.swapQuote.inputMint)
+        )
+      ),
+      supplyMint: publicKey(this.pos.supplyMint),
+      positionSupplyTa: publicKey(this.positionSupplyTa),
+      authoritySupplyTa: addAuthorityTas
+        ? publicKey(getTokenAccount(this.authority, this.pos.supplyMint))
+        : undefined,
+      debtMint: publicKey(this.pos.debtMint),
+      positionDebtTa: publicKey(this.positionDebtTa),
+      authorityDebtTa: addAuthorityTas
+        ? publicKey(getTokenAccount(this.authority, this.pos.debtMint))
+        : undefined,
+      rebalanceSettings: {
+        rebalanceType: SolautoRebalanceType.SwapCpi,
+        targetLiqUtilizationRateBps: data.targetLiqUtilizationRateBps ?? null,
+        swapInAmountBaseUnit: parseInt(data.swapQuote.inAmount),
+        swapType: SwapType.ExactIn,
+        priceType: data.priceType,
+        swapCpi: {
+          accountsLen: swap.keys.length + 1,
+          data: swap.data,
+        },
+      },
+    }).addRemainingAccounts([
+      getAccountMeta(this.marginfiGroup),
+      getAccountMeta(this.marginfiAccountPk, false, true),
+      getAccountMeta(
+        new PublicKey(this.marginfiSupplyAccounts.bank),
+        false,
+        true
+      ),
+      getAccountMeta(this.supplyPriceOracle),
+      getAccountMeta(
+        new PublicKey(this.marginfiSupplyAccounts.liquidityVault),
+        false,
+        true
+      ),
+      getAccountMeta(new PublicKey(this.marginfiSupplyAccounts.vaultAuthority)),
+      getAccountMeta(new PublicKey(this.marginfiDebtAccounts.bank), false, true),
+      getAccountMeta(this.debtPriceOracle),
+      getAccountMeta(
+        new PublicKey(this.marginfiDebtAccounts.liquidityVault),
+        false,
+        true
+      ),
+      getAccountMeta(new PublicKey(this.marginfiDebtAccounts.vaultAuthority)),
+      { pubkey: swap.programId, isSigner: false, isWritable: false },
+      ...swap.keys,
+    ]);
+  }
 }
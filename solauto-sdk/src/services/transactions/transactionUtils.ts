@@ -378,12 +378,14 @@ function getRebalanceInstructions(
       try {
         const serializer = getMarginfiRebalanceInstructionDataSerializer();
         const discriminator = serializer.serialize({
-          swapInAmountBaseUnit: 0,
-          rebalanceType: SolautoRebalanceType.Regular,
-          swapType: SwapType.ExactIn,
-          targetLiqUtilizationRateBps: 0,
-          priceType: PriceType.Realtime,
-          flashLoanFeeBps: null,
+          rebalanceSettings: {
+            swapInAmountBaseUnit: 0,
+            rebalanceType: SolautoRebalanceType.Regular,
+            swapType: SwapType.ExactIn,
+            targetLiqUtilizationRateBps: 0,
+            priceType: PriceType.Realtime,
+            swapCpi: null,
+          },
         })[0];
         const [data, _] = serializer.deserialize(x.data);
         if (data.discriminator === discriminator) {
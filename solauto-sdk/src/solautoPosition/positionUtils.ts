@@ -1,5 +1,10 @@
 import { PublicKey } from "@solana/web3.js";
-import { Umi } from "@metaplex-foundation/umi";
+import {
+  isOption,
+  OptionOrNullable,
+  Umi,
+  unwrapOption,
+} from "@metaplex-foundation/umi";
 import {
   fromWeb3JsPublicKey,
   toWeb3JsPublicKey,
@@ -30,15 +35,34 @@ import {
 } from "./solautoPositionEx";
 import { MarginfiSolautoPositionEx } from "./marginfiSolautoPositionEx";
 
+function optionValue<T>(value: OptionOrNullable<T>): T | null {
+  return isOption(value) ? unwrapOption(value) : value;
+}
+
 export function createSolautoSettings(
   settings: SolautoSettingsParametersInpArgs
 ): SolautoSettingsParameters {
+  const automation = optionValue(settings.automation);
   return {
     boostGap: settings.boostGap,
     boostToBps: settings.boostToBps,
     repayGap: settings.repayGap,
     repayToBps: settings.repayToBps,
-    padding: [],
+    targetBoostToBps:
+      optionValue(settings.targetBoostToBps) ?? settings.boostToBps,
+    targetRepayToBps:
+      optionValue(settings.targetRepayToBps) ?? settings.repayToBps,
+    maxSlippageBps: optionValue(settings.maxSlippageBps) ?? 0,
+    padding1: [],
+    automation: {
+      targetPeriods: automation?.targetPeriods ?? 0,
+      periodsPassed: automation?.periodsPassed ?? 0,
+      unixStartDate: BigInt(automation?.unixStartDate ?? 0),
+      intervalSeconds: BigInt(automation?.intervalSeconds ?? 0),
+      padding1: [],
+      padding: new Uint8Array([]),
+    },
+    padding: new Uint8Array([]),
   };
 }
 
@@ -168,7 +192,8 @@ export function createFakePositionState(
       decimals: supplyDecimals,
       mint: fromWeb3JsPublicKey(supply.mint),
       padding1: [],
-      padding2: [],
+      supplyAprBps: 0,
+      borrowAprBps: 0,
       padding: new Uint8Array([]),
     },
     debt: {
@@ -187,7 +212,8 @@ export function createFakePositionState(
       decimals: debtDecimals,
       mint: fromWeb3JsPublicKey(debt.mint),
       padding1: [],
-      padding2: [],
+      supplyAprBps: 0,
+      borrowAprBps: 0,
       padding: new Uint8Array([]),
     },
     netWorth: {
@@ -199,8 +225,12 @@ export function createFakePositionState(
     maxLtvBps,
     liqThresholdBps,
     lastRefreshed: BigInt(currentUnixSeconds()),
+    priceDivergenceBps: 0,
     padding1: [],
     padding2: [],
-    padding: [],
+    emissionsHarvestedUsd: BigInt(0),
+    supplyPriceHighWaterMarkUsd: BigInt(0),
+    additionalSupply: [],
+    additionalDebt: [],
   };
 }
@@ -48,7 +48,15 @@ export function updateSolautoPosition(
       tx: client.updatePositionIx({
         positionId: client.pos.positionId,
         settings,
+        rebalanceStrategy: null,
+        maxPriceDivergenceBps: null,
+        leverageCurve: null,
+        grid: null,
+        carryGuard: null,
         dca,
+        takeProfit: null,
+        stopLoss: null,
+        trailingStop: null,
       }),
     }),
     "update position"
@@ -125,7 +133,8 @@ export function repay(client: SolautoClient, amount: "All" | bigint) {
 export function rebalance(
   client: SolautoClient,
   targetLiqUtilizationRateBps?: number,
-  bpsDistanceFromRebalance?: number
+  bpsDistanceFromRebalance?: number,
+  swapCpi?: boolean
 ) {
   return new TransactionItem(
     async (attemptNum, prevError) =>
@@ -133,7 +142,8 @@ export function rebalance(
         client,
         targetLiqUtilizationRateBps,
         attemptNum > 2 && prevError instanceof TransactionTooLargeError,
-        bpsDistanceFromRebalance
+        bpsDistanceFromRebalance,
+        swapCpi
       ).buildRebalanceTx(attemptNum),
     "rebalance",
     true
@@ -289,7 +289,8 @@ async function getTokenUsage(
     baseAmountMarketPriceUsd: toBaseUnit(marketPrice, USD_DECIMALS),
     borrowFeeBps: isAsset ? 0 : toBps(originationFee),
     padding1: [],
-    padding2: [],
+    supplyAprBps: 0,
+    borrowAprBps: 0,
     padding: new Uint8Array([]),
   };
 }
@@ -513,9 +514,13 @@ export async function getMarginfiAccountPositionState(
       maxLtvBps,
       liqThresholdBps,
       lastRefreshed: BigInt(currentUnixSeconds()),
+      priceDivergenceBps: 0,
       padding1: [],
       padding2: [],
-      padding: [],
+      emissionsHarvestedUsd: BigInt(0),
+      supplyPriceHighWaterMarkUsd: BigInt(0),
+      additionalSupply: [],
+      additionalDebt: [],
     },
   };
 }
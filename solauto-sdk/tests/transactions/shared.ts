@@ -58,6 +58,10 @@ export async function e2eTransactionTest(
     boostGap: 50,
     repayToBps: maxRepayToBps(maxLtvBps, liqThresholdBps),
     repayGap: 50,
+    targetBoostToBps: null,
+    targetRepayToBps: null,
+    automation: null,
+    maxSlippageBps: null,
   };
 
   const supplyUsd = 100;
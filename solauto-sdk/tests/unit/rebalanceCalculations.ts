@@ -15,6 +15,9 @@ import {
   LendingPlatform,
   PriceType,
   SolautoSettingsParameters,
+  createSolautoSettings,
+  RebalanceStrategyType,
+  TokenType,
   getRebalanceValues,
   SolautoClient,
   SolautoFeesBps,
@@ -104,12 +107,19 @@ async function getFakePosition(
       authority: defaultPk,
       position: {
         lendingPlatform: LendingPlatform.Marginfi,
+        rebalanceStrategy: RebalanceStrategyType.Band,
+        maxPriceDivergenceBps: 0,
         lpUserAccount: defaultPk,
         lpSupplyAccount: defaultPk,
         lpDebtAccount: defaultPk,
         lpPoolAccount: defaultPk,
         settings,
-        padding: [],
+        dca: {
+          automation: settings.automation,
+          dcaInBaseUnit: BigInt(0),
+          tokenType: TokenType.Debt,
+          padding: [],
+        },
         padding1: [],
       },
     },
@@ -149,26 +159,37 @@ describe("Rebalance tests", async () => {
   });
 
   it("Standard rebalance with target rate", async () => {
-    const client = await getFakePosition(supplyPrice, debtPrice, 3450, {
-      boostToBps: 500,
-      boostGap: 100,
-      repayToBps: 7000,
-      repayGap: 250,
-      padding: [],
-    });
+    const client = await getFakePosition(
+      supplyPrice,
+      debtPrice,
+      3450,
+      createSolautoSettings({
+        boostToBps: 500,
+        boostGap: 100,
+        repayToBps: 7000,
+        repayGap: 250,
+        targetBoostToBps: null,
+        targetRepayToBps: null,
+        automation: null,
+        maxSlippageBps: null,
+      })
+    );
 
     assertAccurateRebalance(client, 5000, 5000);
     assertAccurateRebalance(client, 1000, 1000);
   });
 
   it("Standard boost or repay", async () => {
-    const settings: SolautoSettingsParameters = {
+    const settings: SolautoSettingsParameters = createSolautoSettings({
       boostGap: 1000,
       boostToBps: 4000,
       repayGap: 1000,
       repayToBps: 7500,
-      padding: [],
-    };
+      targetBoostToBps: null,
+      targetRepayToBps: null,
+      automation: null,
+      maxSlippageBps: null,
+    });
 
     await rebalanceFromFakePosition(supplyPrice, debtPrice, 1000, settings);
     await rebalanceFromFakePosition(supplyPrice, debtPrice, 9000, settings);